@@ -0,0 +1,141 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Computes `min((src + threshold) >> r_shift, max) << l_shift`, returns processed count
+pub(crate) fn avx2_depth_row_16_to_16(
+    src: &[u16],
+    dst: &mut [u16],
+    thresholds: &[u16],
+    r_shift: u32,
+    l_shift: u32,
+    max: u16,
+    width: usize,
+) -> usize {
+    unsafe { avx2_depth_row_16_to_16_impl(src, dst, thresholds, r_shift, l_shift, max, width) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_depth_row_16_to_16_impl(
+    src: &[u16],
+    dst: &mut [u16],
+    thresholds: &[u16],
+    r_shift: u32,
+    l_shift: u32,
+    max: u16,
+    width: usize,
+) -> usize {
+    let v_r_shift = _mm_cvtsi32_si128(r_shift as i32);
+    let v_l_shift = _mm_cvtsi32_si128(l_shift as i32);
+    let v_max = _mm256_set1_epi16(max as i16);
+
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let v_src = _mm256_loadu_si256(src.get_unchecked(cx..).as_ptr() as *const __m256i);
+        let v_t = _mm256_loadu_si256(thresholds.get_unchecked(cx..).as_ptr() as *const __m256i);
+        let v = _mm256_srl_epi16(_mm256_adds_epu16(v_src, v_t), v_r_shift);
+        let v = _mm256_sll_epi16(_mm256_min_epu16(v, v_max), v_l_shift);
+        _mm256_storeu_si256(dst.get_unchecked_mut(cx..).as_mut_ptr() as *mut __m256i, v);
+        cx += 16;
+    }
+    cx
+}
+
+/// Computes `min((src + threshold) >> r_shift, 255)`, returns processed count
+pub(crate) fn avx2_depth_row_16_to_8(
+    src: &[u16],
+    dst: &mut [u8],
+    thresholds: &[u16],
+    r_shift: u32,
+    width: usize,
+) -> usize {
+    unsafe { avx2_depth_row_16_to_8_impl(src, dst, thresholds, r_shift, width) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_depth_row_16_to_8_impl(
+    src: &[u16],
+    dst: &mut [u8],
+    thresholds: &[u16],
+    r_shift: u32,
+    width: usize,
+) -> usize {
+    let v_r_shift = _mm_cvtsi32_si128(r_shift as i32);
+
+    let mut cx = 0usize;
+    while cx + 32 <= width {
+        let s_ptr = src.get_unchecked(cx..).as_ptr();
+        let t_ptr = thresholds.get_unchecked(cx..).as_ptr();
+        let v_src0 = _mm256_loadu_si256(s_ptr as *const __m256i);
+        let v_src1 = _mm256_loadu_si256(s_ptr.add(16) as *const __m256i);
+        let v_t0 = _mm256_loadu_si256(t_ptr as *const __m256i);
+        let v_t1 = _mm256_loadu_si256(t_ptr.add(16) as *const __m256i);
+        let v0 = _mm256_srl_epi16(_mm256_adds_epu16(v_src0, v_t0), v_r_shift);
+        let v1 = _mm256_srl_epi16(_mm256_adds_epu16(v_src1, v_t1), v_r_shift);
+        let packed = _mm256_permute4x64_epi64::<0xD8>(_mm256_packus_epi16(v0, v1));
+        _mm256_storeu_si256(
+            dst.get_unchecked_mut(cx..).as_mut_ptr() as *mut __m256i,
+            packed,
+        );
+        cx += 32;
+    }
+    cx
+}
+
+/// Computes `src << l_shift`, returns processed count
+pub(crate) fn avx2_depth_row_8_to_16(
+    src: &[u8],
+    dst: &mut [u16],
+    l_shift: u32,
+    width: usize,
+) -> usize {
+    unsafe { avx2_depth_row_8_to_16_impl(src, dst, l_shift, width) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_depth_row_8_to_16_impl(
+    src: &[u8],
+    dst: &mut [u16],
+    l_shift: u32,
+    width: usize,
+) -> usize {
+    let v_l_shift = _mm_cvtsi32_si128(l_shift as i32);
+
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let v_src = _mm_loadu_si128(src.get_unchecked(cx..).as_ptr() as *const __m128i);
+        let v = _mm256_sll_epi16(_mm256_cvtepu8_epi16(v_src), v_l_shift);
+        _mm256_storeu_si256(dst.get_unchecked_mut(cx..).as_mut_ptr() as *mut __m256i, v);
+        cx += 16;
+    }
+    cx
+}
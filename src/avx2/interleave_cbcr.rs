@@ -0,0 +1,197 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Splits interleaved `width` chroma pairs into two planes, returns processed count
+pub(crate) fn avx2_deinterleave_cbcr_u8(
+    uv: &[u8],
+    u: &mut [u8],
+    v: &mut [u8],
+    width: usize,
+) -> usize {
+    unsafe { avx2_deinterleave_cbcr_u8_impl(uv, u, v, width) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_deinterleave_cbcr_u8_impl(
+    uv: &[u8],
+    u: &mut [u8],
+    v: &mut [u8],
+    width: usize,
+) -> usize {
+    let v_mask = _mm256_set1_epi16(0xff);
+
+    let mut cx = 0usize;
+    while cx + 32 <= width {
+        let s_ptr = uv.get_unchecked(cx * 2..).as_ptr();
+        let v_src0 = _mm256_loadu_si256(s_ptr as *const __m256i);
+        let v_src1 = _mm256_loadu_si256(s_ptr.add(32) as *const __m256i);
+        let v_u = _mm256_packus_epi16(
+            _mm256_and_si256(v_src0, v_mask),
+            _mm256_and_si256(v_src1, v_mask),
+        );
+        let v_v = _mm256_packus_epi16(
+            _mm256_srli_epi16::<8>(v_src0),
+            _mm256_srli_epi16::<8>(v_src1),
+        );
+        let v_u = _mm256_permute4x64_epi64::<0b11011000>(v_u);
+        let v_v = _mm256_permute4x64_epi64::<0b11011000>(v_v);
+        _mm256_storeu_si256(u.get_unchecked_mut(cx..).as_mut_ptr() as *mut __m256i, v_u);
+        _mm256_storeu_si256(v.get_unchecked_mut(cx..).as_mut_ptr() as *mut __m256i, v_v);
+        cx += 32;
+    }
+    cx
+}
+
+/// Merges two chroma planes into `width` interleaved pairs, returns processed count
+pub(crate) fn avx2_interleave_cbcr_u8(u: &[u8], v: &[u8], uv: &mut [u8], width: usize) -> usize {
+    unsafe { avx2_interleave_cbcr_u8_impl(u, v, uv, width) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_interleave_cbcr_u8_impl(u: &[u8], v: &[u8], uv: &mut [u8], width: usize) -> usize {
+    let mut cx = 0usize;
+    while cx + 32 <= width {
+        let v_u = _mm256_loadu_si256(u.get_unchecked(cx..).as_ptr() as *const __m256i);
+        let v_v = _mm256_loadu_si256(v.get_unchecked(cx..).as_ptr() as *const __m256i);
+        let lo = _mm256_unpacklo_epi8(v_u, v_v);
+        let hi = _mm256_unpackhi_epi8(v_u, v_v);
+        let d_ptr = uv.get_unchecked_mut(cx * 2..).as_mut_ptr();
+        _mm256_storeu_si256(
+            d_ptr as *mut __m256i,
+            _mm256_permute2x128_si256::<0x20>(lo, hi),
+        );
+        _mm256_storeu_si256(
+            d_ptr.add(32) as *mut __m256i,
+            _mm256_permute2x128_si256::<0x31>(lo, hi),
+        );
+        cx += 32;
+    }
+    cx
+}
+
+/// Splits interleaved `width` chroma pairs into two planes as `(src >> r_shift) << l_shift`,
+/// returns processed count
+pub(crate) fn avx2_deinterleave_cbcr_u16(
+    uv: &[u16],
+    u: &mut [u16],
+    v: &mut [u16],
+    r_shift: u32,
+    l_shift: u32,
+    width: usize,
+) -> usize {
+    unsafe { avx2_deinterleave_cbcr_u16_impl(uv, u, v, r_shift, l_shift, width) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_deinterleave_cbcr_u16_impl(
+    uv: &[u16],
+    u: &mut [u16],
+    v: &mut [u16],
+    r_shift: u32,
+    l_shift: u32,
+    width: usize,
+) -> usize {
+    let v_r_shift = _mm_cvtsi32_si128(r_shift as i32);
+    let v_l_shift = _mm_cvtsi32_si128(l_shift as i32);
+    let v_mask = _mm256_set1_epi32(0xffff);
+
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let s_ptr = uv.get_unchecked(cx * 2..).as_ptr();
+        let v_src0 = _mm256_loadu_si256(s_ptr as *const __m256i);
+        let v_src1 = _mm256_loadu_si256(s_ptr.add(16) as *const __m256i);
+        let v_u = _mm256_packus_epi32(
+            _mm256_and_si256(v_src0, v_mask),
+            _mm256_and_si256(v_src1, v_mask),
+        );
+        let v_v = _mm256_packus_epi32(
+            _mm256_srli_epi32::<16>(v_src0),
+            _mm256_srli_epi32::<16>(v_src1),
+        );
+        let v_u = _mm256_permute4x64_epi64::<0b11011000>(v_u);
+        let v_v = _mm256_permute4x64_epi64::<0b11011000>(v_v);
+        let v_u = _mm256_sll_epi16(_mm256_srl_epi16(v_u, v_r_shift), v_l_shift);
+        let v_v = _mm256_sll_epi16(_mm256_srl_epi16(v_v, v_r_shift), v_l_shift);
+        _mm256_storeu_si256(u.get_unchecked_mut(cx..).as_mut_ptr() as *mut __m256i, v_u);
+        _mm256_storeu_si256(v.get_unchecked_mut(cx..).as_mut_ptr() as *mut __m256i, v_v);
+        cx += 16;
+    }
+    cx
+}
+
+/// Merges two chroma planes into `width` interleaved pairs as `(src >> r_shift) << l_shift`,
+/// returns processed count
+pub(crate) fn avx2_interleave_cbcr_u16(
+    u: &[u16],
+    v: &[u16],
+    uv: &mut [u16],
+    r_shift: u32,
+    l_shift: u32,
+    width: usize,
+) -> usize {
+    unsafe { avx2_interleave_cbcr_u16_impl(u, v, uv, r_shift, l_shift, width) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_interleave_cbcr_u16_impl(
+    u: &[u16],
+    v: &[u16],
+    uv: &mut [u16],
+    r_shift: u32,
+    l_shift: u32,
+    width: usize,
+) -> usize {
+    let v_r_shift = _mm_cvtsi32_si128(r_shift as i32);
+    let v_l_shift = _mm_cvtsi32_si128(l_shift as i32);
+
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let v_u = _mm256_loadu_si256(u.get_unchecked(cx..).as_ptr() as *const __m256i);
+        let v_v = _mm256_loadu_si256(v.get_unchecked(cx..).as_ptr() as *const __m256i);
+        let v_u = _mm256_sll_epi16(_mm256_srl_epi16(v_u, v_r_shift), v_l_shift);
+        let v_v = _mm256_sll_epi16(_mm256_srl_epi16(v_v, v_r_shift), v_l_shift);
+        let lo = _mm256_unpacklo_epi16(v_u, v_v);
+        let hi = _mm256_unpackhi_epi16(v_u, v_v);
+        let d_ptr = uv.get_unchecked_mut(cx * 2..).as_mut_ptr();
+        _mm256_storeu_si256(
+            d_ptr as *mut __m256i,
+            _mm256_permute2x128_si256::<0x20>(lo, hi),
+        );
+        _mm256_storeu_si256(
+            d_ptr.add(16) as *mut __m256i,
+            _mm256_permute2x128_si256::<0x31>(lo, hi),
+        );
+        cx += 16;
+    }
+    cx
+}
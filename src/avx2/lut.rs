@@ -0,0 +1,152 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::lut::{tetrahedral_nodes, trilinear_cell};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline(always)]
+unsafe fn load_pair(t_ptr: *const f32, first: usize, second: usize) -> __m256 {
+    _mm256_insertf128_ps::<1>(
+        _mm256_castps128_ps256(_mm_loadu_ps(t_ptr.add(first))),
+        _mm_loadu_ps(t_ptr.add(second)),
+    )
+}
+
+#[inline(always)]
+unsafe fn load_pair_at(t0: *const f32, t1: *const f32, offset: usize) -> __m256 {
+    _mm256_insertf128_ps::<1>(
+        _mm256_castps128_ps256(_mm_loadu_ps(t0.add(offset))),
+        _mm_loadu_ps(t1.add(offset)),
+    )
+}
+
+#[inline(always)]
+unsafe fn set_pair(first: f32, second: f32) -> __m256 {
+    _mm256_setr_m128(_mm_set1_ps(first), _mm_set1_ps(second))
+}
+
+#[inline(always)]
+unsafe fn lerp_ps(a: __m256, b: __m256, t: __m256) -> __m256 {
+    _mm256_add_ps(a, _mm256_mul_ps(_mm256_sub_ps(b, a), t))
+}
+
+/// Tetrahedral interpolation of `count` pixels holding 3D LUT grid coordinates
+/// in 4 lanes each, two pixels at once, returns processed count
+pub(crate) fn avx2_lut3d_tetrahedral_row(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    unsafe { avx2_lut3d_tetrahedral_row_impl(table, size, row, count) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_lut3d_tetrahedral_row_impl(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    let t_ptr = table.as_ptr();
+    let mut cx = 0usize;
+    for px in row.chunks_exact_mut(8).take(count / 2) {
+        let (nodes0, weights0) = tetrahedral_nodes(size, &px[..4]);
+        let (nodes1, weights1) = tetrahedral_nodes(size, &px[4..]);
+        let mut acc = _mm256_mul_ps(
+            load_pair(t_ptr, nodes0[0], nodes1[0]),
+            set_pair(weights0[0], weights1[0]),
+        );
+        for i in 1..4 {
+            acc = _mm256_add_ps(
+                acc,
+                _mm256_mul_ps(
+                    load_pair(t_ptr, nodes0[i], nodes1[i]),
+                    set_pair(weights0[i], weights1[i]),
+                ),
+            );
+        }
+        _mm256_storeu_ps(px.as_mut_ptr(), acc);
+        cx += 2;
+    }
+    cx
+}
+
+/// Trilinear interpolation of `count` pixels holding 3D LUT grid coordinates
+/// in 4 lanes each, two pixels at once, returns processed count
+pub(crate) fn avx2_lut3d_trilinear_row(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    unsafe { avx2_lut3d_trilinear_row_impl(table, size, row, count) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_lut3d_trilinear_row_impl(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    let t_ptr = table.as_ptr();
+    let mut cx = 0usize;
+    for px in row.chunks_exact_mut(8).take(count / 2) {
+        let (base0, [dr, dg, db], [fr0, fg0, fb0]) = trilinear_cell(size, &px[..4]);
+        let (base1, _, [fr1, fg1, fb1]) = trilinear_cell(size, &px[4..]);
+        let t0 = t_ptr.add(base0);
+        let t1 = t_ptr.add(base1);
+        let v_fr = set_pair(fr0, fr1);
+        let c00 = lerp_ps(load_pair_at(t0, t1, 0), load_pair_at(t0, t1, dr), v_fr);
+        let c10 = lerp_ps(
+            load_pair_at(t0, t1, dg),
+            load_pair_at(t0, t1, dg + dr),
+            v_fr,
+        );
+        let c01 = lerp_ps(
+            load_pair_at(t0, t1, db),
+            load_pair_at(t0, t1, db + dr),
+            v_fr,
+        );
+        let c11 = lerp_ps(
+            load_pair_at(t0, t1, db + dg),
+            load_pair_at(t0, t1, db + dg + dr),
+            v_fr,
+        );
+        let v_fg = set_pair(fg0, fg1);
+        let c0 = lerp_ps(c00, c10, v_fg);
+        let c1 = lerp_ps(c01, c11, v_fg);
+        _mm256_storeu_ps(px.as_mut_ptr(), lerp_ps(c0, c1, set_pair(fb0, fb1)));
+        cx += 2;
+    }
+    cx
+}
@@ -29,9 +29,15 @@
 #![deny(unreachable_code, unreachable_pub)]
 mod avx2_utils;
 mod ayuv_to_rgba;
+mod depth;
 #[cfg(feature = "nightly_f16")]
 mod f16_converter;
 mod gbr_to_rgb;
+mod interleave_cbcr;
+mod lut;
+mod masked_equals;
+mod plane_stats;
+mod premultiply;
 #[cfg(feature = "rdp")]
 mod rdp_to_yuv;
 mod rgb_to_nv;
@@ -59,7 +65,11 @@ mod rgba_to_yuv420;
 mod rgba_to_yuv_fast;
 #[cfg(feature = "fast_mode")]
 mod rgba_to_yuv_fast420;
+mod scale_horizontal;
+mod scale_vertical;
 mod shuffle;
+mod squared_error;
+mod ssim;
 mod y_to_rgba;
 mod y_to_rgba_alpha;
 mod yuv_nv_to_rgba;
@@ -91,9 +101,21 @@ mod yuy2_to_rgb;
 mod yuy2_to_yuv;
 
 pub(crate) use ayuv_to_rgba::avx2_ayuv_to_rgba;
+pub(crate) use depth::{avx2_depth_row_16_to_16, avx2_depth_row_16_to_8, avx2_depth_row_8_to_16};
 #[cfg(feature = "nightly_f16")]
 pub(crate) use f16_converter::{SurfaceU16ToFloat16Avx2, SurfaceU8ToFloat16Avx2};
 pub(crate) use gbr_to_rgb::{avx_yuv_to_rgba_row_full, avx_yuv_to_rgba_row_limited};
+pub(crate) use interleave_cbcr::{
+    avx2_deinterleave_cbcr_u16, avx2_deinterleave_cbcr_u8, avx2_interleave_cbcr_u16,
+    avx2_interleave_cbcr_u8,
+};
+pub(crate) use lut::{avx2_lut3d_tetrahedral_row, avx2_lut3d_trilinear_row};
+pub(crate) use masked_equals::avx2_masked_equals_row;
+pub(crate) use plane_stats::avx2_plane_stats_row;
+pub(crate) use premultiply::{
+    avx2_premultiply_row_u16, avx2_premultiply_row_u8, avx2_unpremultiply_row_u16,
+    avx2_unpremultiply_row_u8,
+};
 #[cfg(feature = "rdp")]
 pub(crate) use rdp_to_yuv::rdp_avx2_rgba_to_yuv;
 pub(crate) use rgb_to_nv::avx2_rgba_to_nv;
@@ -121,7 +143,11 @@ pub(crate) use rgba_to_yuv420::avx2_rgba_to_yuv420;
 pub(crate) use rgba_to_yuv_fast::avx2_rgba_to_yuv_dot_rgba;
 #[cfg(feature = "fast_mode")]
 pub(crate) use rgba_to_yuv_fast420::avx2_rgba_to_yuv_dot_rgba420;
+pub(crate) use scale_horizontal::{avx2_scale_horizontal_row_u16, avx2_scale_horizontal_row_u8};
+pub(crate) use scale_vertical::{avx2_scale_vertical_row_u16, avx2_scale_vertical_row_u8};
 pub(crate) use shuffle::{ShuffleConverterAvx2, ShuffleQTableConverterAvx2};
+pub(crate) use squared_error::avx2_squared_error_row;
+pub(crate) use ssim::avx2_ssim_block_row;
 pub(crate) use y_to_rgba::avx2_y_to_rgba_row;
 pub(crate) use y_to_rgba_alpha::avx2_y_to_rgba_alpha_row;
 pub(crate) use yuv_nv_to_rgba::avx2_yuv_nv_to_rgba_row;
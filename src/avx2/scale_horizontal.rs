@@ -0,0 +1,154 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Accumulates 8 consecutive destination components starting at `n`,
+/// each lane gathers its own pixel taps
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_horizontal_lanes<T: Copy + Into<i32>>(
+    src: &[T],
+    n: usize,
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> __m256i {
+    let mut src_idx = [0usize; 8];
+    let mut w_idx = [0i32; 8];
+    for (l, (src_idx, w_idx)) in src_idx.iter_mut().zip(w_idx.iter_mut()).enumerate() {
+        let px = (n + l) / channels;
+        *src_idx = *bounds.get_unchecked(px) * channels + (n + l) % channels;
+        *w_idx = (px * taps) as i32;
+    }
+    let mut v_w_idx = _mm256_loadu_si256(w_idx.as_ptr() as *const __m256i);
+    let mut acc = _mm256_set1_epi32(1 << 13);
+    for k in 0..taps {
+        let offset = k * channels;
+        let v_src = _mm256_setr_epi32(
+            (*src.get_unchecked(src_idx[0] + offset)).into(),
+            (*src.get_unchecked(src_idx[1] + offset)).into(),
+            (*src.get_unchecked(src_idx[2] + offset)).into(),
+            (*src.get_unchecked(src_idx[3] + offset)).into(),
+            (*src.get_unchecked(src_idx[4] + offset)).into(),
+            (*src.get_unchecked(src_idx[5] + offset)).into(),
+            (*src.get_unchecked(src_idx[6] + offset)).into(),
+            (*src.get_unchecked(src_idx[7] + offset)).into(),
+        );
+        let v_weight = _mm256_i32gather_epi32::<4>(weights.as_ptr(), v_w_idx);
+        acc = _mm256_add_epi32(acc, _mm256_mullo_epi32(v_src, v_weight));
+        v_w_idx = _mm256_add_epi32(v_w_idx, _mm256_set1_epi32(1));
+    }
+    _mm256_min_epi32(
+        _mm256_max_epi32(_mm256_srai_epi32::<14>(acc), _mm256_setzero_si256()),
+        _mm256_set1_epi32(max),
+    )
+}
+
+/// Filters row with interleaved `channels`, destination pixel `i` reads `taps` pixels
+/// from `bounds[i]` with weights in 14 bits fixed point, returns processed components count
+pub(crate) fn avx2_scale_horizontal_row_u8(
+    src: &[u8],
+    dst: &mut [u8],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    unsafe { avx2_scale_horizontal_row_u8_impl(src, dst, channels, bounds, weights, taps, max) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_scale_horizontal_row_u8_impl(
+    src: &[u8],
+    dst: &mut [u8],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    let length = dst.len();
+
+    let mut n = 0usize;
+    while n + 8 <= length {
+        let v = avx2_horizontal_lanes(src, n, channels, bounds, weights, taps, max);
+        let v = _mm_packs_epi32(_mm256_castsi256_si128(v), _mm256_extracti128_si256::<1>(v));
+        _mm_storel_epi64(
+            dst.get_unchecked_mut(n..).as_mut_ptr() as *mut __m128i,
+            _mm_packus_epi16(v, v),
+        );
+        n += 8;
+    }
+    n
+}
+
+/// Filters row with interleaved `channels`, destination pixel `i` reads `taps` pixels
+/// from `bounds[i]` with weights in 14 bits fixed point clamped to `max`,
+/// returns processed components count
+pub(crate) fn avx2_scale_horizontal_row_u16(
+    src: &[u16],
+    dst: &mut [u16],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    unsafe { avx2_scale_horizontal_row_u16_impl(src, dst, channels, bounds, weights, taps, max) }
+}
+
+#[target_feature(enable = "avx2")]
+unsafe fn avx2_scale_horizontal_row_u16_impl(
+    src: &[u16],
+    dst: &mut [u16],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    let length = dst.len();
+
+    let mut n = 0usize;
+    while n + 8 <= length {
+        let v = avx2_horizontal_lanes(src, n, channels, bounds, weights, taps, max);
+        _mm_storeu_si128(
+            dst.get_unchecked_mut(n..).as_mut_ptr() as *mut __m128i,
+            _mm_packus_epi32(_mm256_castsi256_si128(v), _mm256_extracti128_si256::<1>(v)),
+        );
+        n += 8;
+    }
+    n
+}
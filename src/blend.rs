@@ -0,0 +1,894 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::{check_bit_depth, check_rgba_destination};
+use crate::yuv_support::{
+    get_yuv_range, search_forward_transform, CbCrForwardTransform, YuvChromaSubsampling, YuvNVOrder,
+};
+use crate::{YuvBiPlanarImageMut, YuvError, YuvPlanarImageMut, YuvRange, YuvStandardMatrix};
+use num_traits::AsPrimitive;
+
+const PRECISION: i32 = 13;
+
+/// Overlay placed over the frame, `x` and `y` may be negative or run past the frame
+struct Overlay<'a, T> {
+    rgba: &'a [T],
+    rgba_stride: usize,
+    x: i64,
+    y: i64,
+    premultiplied: bool,
+}
+
+/// Frame region covered by the overlay
+#[derive(Debug, Copy, Clone)]
+struct Region {
+    x0: usize,
+    y0: usize,
+    x1: usize,
+    y1: usize,
+}
+
+struct OverlayBlender {
+    transform: CbCrForwardTransform<i32>,
+    bias_y: i64,
+    bias_uv: i64,
+    max: i64,
+    /// Padding bits below MSB-aligned samples of the frame
+    msb_shift: u32,
+}
+
+impl OverlayBlender {
+    #[inline(always)]
+    fn dot(r: i64, g: i64, b: i64, c: (i32, i32, i32)) -> i64 {
+        (r * c.0 as i64 + g * c.1 as i64 + b * c.2 as i64 + (1 << (PRECISION - 1))) >> PRECISION
+    }
+
+    /// Returns unbiased Y, Cb, Cr of overlay pixel already weighted by its alpha and scaled by max,
+    /// together with alpha
+    #[inline(always)]
+    fn weighted<T: AsPrimitive<i64>>(&self, px: &[T], premultiplied: bool) -> ([i64; 3], i64) {
+        let (r, g, b, a) = (px[0].as_(), px[1].as_(), px[2].as_(), px[3].as_());
+        let t = &self.transform;
+        let weight = if premultiplied { self.max } else { a };
+        (
+            [
+                Self::dot(r, g, b, (t.yr, t.yg, t.yb)) * weight,
+                Self::dot(r, g, b, (t.cb_r, t.cb_g, t.cb_b)) * weight,
+                Self::dot(r, g, b, (t.cr_r, t.cr_g, t.cr_b)) * weight,
+            ],
+            a,
+        )
+    }
+
+    /// Composites sum of `count` weighted overlay samples over `dst`
+    #[inline(always)]
+    fn composite(&self, dst: i64, sum: i64, alpha: i64, bias: i64, count: i64) -> i64 {
+        let scale = count * self.max;
+        let v = sum + bias * alpha + dst * (scale - alpha);
+        ((v + scale / 2).div_euclid(scale)).clamp(0, self.max)
+    }
+}
+
+fn covered_region(
+    width: u32,
+    height: u32,
+    x: i64,
+    y: i64,
+    overlay_width: u32,
+    overlay_height: u32,
+) -> Option<Region> {
+    let x0 = x.max(0);
+    let y0 = y.max(0);
+    let x1 = (x + overlay_width as i64).min(width as i64);
+    let y1 = (y + overlay_height as i64).min(height as i64);
+    if x0 >= x1 || y0 >= y1 {
+        return None;
+    }
+    Some(Region {
+        x0: x0 as usize,
+        y0: y0 as usize,
+        x1: x1 as usize,
+        y1: y1 as usize,
+    })
+}
+
+/// Chroma planes of the frame
+enum ChromaPlanes<'a, T> {
+    Planar {
+        u_plane: &'a mut [T],
+        u_stride: usize,
+        v_plane: &'a mut [T],
+        v_stride: usize,
+    },
+    BiPlanar {
+        uv_plane: &'a mut [T],
+        uv_stride: usize,
+        order: YuvNVOrder,
+    },
+}
+
+impl<T: Copy> ChromaPlanes<'_, T> {
+    #[inline(always)]
+    fn get(&self, cx: usize, cy: usize) -> (T, T) {
+        match self {
+            ChromaPlanes::Planar {
+                u_plane,
+                u_stride,
+                v_plane,
+                v_stride,
+            } => (u_plane[cy * u_stride + cx], v_plane[cy * v_stride + cx]),
+            ChromaPlanes::BiPlanar {
+                uv_plane,
+                uv_stride,
+                order,
+            } => {
+                let uv = &uv_plane[cy * uv_stride + cx * 2..];
+                (uv[order.get_u_position()], uv[order.get_v_position()])
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn set(&mut self, cx: usize, cy: usize, u: T, v: T) {
+        match self {
+            ChromaPlanes::Planar {
+                u_plane,
+                u_stride,
+                v_plane,
+                v_stride,
+            } => {
+                u_plane[cy * *u_stride + cx] = u;
+                v_plane[cy * *v_stride + cx] = v;
+            }
+            ChromaPlanes::BiPlanar {
+                uv_plane,
+                uv_stride,
+                order,
+            } => {
+                let uv = &mut uv_plane[cy * *uv_stride + cx * 2..];
+                uv[order.get_u_position()] = u;
+                uv[order.get_v_position()] = v;
+            }
+        }
+    }
+}
+
+fn blend_impl<T>(
+    y_plane: &mut [T],
+    y_stride: usize,
+    mut chroma: ChromaPlanes<T>,
+    size: (u32, u32),
+    subsampling: YuvChromaSubsampling,
+    overlay: Overlay<T>,
+    region: Region,
+    blender: &OverlayBlender,
+) where
+    T: Copy + AsPrimitive<i64>,
+    i64: AsPrimitive<T>,
+{
+    let pixel = |x: usize, y: usize| -> &[T] {
+        let ox = (x as i64 - overlay.x) as usize;
+        let oy = (y as i64 - overlay.y) as usize;
+        let offset = oy * overlay.rgba_stride + ox * 4;
+        &overlay.rgba[offset..offset + 4]
+    };
+
+    for y in region.y0..region.y1 {
+        let row = &mut y_plane[y * y_stride + region.x0..y * y_stride + region.x1];
+        for (x, dst) in (region.x0..region.x1).zip(row.iter_mut()) {
+            let (weighted, a) = blender.weighted(pixel(x, y), overlay.premultiplied);
+            if a == 0 && !overlay.premultiplied {
+                continue;
+            }
+            let v = blender.composite(
+                (*dst).as_() >> blender.msb_shift,
+                weighted[0],
+                a,
+                blender.bias_y,
+                1,
+            );
+            *dst = (v << blender.msb_shift).as_();
+        }
+    }
+
+    // Chroma averages weighted overlay and alpha over every luma pixel of the block,
+    // pixels outside of the overlay are transparent
+    let h_factor = subsampling.horizontal_factor();
+    let v_factor = subsampling.vertical_factor();
+    let (width, height) = (size.0 as usize, size.1 as usize);
+    for cy in region.y0 / v_factor..region.y1.div_ceil(v_factor) {
+        let block_y0 = cy * v_factor;
+        let block_y1 = (block_y0 + v_factor).min(height);
+        for cx in region.x0 / h_factor..region.x1.div_ceil(h_factor) {
+            let block_x0 = cx * h_factor;
+            let block_x1 = (block_x0 + h_factor).min(width);
+            let count = ((block_y1 - block_y0) * (block_x1 - block_x0)) as i64;
+            let mut sum_cb = 0i64;
+            let mut sum_cr = 0i64;
+            let mut sum_a = 0i64;
+            for y in block_y0.max(region.y0)..block_y1.min(region.y1) {
+                for x in block_x0.max(region.x0)..block_x1.min(region.x1) {
+                    let (weighted, a) = blender.weighted(pixel(x, y), overlay.premultiplied);
+                    sum_cb += weighted[1];
+                    sum_cr += weighted[2];
+                    sum_a += a;
+                }
+            }
+            if sum_a == 0 && !overlay.premultiplied {
+                continue;
+            }
+            let (u, v) = chroma.get(cx, cy);
+            let shift = blender.msb_shift;
+            let u = blender.composite(u.as_() >> shift, sum_cb, sum_a, blender.bias_uv, count);
+            let v = blender.composite(v.as_() >> shift, sum_cr, sum_a, blender.bias_uv, count);
+            chroma.set(cx, cy, (u << shift).as_(), (v << shift).as_());
+        }
+    }
+}
+
+fn make_blender(
+    bit_depth: u32,
+    msb_shift: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<OverlayBlender, YuvError> {
+    check_bit_depth(bit_depth, 8..=16)?;
+    let chroma_range = get_yuv_range(bit_depth, range);
+    let kr_kb = matrix.get_kr_kb();
+    let transform =
+        search_forward_transform(PRECISION, bit_depth, range, matrix, chroma_range, kr_kb);
+    Ok(OverlayBlender {
+        transform,
+        bias_y: chroma_range.bias_y as i64,
+        bias_uv: chroma_range.bias_uv as i64,
+        max: (1i64 << bit_depth) - 1,
+        msb_shift,
+    })
+}
+
+fn blend_planar_impl<T>(
+    image: &mut YuvPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    overlay: &[T],
+    overlay_stride: u32,
+    overlay_width: u32,
+    overlay_height: u32,
+    x: i32,
+    y: i32,
+    bit_depth: u32,
+    msb_shift: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    premultiplied: bool,
+) -> Result<(), YuvError>
+where
+    T: Copy + std::fmt::Debug + AsPrimitive<i64>,
+    i64: AsPrimitive<T>,
+{
+    image.check_constraints(subsampling)?;
+    check_rgba_destination(overlay, overlay_stride, overlay_width, overlay_height, 4)?;
+    let blender = make_blender(bit_depth, msb_shift, range, matrix)?;
+    let Some(region) = covered_region(
+        image.width,
+        image.height,
+        x as i64,
+        y as i64,
+        overlay_width,
+        overlay_height,
+    ) else {
+        return Ok(());
+    };
+    let size = (image.width, image.height);
+    let y_stride = image.y_stride as usize;
+    let u_stride = image.u_stride as usize;
+    let v_stride = image.v_stride as usize;
+    blend_impl(
+        image.y_plane.borrow_mut(),
+        y_stride,
+        ChromaPlanes::Planar {
+            u_plane: image.u_plane.borrow_mut(),
+            u_stride,
+            v_plane: image.v_plane.borrow_mut(),
+            v_stride,
+        },
+        size,
+        subsampling,
+        Overlay {
+            rgba: overlay,
+            rgba_stride: overlay_stride as usize,
+            x: x as i64,
+            y: y as i64,
+            premultiplied,
+        },
+        region,
+        &blender,
+    );
+    Ok(())
+}
+
+fn blend_bi_planar_impl<T>(
+    image: &mut YuvBiPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    order: YuvNVOrder,
+    overlay: &[T],
+    overlay_stride: u32,
+    overlay_width: u32,
+    overlay_height: u32,
+    x: i32,
+    y: i32,
+    bit_depth: u32,
+    msb_shift: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    premultiplied: bool,
+) -> Result<(), YuvError>
+where
+    T: Copy + std::fmt::Debug + AsPrimitive<i64>,
+    i64: AsPrimitive<T>,
+{
+    image.check_constraints(subsampling)?;
+    check_rgba_destination(overlay, overlay_stride, overlay_width, overlay_height, 4)?;
+    let blender = make_blender(bit_depth, msb_shift, range, matrix)?;
+    let Some(region) = covered_region(
+        image.width,
+        image.height,
+        x as i64,
+        y as i64,
+        overlay_width,
+        overlay_height,
+    ) else {
+        return Ok(());
+    };
+    let size = (image.width, image.height);
+    let y_stride = image.y_stride as usize;
+    let uv_stride = image.uv_stride as usize;
+    blend_impl(
+        image.y_plane.borrow_mut(),
+        y_stride,
+        ChromaPlanes::BiPlanar {
+            uv_plane: image.uv_plane.borrow_mut(),
+            uv_stride,
+            order,
+        },
+        size,
+        subsampling,
+        Overlay {
+            rgba: overlay,
+            rgba_stride: overlay_stride as usize,
+            x: x as i64,
+            y: y as i64,
+            premultiplied,
+        },
+        region,
+        &blender,
+    );
+    Ok(())
+}
+
+macro_rules! d_blend_planar {
+    ($method: ident, $sampling: expr, $yuv_name: expr) => {
+        #[doc = concat!("Alpha composites RGBA overlay onto ", $yuv_name, " planar image.
+
+Overlay is converted with the frame's range and matrix and blended in YUV space,
+alpha is averaged over every chroma block. Only the region covered by overlay is modified.
+
+# Arguments
+
+* `image` - Target planar image.
+* `overlay` - Source RGBA overlay.
+* `overlay_stride` - Elements per row of overlay.
+* `overlay_width` - Overlay width.
+* `overlay_height` - Overlay height.
+* `x` - Horizontal position of overlay in the image, may be negative.
+* `y` - Vertical position of overlay in the image, may be negative.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+* `premultiplied` - Whether overlay color is premultiplied by alpha.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the overlay are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            image: &mut YuvPlanarImageMut<u8>,
+            overlay: &[u8],
+            overlay_stride: u32,
+            overlay_width: u32,
+            overlay_height: u32,
+            x: i32,
+            y: i32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            premultiplied: bool,
+        ) -> Result<(), YuvError> {
+            blend_planar_impl(
+                image,
+                $sampling,
+                overlay,
+                overlay_stride,
+                overlay_width,
+                overlay_height,
+                x,
+                y,
+                8,
+                0,
+                range,
+                matrix,
+                premultiplied,
+            )
+        }
+    };
+}
+
+d_blend_planar!(
+    blend_rgba_on_yuv420,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420"
+);
+d_blend_planar!(
+    blend_rgba_on_yuv422,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422"
+);
+d_blend_planar!(
+    blend_rgba_on_yuv444,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444"
+);
+
+macro_rules! d_blend_planar16 {
+    ($method: ident, $sampling: expr, $yuv_name: expr) => {
+        #[doc = concat!("Alpha composites high bit-depth RGBA overlay onto ", $yuv_name, " planar image.
+
+Overlay is converted with the frame's range and matrix and blended in YUV space,
+alpha is averaged over every chroma block. Only the region covered by overlay is modified.
+Overlay and image share `bit_depth`, samples are expected in host endianness.
+
+# Arguments
+
+* `image` - Target planar image.
+* `overlay` - Source RGBA overlay.
+* `overlay_stride` - Elements per row of overlay.
+* `overlay_width` - Overlay width.
+* `overlay_height` - Overlay height.
+* `x` - Horizontal position of overlay in the image, may be negative.
+* `y` - Vertical position of overlay in the image, may be negative.
+* `bit_depth` - Bit-depth of image and overlay, 8..=16.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+* `premultiplied` - Whether overlay color is premultiplied by alpha.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the overlay are not valid based
+on the specified width, height, and strides, or bit depth is not in 8..=16.")]
+        pub fn $method(
+            image: &mut YuvPlanarImageMut<u16>,
+            overlay: &[u16],
+            overlay_stride: u32,
+            overlay_width: u32,
+            overlay_height: u32,
+            x: i32,
+            y: i32,
+            bit_depth: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            premultiplied: bool,
+        ) -> Result<(), YuvError> {
+            blend_planar_impl(
+                image,
+                $sampling,
+                overlay,
+                overlay_stride,
+                overlay_width,
+                overlay_height,
+                x,
+                y,
+                bit_depth,
+                0,
+                range,
+                matrix,
+                premultiplied,
+            )
+        }
+    };
+}
+
+d_blend_planar16!(
+    blend_rgba16_on_yuv420_p16,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420"
+);
+d_blend_planar16!(
+    blend_rgba16_on_yuv422_p16,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422"
+);
+d_blend_planar16!(
+    blend_rgba16_on_yuv444_p16,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444"
+);
+
+macro_rules! d_blend_bi_planar {
+    ($method: ident, $sampling: expr, $order: expr, $yuv_name: expr) => {
+        #[doc = concat!("Alpha composites RGBA overlay onto ", $yuv_name, " bi-planar image.
+
+Overlay is converted with the frame's range and matrix and blended in YUV space,
+alpha is averaged over every chroma block. Only the region covered by overlay is modified.
+
+# Arguments
+
+* `image` - Target bi-planar image.
+* `overlay` - Source RGBA overlay.
+* `overlay_stride` - Elements per row of overlay.
+* `overlay_width` - Overlay width.
+* `overlay_height` - Overlay height.
+* `x` - Horizontal position of overlay in the image, may be negative.
+* `y` - Vertical position of overlay in the image, may be negative.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+* `premultiplied` - Whether overlay color is premultiplied by alpha.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the overlay are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            image: &mut YuvBiPlanarImageMut<u8>,
+            overlay: &[u8],
+            overlay_stride: u32,
+            overlay_width: u32,
+            overlay_height: u32,
+            x: i32,
+            y: i32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            premultiplied: bool,
+        ) -> Result<(), YuvError> {
+            blend_bi_planar_impl(
+                image,
+                $sampling,
+                $order,
+                overlay,
+                overlay_stride,
+                overlay_width,
+                overlay_height,
+                x,
+                y,
+                8,
+                0,
+                range,
+                matrix,
+                premultiplied,
+            )
+        }
+    };
+}
+
+d_blend_bi_planar!(
+    blend_rgba_on_nv12,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::UV,
+    "NV12"
+);
+d_blend_bi_planar!(
+    blend_rgba_on_nv21,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::VU,
+    "NV21"
+);
+d_blend_bi_planar!(
+    blend_rgba_on_nv16,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::UV,
+    "NV16"
+);
+d_blend_bi_planar!(
+    blend_rgba_on_nv61,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::VU,
+    "NV61"
+);
+d_blend_bi_planar!(
+    blend_rgba_on_nv24,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::UV,
+    "NV24"
+);
+d_blend_bi_planar!(
+    blend_rgba_on_nv42,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::VU,
+    "NV42"
+);
+
+macro_rules! d_blend_bi_planar16 {
+    ($method: ident, $sampling: expr, $order: expr, $yuv_name: expr) => {
+        #[doc = concat!("Alpha composites high bit-depth RGBA overlay onto ", $yuv_name, " bi-planar image.
+
+Overlay is converted with the frame's range and matrix and blended in YUV space,
+alpha is averaged over every chroma block. Only the region covered by overlay is modified.
+Overlay and image share `bit_depth`, samples are expected in host endianness.
+For MSB-aligned formats as P010 use [blend_rgba10_on_p010] and [blend_rgba10_on_p210].
+
+# Arguments
+
+* `image` - Target bi-planar image.
+* `overlay` - Source RGBA overlay.
+* `overlay_stride` - Elements per row of overlay.
+* `overlay_width` - Overlay width.
+* `overlay_height` - Overlay height.
+* `x` - Horizontal position of overlay in the image, may be negative.
+* `y` - Vertical position of overlay in the image, may be negative.
+* `bit_depth` - Bit-depth of image and overlay, 8..=16.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+* `premultiplied` - Whether overlay color is premultiplied by alpha.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the overlay are not valid based
+on the specified width, height, and strides, or bit depth is not in 8..=16.")]
+        pub fn $method(
+            image: &mut YuvBiPlanarImageMut<u16>,
+            overlay: &[u16],
+            overlay_stride: u32,
+            overlay_width: u32,
+            overlay_height: u32,
+            x: i32,
+            y: i32,
+            bit_depth: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            premultiplied: bool,
+        ) -> Result<(), YuvError> {
+            blend_bi_planar_impl(
+                image,
+                $sampling,
+                $order,
+                overlay,
+                overlay_stride,
+                overlay_width,
+                overlay_height,
+                x,
+                y,
+                bit_depth,
+                0,
+                range,
+                matrix,
+                premultiplied,
+            )
+        }
+    };
+}
+
+d_blend_bi_planar16!(
+    blend_rgba16_on_nv12_p16,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::UV,
+    "NV12"
+);
+d_blend_bi_planar16!(
+    blend_rgba16_on_nv21_p16,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::VU,
+    "NV21"
+);
+d_blend_bi_planar16!(
+    blend_rgba16_on_nv16_p16,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::UV,
+    "NV16"
+);
+d_blend_bi_planar16!(
+    blend_rgba16_on_nv61_p16,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::VU,
+    "NV61"
+);
+d_blend_bi_planar16!(
+    blend_rgba16_on_nv24_p16,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::UV,
+    "NV24"
+);
+d_blend_bi_planar16!(
+    blend_rgba16_on_nv42_p16,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::VU,
+    "NV42"
+);
+
+macro_rules! d_blend_msb10 {
+    ($method: ident, $sampling: expr, $yuv_name: expr) => {
+        #[doc = concat!("Alpha composites 10-bit RGBA overlay onto ", $yuv_name, " bi-planar image.
+
+Image samples are MSB-aligned 10-bit, so the low 6 bits are padding and are written as zeros,
+overlay is regular 10-bit in 0..=1023. Samples are expected in host endianness.
+Overlay is converted with the frame's range and matrix and blended in YUV space,
+alpha is averaged over every chroma block. Only the region covered by overlay is modified.
+
+# Arguments
+
+* `image` - Target bi-planar image.
+* `overlay` - Source RGBA overlay.
+* `overlay_stride` - Elements per row of overlay.
+* `overlay_width` - Overlay width.
+* `overlay_height` - Overlay height.
+* `x` - Horizontal position of overlay in the image, may be negative.
+* `y` - Vertical position of overlay in the image, may be negative.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+* `premultiplied` - Whether overlay color is premultiplied by alpha.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the overlay are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            image: &mut YuvBiPlanarImageMut<u16>,
+            overlay: &[u16],
+            overlay_stride: u32,
+            overlay_width: u32,
+            overlay_height: u32,
+            x: i32,
+            y: i32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            premultiplied: bool,
+        ) -> Result<(), YuvError> {
+            blend_bi_planar_impl(
+                image,
+                $sampling,
+                YuvNVOrder::UV,
+                overlay,
+                overlay_stride,
+                overlay_width,
+                overlay_height,
+                x,
+                y,
+                10,
+                6,
+                range,
+                matrix,
+                premultiplied,
+            )
+        }
+    };
+}
+
+d_blend_msb10!(blend_rgba10_on_p010, YuvChromaSubsampling::Yuv420, "P010");
+d_blend_msb10!(blend_rgba10_on_p210, YuvChromaSubsampling::Yuv422, "P210");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blend_touches_only_covered_region() {
+        let width = 8u32;
+        let height = 6u32;
+        let mut image = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        image.y_plane.borrow_mut().fill(100);
+        image.u_plane.borrow_mut().fill(128);
+        image.v_plane.borrow_mut().fill(128);
+
+        // Opaque white on the left column, half transparent white on the right one
+        let overlay = [255u8, 255, 255, 255, 255, 255, 255, 128].repeat(3);
+        blend_rgba_on_yuv420(
+            &mut image,
+            &overlay,
+            8,
+            2,
+            3,
+            -1,
+            3,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+            false,
+        )
+        .unwrap();
+        let y_plane = image.y_plane.borrow();
+        for (i, &v) in y_plane.iter().enumerate() {
+            let (x, y) = (i % 8, i / 8);
+            if x == 0 && (3..6).contains(&y) {
+                assert!(v.abs_diff(178) <= 1, "at {x}, {y}: {v}");
+            } else {
+                assert_eq!(v, 100, "at {x}, {y}");
+            }
+        }
+        // Neutral overlay keeps chroma neutral, untouched blocks remain as is
+        assert!(image.u_plane.borrow().iter().all(|&v| v == 128));
+
+        let premultiplied = [128u8, 128, 128, 128];
+        let mut nv12 =
+            YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        nv12.y_plane.borrow_mut().fill(100);
+        nv12.uv_plane.borrow_mut().fill(128);
+        blend_rgba_on_nv12(
+            &mut nv12,
+            &premultiplied,
+            4,
+            1,
+            1,
+            2,
+            2,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+            true,
+        )
+        .unwrap();
+        assert!(nv12.y_plane.borrow()[2 * 8 + 2].abs_diff(178) <= 1);
+    }
+
+    #[test]
+    fn blend_p010_keeps_padding_bits_clear() {
+        let width = 4u32;
+        let height = 2u32;
+        let mut p010 =
+            YuvBiPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        p010.y_plane.borrow_mut().fill(400 << 6);
+        p010.uv_plane.borrow_mut().fill(512 << 6);
+        let overlay = [1023u16, 1023, 1023, 1023].repeat(2);
+        blend_rgba10_on_p010(
+            &mut p010,
+            &overlay,
+            8,
+            2,
+            1,
+            0,
+            0,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+            false,
+        )
+        .unwrap();
+        let y_plane = p010.y_plane.borrow();
+        assert_eq!(&y_plane[..4], &[1023 << 6, 1023 << 6, 400 << 6, 400 << 6]);
+        assert!(y_plane.iter().all(|&v| v & 0x3f == 0));
+        assert!(p010.uv_plane.borrow().iter().all(|&v| v == 512 << 6));
+
+        assert!(matches!(
+            blend_rgba16_on_nv12_p16(
+                &mut p010,
+                &overlay,
+                8,
+                2,
+                1,
+                0,
+                0,
+                17,
+                YuvRange::Full,
+                YuvStandardMatrix::Bt709,
+                false,
+            ),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+    }
+}
@@ -0,0 +1,907 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::numerics::{from_ne, to_ne};
+use crate::sharpyuv::{rec709_from_linear, rec709_to_linear};
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::{get_yuv_range, YuvChromaRange, YuvSourceChannels};
+use crate::{
+    YuvBiPlanarImage, YuvBiPlanarImageMut, YuvBytesPacking, YuvChromaSubsampling, YuvEndianness,
+    YuvError, YuvPlanarImage, YuvPlanarImageMut, YuvRange,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+const KR: f32 = 0.2627f32;
+const KB: f32 = 0.0593f32;
+const KG: f32 = 1f32 - KR - KB;
+
+// Cb and Cr scales for negative and positive differences, ITU-R BT.2020 Table 4
+const CB_NEGATIVE: f32 = 1.9404f32;
+const CB_POSITIVE: f32 = 1.5816f32;
+const CR_NEGATIVE: f32 = 1.7184f32;
+const CR_POSITIVE: f32 = 0.9936f32;
+
+/// Computes constant luminance Y'c, Cb'c, Cr'c from non-linear R'G'B'
+#[inline]
+fn rgb_to_ycbcr_cl(r: f32, g: f32, b: f32, linearize_lut: &[f32], max_value: usize) -> [f32; 3] {
+    let linear_r = linearize_lut[(r as usize).min(max_value)];
+    let linear_g = linearize_lut[(g as usize).min(max_value)];
+    let linear_b = linearize_lut[(b as usize).min(max_value)];
+    let scale = 1f32 / max_value as f32;
+    let y = rec709_from_linear(KR * linear_r + KG * linear_g + KB * linear_b);
+    let diff_b = b * scale - y;
+    let cb = if diff_b <= 0f32 {
+        diff_b / CB_NEGATIVE
+    } else {
+        diff_b / CB_POSITIVE
+    };
+    let diff_r = r * scale - y;
+    let cr = if diff_r <= 0f32 {
+        diff_r / CR_NEGATIVE
+    } else {
+        diff_r / CR_POSITIVE
+    };
+    [y, cb, cr]
+}
+
+/// Computes non-linear R'G'B' from constant luminance Y'c, Cb'c, Cr'c
+#[inline]
+fn ycbcr_cl_to_rgb(y: f32, cb: f32, cr: f32) -> [f32; 3] {
+    let b = y + if cb <= 0f32 {
+        cb * CB_NEGATIVE
+    } else {
+        cb * CB_POSITIVE
+    };
+    let r = y + if cr <= 0f32 {
+        cr * CR_NEGATIVE
+    } else {
+        cr * CR_POSITIVE
+    };
+    let linear_y = rec709_to_linear(y);
+    let linear_r = rec709_to_linear(r);
+    let linear_b = rec709_to_linear(b);
+    let linear_g = (linear_y - KR * linear_r - KB * linear_b) / KG;
+    [
+        r.min(1f32).max(0f32),
+        rec709_from_linear(linear_g),
+        b.min(1f32).max(0f32),
+    ]
+}
+
+#[inline(always)]
+fn quantize(value: f32, range: u32, bias: u32, max_value: f32) -> u16 {
+    (value * range as f32 + bias as f32)
+        .round()
+        .min(max_value)
+        .max(0f32) as u16
+}
+
+/// Encodes luma rows of one chroma row block and stores averaged unquantized chroma
+fn encode_cl_rows<
+    const ORIGIN_CHANNELS: u8,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+    const BIT_DEPTH: usize,
+>(
+    rgba: &[u16],
+    rgba_stride: usize,
+    y_dst: &mut [u16],
+    y_stride: usize,
+    chroma: &mut [[f32; 2]],
+    width: usize,
+    chroma_range: &YuvChromaRange,
+    linearize_lut: &[f32],
+) {
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    let channels = src_chans.get_channels_count();
+    let horizontal = chroma_subsampling.horizontal_factor();
+    let max_value = (1usize << BIT_DEPTH) - 1;
+    let msb_shift = (16 - BIT_DEPTH) as i32;
+
+    chroma.fill([0f32; 2]);
+    let mut counts = vec![0f32; chroma.len()];
+
+    for (y_dst, rgba) in y_dst
+        .chunks_mut(y_stride)
+        .zip(rgba.chunks(rgba_stride))
+        .take(chroma_subsampling.vertical_factor())
+    {
+        for (x, (y_dst, rgba)) in y_dst[0..width]
+            .iter_mut()
+            .zip(rgba[0..width * channels].chunks_exact(channels))
+            .enumerate()
+        {
+            let [y, cb, cr] = rgb_to_ycbcr_cl(
+                rgba[src_chans.get_r_channel_offset()] as f32,
+                rgba[src_chans.get_g_channel_offset()] as f32,
+                rgba[src_chans.get_b_channel_offset()] as f32,
+                linearize_lut,
+                max_value,
+            );
+            *y_dst = from_ne::<ENDIANNESS, BYTES_POSITION>(
+                quantize(
+                    y,
+                    chroma_range.range_y,
+                    chroma_range.bias_y,
+                    max_value as f32,
+                ),
+                msb_shift,
+            );
+            let cx = x / horizontal;
+            chroma[cx][0] += cb;
+            chroma[cx][1] += cr;
+            counts[cx] += 1f32;
+        }
+    }
+
+    for (chroma, &count) in chroma.iter_mut().zip(counts.iter()) {
+        let recip = 1f32 / count;
+        chroma[0] *= recip;
+        chroma[1] *= recip;
+    }
+}
+
+#[inline]
+fn quantize_cl_chroma<const ENDIANNESS: u8, const BYTES_POSITION: u8, const BIT_DEPTH: usize>(
+    value: f32,
+    chroma_range: &YuvChromaRange,
+) -> u16 {
+    from_ne::<ENDIANNESS, BYTES_POSITION>(
+        quantize(
+            value,
+            chroma_range.range_uv,
+            chroma_range.bias_uv,
+            ((1u32 << BIT_DEPTH) - 1) as f32,
+        ),
+        (16 - BIT_DEPTH) as i32,
+    )
+}
+
+fn make_linearize_lut<const BIT_DEPTH: usize>() -> Vec<f32> {
+    let max_value = ((1u32 << BIT_DEPTH) - 1) as f32;
+    (0..(1usize << BIT_DEPTH))
+        .map(|x| rec709_to_linear(x as f32 / max_value))
+        .collect()
+}
+
+fn rgb16_to_yuv_cl<
+    const ORIGIN_CHANNELS: u8,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+    const BIT_DEPTH: usize,
+>(
+    planar_image: &mut YuvPlanarImageMut<u16>,
+    rgba: &[u16],
+    rgba_stride: u32,
+    range: YuvRange,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+
+    planar_image.check_constraints(chroma_subsampling)?;
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        planar_image.width,
+        planar_image.height,
+        src_chans.get_channels_count(),
+    )?;
+
+    let chroma_range = get_yuv_range(BIT_DEPTH as u32, range);
+    let linearize_lut = make_linearize_lut::<BIT_DEPTH>();
+    let width = planar_image.width as usize;
+    let chroma_width = chroma_subsampling.chroma_width(width);
+    let rows_per_chroma = chroma_subsampling.vertical_factor();
+    let chroma_rows = chroma_subsampling.chroma_height(planar_image.height as usize);
+
+    let y_stride = planar_image.y_stride as usize;
+    let u_stride = planar_image.u_stride as usize;
+    let v_stride = planar_image.v_stride as usize;
+    let y_plane = planar_image.y_plane.borrow_mut();
+    let u_plane = planar_image.u_plane.borrow_mut();
+    let v_plane = planar_image.v_plane.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = y_plane
+            .par_chunks_mut(y_stride * rows_per_chroma)
+            .zip(u_plane.par_chunks_mut(u_stride))
+            .zip(v_plane.par_chunks_mut(v_stride))
+            .zip(rgba.par_chunks(rgba_stride as usize * rows_per_chroma))
+            .take(chroma_rows);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = y_plane
+            .chunks_mut(y_stride * rows_per_chroma)
+            .zip(u_plane.chunks_mut(u_stride))
+            .zip(v_plane.chunks_mut(v_stride))
+            .zip(rgba.chunks(rgba_stride as usize * rows_per_chroma))
+            .take(chroma_rows);
+    }
+    iter.for_each(|(((y_dst, u_dst), v_dst), rgba)| {
+        let mut chroma = vec![[0f32; 2]; chroma_width];
+        encode_cl_rows::<ORIGIN_CHANNELS, SAMPLING, ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(
+            rgba,
+            rgba_stride as usize,
+            y_dst,
+            y_stride,
+            &mut chroma,
+            width,
+            &chroma_range,
+            &linearize_lut,
+        );
+        for ((u_dst, v_dst), chroma) in u_dst[0..chroma_width]
+            .iter_mut()
+            .zip(v_dst[0..chroma_width].iter_mut())
+            .zip(chroma.iter())
+        {
+            *u_dst = quantize_cl_chroma::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(
+                chroma[0],
+                &chroma_range,
+            );
+            *v_dst = quantize_cl_chroma::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(
+                chroma[1],
+                &chroma_range,
+            );
+        }
+    });
+
+    Ok(())
+}
+
+fn rgb16_to_yuv_nv_cl<
+    const ORIGIN_CHANNELS: u8,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+    const BIT_DEPTH: usize,
+>(
+    bi_planar_image: &mut YuvBiPlanarImageMut<u16>,
+    rgba: &[u16],
+    rgba_stride: u32,
+    range: YuvRange,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+
+    bi_planar_image.check_constraints(chroma_subsampling)?;
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        bi_planar_image.width,
+        bi_planar_image.height,
+        src_chans.get_channels_count(),
+    )?;
+
+    let chroma_range = get_yuv_range(BIT_DEPTH as u32, range);
+    let linearize_lut = make_linearize_lut::<BIT_DEPTH>();
+    let width = bi_planar_image.width as usize;
+    let chroma_width = chroma_subsampling.chroma_width(width);
+    let rows_per_chroma = chroma_subsampling.vertical_factor();
+    let chroma_rows = chroma_subsampling.chroma_height(bi_planar_image.height as usize);
+
+    let y_stride = bi_planar_image.y_stride as usize;
+    let uv_stride = bi_planar_image.uv_stride as usize;
+    let y_plane = bi_planar_image.y_plane.borrow_mut();
+    let uv_plane = bi_planar_image.uv_plane.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = y_plane
+            .par_chunks_mut(y_stride * rows_per_chroma)
+            .zip(uv_plane.par_chunks_mut(uv_stride))
+            .zip(rgba.par_chunks(rgba_stride as usize * rows_per_chroma))
+            .take(chroma_rows);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = y_plane
+            .chunks_mut(y_stride * rows_per_chroma)
+            .zip(uv_plane.chunks_mut(uv_stride))
+            .zip(rgba.chunks(rgba_stride as usize * rows_per_chroma))
+            .take(chroma_rows);
+    }
+    iter.for_each(|((y_dst, uv_dst), rgba)| {
+        let mut chroma = vec![[0f32; 2]; chroma_width];
+        encode_cl_rows::<ORIGIN_CHANNELS, SAMPLING, ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(
+            rgba,
+            rgba_stride as usize,
+            y_dst,
+            y_stride,
+            &mut chroma,
+            width,
+            &chroma_range,
+            &linearize_lut,
+        );
+        for (uv_dst, chroma) in uv_dst[0..chroma_width * 2]
+            .chunks_exact_mut(2)
+            .zip(chroma.iter())
+        {
+            uv_dst[0] = quantize_cl_chroma::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(
+                chroma[0],
+                &chroma_range,
+            );
+            uv_dst[1] = quantize_cl_chroma::<ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(
+                chroma[1],
+                &chroma_range,
+            );
+        }
+    });
+
+    Ok(())
+}
+
+/// Decodes one row, `chroma` returns stored Cb and Cr for chroma column
+#[inline]
+fn decode_cl_row<
+    const DESTINATION_CHANNELS: u8,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+    const BIT_DEPTH: usize,
+>(
+    y_src: &[u16],
+    chroma: impl Fn(usize) -> (u16, u16),
+    rgba: &mut [u16],
+    chroma_range: &YuvChromaRange,
+) {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    let horizontal = chroma_subsampling.horizontal_factor();
+    let msb_shift = (16 - BIT_DEPTH) as i32;
+    let max_value = ((1u32 << BIT_DEPTH) - 1) as f32;
+    let y_scale = 1f32 / chroma_range.range_y as f32;
+    let uv_scale = 1f32 / chroma_range.range_uv as f32;
+
+    for (x, (&y_src, rgba)) in y_src
+        .iter()
+        .zip(rgba.chunks_exact_mut(dst_chans.get_channels_count()))
+        .enumerate()
+    {
+        let (cb, cr) = chroma(x / horizontal);
+        let y = (to_ne::<ENDIANNESS, BYTES_POSITION>(y_src, msb_shift) as f32
+            - chroma_range.bias_y as f32)
+            * y_scale;
+        let cb = (to_ne::<ENDIANNESS, BYTES_POSITION>(cb, msb_shift) as f32
+            - chroma_range.bias_uv as f32)
+            * uv_scale;
+        let cr = (to_ne::<ENDIANNESS, BYTES_POSITION>(cr, msb_shift) as f32
+            - chroma_range.bias_uv as f32)
+            * uv_scale;
+        let [r, g, b] = ycbcr_cl_to_rgb(y, cb, cr);
+        rgba[dst_chans.get_r_channel_offset()] = (r * max_value).round() as u16;
+        rgba[dst_chans.get_g_channel_offset()] = (g * max_value).round() as u16;
+        rgba[dst_chans.get_b_channel_offset()] = (b * max_value).round() as u16;
+        if dst_chans.has_alpha() {
+            rgba[dst_chans.get_a_channel_offset()] = max_value as u16;
+        }
+    }
+}
+
+fn yuv_cl_to_rgb16<
+    const DESTINATION_CHANNELS: u8,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+    const BIT_DEPTH: usize,
+>(
+    planar_image: &YuvPlanarImage<u16>,
+    rgba: &mut [u16],
+    rgba_stride: u32,
+    range: YuvRange,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+
+    planar_image.check_constraints(chroma_subsampling)?;
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        planar_image.width,
+        planar_image.height,
+        channels,
+    )?;
+
+    let chroma_range = get_yuv_range(BIT_DEPTH as u32, range);
+    let width = planar_image.width as usize;
+    let rows_per_chroma = chroma_subsampling.vertical_factor();
+    let chroma_rows = chroma_subsampling.chroma_height(planar_image.height as usize);
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba
+            .par_chunks_mut(rgba_stride as usize * rows_per_chroma)
+            .zip(
+                planar_image
+                    .y_plane
+                    .par_chunks(planar_image.y_stride as usize * rows_per_chroma),
+            )
+            .zip(
+                planar_image
+                    .u_plane
+                    .par_chunks(planar_image.u_stride as usize),
+            )
+            .zip(
+                planar_image
+                    .v_plane
+                    .par_chunks(planar_image.v_stride as usize),
+            )
+            .take(chroma_rows);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba
+            .chunks_mut(rgba_stride as usize * rows_per_chroma)
+            .zip(
+                planar_image
+                    .y_plane
+                    .chunks(planar_image.y_stride as usize * rows_per_chroma),
+            )
+            .zip(planar_image.u_plane.chunks(planar_image.u_stride as usize))
+            .zip(planar_image.v_plane.chunks(planar_image.v_stride as usize))
+            .take(chroma_rows);
+    }
+    iter.for_each(|(((rgba, y_src), u_src), v_src)| {
+        for (rgba, y_src) in rgba
+            .chunks_mut(rgba_stride as usize)
+            .zip(y_src.chunks(planar_image.y_stride as usize))
+        {
+            decode_cl_row::<DESTINATION_CHANNELS, SAMPLING, ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(
+                &y_src[0..width],
+                |cx| (u_src[cx], v_src[cx]),
+                &mut rgba[0..width * channels],
+                &chroma_range,
+            );
+        }
+    });
+
+    Ok(())
+}
+
+fn yuv_nv_cl_to_rgb16<
+    const DESTINATION_CHANNELS: u8,
+    const SAMPLING: u8,
+    const ENDIANNESS: u8,
+    const BYTES_POSITION: u8,
+    const BIT_DEPTH: usize,
+>(
+    bi_planar_image: &YuvBiPlanarImage<u16>,
+    rgba: &mut [u16],
+    rgba_stride: u32,
+    range: YuvRange,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+
+    bi_planar_image.check_constraints(chroma_subsampling)?;
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        bi_planar_image.width,
+        bi_planar_image.height,
+        channels,
+    )?;
+
+    let chroma_range = get_yuv_range(BIT_DEPTH as u32, range);
+    let width = bi_planar_image.width as usize;
+    let rows_per_chroma = chroma_subsampling.vertical_factor();
+    let chroma_rows = chroma_subsampling.chroma_height(bi_planar_image.height as usize);
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba
+            .par_chunks_mut(rgba_stride as usize * rows_per_chroma)
+            .zip(
+                bi_planar_image
+                    .y_plane
+                    .par_chunks(bi_planar_image.y_stride as usize * rows_per_chroma),
+            )
+            .zip(
+                bi_planar_image
+                    .uv_plane
+                    .par_chunks(bi_planar_image.uv_stride as usize),
+            )
+            .take(chroma_rows);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba
+            .chunks_mut(rgba_stride as usize * rows_per_chroma)
+            .zip(
+                bi_planar_image
+                    .y_plane
+                    .chunks(bi_planar_image.y_stride as usize * rows_per_chroma),
+            )
+            .zip(
+                bi_planar_image
+                    .uv_plane
+                    .chunks(bi_planar_image.uv_stride as usize),
+            )
+            .take(chroma_rows);
+    }
+    iter.for_each(|((rgba, y_src), uv_src)| {
+        for (rgba, y_src) in rgba
+            .chunks_mut(rgba_stride as usize)
+            .zip(y_src.chunks(bi_planar_image.y_stride as usize))
+        {
+            decode_cl_row::<DESTINATION_CHANNELS, SAMPLING, ENDIANNESS, BYTES_POSITION, BIT_DEPTH>(
+                &y_src[0..width],
+                |cx| (uv_src[cx * 2], uv_src[cx * 2 + 1]),
+                &mut rgba[0..width * channels],
+                &chroma_range,
+            );
+        }
+    });
+
+    Ok(())
+}
+
+macro_rules! d_forward {
+    ($method: ident, $px_fmt: expr, $yuv_name: expr, $rgb_name: expr, $bit_depth: expr) => {
+        #[doc = concat!("Convert ", $rgb_name, " image data to ", $yuv_name, " BT.2020 constant luminance format.
+
+This function performs ", $rgb_name, " to ", $yuv_name, " conversion with constant luminance semantics
+as specified in ITU-R BT.2020 ( matrix coefficients 10 in H.273 ): luminance is computed from linear light,
+and Cb, Cr are piecewise scaled differences of non-linear B' and R' against Y'c.
+
+# Arguments
+
+* `planar_image` - Target planar image.
+* `rgba` - The input ", $rgb_name, " image data slice, BT.2020 transfer encoded.
+* `rgba_stride` - The stride (components per row) for the ", $rgb_name, " image data.
+* `range` - The YUV range (limited or full).
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $rgb_name, " data are not valid based
+on the specified width, height, and strides.
+")]
+        pub fn $method(
+            planar_image: &mut YuvPlanarImageMut<u16>,
+            rgba: &[u16],
+            rgba_stride: u32,
+            range: YuvRange,
+        ) -> Result<(), YuvError> {
+            rgb16_to_yuv_cl::<
+                { $px_fmt as u8 },
+                { YuvChromaSubsampling::Yuv420 as u8 },
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+                $bit_depth,
+            >(planar_image, rgba, rgba_stride, range)
+        }
+    };
+}
+
+d_forward!(
+    rgb10_to_i010_cl,
+    YuvSourceChannels::Rgb,
+    "I010",
+    "RGB10",
+    10
+);
+d_forward!(
+    rgba10_to_i010_cl,
+    YuvSourceChannels::Rgba,
+    "I010",
+    "RGBA10",
+    10
+);
+d_forward!(
+    rgb12_to_i012_cl,
+    YuvSourceChannels::Rgb,
+    "I012",
+    "RGB12",
+    12
+);
+d_forward!(
+    rgba12_to_i012_cl,
+    YuvSourceChannels::Rgba,
+    "I012",
+    "RGBA12",
+    12
+);
+
+macro_rules! d_forward_nv {
+    ($method: ident, $px_fmt: expr, $yuv_name: expr, $rgb_name: expr, $bit_depth: expr) => {
+        #[doc = concat!("Convert ", $rgb_name, " image data to ", $yuv_name, " BT.2020 constant luminance format.
+
+This function performs ", $rgb_name, " to ", $yuv_name, " conversion with constant luminance semantics
+as specified in ITU-R BT.2020 ( matrix coefficients 10 in H.273 ).
+Values are stored in most significant bits, as ", $yuv_name, " expects.
+
+# Arguments
+
+* `bi_planar_image` - Target bi-planar image.
+* `rgba` - The input ", $rgb_name, " image data slice, BT.2020 transfer encoded.
+* `rgba_stride` - The stride (components per row) for the ", $rgb_name, " image data.
+* `range` - The YUV range (limited or full).
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $rgb_name, " data are not valid based
+on the specified width, height, and strides.
+")]
+        pub fn $method(
+            bi_planar_image: &mut YuvBiPlanarImageMut<u16>,
+            rgba: &[u16],
+            rgba_stride: u32,
+            range: YuvRange,
+        ) -> Result<(), YuvError> {
+            rgb16_to_yuv_nv_cl::<
+                { $px_fmt as u8 },
+                { YuvChromaSubsampling::Yuv420 as u8 },
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+                $bit_depth,
+            >(bi_planar_image, rgba, rgba_stride, range)
+        }
+    };
+}
+
+d_forward_nv!(
+    rgb10_to_p010_cl,
+    YuvSourceChannels::Rgb,
+    "P010",
+    "RGB10",
+    10
+);
+d_forward_nv!(
+    rgba10_to_p010_cl,
+    YuvSourceChannels::Rgba,
+    "P010",
+    "RGBA10",
+    10
+);
+
+macro_rules! d_inverse {
+    ($method: ident, $px_fmt: expr, $yuv_name: expr, $rgb_name: expr, $bit_depth: expr) => {
+        #[doc = concat!("Convert ", $yuv_name, " BT.2020 constant luminance format to ", $rgb_name, " format.
+
+This function performs ", $yuv_name, " to ", $rgb_name, " conversion with constant luminance semantics
+as specified in ITU-R BT.2020 ( matrix coefficients 10 in H.273 ): R' and B' are restored from
+piecewise scaled Cb, Cr and G is derived from linear light luminance.
+
+# Arguments
+
+* `planar_image` - Source ", $yuv_name, " planar image.
+* `rgba` - A mutable slice to store the converted ", $rgb_name, " data, BT.2020 transfer encoded.
+* `rgba_stride` - The stride (components per row) for the ", $rgb_name, " image data.
+* `range` - The YUV range (limited or full).
+
+# Errors
+
+This function returns an error if the lengths of the planes or the output ", $rgb_name, " data are not valid based
+on the specified width, height, and strides.
+")]
+        pub fn $method(
+            planar_image: &YuvPlanarImage<u16>,
+            rgba: &mut [u16],
+            rgba_stride: u32,
+            range: YuvRange,
+        ) -> Result<(), YuvError> {
+            yuv_cl_to_rgb16::<
+                { $px_fmt as u8 },
+                { YuvChromaSubsampling::Yuv420 as u8 },
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::LeastSignificantBytes as u8 },
+                $bit_depth,
+            >(planar_image, rgba, rgba_stride, range)
+        }
+    };
+}
+
+d_inverse!(
+    i010_cl_to_rgb10,
+    YuvSourceChannels::Rgb,
+    "I010",
+    "RGB10",
+    10
+);
+d_inverse!(
+    i010_cl_to_rgba10,
+    YuvSourceChannels::Rgba,
+    "I010",
+    "RGBA10",
+    10
+);
+d_inverse!(
+    i012_cl_to_rgb12,
+    YuvSourceChannels::Rgb,
+    "I012",
+    "RGB12",
+    12
+);
+d_inverse!(
+    i012_cl_to_rgba12,
+    YuvSourceChannels::Rgba,
+    "I012",
+    "RGBA12",
+    12
+);
+
+macro_rules! d_inverse_nv {
+    ($method: ident, $px_fmt: expr, $yuv_name: expr, $rgb_name: expr, $bit_depth: expr) => {
+        #[doc = concat!("Convert ", $yuv_name, " BT.2020 constant luminance format to ", $rgb_name, " format.
+
+This function performs ", $yuv_name, " to ", $rgb_name, " conversion with constant luminance semantics
+as specified in ITU-R BT.2020 ( matrix coefficients 10 in H.273 ).
+Values are expected in most significant bits, as ", $yuv_name, " stores them.
+
+# Arguments
+
+* `bi_planar_image` - Source ", $yuv_name, " bi-planar image.
+* `rgba` - A mutable slice to store the converted ", $rgb_name, " data, BT.2020 transfer encoded.
+* `rgba_stride` - The stride (components per row) for the ", $rgb_name, " image data.
+* `range` - The YUV range (limited or full).
+
+# Errors
+
+This function returns an error if the lengths of the planes or the output ", $rgb_name, " data are not valid based
+on the specified width, height, and strides.
+")]
+        pub fn $method(
+            bi_planar_image: &YuvBiPlanarImage<u16>,
+            rgba: &mut [u16],
+            rgba_stride: u32,
+            range: YuvRange,
+        ) -> Result<(), YuvError> {
+            yuv_nv_cl_to_rgb16::<
+                { $px_fmt as u8 },
+                { YuvChromaSubsampling::Yuv420 as u8 },
+                { YuvEndianness::LittleEndian as u8 },
+                { YuvBytesPacking::MostSignificantBytes as u8 },
+                $bit_depth,
+            >(bi_planar_image, rgba, rgba_stride, range)
+        }
+    };
+}
+
+d_inverse_nv!(
+    p010_cl_to_rgb10,
+    YuvSourceChannels::Rgb,
+    "P010",
+    "RGB10",
+    10
+);
+d_inverse_nv!(
+    p010_cl_to_rgba10,
+    YuvSourceChannels::Rgba,
+    "P010",
+    "RGBA10",
+    10
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bt2020_cl_round_trip() {
+        let width = 33usize;
+        let height = 17usize;
+        let rgb = [612u16, 418u16, 257u16].repeat(width * height);
+
+        let mut planar_image = YuvPlanarImageMut::<u16>::alloc(
+            width as u32,
+            height as u32,
+            YuvChromaSubsampling::Yuv420,
+        );
+        rgb10_to_i010_cl(&mut planar_image, &rgb, width as u32 * 3, YuvRange::Limited).unwrap();
+        let mut decoded = vec![0u16; width * height * 3];
+        i010_cl_to_rgb10(
+            &planar_image.to_fixed(),
+            &mut decoded,
+            width as u32 * 3,
+            YuvRange::Limited,
+        )
+        .unwrap();
+        for (&src, &dst) in rgb.iter().zip(decoded.iter()) {
+            assert!(
+                (src as i32 - dst as i32).abs() <= 3,
+                "Original {src}, Round-tripped {dst}"
+            );
+        }
+
+        let mut bi_planar_image = YuvBiPlanarImageMut::<u16>::alloc(
+            width as u32,
+            height as u32,
+            YuvChromaSubsampling::Yuv420,
+        );
+        rgb10_to_p010_cl(&mut bi_planar_image, &rgb, width as u32 * 3, YuvRange::Full).unwrap();
+        decoded.fill(0);
+        p010_cl_to_rgb10(
+            &bi_planar_image.to_fixed(),
+            &mut decoded,
+            width as u32 * 3,
+            YuvRange::Full,
+        )
+        .unwrap();
+        for (&src, &dst) in rgb.iter().zip(decoded.iter()) {
+            assert!(
+                (src as i32 - dst as i32).abs() <= 3,
+                "Original {src}, Round-tripped {dst}"
+            );
+        }
+    }
+
+    fn encode_2x2(rgb: &[u16]) -> YuvPlanarImageMut<'static, u16> {
+        let mut image = YuvPlanarImageMut::<u16>::alloc(2, 2, YuvChromaSubsampling::Yuv420);
+        rgb10_to_i010_cl(&mut image, rgb, 2 * 3, YuvRange::Limited).unwrap();
+        image
+    }
+
+    #[test]
+    fn test_bt2020_cl_known_values() {
+        // Hand computed from BT.2020 with Table 4 divisors, limited range 10-bit.
+        // Saturated primaries land on +/-0.5, exercising both sign branches of Cb and Cr.
+        for (rgb, expected) in [
+            ([0u16, 0, 1023], [247u16, 960, 403]),
+            ([1023, 0, 0], [505, 280, 960]),
+            ([1023, 1023, 0], [914, 64, 539]),
+        ] {
+            let image = encode_2x2(&rgb.repeat(4));
+            let y = image.y_plane.borrow()[0];
+            let u = image.u_plane.borrow()[0];
+            let v = image.v_plane.borrow()[0];
+            for (value, expected) in [y, u, v].into_iter().zip(expected) {
+                assert!(
+                    value.abs_diff(expected) <= 1,
+                    "{rgb:?}: {value} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_bt2020_cl_chroma_averaging() {
+        // Blue left column, red right column, chroma is mean of unquantized Cb, Cr
+        let blue = [0u16, 0, 1023];
+        let red = [1023u16, 0, 0];
+        let rgb = [blue, red, blue, red].concat();
+        let image = encode_2x2(&rgb);
+        let y_plane = image.y_plane.borrow();
+        assert!(y_plane[0].abs_diff(247) <= 1 && y_plane[1].abs_diff(505) <= 1);
+        assert!(image.u_plane.borrow()[0].abs_diff(620) <= 1);
+        assert!(image.v_plane.borrow()[0].abs_diff(682) <= 1);
+    }
+}
@@ -0,0 +1,539 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::{IctCpTransfer, SharpYuvGammaTransfer, YuvBias, YuvRange, YuvStandardMatrix};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Colour primaries as defined in ITU-T H.273 table 2
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorPrimaries {
+    /// 1: BT.709, sRGB, BT.1361
+    Bt709 = 1,
+    /// 2: Unspecified, image characteristics are unknown
+    Unspecified = 2,
+    /// 4: BT.470 System M, FCC Title 47
+    Bt470M = 4,
+    /// 5: BT.470 System B, G, BT.601 625 lines
+    Bt470Bg = 5,
+    /// 6: BT.601 525 lines, SMPTE 170M
+    Bt601 = 6,
+    /// 7: SMPTE 240M, same as code point 6
+    Smpte240 = 7,
+    /// 8: Generic film, colour filters using Illuminant C
+    GenericFilm = 8,
+    /// 9: BT.2020, BT.2100
+    Bt2020 = 9,
+    /// 10: SMPTE ST 428-1, CIE 1931 XYZ
+    Xyz = 10,
+    /// 11: SMPTE RP 431-2, DCI-P3
+    Smpte431 = 11,
+    /// 12: SMPTE EG 432-1, Display P3
+    Smpte432 = 12,
+    /// 22: EBU Tech. 3213-E
+    Ebu3213 = 22,
+}
+
+/// Transfer characteristics as defined in ITU-T H.273 table 3
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TransferCharacteristics {
+    /// 1: BT.709, BT.1361 conventional colour gamut
+    Bt709 = 1,
+    /// 2: Unspecified, image characteristics are unknown
+    Unspecified = 2,
+    /// 4: BT.470 System M, assumed display gamma 2.2
+    Bt470M = 4,
+    /// 5: BT.470 System B, G, assumed display gamma 2.8
+    Bt470Bg = 5,
+    /// 6: BT.601, SMPTE 170M, functionally the same as code point 1
+    Bt601 = 6,
+    /// 7: SMPTE 240M
+    Smpte240 = 7,
+    /// 8: Linear transfer characteristics
+    Linear = 8,
+    /// 9: Logarithmic, 100:1 range
+    Log100 = 9,
+    /// 10: Logarithmic, 100 * Sqrt(10):1 range
+    Log100Sqrt10 = 10,
+    /// 11: IEC 61966-2-4, xvYCC
+    Iec61966 = 11,
+    /// 12: BT.1361 extended colour gamut
+    Bt1361 = 12,
+    /// 13: IEC 61966-2-1, sRGB and sYCC
+    Srgb = 13,
+    /// 14: BT.2020 10 bit, functionally the same as code point 1
+    Bt2020_10 = 14,
+    /// 15: BT.2020 12 bit, functionally the same as code point 1
+    Bt2020_12 = 15,
+    /// 16: SMPTE ST 2084, BT.2100 perceptual quantization (PQ)
+    Smpte2084 = 16,
+    /// 17: SMPTE ST 428-1
+    Smpte428 = 17,
+    /// 18: ARIB STD-B67, BT.2100 hybrid log-gamma (HLG)
+    Hlg = 18,
+}
+
+/// Matrix coefficients as defined in ITU-T H.273 table 4
+#[repr(u8)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatrixCoefficients {
+    /// 0: Identity, GBR, IEC 61966-2-1 sRGB
+    Identity = 0,
+    /// 1: BT.709, BT.1361 conventional colour gamut
+    Bt709 = 1,
+    /// 2: Unspecified, image characteristics are unknown
+    Unspecified = 2,
+    /// 4: FCC Title 47, Kr = 0.30, Kb = 0.11
+    Fcc = 4,
+    /// 5: BT.470 System B, G, BT.601 625 lines, functionally the same as code point 6
+    Bt470Bg = 5,
+    /// 6: BT.601 525 lines, SMPTE 170M
+    Bt601 = 6,
+    /// 7: SMPTE 240M
+    Smpte240 = 7,
+    /// 8: YCgCo
+    YCgCo = 8,
+    /// 9: BT.2020 non-constant luminance, BT.2100 YCbCr
+    Bt2020Ncl = 9,
+    /// 10: BT.2020 constant luminance
+    Bt2020Cl = 10,
+    /// 11: SMPTE ST 2085, Y'D'zD'x
+    Smpte2085 = 11,
+    /// 12: Chromaticity derived non-constant luminance
+    ChromaticityDerivedNcl = 12,
+    /// 13: Chromaticity derived constant luminance
+    ChromaticityDerivedCl = 13,
+    /// 14: BT.2100 ICtCp
+    ICtCp = 14,
+    /// 15: SMPTE ST 2128, IPT-C2
+    IptC2 = 15,
+    /// 16: YCgCo-Re, reversible with even bit-depth increase
+    YCgCoRe = 16,
+    /// 17: YCgCo-Ro, reversible with odd bit-depth increase
+    YCgCoRo = 17,
+}
+
+/// Error returned when ITU-T H.273 code points can't be mapped to a colour description
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ColorDescriptionError {
+    /// Colour primaries code point is reserved by H.273
+    ReservedColorPrimaries(u8),
+    /// Transfer characteristics code point is reserved by H.273
+    ReservedTransferCharacteristics(u8),
+    /// Matrix coefficients code point is reserved by H.273
+    ReservedMatrixCoefficients(u8),
+    /// Matrix coefficients can't be expressed as [YuvStandardMatrix]
+    UnsupportedMatrixCoefficients(MatrixCoefficients),
+    /// Matrix coefficients are derived from colour primaries, but primaries do not define chromaticities
+    UndefinedChromaticities(ColorPrimaries),
+}
+
+impl Display for ColorDescriptionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColorDescriptionError::ReservedColorPrimaries(code) => {
+                f.write_fmt(format_args!("Colour primaries code {} is reserved", code))
+            }
+            ColorDescriptionError::ReservedTransferCharacteristics(code) => f.write_fmt(
+                format_args!("Transfer characteristics code {} is reserved", code),
+            ),
+            ColorDescriptionError::ReservedMatrixCoefficients(code) => f.write_fmt(format_args!(
+                "Matrix coefficients code {} is reserved",
+                code
+            )),
+            ColorDescriptionError::UnsupportedMatrixCoefficients(matrix) => f.write_fmt(
+                format_args!("Matrix coefficients {:?} is not a YCbCr matrix", matrix),
+            ),
+            ColorDescriptionError::UndefinedChromaticities(primaries) => f.write_fmt(format_args!(
+                "Colour primaries {:?} do not define chromaticities",
+                primaries
+            )),
+        }
+    }
+}
+
+impl Error for ColorDescriptionError {}
+
+impl TryFrom<u8> for ColorPrimaries {
+    type Error = ColorDescriptionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(ColorPrimaries::Bt709),
+            2 => Ok(ColorPrimaries::Unspecified),
+            4 => Ok(ColorPrimaries::Bt470M),
+            5 => Ok(ColorPrimaries::Bt470Bg),
+            6 => Ok(ColorPrimaries::Bt601),
+            7 => Ok(ColorPrimaries::Smpte240),
+            8 => Ok(ColorPrimaries::GenericFilm),
+            9 => Ok(ColorPrimaries::Bt2020),
+            10 => Ok(ColorPrimaries::Xyz),
+            11 => Ok(ColorPrimaries::Smpte431),
+            12 => Ok(ColorPrimaries::Smpte432),
+            22 => Ok(ColorPrimaries::Ebu3213),
+            _ => Err(ColorDescriptionError::ReservedColorPrimaries(value)),
+        }
+    }
+}
+
+impl TryFrom<u8> for TransferCharacteristics {
+    type Error = ColorDescriptionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(TransferCharacteristics::Bt709),
+            2 => Ok(TransferCharacteristics::Unspecified),
+            4 => Ok(TransferCharacteristics::Bt470M),
+            5 => Ok(TransferCharacteristics::Bt470Bg),
+            6 => Ok(TransferCharacteristics::Bt601),
+            7 => Ok(TransferCharacteristics::Smpte240),
+            8 => Ok(TransferCharacteristics::Linear),
+            9 => Ok(TransferCharacteristics::Log100),
+            10 => Ok(TransferCharacteristics::Log100Sqrt10),
+            11 => Ok(TransferCharacteristics::Iec61966),
+            12 => Ok(TransferCharacteristics::Bt1361),
+            13 => Ok(TransferCharacteristics::Srgb),
+            14 => Ok(TransferCharacteristics::Bt2020_10),
+            15 => Ok(TransferCharacteristics::Bt2020_12),
+            16 => Ok(TransferCharacteristics::Smpte2084),
+            17 => Ok(TransferCharacteristics::Smpte428),
+            18 => Ok(TransferCharacteristics::Hlg),
+            _ => Err(ColorDescriptionError::ReservedTransferCharacteristics(
+                value,
+            )),
+        }
+    }
+}
+
+impl TryFrom<u8> for MatrixCoefficients {
+    type Error = ColorDescriptionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(MatrixCoefficients::Identity),
+            1 => Ok(MatrixCoefficients::Bt709),
+            2 => Ok(MatrixCoefficients::Unspecified),
+            4 => Ok(MatrixCoefficients::Fcc),
+            5 => Ok(MatrixCoefficients::Bt470Bg),
+            6 => Ok(MatrixCoefficients::Bt601),
+            7 => Ok(MatrixCoefficients::Smpte240),
+            8 => Ok(MatrixCoefficients::YCgCo),
+            9 => Ok(MatrixCoefficients::Bt2020Ncl),
+            10 => Ok(MatrixCoefficients::Bt2020Cl),
+            11 => Ok(MatrixCoefficients::Smpte2085),
+            12 => Ok(MatrixCoefficients::ChromaticityDerivedNcl),
+            13 => Ok(MatrixCoefficients::ChromaticityDerivedCl),
+            14 => Ok(MatrixCoefficients::ICtCp),
+            15 => Ok(MatrixCoefficients::IptC2),
+            16 => Ok(MatrixCoefficients::YCgCoRe),
+            17 => Ok(MatrixCoefficients::YCgCoRo),
+            _ => Err(ColorDescriptionError::ReservedMatrixCoefficients(value)),
+        }
+    }
+}
+
+/// CIE 1931 xy chromaticity coordinates
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct Chromaticity {
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Chromaticities of red, green, blue primaries and white point
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct ColorPrimariesChromaticities {
+    pub red: Chromaticity,
+    pub green: Chromaticity,
+    pub blue: Chromaticity,
+    pub white: Chromaticity,
+}
+
+const fn xy(x: f32, y: f32) -> Chromaticity {
+    Chromaticity { x, y }
+}
+
+const WHITE_D65: Chromaticity = xy(0.3127, 0.3290);
+const WHITE_C: Chromaticity = xy(0.310, 0.316);
+
+impl ColorPrimaries {
+    /// Returns chromaticities of the primaries, `None` if primaries are unspecified
+    pub const fn chromaticities(self) -> Option<ColorPrimariesChromaticities> {
+        let (red, green, blue, white) = match self {
+            ColorPrimaries::Bt709 => (xy(0.64, 0.33), xy(0.30, 0.60), xy(0.15, 0.06), WHITE_D65),
+            ColorPrimaries::Unspecified => return None,
+            ColorPrimaries::Bt470M => (xy(0.67, 0.33), xy(0.21, 0.71), xy(0.14, 0.08), WHITE_C),
+            ColorPrimaries::Bt470Bg => (xy(0.64, 0.33), xy(0.29, 0.60), xy(0.15, 0.06), WHITE_D65),
+            ColorPrimaries::Bt601 | ColorPrimaries::Smpte240 => (
+                xy(0.630, 0.340),
+                xy(0.310, 0.595),
+                xy(0.155, 0.070),
+                WHITE_D65,
+            ),
+            ColorPrimaries::GenericFilm => (
+                xy(0.681, 0.319),
+                xy(0.243, 0.692),
+                xy(0.145, 0.049),
+                WHITE_C,
+            ),
+            ColorPrimaries::Bt2020 => (
+                xy(0.708, 0.292),
+                xy(0.170, 0.797),
+                xy(0.131, 0.046),
+                WHITE_D65,
+            ),
+            ColorPrimaries::Xyz => (
+                xy(1.0, 0.0),
+                xy(0.0, 1.0),
+                xy(0.0, 0.0),
+                xy(1.0 / 3.0, 1.0 / 3.0),
+            ),
+            ColorPrimaries::Smpte431 => (
+                xy(0.680, 0.320),
+                xy(0.265, 0.690),
+                xy(0.150, 0.060),
+                xy(0.314, 0.351),
+            ),
+            ColorPrimaries::Smpte432 => (
+                xy(0.680, 0.320),
+                xy(0.265, 0.690),
+                xy(0.150, 0.060),
+                WHITE_D65,
+            ),
+            ColorPrimaries::Ebu3213 => (
+                xy(0.630, 0.340),
+                xy(0.295, 0.605),
+                xy(0.155, 0.077),
+                WHITE_D65,
+            ),
+        };
+        Some(ColorPrimariesChromaticities {
+            red,
+            green,
+            blue,
+            white,
+        })
+    }
+}
+
+impl ColorPrimariesChromaticities {
+    /// Computes Kr and Kb from chromaticities, as defined in ITU-T H.273 equations 39-44
+    pub fn get_kr_kb(&self) -> YuvBias {
+        let (xr, yr) = (self.red.x as f64, self.red.y as f64);
+        let (xg, yg) = (self.green.x as f64, self.green.y as f64);
+        let (xb, yb) = (self.blue.x as f64, self.blue.y as f64);
+        let (xw, yw) = (self.white.x as f64, self.white.y as f64);
+        let zr = 1. - (xr + yr);
+        let zg = 1. - (xg + yg);
+        let zb = 1. - (xb + yb);
+        let zw = 1. - (xw + yw);
+
+        let denominator =
+            yw * (xr * (yg * zb - yb * zg) + xg * (yb * zr - yr * zb) + xb * (yr * zg - yg * zr));
+        let kr = yr
+            * (xw * (yg * zb - yb * zg) + yw * (xb * zg - xg * zb) + zw * (xg * yb - xb * yg))
+            / denominator;
+        let kb = yb
+            * (xw * (yr * zg - yg * zr) + yw * (xg * zr - xr * zg) + zw * (xr * yg - xg * yr))
+            / denominator;
+        YuvBias {
+            kr: kr as f32,
+            kb: kb as f32,
+        }
+    }
+}
+
+impl TransferCharacteristics {
+    /// Returns matching transfer function usable for Sharp YUV, if any
+    pub const fn sharp_yuv_transfer(self) -> Option<SharpYuvGammaTransfer> {
+        match self {
+            TransferCharacteristics::Bt709
+            | TransferCharacteristics::Bt601
+            | TransferCharacteristics::Bt2020_10
+            | TransferCharacteristics::Bt2020_12 => Some(SharpYuvGammaTransfer::Rec709),
+            TransferCharacteristics::Srgb | TransferCharacteristics::Iec61966 => {
+                Some(SharpYuvGammaTransfer::Srgb)
+            }
+            TransferCharacteristics::Bt470M => Some(SharpYuvGammaTransfer::Gamma2p2),
+            TransferCharacteristics::Bt470Bg => Some(SharpYuvGammaTransfer::Gamma2p8),
+            _ => None,
+        }
+    }
+
+    /// Returns matching ICtCp transfer function, if any
+    pub const fn ictcp_transfer(self) -> Option<IctCpTransfer> {
+        match self {
+            TransferCharacteristics::Smpte2084 => Some(IctCpTransfer::Pq),
+            TransferCharacteristics::Hlg => Some(IctCpTransfer::Hlg),
+            _ => None,
+        }
+    }
+}
+
+/// Colour description as signalled by ITU-T H.273 code points,
+/// e.g. in AV1 sequence header, HEVC VUI or AVIF `colr` nclx box
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ColorDescription {
+    pub color_primaries: ColorPrimaries,
+    pub transfer_characteristics: TransferCharacteristics,
+    pub matrix_coefficients: MatrixCoefficients,
+    pub full_range: bool,
+}
+
+impl ColorDescription {
+    /// Parses H.273 code points
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of code points is reserved.
+    pub fn from_codes(
+        color_primaries: u8,
+        transfer_characteristics: u8,
+        matrix_coefficients: u8,
+        full_range: bool,
+    ) -> Result<Self, ColorDescriptionError> {
+        Ok(ColorDescription {
+            color_primaries: color_primaries.try_into()?,
+            transfer_characteristics: transfer_characteristics.try_into()?,
+            matrix_coefficients: matrix_coefficients.try_into()?,
+            full_range,
+        })
+    }
+
+    /// Returns YUV range declared by full range flag
+    pub const fn range(&self) -> YuvRange {
+        if self.full_range {
+            YuvRange::Full
+        } else {
+            YuvRange::Limited
+        }
+    }
+
+    /// Returns Kr and Kb for matrix coefficients.
+    ///
+    /// For chromaticity derived matrices (12 and 13) coefficients are computed from colour primaries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if matrix is not defined by Kr and Kb
+    /// or if primaries do not define chromaticities for derived matrices.
+    pub fn get_kr_kb(&self) -> Result<YuvBias, ColorDescriptionError> {
+        match self.matrix_coefficients {
+            MatrixCoefficients::Bt709 => Ok(YuvStandardMatrix::Bt709.get_kr_kb()),
+            MatrixCoefficients::Fcc => Ok(YuvBias {
+                kr: 0.30f32,
+                kb: 0.11f32,
+            }),
+            MatrixCoefficients::Bt470Bg | MatrixCoefficients::Bt601 => {
+                Ok(YuvStandardMatrix::Bt601.get_kr_kb())
+            }
+            MatrixCoefficients::Smpte240 => Ok(YuvStandardMatrix::Smpte240.get_kr_kb()),
+            MatrixCoefficients::Bt2020Ncl | MatrixCoefficients::Bt2020Cl => {
+                Ok(YuvStandardMatrix::Bt2020.get_kr_kb())
+            }
+            MatrixCoefficients::ChromaticityDerivedNcl
+            | MatrixCoefficients::ChromaticityDerivedCl => self
+                .color_primaries
+                .chromaticities()
+                .map(|chromaticities| chromaticities.get_kr_kb())
+                .ok_or(ColorDescriptionError::UndefinedChromaticities(
+                    self.color_primaries,
+                )),
+            _ => Err(ColorDescriptionError::UnsupportedMatrixCoefficients(
+                self.matrix_coefficients,
+            )),
+        }
+    }
+
+    /// Maps matrix coefficients to [YuvStandardMatrix] for non-constant luminance YCbCr conversions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for matrices that are not non-constant luminance YCbCr
+    /// ( Identity, YCgCo, constant luminance, ICtCp etc. ) and unspecified matrix.
+    pub fn yuv_matrix(&self) -> Result<YuvStandardMatrix, ColorDescriptionError> {
+        match self.matrix_coefficients {
+            MatrixCoefficients::Bt709 => Ok(YuvStandardMatrix::Bt709),
+            MatrixCoefficients::Bt470Bg | MatrixCoefficients::Bt601 => Ok(YuvStandardMatrix::Bt601),
+            MatrixCoefficients::Smpte240 => Ok(YuvStandardMatrix::Smpte240),
+            MatrixCoefficients::Bt2020Ncl => Ok(YuvStandardMatrix::Bt2020),
+            MatrixCoefficients::Fcc | MatrixCoefficients::ChromaticityDerivedNcl => {
+                let bias = self.get_kr_kb()?;
+                Ok(YuvStandardMatrix::Custom(bias.kr, bias.kb))
+            }
+            _ => Err(ColorDescriptionError::UnsupportedMatrixCoefficients(
+                self.matrix_coefficients,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chromaticity_derived_matrix() {
+        let description = ColorDescription::from_codes(9, 16, 12, false).unwrap();
+        let bias = description.get_kr_kb().unwrap();
+        let reference = YuvStandardMatrix::Bt2020.get_kr_kb();
+        assert!((bias.kr - reference.kr).abs() < 1e-3, "kr {}", bias.kr);
+        assert!((bias.kb - reference.kb).abs() < 1e-3, "kb {}", bias.kb);
+        assert_eq!(description.range(), YuvRange::Limited);
+
+        let description = ColorDescription::from_codes(1, 13, 12, true).unwrap();
+        let bias = description.get_kr_kb().unwrap();
+        let reference = YuvStandardMatrix::Bt709.get_kr_kb();
+        assert!((bias.kr - reference.kr).abs() < 1e-3, "kr {}", bias.kr);
+        assert!((bias.kb - reference.kb).abs() < 1e-3, "kb {}", bias.kb);
+    }
+
+    #[test]
+    fn test_unsupported_descriptions() {
+        assert_eq!(
+            ColorDescription::from_codes(3, 1, 1, false),
+            Err(ColorDescriptionError::ReservedColorPrimaries(3))
+        );
+        let description = ColorDescription::from_codes(2, 2, 12, false).unwrap();
+        assert_eq!(
+            description.yuv_matrix(),
+            Err(ColorDescriptionError::UndefinedChromaticities(
+                ColorPrimaries::Unspecified
+            ))
+        );
+        let description = ColorDescription::from_codes(1, 1, 0, true).unwrap();
+        assert_eq!(
+            description.yuv_matrix(),
+            Err(ColorDescriptionError::UnsupportedMatrixCoefficients(
+                MatrixCoefficients::Identity
+            ))
+        );
+    }
+}
@@ -0,0 +1,1278 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::{check_bit_depth, check_rgba_destination};
+use crate::yuv_support::{get_yuv_range, YuvChromaSubsampling, YuvNVOrder, YuvSourceChannels};
+use crate::{
+    yuv420_to_bgr, yuv420_to_bgra, yuv420_to_rgb, yuv420_to_rgba, yuv422_to_bgr, yuv422_to_bgra,
+    yuv422_to_rgb, yuv422_to_rgba, yuv444_to_bgr, yuv444_to_bgra, yuv444_to_rgb, yuv444_to_rgba,
+    yuv_nv12_to_bgr, yuv_nv12_to_bgra, yuv_nv12_to_rgb, yuv_nv12_to_rgba, yuv_nv16_to_bgr,
+    yuv_nv16_to_bgra, yuv_nv16_to_rgb, yuv_nv16_to_rgba, yuv_nv21_to_bgr, yuv_nv21_to_bgra,
+    yuv_nv21_to_rgb, yuv_nv21_to_rgba, yuv_nv24_to_bgr, yuv_nv24_to_bgra, yuv_nv24_to_rgb,
+    yuv_nv24_to_rgba, yuv_nv42_to_bgr, yuv_nv42_to_bgra, yuv_nv42_to_rgb, yuv_nv42_to_rgba,
+    yuv_nv61_to_bgr, yuv_nv61_to_bgra, yuv_nv61_to_rgb, yuv_nv61_to_rgba, YuvBiPlanarImage,
+    YuvBiPlanarImageMut, YuvConversionMode, YuvError, YuvPlanarImage, YuvPlanarImageMut, YuvRange,
+    YuvStandardMatrix,
+};
+use num_traits::AsPrimitive;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+const PRECISION: i32 = 13;
+
+/// Rows of YUV adjusted at once by fused decoders, multiple of any vertical subsampling
+const STRIP_HEIGHT: usize = 16;
+
+/// Brightness, contrast, saturation and hue adjustment done in YCbCr domain.
+///
+/// Luma is scaled around mid grey and offset, chroma is rotated and scaled.
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct YuvAdjustment {
+    /// Offset added to luma as a fraction of nominal luma range, 0 keeps luma unchanged
+    pub brightness: f32,
+    /// Luma gain around mid grey, 1 keeps luma unchanged
+    pub contrast: f32,
+    /// Chroma gain, 0 produces gray image and 1 keeps chroma unchanged
+    pub saturation: f32,
+    /// Chroma rotation in degrees
+    pub hue: f32,
+}
+
+impl Default for YuvAdjustment {
+    fn default() -> Self {
+        YuvAdjustment {
+            brightness: 0f32,
+            contrast: 1f32,
+            saturation: 1f32,
+            hue: 0f32,
+        }
+    }
+}
+
+impl YuvAdjustment {
+    /// Chroma rotation and scale as `[cos, sin]` multiplied by saturation
+    fn chroma_rotation(&self) -> [f32; 2] {
+        let (sin, cos) = self.hue.to_radians().sin_cos();
+        [cos * self.saturation, sin * self.saturation]
+    }
+
+    /// Builds colour matrix doing the same adjustment on full range RGB
+    /// which is encoded to YCbCr with `matrix`.
+    ///
+    /// Matrix is exact up to clamping, so it may be used to apply identical
+    /// adjustment to RGB buffers with [rgba_color_matrix] and friends.
+    pub fn to_rgb_color_matrix(&self, matrix: YuvStandardMatrix) -> RgbColorMatrix {
+        let bias = matrix.get_kr_kb();
+        let (kr, kb) = (bias.kr, bias.kb);
+        let kg = 1f32 - kr - kb;
+        let forward = [
+            [kr, kg, kb],
+            [
+                -kr / (2f32 * (1f32 - kb)),
+                -kg / (2f32 * (1f32 - kb)),
+                0.5f32,
+            ],
+            [
+                0.5f32,
+                -kg / (2f32 * (1f32 - kr)),
+                -kb / (2f32 * (1f32 - kr)),
+            ],
+        ];
+        let inverse = [
+            [1f32, 0f32, 2f32 * (1f32 - kr)],
+            [
+                1f32,
+                -2f32 * (1f32 - kb) * kb / kg,
+                -2f32 * (1f32 - kr) * kr / kg,
+            ],
+            [1f32, 2f32 * (1f32 - kb), 0f32],
+        ];
+        let [cos, sin] = self.chroma_rotation();
+        let adjust = [
+            [self.contrast, 0f32, 0f32],
+            [0f32, cos, -sin],
+            [0f32, sin, cos],
+        ];
+        let luma_offset = 0.5f32 * (1f32 - self.contrast) + self.brightness;
+
+        let mut rgb_matrix = RgbColorMatrix::default();
+        for (i, row) in rgb_matrix.matrix.iter_mut().take(3).enumerate() {
+            for (j, dst) in row.iter_mut().take(3).enumerate() {
+                let mut sum = 0f32;
+                for (k, adjust_row) in adjust.iter().enumerate() {
+                    for (l, forward_row) in forward.iter().enumerate() {
+                        sum += inverse[i][k] * adjust_row[l] * forward_row[j];
+                    }
+                }
+                *dst = sum;
+            }
+            row[4] = inverse[i][0] * luma_offset;
+        }
+        rgb_matrix
+    }
+}
+
+/// Precomputed adjustment for given bit-depth and range
+struct AdjustmentTables<T> {
+    luma: Vec<T>,
+    cos: i32,
+    sin: i32,
+    bias_uv: i32,
+    max: i32,
+}
+
+impl<T: Copy + Send + Sync + 'static + AsPrimitive<i32>> AdjustmentTables<T>
+where
+    i32: AsPrimitive<T>,
+{
+    fn new(adjustment: YuvAdjustment, bit_depth: u32, range: YuvRange) -> Result<Self, YuvError> {
+        check_bit_depth(bit_depth, 8..=16)?;
+        let chroma_range = get_yuv_range(bit_depth, range);
+        let max = (1i32 << bit_depth) - 1;
+        let pivot = chroma_range.bias_y as f32 + chroma_range.range_y as f32 / 2f32;
+        let offset = adjustment.brightness * chroma_range.range_y as f32;
+        let luma = (0..=max)
+            .map(|y| {
+                let adjusted = (y as f32 - pivot) * adjustment.contrast + pivot + offset;
+                (adjusted.round() as i32).clamp(0, max).as_()
+            })
+            .collect();
+        let scale = (1 << PRECISION) as f32;
+        let [cos, sin] = adjustment.chroma_rotation();
+        Ok(AdjustmentTables {
+            luma,
+            cos: (cos * scale).round() as i32,
+            sin: (sin * scale).round() as i32,
+            bias_uv: chroma_range.bias_uv as i32,
+            max,
+        })
+    }
+
+    #[inline]
+    fn adjust_luma(&self, row: &mut [T]) {
+        for y in row.iter_mut() {
+            *y = self.luma[(y.as_()).min(self.max) as usize];
+        }
+    }
+
+    #[inline]
+    fn adjust_cbcr(&self, cb: &mut T, cr: &mut T) {
+        // Saturation is unbounded, so products are accumulated in 64 bits
+        const ROUNDING: i64 = 1 << (PRECISION - 1);
+        let u = (cb.as_() - self.bias_uv) as i64;
+        let v = (cr.as_() - self.bias_uv) as i64;
+        let (cos, sin) = (self.cos as i64, self.sin as i64);
+        let bias = ((self.bias_uv as i64) << PRECISION) + ROUNDING;
+        let new_u = (bias + cos * u - sin * v) >> PRECISION;
+        let new_v = (bias + sin * u + cos * v) >> PRECISION;
+        *cb = (new_u.clamp(0, self.max as i64) as i32).as_();
+        *cr = (new_v.clamp(0, self.max as i64) as i32).as_();
+    }
+
+    fn adjust_planar_chroma(&self, u_row: &mut [T], v_row: &mut [T]) {
+        for (cb, cr) in u_row.iter_mut().zip(v_row.iter_mut()) {
+            self.adjust_cbcr(cb, cr);
+        }
+    }
+
+    fn adjust_interleaved_chroma(&self, uv_row: &mut [T], order: YuvNVOrder) {
+        let (u_pos, v_pos) = (order.get_u_position(), order.get_v_position());
+        for uv in uv_row.chunks_exact_mut(2) {
+            let (mut cb, mut cr) = (uv[u_pos], uv[v_pos]);
+            self.adjust_cbcr(&mut cb, &mut cr);
+            uv[u_pos] = cb;
+            uv[v_pos] = cr;
+        }
+    }
+
+    fn adjust_plane(&self, plane: &mut [T], stride: usize, width: usize, height: usize) {
+        let iter;
+        #[cfg(feature = "rayon")]
+        {
+            iter = plane.par_chunks_mut(stride);
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            iter = plane.chunks_mut(stride);
+        }
+        iter.take(height)
+            .for_each(|row| self.adjust_luma(&mut row[..width]));
+    }
+}
+
+fn adjust_planar_impl<T: Copy + std::fmt::Debug + Send + Sync + 'static + AsPrimitive<i32>>(
+    image: &mut YuvPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    range: YuvRange,
+    adjustment: YuvAdjustment,
+) -> Result<(), YuvError>
+where
+    i32: AsPrimitive<T>,
+{
+    image.check_constraints(subsampling)?;
+    let tables = AdjustmentTables::<T>::new(adjustment, bit_depth, range)?;
+    let width = image.width as usize;
+    let chroma_width = subsampling.chroma_width(width);
+    let chroma_height = subsampling.chroma_height(image.height as usize);
+    tables.adjust_plane(
+        image.y_plane.borrow_mut(),
+        image.y_stride as usize,
+        width,
+        image.height as usize,
+    );
+    let (u_stride, v_stride) = (image.u_stride as usize, image.v_stride as usize);
+    let u_iter;
+    let v_iter;
+    #[cfg(feature = "rayon")]
+    {
+        u_iter = image.u_plane.borrow_mut().par_chunks_mut(u_stride);
+        v_iter = image.v_plane.borrow_mut().par_chunks_mut(v_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        u_iter = image.u_plane.borrow_mut().chunks_mut(u_stride);
+        v_iter = image.v_plane.borrow_mut().chunks_mut(v_stride);
+    }
+    u_iter
+        .zip(v_iter)
+        .take(chroma_height)
+        .for_each(|(u_row, v_row)| {
+            tables.adjust_planar_chroma(&mut u_row[..chroma_width], &mut v_row[..chroma_width])
+        });
+    Ok(())
+}
+
+fn adjust_bi_planar_impl<T: Copy + std::fmt::Debug + Send + Sync + 'static + AsPrimitive<i32>>(
+    image: &mut YuvBiPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    order: YuvNVOrder,
+    bit_depth: u32,
+    range: YuvRange,
+    adjustment: YuvAdjustment,
+) -> Result<(), YuvError>
+where
+    i32: AsPrimitive<T>,
+{
+    image.check_constraints(subsampling)?;
+    let tables = AdjustmentTables::<T>::new(adjustment, bit_depth, range)?;
+    let width = image.width as usize;
+    let chroma_width = subsampling.chroma_width(width);
+    let chroma_height = subsampling.chroma_height(image.height as usize);
+    tables.adjust_plane(
+        image.y_plane.borrow_mut(),
+        image.y_stride as usize,
+        width,
+        image.height as usize,
+    );
+    let uv_stride = image.uv_stride as usize;
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = image.uv_plane.borrow_mut().par_chunks_mut(uv_stride);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = image.uv_plane.borrow_mut().chunks_mut(uv_stride);
+    }
+    iter.take(chroma_height).for_each(|uv_row| {
+        tables.adjust_interleaved_chroma(&mut uv_row[..chroma_width * 2], order)
+    });
+    Ok(())
+}
+
+macro_rules! d_adjust {
+    ($method: ident, $method_p16: ident, $image: ident, $sampling: expr, $yuv_name: expr, $image_name: expr $(, $order: expr)?) => {
+        #[doc = concat!("Applies brightness, contrast, saturation and hue adjustment to 8 bit-depth ", $yuv_name, " ", $image_name, " image in place.
+
+# Arguments
+
+* `image` - Image to adjust.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `adjustment` - Adjustment to apply. See [YuvAdjustment] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            image: &mut $image<u8>,
+            range: YuvRange,
+            adjustment: YuvAdjustment,
+        ) -> Result<(), YuvError> {
+            d_adjust!(@call image, $sampling, 8, range, adjustment $(, $order)?)
+        }
+
+        #[doc = concat!("Applies brightness, contrast, saturation and hue adjustment to high bit-depth ", $yuv_name, " ", $image_name, " image in place.
+
+# Arguments
+
+* `image` - Image to adjust.
+* `bit_depth` - Bit-depth of the image, 8..=16.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `adjustment` - Adjustment to apply. See [YuvAdjustment] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or bit depth is not in 8..=16.")]
+        pub fn $method_p16(
+            image: &mut $image<u16>,
+            bit_depth: u32,
+            range: YuvRange,
+            adjustment: YuvAdjustment,
+        ) -> Result<(), YuvError> {
+            d_adjust!(@call image, $sampling, bit_depth, range, adjustment $(, $order)?)
+        }
+    };
+    (@call $image: ident, $sampling: expr, $bit_depth: expr, $range: ident, $adjustment: ident) => {
+        adjust_planar_impl($image, $sampling, $bit_depth, $range, $adjustment)
+    };
+    (@call $image: ident, $sampling: expr, $bit_depth: expr, $range: ident, $adjustment: ident, $order: expr) => {
+        adjust_bi_planar_impl($image, $sampling, $order, $bit_depth, $range, $adjustment)
+    };
+}
+
+d_adjust!(
+    adjust_yuv420,
+    adjust_yuv420_p16,
+    YuvPlanarImageMut,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420",
+    "planar"
+);
+d_adjust!(
+    adjust_yuv422,
+    adjust_yuv422_p16,
+    YuvPlanarImageMut,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422",
+    "planar"
+);
+d_adjust!(
+    adjust_yuv444,
+    adjust_yuv444_p16,
+    YuvPlanarImageMut,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444",
+    "planar"
+);
+d_adjust!(
+    adjust_nv12,
+    adjust_nv12_p16,
+    YuvBiPlanarImageMut,
+    YuvChromaSubsampling::Yuv420,
+    "NV12",
+    "bi-planar",
+    YuvNVOrder::UV
+);
+d_adjust!(
+    adjust_nv21,
+    adjust_nv21_p16,
+    YuvBiPlanarImageMut,
+    YuvChromaSubsampling::Yuv420,
+    "NV21",
+    "bi-planar",
+    YuvNVOrder::VU
+);
+d_adjust!(
+    adjust_nv16,
+    adjust_nv16_p16,
+    YuvBiPlanarImageMut,
+    YuvChromaSubsampling::Yuv422,
+    "NV16",
+    "bi-planar",
+    YuvNVOrder::UV
+);
+d_adjust!(
+    adjust_nv61,
+    adjust_nv61_p16,
+    YuvBiPlanarImageMut,
+    YuvChromaSubsampling::Yuv422,
+    "NV61",
+    "bi-planar",
+    YuvNVOrder::VU
+);
+d_adjust!(
+    adjust_nv24,
+    adjust_nv24_p16,
+    YuvBiPlanarImageMut,
+    YuvChromaSubsampling::Yuv444,
+    "NV24",
+    "bi-planar",
+    YuvNVOrder::UV
+);
+d_adjust!(
+    adjust_nv42,
+    adjust_nv42_p16,
+    YuvBiPlanarImageMut,
+    YuvChromaSubsampling::Yuv444,
+    "NV42",
+    "bi-planar",
+    YuvNVOrder::VU
+);
+
+/// Copies `rows` of a plane starting at `row` into tightly packed strip
+fn copy_strip(src: &[u8], stride: usize, row: usize, rows: usize, dst: &mut [u8], width: usize) {
+    for (dst, src) in dst
+        .chunks_exact_mut(width)
+        .zip(src.chunks(stride).skip(row))
+        .take(rows)
+    {
+        dst.copy_from_slice(&src[..width]);
+    }
+}
+
+/// Decodes planar image strip by strip, adjusting every strip in cache-resident scratch
+/// right before it's handed to `decoder`
+fn decode_planar_adjusted(
+    image: &YuvPlanarImage<u8>,
+    subsampling: YuvChromaSubsampling,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    channels: usize,
+    range: YuvRange,
+    adjustment: YuvAdjustment,
+    decoder: impl Fn(&YuvPlanarImage<u8>, &mut [u8]) -> Result<(), YuvError>,
+) -> Result<(), YuvError> {
+    image.check_constraints(subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, image.width, image.height, channels)?;
+    let tables = AdjustmentTables::<u8>::new(adjustment, 8, range)?;
+    let width = image.width as usize;
+    let height = image.height as usize;
+    let chroma_width = subsampling.chroma_width(width);
+    let rgba_stride_u = rgba_stride as usize;
+
+    let mut y_strip = vec![0u8; width * STRIP_HEIGHT];
+    let mut u_strip = vec![0u8; chroma_width * STRIP_HEIGHT];
+    let mut v_strip = vec![0u8; chroma_width * STRIP_HEIGHT];
+
+    for row in (0..height).step_by(STRIP_HEIGHT) {
+        let rows = STRIP_HEIGHT.min(height - row);
+        let chroma_row = row / subsampling.vertical_factor();
+        let chroma_rows = subsampling.chroma_height(rows);
+        let y_strip = &mut y_strip[..width * rows];
+        let u_strip = &mut u_strip[..chroma_width * chroma_rows];
+        let v_strip = &mut v_strip[..chroma_width * chroma_rows];
+        copy_strip(
+            image.y_plane,
+            image.y_stride as usize,
+            row,
+            rows,
+            y_strip,
+            width,
+        );
+        copy_strip(
+            image.u_plane,
+            image.u_stride as usize,
+            chroma_row,
+            chroma_rows,
+            u_strip,
+            chroma_width,
+        );
+        copy_strip(
+            image.v_plane,
+            image.v_stride as usize,
+            chroma_row,
+            chroma_rows,
+            v_strip,
+            chroma_width,
+        );
+        tables.adjust_luma(y_strip);
+        tables.adjust_planar_chroma(u_strip, v_strip);
+        let strip = YuvPlanarImage {
+            y_plane: y_strip,
+            y_stride: width as u32,
+            u_plane: u_strip,
+            u_stride: chroma_width as u32,
+            v_plane: v_strip,
+            v_stride: chroma_width as u32,
+            width: image.width,
+            height: rows as u32,
+        };
+        let start = row * rgba_stride_u;
+        let end = (start + rows * rgba_stride_u).min(rgba.len());
+        decoder(&strip, &mut rgba[start..end])?;
+    }
+    Ok(())
+}
+
+/// Bi-planar counterpart of [decode_planar_adjusted]
+fn decode_bi_planar_adjusted(
+    image: &YuvBiPlanarImage<u8>,
+    subsampling: YuvChromaSubsampling,
+    order: YuvNVOrder,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    channels: usize,
+    range: YuvRange,
+    adjustment: YuvAdjustment,
+    decoder: impl Fn(&YuvBiPlanarImage<u8>, &mut [u8]) -> Result<(), YuvError>,
+) -> Result<(), YuvError> {
+    image.check_constraints(subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, image.width, image.height, channels)?;
+    let tables = AdjustmentTables::<u8>::new(adjustment, 8, range)?;
+    let width = image.width as usize;
+    let height = image.height as usize;
+    let uv_width = subsampling.chroma_width(width) * 2;
+    let rgba_stride_u = rgba_stride as usize;
+
+    let mut y_strip = vec![0u8; width * STRIP_HEIGHT];
+    let mut uv_strip = vec![0u8; uv_width * STRIP_HEIGHT];
+
+    for row in (0..height).step_by(STRIP_HEIGHT) {
+        let rows = STRIP_HEIGHT.min(height - row);
+        let chroma_row = row / subsampling.vertical_factor();
+        let chroma_rows = subsampling.chroma_height(rows);
+        let y_strip = &mut y_strip[..width * rows];
+        let uv_strip = &mut uv_strip[..uv_width * chroma_rows];
+        copy_strip(
+            image.y_plane,
+            image.y_stride as usize,
+            row,
+            rows,
+            y_strip,
+            width,
+        );
+        copy_strip(
+            image.uv_plane,
+            image.uv_stride as usize,
+            chroma_row,
+            chroma_rows,
+            uv_strip,
+            uv_width,
+        );
+        tables.adjust_luma(y_strip);
+        tables.adjust_interleaved_chroma(uv_strip, order);
+        let strip = YuvBiPlanarImage {
+            y_plane: y_strip,
+            y_stride: width as u32,
+            uv_plane: uv_strip,
+            uv_stride: uv_width as u32,
+            width: image.width,
+            height: rows as u32,
+        };
+        let start = row * rgba_stride_u;
+        let end = (start + rows * rgba_stride_u).min(rgba.len());
+        decoder(&strip, &mut rgba[start..end])?;
+    }
+    Ok(())
+}
+
+macro_rules! d_planar_adjusted {
+    ($method: ident, $decoder: ident, $sampling: expr, $channels: expr, $yuv_name: expr, $rgb_name: expr) => {
+        #[doc = concat!("Converts ", $yuv_name, " planar 8 bit-depth image to ", $rgb_name, " applying brightness, contrast, saturation and hue adjustment on the way.
+
+Same as [", stringify!($decoder), "] on adjusted image, adjustment is done on small strips of rows
+right before they are decoded so no separate pass over the whole frame is needed.
+
+# Arguments
+
+* `planar_image` - Source planar image.
+* `rgb` - A mutable slice to store the converted ", $rgb_name, " data.
+* `rgb_stride` - The stride (components per row) for ", $rgb_name, " data.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+* `adjustment` - Adjustment to apply. See [YuvAdjustment] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $rgb_name, " data are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            planar_image: &YuvPlanarImage<u8>,
+            rgb: &mut [u8],
+            rgb_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            adjustment: YuvAdjustment,
+        ) -> Result<(), YuvError> {
+            decode_planar_adjusted(
+                planar_image,
+                $sampling,
+                rgb,
+                rgb_stride,
+                $channels.get_channels_count(),
+                range,
+                adjustment,
+                |strip, dst| $decoder(strip, dst, rgb_stride, range, matrix),
+            )
+        }
+    };
+}
+
+d_planar_adjusted!(
+    yuv420_to_rgb_adjusted,
+    yuv420_to_rgb,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    "YUV 420",
+    "RGB"
+);
+d_planar_adjusted!(
+    yuv420_to_bgr_adjusted,
+    yuv420_to_bgr,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Bgr,
+    "YUV 420",
+    "BGR"
+);
+d_planar_adjusted!(
+    yuv420_to_rgba_adjusted,
+    yuv420_to_rgba,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    "YUV 420",
+    "RGBA"
+);
+d_planar_adjusted!(
+    yuv420_to_bgra_adjusted,
+    yuv420_to_bgra,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Bgra,
+    "YUV 420",
+    "BGRA"
+);
+d_planar_adjusted!(
+    yuv422_to_rgb_adjusted,
+    yuv422_to_rgb,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    "YUV 422",
+    "RGB"
+);
+d_planar_adjusted!(
+    yuv422_to_bgr_adjusted,
+    yuv422_to_bgr,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Bgr,
+    "YUV 422",
+    "BGR"
+);
+d_planar_adjusted!(
+    yuv422_to_rgba_adjusted,
+    yuv422_to_rgba,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    "YUV 422",
+    "RGBA"
+);
+d_planar_adjusted!(
+    yuv422_to_bgra_adjusted,
+    yuv422_to_bgra,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Bgra,
+    "YUV 422",
+    "BGRA"
+);
+d_planar_adjusted!(
+    yuv444_to_rgb_adjusted,
+    yuv444_to_rgb,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    "YUV 444",
+    "RGB"
+);
+d_planar_adjusted!(
+    yuv444_to_bgr_adjusted,
+    yuv444_to_bgr,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Bgr,
+    "YUV 444",
+    "BGR"
+);
+d_planar_adjusted!(
+    yuv444_to_rgba_adjusted,
+    yuv444_to_rgba,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    "YUV 444",
+    "RGBA"
+);
+d_planar_adjusted!(
+    yuv444_to_bgra_adjusted,
+    yuv444_to_bgra,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Bgra,
+    "YUV 444",
+    "BGRA"
+);
+
+macro_rules! d_bi_planar_adjusted {
+    ($method: ident, $decoder: ident, $sampling: expr, $order: expr, $channels: expr, $yuv_name: expr, $rgb_name: expr) => {
+        #[doc = concat!("Converts ", $yuv_name, " 8 bit-depth image to ", $rgb_name, " applying brightness, contrast, saturation and hue adjustment on the way.
+
+Same as [", stringify!($decoder), "] on adjusted image, adjustment is done on small strips of rows
+right before they are decoded so no separate pass over the whole frame is needed.
+
+# Arguments
+
+* `bi_planar_image` - Source bi-planar image.
+* `rgb` - A mutable slice to store the converted ", $rgb_name, " data.
+* `rgb_stride` - The stride (components per row) for ", $rgb_name, " data.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+* `mode` - See [YuvConversionMode] for more info.
+* `adjustment` - Adjustment to apply. See [YuvAdjustment] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $rgb_name, " data are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            bi_planar_image: &YuvBiPlanarImage<u8>,
+            rgb: &mut [u8],
+            rgb_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            mode: YuvConversionMode,
+            adjustment: YuvAdjustment,
+        ) -> Result<(), YuvError> {
+            decode_bi_planar_adjusted(
+                bi_planar_image,
+                $sampling,
+                $order,
+                rgb,
+                rgb_stride,
+                $channels.get_channels_count(),
+                range,
+                adjustment,
+                |strip, dst| $decoder(strip, dst, rgb_stride, range, matrix, mode),
+            )
+        }
+    };
+}
+
+d_bi_planar_adjusted!(
+    yuv_nv12_to_rgb_adjusted,
+    yuv_nv12_to_rgb,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Rgb,
+    "NV12",
+    "RGB"
+);
+d_bi_planar_adjusted!(
+    yuv_nv12_to_bgr_adjusted,
+    yuv_nv12_to_bgr,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Bgr,
+    "NV12",
+    "BGR"
+);
+d_bi_planar_adjusted!(
+    yuv_nv12_to_rgba_adjusted,
+    yuv_nv12_to_rgba,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Rgba,
+    "NV12",
+    "RGBA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv12_to_bgra_adjusted,
+    yuv_nv12_to_bgra,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Bgra,
+    "NV12",
+    "BGRA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv21_to_rgb_adjusted,
+    yuv_nv21_to_rgb,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Rgb,
+    "NV21",
+    "RGB"
+);
+d_bi_planar_adjusted!(
+    yuv_nv21_to_bgr_adjusted,
+    yuv_nv21_to_bgr,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Bgr,
+    "NV21",
+    "BGR"
+);
+d_bi_planar_adjusted!(
+    yuv_nv21_to_rgba_adjusted,
+    yuv_nv21_to_rgba,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Rgba,
+    "NV21",
+    "RGBA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv21_to_bgra_adjusted,
+    yuv_nv21_to_bgra,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Bgra,
+    "NV21",
+    "BGRA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv16_to_rgb_adjusted,
+    yuv_nv16_to_rgb,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Rgb,
+    "NV16",
+    "RGB"
+);
+d_bi_planar_adjusted!(
+    yuv_nv16_to_bgr_adjusted,
+    yuv_nv16_to_bgr,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Bgr,
+    "NV16",
+    "BGR"
+);
+d_bi_planar_adjusted!(
+    yuv_nv16_to_rgba_adjusted,
+    yuv_nv16_to_rgba,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Rgba,
+    "NV16",
+    "RGBA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv16_to_bgra_adjusted,
+    yuv_nv16_to_bgra,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Bgra,
+    "NV16",
+    "BGRA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv61_to_rgb_adjusted,
+    yuv_nv61_to_rgb,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Rgb,
+    "NV61",
+    "RGB"
+);
+d_bi_planar_adjusted!(
+    yuv_nv61_to_bgr_adjusted,
+    yuv_nv61_to_bgr,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Bgr,
+    "NV61",
+    "BGR"
+);
+d_bi_planar_adjusted!(
+    yuv_nv61_to_rgba_adjusted,
+    yuv_nv61_to_rgba,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Rgba,
+    "NV61",
+    "RGBA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv61_to_bgra_adjusted,
+    yuv_nv61_to_bgra,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Bgra,
+    "NV61",
+    "BGRA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv24_to_rgb_adjusted,
+    yuv_nv24_to_rgb,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Rgb,
+    "NV24",
+    "RGB"
+);
+d_bi_planar_adjusted!(
+    yuv_nv24_to_bgr_adjusted,
+    yuv_nv24_to_bgr,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Bgr,
+    "NV24",
+    "BGR"
+);
+d_bi_planar_adjusted!(
+    yuv_nv24_to_rgba_adjusted,
+    yuv_nv24_to_rgba,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Rgba,
+    "NV24",
+    "RGBA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv24_to_bgra_adjusted,
+    yuv_nv24_to_bgra,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::UV,
+    YuvSourceChannels::Bgra,
+    "NV24",
+    "BGRA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv42_to_rgb_adjusted,
+    yuv_nv42_to_rgb,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Rgb,
+    "NV42",
+    "RGB"
+);
+d_bi_planar_adjusted!(
+    yuv_nv42_to_bgr_adjusted,
+    yuv_nv42_to_bgr,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Bgr,
+    "NV42",
+    "BGR"
+);
+d_bi_planar_adjusted!(
+    yuv_nv42_to_rgba_adjusted,
+    yuv_nv42_to_rgba,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Rgba,
+    "NV42",
+    "RGBA"
+);
+d_bi_planar_adjusted!(
+    yuv_nv42_to_bgra_adjusted,
+    yuv_nv42_to_bgra,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::VU,
+    YuvSourceChannels::Bgra,
+    "NV42",
+    "BGRA"
+);
+
+/// 4x5 colour matrix applied to RGBA pixels.
+///
+/// Rows produce R, G, B, A; columns weight source R, G, B, A and the last one
+/// is an offset as a fraction of maximum value. Images without alpha
+/// treat source alpha as opaque and ignore alpha row.
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct RgbColorMatrix {
+    pub matrix: [[f32; 5]; 4],
+}
+
+impl Default for RgbColorMatrix {
+    fn default() -> Self {
+        RgbColorMatrix {
+            matrix: [
+                [1f32, 0f32, 0f32, 0f32, 0f32],
+                [0f32, 1f32, 0f32, 0f32, 0f32],
+                [0f32, 0f32, 1f32, 0f32, 0f32],
+                [0f32, 0f32, 0f32, 1f32, 0f32],
+            ],
+        }
+    }
+}
+
+fn color_matrix_impl<T: Copy + Send + Sync + 'static + AsPrimitive<i64>, const CHANNELS: u8>(
+    rgba: &mut [T],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    bit_depth: u32,
+    matrix: RgbColorMatrix,
+) -> Result<(), YuvError>
+where
+    i64: AsPrimitive<T>,
+{
+    check_bit_depth(bit_depth, 8..=16)?;
+    let source_channels: YuvSourceChannels = CHANNELS.into();
+    let channels = source_channels.get_channels_count();
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    let max = (1i64 << bit_depth) - 1;
+    let scale = (1i64 << PRECISION) as f32;
+    let weights = matrix.matrix.map(|row| {
+        [
+            (row[0] * scale).round() as i64,
+            (row[1] * scale).round() as i64,
+            (row[2] * scale).round() as i64,
+            (row[3] * scale).round() as i64,
+            (row[4] * max as f32 * scale).round() as i64 + (1i64 << (PRECISION - 1)),
+        ]
+    });
+    let offsets = [
+        source_channels.get_r_channel_offset(),
+        source_channels.get_g_channel_offset(),
+        source_channels.get_b_channel_offset(),
+    ];
+    let has_alpha = source_channels.has_alpha();
+    let a_offset = if has_alpha {
+        source_channels.get_a_channel_offset()
+    } else {
+        0
+    };
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba.par_chunks_mut(rgba_stride as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba.chunks_mut(rgba_stride as usize);
+    }
+    iter.take(height as usize).for_each(|row| {
+        for px in row[..width as usize * channels].chunks_exact_mut(channels) {
+            let src = [
+                px[offsets[0]].as_(),
+                px[offsets[1]].as_(),
+                px[offsets[2]].as_(),
+                if has_alpha { px[a_offset].as_() } else { max },
+            ];
+            let apply = |w: &[i64; 5]| -> T {
+                let v = w[0] * src[0] + w[1] * src[1] + w[2] * src[2] + w[3] * src[3] + w[4];
+                (v >> PRECISION).clamp(0, max).as_()
+            };
+            px[offsets[0]] = apply(&weights[0]);
+            px[offsets[1]] = apply(&weights[1]);
+            px[offsets[2]] = apply(&weights[2]);
+            if has_alpha {
+                px[a_offset] = apply(&weights[3]);
+            }
+        }
+    });
+    Ok(())
+}
+
+macro_rules! d_color_matrix {
+    ($method: ident, $method_p16: ident, $channels: expr, $rgb_name: expr) => {
+        #[doc = concat!("Applies colour matrix to 8 bit-depth ", $rgb_name, " image in place.
+
+# Arguments
+
+* `rgba` - ", $rgb_name, " image data.
+* `rgba_stride` - The stride (components per row) for ", $rgb_name, " data.
+* `width` - Image width.
+* `height` - Image height.
+* `matrix` - Colour matrix. See [RgbColorMatrix] for more info.
+
+# Errors
+
+This function returns an error if the length of ", $rgb_name, " data is not valid based
+on the specified width, height, and stride.")]
+        pub fn $method(
+            rgba: &mut [u8],
+            rgba_stride: u32,
+            width: u32,
+            height: u32,
+            matrix: RgbColorMatrix,
+        ) -> Result<(), YuvError> {
+            color_matrix_impl::<u8, { $channels as u8 }>(
+                rgba,
+                rgba_stride,
+                width,
+                height,
+                8,
+                matrix,
+            )
+        }
+
+        #[doc = concat!("Applies colour matrix to high bit-depth ", $rgb_name, " image in place.
+
+# Arguments
+
+* `rgba` - ", $rgb_name, " image data.
+* `rgba_stride` - The stride (components per row) for ", $rgb_name, " data.
+* `width` - Image width.
+* `height` - Image height.
+* `bit_depth` - Bit-depth of the image, 8..=16.
+* `matrix` - Colour matrix. See [RgbColorMatrix] for more info.
+
+# Errors
+
+This function returns an error if the length of ", $rgb_name, " data is not valid based
+on the specified width, height, and stride, or bit depth is not in 8..=16.")]
+        pub fn $method_p16(
+            rgba: &mut [u16],
+            rgba_stride: u32,
+            width: u32,
+            height: u32,
+            bit_depth: u32,
+            matrix: RgbColorMatrix,
+        ) -> Result<(), YuvError> {
+            color_matrix_impl::<u16, { $channels as u8 }>(
+                rgba,
+                rgba_stride,
+                width,
+                height,
+                bit_depth,
+                matrix,
+            )
+        }
+    };
+}
+
+d_color_matrix!(
+    rgb_color_matrix,
+    rgb16_color_matrix,
+    YuvSourceChannels::Rgb,
+    "RGB"
+);
+d_color_matrix!(
+    bgr_color_matrix,
+    bgr16_color_matrix,
+    YuvSourceChannels::Bgr,
+    "BGR"
+);
+d_color_matrix!(
+    rgba_color_matrix,
+    rgba16_color_matrix,
+    YuvSourceChannels::Rgba,
+    "RGBA"
+);
+d_color_matrix!(
+    bgra_color_matrix,
+    bgra16_color_matrix,
+    YuvSourceChannels::Bgra,
+    "BGRA"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fused_decode_matches_adjust_then_decode() {
+        let (width, height) = (21u32, 37u32);
+        let mut image = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in image.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (16 + i * 7 % 220) as u8;
+        }
+        for (i, v) in image.u_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (40 + i * 13 % 180) as u8;
+        }
+        for (i, v) in image.v_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (30 + i * 11 % 200) as u8;
+        }
+        let adjustment = YuvAdjustment {
+            brightness: 0.05,
+            contrast: 1.2,
+            saturation: 0.7,
+            hue: 30f32,
+        };
+        let stride = width * 4;
+        let mut fused = vec![0u8; (stride * height) as usize];
+        yuv420_to_rgba_adjusted(
+            &image.to_fixed(),
+            &mut fused,
+            stride,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+            adjustment,
+        )
+        .unwrap();
+
+        adjust_yuv420(&mut image, YuvRange::Limited, adjustment).unwrap();
+        let mut reference = vec![0u8; (stride * height) as usize];
+        yuv420_to_rgba(
+            &image.to_fixed(),
+            &mut reference,
+            stride,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        assert_eq!(fused, reference);
+
+        // Same adjustment as RGB matrix on gray keeps it gray and applies luma curve
+        let mut gray = [128u8, 128, 128, 255];
+        let rgb_matrix = adjustment.to_rgb_color_matrix(YuvStandardMatrix::Bt709);
+        rgba_color_matrix(&mut gray, 4, 1, 1, rgb_matrix).unwrap();
+        let expected = ((128f32 / 255f32 - 0.5) * 1.2 + 0.5 + 0.05) * 255f32;
+        for &c in &gray[..3] {
+            assert!((c as f32 - expected).abs() <= 1f32, "{c} vs {expected}");
+        }
+        assert_eq!(gray[3], 255);
+    }
+
+    #[test]
+    fn rejects_unsupported_bit_depth() {
+        let mut image = YuvPlanarImageMut::<u16>::alloc(4, 4, YuvChromaSubsampling::Yuv420);
+        assert!(matches!(
+            adjust_yuv420_p16(&mut image, 17, YuvRange::Limited, YuvAdjustment::default()),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+        let mut rgb = vec![0u16; 4 * 4 * 3];
+        assert!(matches!(
+            rgb16_color_matrix(&mut rgb, 12, 4, 4, 7, RgbColorMatrix::default()),
+            Err(YuvError::UnsupportedBitDepth(7))
+        ));
+    }
+
+    #[test]
+    fn high_saturation_does_not_overflow() {
+        let adjustment = YuvAdjustment {
+            saturation: 6f32,
+            hue: 30f32,
+            ..YuvAdjustment::default()
+        };
+        let mut image = YuvPlanarImageMut::<u16>::alloc(2, 1, YuvChromaSubsampling::Yuv444);
+        image.u_plane.borrow_mut().copy_from_slice(&[65535, 33768]);
+        image.v_plane.borrow_mut().copy_from_slice(&[0, 33768]);
+        adjust_yuv444_p16(&mut image, 16, YuvRange::Full, adjustment).unwrap();
+
+        let [cos, sin] = adjustment.chroma_rotation().map(|x| x as f64);
+        let expect = |u: f64, v: f64| {
+            [
+                (32768f64 + cos * u - sin * v).round().clamp(0f64, 65535f64),
+                (32768f64 + sin * u + cos * v).round().clamp(0f64, 65535f64),
+            ]
+        };
+        for (i, (u, v)) in [(32767f64, -32768f64), (1000f64, 1000f64)]
+            .into_iter()
+            .enumerate()
+        {
+            let [eu, ev] = expect(u, v);
+            let cb = image.u_plane.borrow()[i] as f64;
+            let cr = image.v_plane.borrow()[i] as f64;
+            assert!((cb - eu).abs() <= 1f64, "{cb} vs {eu}");
+            assert!((cr - ev).abs() <= 1f64, "{cr} vs {ev}");
+        }
+    }
+}
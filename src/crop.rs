@@ -0,0 +1,945 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::images::{
+    BufferStoreMut, YuvBiPlanarImage, YuvBiPlanarImageMut, YuvGrayAlphaImage, YuvGrayImage,
+    YuvGrayImageMut, YuvPackedImage, YuvPackedImageMut, YuvPlanarImage, YuvPlanarImageMut,
+    YuvPlanarImageWithAlpha,
+};
+use crate::yuv_error::YuvError;
+use crate::yuv_support::{YuvChromaSubsampling, Yuy2Description};
+use num_traits::AsPrimitive;
+use std::fmt::Debug;
+
+#[inline]
+pub(crate) fn check_region(
+    image_width: u32,
+    image_height: u32,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+) -> Result<(), YuvError> {
+    if width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    if x as u64 + width as u64 > image_width as u64
+        || y as u64 + height as u64 > image_height as u64
+    {
+        return Err(YuvError::RegionOutOfBounds);
+    }
+    Ok(())
+}
+
+#[inline]
+fn check_origin(x: u32, y: u32, subsampling: YuvChromaSubsampling) -> Result<(), YuvError> {
+    if x as usize % subsampling.horizontal_factor() != 0
+        || y as usize % subsampling.vertical_factor() != 0
+    {
+        return Err(YuvError::CropOriginNotAligned);
+    }
+    Ok(())
+}
+
+/// Borrows `height` rows of source stride starting at (`x`, `y`).
+///
+/// Conversions walk planes in whole strides, so a window whose last row
+/// would be cut by the end of the plane can't be borrowed.
+#[inline]
+fn plane_window<T>(
+    plane: &[T],
+    stride: usize,
+    x: usize,
+    y: usize,
+    height: usize,
+) -> Result<&[T], YuvError> {
+    let start = y * stride + x;
+    plane
+        .get(start..start + stride * height)
+        .ok_or(YuvError::RegionNotBorrowable)
+}
+
+fn copy_plane<T: Copy + Default>(
+    plane: &[T],
+    stride: usize,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+) -> Vec<T> {
+    let mut dst = vec![T::default(); width * height];
+    for (dst, src) in dst
+        .chunks_exact_mut(width)
+        .zip(plane.chunks(stride).skip(y).take(height))
+    {
+        dst.copy_from_slice(&src[x..x + width]);
+    }
+    dst
+}
+
+/// Describes where cropped chroma starts in the source chroma grid.
+///
+/// When the crop origin falls between two chroma samples, the new first chroma sample
+/// sits exactly halfway between them. This holds for both co-sited and centered
+/// chroma siting, so averaging the neighbours always yields the correct phase.
+#[derive(Debug, Copy, Clone)]
+struct ChromaWindow {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    half_x: bool,
+    half_y: bool,
+}
+
+impl ChromaWindow {
+    fn new(x: u32, y: u32, width: u32, height: u32, subsampling: YuvChromaSubsampling) -> Self {
+        let h_factor = subsampling.horizontal_factor();
+        let v_factor = subsampling.vertical_factor();
+        ChromaWindow {
+            x: x as usize / h_factor,
+            y: y as usize / v_factor,
+            width: subsampling.chroma_width(width as usize),
+            height: subsampling.chroma_height(height as usize),
+            half_x: x as usize % h_factor != 0,
+            half_y: y as usize % v_factor != 0,
+        }
+    }
+}
+
+/// Copies chroma window into compact plane, interpolating half sample phase when required.
+///
+/// `src_width` and `src_height` is the size of source chroma grid in samples.
+fn crop_chroma<T, const CHANNELS: usize>(
+    plane: &[T],
+    stride: usize,
+    src_width: usize,
+    src_height: usize,
+    window: ChromaWindow,
+) -> Vec<T>
+where
+    T: Copy + Default + AsPrimitive<u32>,
+    u32: AsPrimitive<T>,
+{
+    let row_length = window.width * CHANNELS;
+    let mut dst = vec![T::default(); row_length * window.height];
+    for (dy, dst) in dst.chunks_exact_mut(row_length).enumerate() {
+        let y0 = window.y + dy;
+        let y1 = if window.half_y {
+            (y0 + 1).min(src_height - 1)
+        } else {
+            y0
+        };
+        let row0 = &plane[y0 * stride..];
+        let row1 = &plane[y1 * stride..];
+        if !window.half_x && !window.half_y {
+            dst.copy_from_slice(&row0[window.x * CHANNELS..window.x * CHANNELS + row_length]);
+            continue;
+        }
+        for (dx, dst) in dst.chunks_exact_mut(CHANNELS).enumerate() {
+            let x0 = window.x + dx;
+            let x1 = if window.half_x {
+                (x0 + 1).min(src_width - 1)
+            } else {
+                x0
+            };
+            for c in 0..CHANNELS {
+                let sum = row0[x0 * CHANNELS + c].as_()
+                    + row0[x1 * CHANNELS + c].as_()
+                    + row1[x0 * CHANNELS + c].as_()
+                    + row1[x1 * CHANNELS + c].as_();
+                dst[c] = ((sum + 2) >> 2).as_();
+            }
+        }
+    }
+    dst
+}
+
+impl<'a, T> YuvGrayImage<'a, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns view into `width` x `height` region starting at (`x`, `y`).
+    ///
+    /// No data is copied, the view keeps whole rows of the source stride,
+    /// a region whose rows would overrun the plane fails with [YuvError::RegionNotBorrowable].
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> Result<Self, YuvError> {
+        self.check_constraints()?;
+        check_region(self.width, self.height, x, y, width, height)?;
+        Ok(YuvGrayImage {
+            y_plane: plane_window(
+                self.y_plane,
+                self.y_stride as usize,
+                x as usize,
+                y as usize,
+                height as usize,
+            )?,
+            y_stride: self.y_stride,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> YuvGrayImageMut<'_, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns non-mutable view into `width` x `height` region starting at (`x`, `y`).
+    pub fn crop(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) -> Result<YuvGrayImage<'_, T>, YuvError> {
+        YuvGrayImage {
+            y_plane: self.y_plane.borrow(),
+            y_stride: self.y_stride,
+            width: self.width,
+            height: self.height,
+        }
+        .crop(x, y, width, height)
+    }
+}
+
+impl<'a, T> YuvGrayAlphaImage<'a, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns view into `width` x `height` region starting at (`x`, `y`).
+    ///
+    /// No data is copied, the view keeps whole rows of the source strides,
+    /// a region whose rows would overrun the planes fails with [YuvError::RegionNotBorrowable].
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> Result<Self, YuvError> {
+        self.check_constraints()?;
+        check_region(self.width, self.height, x, y, width, height)?;
+        let (x, y, h) = (x as usize, y as usize, height as usize);
+        Ok(YuvGrayAlphaImage {
+            y_plane: plane_window(self.y_plane, self.y_stride as usize, x, y, h)?,
+            y_stride: self.y_stride,
+            a_plane: plane_window(self.a_plane, self.a_stride as usize, x, y, h)?,
+            a_stride: self.a_stride,
+            width,
+            height,
+        })
+    }
+}
+
+impl<'a, T> YuvPlanarImage<'a, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns view into `width` x `height` region starting at (`x`, `y`).
+    ///
+    /// No data is copied, the view keeps whole rows of the source strides.
+    /// Origin must be aligned to the chroma subsampling, otherwise chroma would start
+    /// in the middle of a sample. The view holds whole rows, so a region reaching the last row
+    /// at non-zero `x` of a plane without room past that row fails with [YuvError::RegionNotBorrowable].
+    /// See [YuvPlanarImage::crop_resampled] for copying such regions.
+    pub fn crop(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        subsampling: YuvChromaSubsampling,
+    ) -> Result<Self, YuvError> {
+        self.check_constraints(subsampling)?;
+        check_region(self.width, self.height, x, y, width, height)?;
+        check_origin(x, y, subsampling)?;
+        let chroma = ChromaWindow::new(x, y, width, height, subsampling);
+        Ok(YuvPlanarImage {
+            y_plane: plane_window(
+                self.y_plane,
+                self.y_stride as usize,
+                x as usize,
+                y as usize,
+                height as usize,
+            )?,
+            y_stride: self.y_stride,
+            u_plane: plane_window(
+                self.u_plane,
+                self.u_stride as usize,
+                chroma.x,
+                chroma.y,
+                chroma.height,
+            )?,
+            u_stride: self.u_stride,
+            v_plane: plane_window(
+                self.v_plane,
+                self.v_stride as usize,
+                chroma.x,
+                chroma.y,
+                chroma.height,
+            )?,
+            v_stride: self.v_stride,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> YuvPlanarImage<'_, T>
+where
+    T: Copy + Debug + Default + AsPrimitive<u32>,
+    u32: AsPrimitive<T>,
+{
+    /// Copies `width` x `height` region starting at (`x`, `y`) into a new compact image.
+    ///
+    /// Any origin is accepted. When origin is not aligned to the chroma subsampling
+    /// chroma is interpolated to the phase of the new origin.
+    pub fn crop_resampled(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        subsampling: YuvChromaSubsampling,
+    ) -> Result<YuvPlanarImageMut<'static, T>, YuvError> {
+        self.check_constraints(subsampling)?;
+        check_region(self.width, self.height, x, y, width, height)?;
+        let chroma = ChromaWindow::new(x, y, width, height, subsampling);
+        let src_chroma_width = subsampling.chroma_width(self.width as usize);
+        let src_chroma_height = subsampling.chroma_height(self.height as usize);
+        let y_plane = copy_plane(
+            self.y_plane,
+            self.y_stride as usize,
+            x as usize,
+            y as usize,
+            width as usize,
+            height as usize,
+        );
+        let u_plane = crop_chroma::<T, 1>(
+            self.u_plane,
+            self.u_stride as usize,
+            src_chroma_width,
+            src_chroma_height,
+            chroma,
+        );
+        let v_plane = crop_chroma::<T, 1>(
+            self.v_plane,
+            self.v_stride as usize,
+            src_chroma_width,
+            src_chroma_height,
+            chroma,
+        );
+        Ok(YuvPlanarImageMut {
+            y_plane: BufferStoreMut::Owned(y_plane),
+            y_stride: width,
+            u_plane: BufferStoreMut::Owned(u_plane),
+            u_stride: chroma.width as u32,
+            v_plane: BufferStoreMut::Owned(v_plane),
+            v_stride: chroma.width as u32,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> YuvPlanarImageMut<'_, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns non-mutable view into `width` x `height` region starting at (`x`, `y`).
+    ///
+    /// See [YuvPlanarImage::crop] for the origin requirements.
+    pub fn crop(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        subsampling: YuvChromaSubsampling,
+    ) -> Result<YuvPlanarImage<'_, T>, YuvError> {
+        YuvPlanarImage {
+            y_plane: self.y_plane.borrow(),
+            y_stride: self.y_stride,
+            u_plane: self.u_plane.borrow(),
+            u_stride: self.u_stride,
+            v_plane: self.v_plane.borrow(),
+            v_stride: self.v_stride,
+            width: self.width,
+            height: self.height,
+        }
+        .crop(x, y, width, height, subsampling)
+    }
+}
+
+impl<T> YuvPlanarImageMut<'_, T>
+where
+    T: Copy + Debug + Default + AsPrimitive<u32>,
+    u32: AsPrimitive<T>,
+{
+    /// Copies `width` x `height` region starting at (`x`, `y`) into a new compact image.
+    ///
+    /// See [YuvPlanarImage::crop_resampled] for details.
+    pub fn crop_resampled(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        subsampling: YuvChromaSubsampling,
+    ) -> Result<YuvPlanarImageMut<'static, T>, YuvError> {
+        YuvPlanarImage {
+            y_plane: self.y_plane.borrow(),
+            y_stride: self.y_stride,
+            u_plane: self.u_plane.borrow(),
+            u_stride: self.u_stride,
+            v_plane: self.v_plane.borrow(),
+            v_stride: self.v_stride,
+            width: self.width,
+            height: self.height,
+        }
+        .crop_resampled(x, y, width, height, subsampling)
+    }
+}
+
+impl<'a, T> YuvPlanarImageWithAlpha<'a, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns view into `width` x `height` region starting at (`x`, `y`).
+    ///
+    /// No data is copied, see [YuvPlanarImage::crop] for the requirements.
+    pub fn crop(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        subsampling: YuvChromaSubsampling,
+    ) -> Result<Self, YuvError> {
+        self.check_constraints(subsampling)?;
+        let planar = YuvPlanarImage {
+            y_plane: self.y_plane,
+            y_stride: self.y_stride,
+            u_plane: self.u_plane,
+            u_stride: self.u_stride,
+            v_plane: self.v_plane,
+            v_stride: self.v_stride,
+            width: self.width,
+            height: self.height,
+        }
+        .crop(x, y, width, height, subsampling)?;
+        Ok(YuvPlanarImageWithAlpha {
+            y_plane: planar.y_plane,
+            y_stride: planar.y_stride,
+            u_plane: planar.u_plane,
+            u_stride: planar.u_stride,
+            v_plane: planar.v_plane,
+            v_stride: planar.v_stride,
+            a_plane: plane_window(
+                self.a_plane,
+                self.a_stride as usize,
+                x as usize,
+                y as usize,
+                height as usize,
+            )?,
+            a_stride: self.a_stride,
+            width,
+            height,
+        })
+    }
+}
+
+impl<'a, T> YuvBiPlanarImage<'a, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns view into `width` x `height` region starting at (`x`, `y`).
+    ///
+    /// No data is copied, the view keeps whole rows of the source strides.
+    /// Origin must be aligned to the chroma subsampling, otherwise chroma would start
+    /// in the middle of a sample. The view holds whole rows, so a region reaching the last row
+    /// at non-zero `x` of a plane without room past that row fails with [YuvError::RegionNotBorrowable].
+    /// See [YuvBiPlanarImage::crop_resampled] for copying such regions.
+    pub fn crop(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        subsampling: YuvChromaSubsampling,
+    ) -> Result<Self, YuvError> {
+        self.check_constraints(subsampling)?;
+        check_region(self.width, self.height, x, y, width, height)?;
+        check_origin(x, y, subsampling)?;
+        let chroma = ChromaWindow::new(x, y, width, height, subsampling);
+        Ok(YuvBiPlanarImage {
+            y_plane: plane_window(
+                self.y_plane,
+                self.y_stride as usize,
+                x as usize,
+                y as usize,
+                height as usize,
+            )?,
+            y_stride: self.y_stride,
+            uv_plane: plane_window(
+                self.uv_plane,
+                self.uv_stride as usize,
+                chroma.x * 2,
+                chroma.y,
+                chroma.height,
+            )?,
+            uv_stride: self.uv_stride,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> YuvBiPlanarImage<'_, T>
+where
+    T: Copy + Debug + Default + AsPrimitive<u32>,
+    u32: AsPrimitive<T>,
+{
+    /// Copies `width` x `height` region starting at (`x`, `y`) into a new compact image.
+    ///
+    /// Any origin is accepted. When origin is not aligned to the chroma subsampling
+    /// chroma is interpolated to the phase of the new origin.
+    pub fn crop_resampled(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        subsampling: YuvChromaSubsampling,
+    ) -> Result<YuvBiPlanarImageMut<'static, T>, YuvError> {
+        self.check_constraints(subsampling)?;
+        check_region(self.width, self.height, x, y, width, height)?;
+        let chroma = ChromaWindow::new(x, y, width, height, subsampling);
+        let y_plane = copy_plane(
+            self.y_plane,
+            self.y_stride as usize,
+            x as usize,
+            y as usize,
+            width as usize,
+            height as usize,
+        );
+        let uv_plane = crop_chroma::<T, 2>(
+            self.uv_plane,
+            self.uv_stride as usize,
+            subsampling.chroma_width(self.width as usize),
+            subsampling.chroma_height(self.height as usize),
+            chroma,
+        );
+        Ok(YuvBiPlanarImageMut {
+            y_plane: BufferStoreMut::Owned(y_plane),
+            y_stride: width,
+            uv_plane: BufferStoreMut::Owned(uv_plane),
+            uv_stride: chroma.width as u32 * 2,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> YuvBiPlanarImageMut<'_, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns non-mutable view into `width` x `height` region starting at (`x`, `y`).
+    ///
+    /// See [YuvBiPlanarImage::crop] for the origin requirements.
+    pub fn crop(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        subsampling: YuvChromaSubsampling,
+    ) -> Result<YuvBiPlanarImage<'_, T>, YuvError> {
+        YuvBiPlanarImage {
+            y_plane: self.y_plane.borrow(),
+            y_stride: self.y_stride,
+            uv_plane: self.uv_plane.borrow(),
+            uv_stride: self.uv_stride,
+            width: self.width,
+            height: self.height,
+        }
+        .crop(x, y, width, height, subsampling)
+    }
+}
+
+impl<T> YuvBiPlanarImageMut<'_, T>
+where
+    T: Copy + Debug + Default + AsPrimitive<u32>,
+    u32: AsPrimitive<T>,
+{
+    /// Copies `width` x `height` region starting at (`x`, `y`) into a new compact image.
+    ///
+    /// See [YuvBiPlanarImage::crop_resampled] for details.
+    pub fn crop_resampled(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        subsampling: YuvChromaSubsampling,
+    ) -> Result<YuvBiPlanarImageMut<'static, T>, YuvError> {
+        YuvBiPlanarImage {
+            y_plane: self.y_plane.borrow(),
+            y_stride: self.y_stride,
+            uv_plane: self.uv_plane.borrow(),
+            uv_stride: self.uv_stride,
+            width: self.width,
+            height: self.height,
+        }
+        .crop_resampled(x, y, width, height, subsampling)
+    }
+}
+
+impl<'a, T> YuvPackedImage<'a, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns view into `width` x `height` region starting at (`x`, `y`).
+    ///
+    /// No data is copied, the view keeps whole rows of the source stride.
+    /// `x` must be even, because two pixels share one chroma pair inside each macropixel.
+    /// The view holds whole rows, so a region reaching the last row at non-zero `x` of an image
+    /// without room past that row fails with [YuvError::RegionNotBorrowable].
+    /// See [YuvPackedImage::crop_resampled] for copying such regions.
+    pub fn crop(&self, x: u32, y: u32, width: u32, height: u32) -> Result<Self, YuvError> {
+        self.check_constraints()?;
+        check_region(self.width, self.height, x, y, width, height)?;
+        check_origin(x, y, YuvChromaSubsampling::Yuv422)?;
+        Ok(YuvPackedImage {
+            yuy: plane_window(
+                self.yuy,
+                self.yuy_stride as usize,
+                x as usize * 2,
+                y as usize,
+                height as usize,
+            )?,
+            yuy_stride: self.yuy_stride,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> YuvPackedImage<'_, T>
+where
+    T: Copy + Debug + Default + AsPrimitive<u32>,
+    u32: AsPrimitive<T>,
+{
+    /// Copies `width` x `height` region starting at (`x`, `y`) into a new compact image.
+    ///
+    /// Any origin is accepted. When `x` is odd new macropixels straddle the source ones,
+    /// so chroma is interpolated to the phase of the new origin, `packing` tells
+    /// where luma and chroma sit inside macropixel.
+    pub fn crop_resampled(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        packing: Yuy2Description,
+    ) -> Result<YuvPackedImageMut<'static, T>, YuvError> {
+        self.check_constraints()?;
+        check_region(self.width, self.height, x, y, width, height)?;
+        let chroma = ChromaWindow::new(x, y, width, height, YuvChromaSubsampling::Yuv422);
+        let src_chroma_width = YuvChromaSubsampling::Yuv422.chroma_width(self.width as usize);
+        let (y0, y1) = (
+            packing.get_first_y_position(),
+            packing.get_second_y_position(),
+        );
+        let (u, v) = (packing.get_u_position(), packing.get_v_position());
+        let row_length = chroma.width * 4;
+        let mut yuy = vec![T::default(); row_length * height as usize];
+        for (dst, src) in yuy.chunks_exact_mut(row_length).zip(
+            self.yuy
+                .chunks(self.yuy_stride as usize)
+                .skip(y as usize)
+                .take(height as usize),
+        ) {
+            let luma = |px: usize| src[(px / 2) * 4 + if px % 2 == 0 { y0 } else { y1 }];
+            let last = (x + width - 1) as usize;
+            for (j, dst) in dst.chunks_exact_mut(4).enumerate() {
+                let px = x as usize + j * 2;
+                dst[y0] = luma(px);
+                dst[y1] = luma((px + 1).min(last));
+                let c0 = chroma.x + j;
+                let c1 = if chroma.half_x {
+                    (c0 + 1).min(src_chroma_width - 1)
+                } else {
+                    c0
+                };
+                for offset in [u, v] {
+                    let sum = src[c0 * 4 + offset].as_() + src[c1 * 4 + offset].as_();
+                    dst[offset] = ((sum + 1) >> 1).as_();
+                }
+            }
+        }
+        Ok(YuvPackedImageMut {
+            yuy: BufferStoreMut::Owned(yuy),
+            yuy_stride: row_length as u32,
+            width,
+            height,
+        })
+    }
+}
+
+impl<T> YuvPackedImageMut<'_, T>
+where
+    T: Copy + Debug,
+{
+    /// Returns non-mutable view into `width` x `height` region starting at (`x`, `y`).
+    ///
+    /// See [YuvPackedImage::crop] for the origin requirements.
+    pub fn crop(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    ) -> Result<YuvPackedImage<'_, T>, YuvError> {
+        YuvPackedImage {
+            yuy: self.yuy.borrow(),
+            yuy_stride: self.yuy_stride,
+            width: self.width,
+            height: self.height,
+        }
+        .crop(x, y, width, height)
+    }
+}
+
+impl<T> YuvPackedImageMut<'_, T>
+where
+    T: Copy + Debug + Default + AsPrimitive<u32>,
+    u32: AsPrimitive<T>,
+{
+    /// Copies `width` x `height` region starting at (`x`, `y`) into a new compact image.
+    ///
+    /// See [YuvPackedImage::crop_resampled] for details.
+    pub fn crop_resampled(
+        &self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        packing: Yuy2Description,
+    ) -> Result<YuvPackedImageMut<'static, T>, YuvError> {
+        YuvPackedImage {
+            yuy: self.yuy.borrow(),
+            yuy_stride: self.yuy_stride,
+            width: self.width,
+            height: self.height,
+        }
+        .crop_resampled(x, y, width, height, packing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        yuv420_to_rgb, yuv_nv12_to_rgb, yuyv422_to_rgb, YuvConversionMode, YuvRange,
+        YuvStandardMatrix,
+    };
+
+    #[test]
+    fn crop_resampled_odd_origin_420() {
+        let width = 8u32;
+        let height = 6u32;
+        let mut image =
+            YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in image.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = i as u8;
+        }
+        for (i, v) in image.uv_plane.borrow_mut().chunks_exact_mut(2).enumerate() {
+            v[0] = (i * 8) as u8;
+            v[1] = 200 - (i * 8) as u8;
+        }
+
+        let cropped = image
+            .crop_resampled(1, 1, 4, 4, YuvChromaSubsampling::Yuv420)
+            .unwrap();
+        cropped
+            .check_constraints(YuvChromaSubsampling::Yuv420)
+            .unwrap();
+        assert_eq!(&cropped.y_plane.borrow()[..4], &[9, 10, 11, 12]);
+        // Chroma grid is 4x3, new first sample sits between samples 0, 1, 4 and 5.
+        let uv = cropped.uv_plane.borrow();
+        assert_eq!(uv[0], ((8 + 32 + 40 + 2) / 4) as u8);
+        assert_eq!(uv[1], ((200 + 192 + 168 + 160 + 2) / 4) as u8);
+
+        let aligned = image
+            .crop(2, 2, 4, 2, YuvChromaSubsampling::Yuv420)
+            .unwrap();
+        assert_eq!(&aligned.uv_plane[..4], &[40, 160, 48, 152]);
+        assert!(matches!(
+            image.crop(1, 2, 4, 4, YuvChromaSubsampling::Yuv420),
+            Err(YuvError::CropOriginNotAligned)
+        ));
+
+        let planar = YuvPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        let view = planar
+            .crop(2, 2, 5, 2, YuvChromaSubsampling::Yuv420)
+            .unwrap();
+        view.check_constraints(YuvChromaSubsampling::Yuv420)
+            .unwrap();
+        assert!(matches!(
+            planar.crop(1, 2, 4, 4, YuvChromaSubsampling::Yuv420),
+            Err(YuvError::CropOriginNotAligned)
+        ));
+        assert!(matches!(
+            planar.crop(4, 0, 5, 2, YuvChromaSubsampling::Yuv420),
+            Err(YuvError::RegionOutOfBounds)
+        ));
+        let resampled = planar
+            .crop_resampled(1, 1, 5, 3, YuvChromaSubsampling::Yuv420)
+            .unwrap();
+        resampled
+            .check_constraints(YuvChromaSubsampling::Yuv420)
+            .unwrap();
+    }
+
+    #[test]
+    fn crop_resampled_odd_origin_packed() {
+        // YUYV, 6x1: luma 10..15, chroma pairs (100, 200), (110, 190), (120, 180)
+        let yuy = [10u8, 100, 11, 200, 12, 110, 13, 190, 14, 120, 15, 180];
+        let image = YuvPackedImage {
+            yuy: &yuy,
+            yuy_stride: 12,
+            width: 6,
+            height: 1,
+        };
+        let cropped = image
+            .crop_resampled(1, 0, 3, 1, Yuy2Description::YUYV)
+            .unwrap();
+        cropped.check_constraints().unwrap();
+        // Last macropixel has a single pixel, its second luma replicates the edge
+        assert_eq!(cropped.yuy.borrow(), &[11, 105, 12, 195, 13, 115, 13, 185]);
+        assert!(matches!(
+            image.crop(1, 0, 3, 1),
+            Err(YuvError::CropOriginNotAligned)
+        ));
+        assert!(matches!(
+            image.crop(2, 0, 3, 1),
+            Err(YuvError::RegionNotBorrowable)
+        ));
+        let view = image.crop(0, 0, 4, 1).unwrap();
+        assert_eq!(view.yuy, &yuy);
+    }
+
+    #[test]
+    fn views_convert_as_copies() {
+        let (width, height) = (10u32, 8u32);
+        let (x, y, w, h) = (2u32, 2u32, 6u32, 4u32);
+        let mut nv12 =
+            YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in nv12.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (i * 7 % 256) as u8;
+        }
+        for (i, v) in nv12.uv_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (64 + i * 13 % 128) as u8;
+        }
+        let mut i420 = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        i420.y_plane
+            .borrow_mut()
+            .copy_from_slice(nv12.y_plane.borrow());
+        for (uv, (u, v)) in nv12.uv_plane.borrow().chunks_exact(2).zip(
+            i420.u_plane
+                .borrow_mut()
+                .iter_mut()
+                .zip(i420.v_plane.borrow_mut().iter_mut()),
+        ) {
+            *u = uv[0];
+            *v = uv[1];
+        }
+        let yuyv = (0..width * height * 2)
+            .map(|i| (i * 11 % 256) as u8)
+            .collect::<Vec<_>>();
+        let yuyv = YuvPackedImage {
+            yuy: &yuyv,
+            yuy_stride: width * 2,
+            width,
+            height,
+        };
+
+        let (range, matrix) = (YuvRange::Limited, YuvStandardMatrix::Bt709);
+        let convert_nv = |image: &YuvBiPlanarImage<u8>| {
+            let mut rgb = vec![0u8; (w * h * 3) as usize];
+            yuv_nv12_to_rgb(
+                image,
+                &mut rgb,
+                w * 3,
+                range,
+                matrix,
+                YuvConversionMode::Balanced,
+            )
+            .unwrap();
+            rgb
+        };
+        let view = nv12.crop(x, y, w, h, YuvChromaSubsampling::Yuv420).unwrap();
+        let copy = nv12
+            .crop_resampled(x, y, w, h, YuvChromaSubsampling::Yuv420)
+            .unwrap();
+        assert_eq!(convert_nv(&view), convert_nv(&copy.to_fixed()));
+
+        let convert_planar = |image: &YuvPlanarImage<u8>| {
+            let mut rgb = vec![0u8; (w * h * 3) as usize];
+            yuv420_to_rgb(image, &mut rgb, w * 3, range, matrix).unwrap();
+            rgb
+        };
+        let view = i420.crop(x, y, w, h, YuvChromaSubsampling::Yuv420).unwrap();
+        let copy = i420
+            .crop_resampled(x, y, w, h, YuvChromaSubsampling::Yuv420)
+            .unwrap();
+        assert_eq!(convert_planar(&view), convert_planar(&copy.to_fixed()));
+
+        let convert_packed = |image: &YuvPackedImage<u8>| {
+            let mut rgb = vec![0u8; (w * h * 3) as usize];
+            yuyv422_to_rgb(image, &mut rgb, w * 3, range, matrix).unwrap();
+            rgb
+        };
+        let view = yuyv.crop(x, y, w, h).unwrap();
+        let copy = yuyv
+            .crop_resampled(x, y, w, h, Yuy2Description::YUYV)
+            .unwrap();
+        assert_eq!(convert_packed(&view), convert_packed(&copy.to_fixed()));
+    }
+
+    #[test]
+    fn bottom_edge_view_needs_a_copy() {
+        let image = YuvPlanarImageMut::<u8>::alloc(10, 8, YuvChromaSubsampling::Yuv420);
+        assert!(matches!(
+            image.crop(2, 4, 6, 4, YuvChromaSubsampling::Yuv420),
+            Err(YuvError::RegionNotBorrowable)
+        ));
+        assert!(image.crop(0, 4, 6, 4, YuvChromaSubsampling::Yuv420).is_ok());
+        let copy = image
+            .crop_resampled(2, 4, 6, 4, YuvChromaSubsampling::Yuv420)
+            .unwrap();
+        assert_eq!((copy.width, copy.height), (6, 4));
+    }
+}
@@ -0,0 +1,160 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+
+/// Declares how values are reduced when bit depth is narrowed
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum YuvDitherMode {
+    /// Drops low bits
+    Truncate,
+    /// Rounds to nearest value
+    #[default]
+    Round,
+    /// Ordered dithering with 8x8 Bayer matrix
+    Ordered,
+    /// Dithering with 16x16 blue noise texture
+    BlueNoise,
+}
+
+const BAYER_8X8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+/// Void-and-cluster blue noise, ranks 0..=255, tiles toroidally
+const BLUE_NOISE_16X16: [[u8; 16]; 16] = [
+    [
+        234, 50, 188, 19, 58, 171, 121, 47, 163, 3, 247, 104, 22, 132, 14, 65,
+    ],
+    [
+        209, 8, 118, 97, 240, 205, 23, 228, 138, 64, 123, 170, 72, 224, 99, 149,
+    ],
+    [
+        85, 139, 229, 165, 78, 146, 111, 84, 176, 216, 30, 231, 153, 201, 42, 180,
+    ],
+    [
+        25, 62, 195, 29, 43, 185, 7, 249, 41, 100, 191, 48, 87, 5, 128, 243,
+    ],
+    [
+        221, 152, 101, 253, 130, 220, 59, 200, 156, 12, 136, 112, 254, 174, 69, 109,
+    ],
+    [
+        46, 189, 2, 73, 172, 90, 142, 116, 80, 237, 210, 61, 147, 33, 206, 160,
+    ],
+    [
+        81, 124, 217, 113, 208, 15, 241, 27, 168, 45, 178, 20, 193, 96, 225, 18,
+    ],
+    [
+        242, 164, 60, 35, 157, 53, 181, 68, 223, 105, 125, 83, 236, 131, 55, 141,
+    ],
+    [
+        197, 10, 227, 134, 246, 95, 126, 198, 148, 1, 244, 161, 71, 9, 182, 106,
+    ],
+    [
+        40, 93, 179, 75, 192, 6, 218, 36, 91, 57, 202, 34, 215, 155, 233, 74,
+    ],
+    [
+        252, 120, 150, 24, 110, 63, 166, 119, 232, 183, 133, 103, 49, 117, 31, 167,
+    ],
+    [
+        16, 212, 51, 238, 207, 137, 255, 21, 76, 151, 13, 250, 190, 88, 203, 135,
+    ],
+    [
+        102, 184, 82, 169, 38, 89, 187, 52, 204, 98, 173, 67, 129, 4, 222, 56,
+    ],
+    [
+        230, 144, 0, 127, 226, 11, 154, 114, 239, 39, 219, 28, 235, 145, 175, 77,
+    ],
+    [
+        196, 37, 248, 70, 107, 199, 66, 177, 17, 143, 115, 159, 86, 44, 108, 26,
+    ],
+    [
+        122, 92, 158, 214, 140, 32, 245, 94, 213, 79, 194, 54, 211, 186, 251, 162,
+    ],
+];
+
+/// Rows of dither thresholds repeating with the dither pattern period
+pub(crate) struct DitherTable {
+    rows: Vec<Vec<u16>>,
+}
+
+impl DitherTable {
+    /// Builds thresholds to add before shifting right by `shift` bits
+    pub(crate) fn new(mode: YuvDitherMode, shift: u32, width: usize) -> DitherTable {
+        DitherTable::interleaved(mode, shift, width, 1)
+    }
+
+    /// Builds thresholds for interleaved rows where all `channels` of a pixel share one threshold
+    pub(crate) fn interleaved(
+        mode: YuvDitherMode,
+        shift: u32,
+        width: usize,
+        channels: usize,
+    ) -> DitherTable {
+        if shift == 0 {
+            return DitherTable {
+                rows: vec![vec![0u16; width]],
+            };
+        }
+        let rows = match mode {
+            YuvDitherMode::Truncate => vec![vec![0u16; width]],
+            YuvDitherMode::Round => vec![vec![1u16 << (shift - 1); width]],
+            YuvDitherMode::Ordered => BAYER_8X8
+                .iter()
+                .map(|row| {
+                    (0..width)
+                        .map(|x| x / channels)
+                        .map(|x| (((2 * row[x & 7] as u32 + 1) << shift) >> 7) as u16)
+                        .collect()
+                })
+                .collect(),
+            YuvDitherMode::BlueNoise => BLUE_NOISE_16X16
+                .iter()
+                .map(|row| {
+                    (0..width)
+                        .map(|x| x / channels)
+                        .map(|x| (((2 * row[x & 15] as u32 + 1) << shift) >> 9) as u16)
+                        .collect()
+                })
+                .collect(),
+        };
+        DitherTable { rows }
+    }
+
+    #[inline]
+    pub(crate) fn row(&self, y: usize) -> &[u16] {
+        &self.rows[y % self.rows.len()]
+    }
+}
@@ -0,0 +1,511 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::check_rgba_destination;
+use crate::{
+    YuvBiPlanarImage, YuvBiPlanarImageMut, YuvChromaSubsampling, YuvError, YuvGrayAlphaImage,
+    YuvGrayImage, YuvGrayImageMut, YuvPackedImage, YuvPackedImageMut, YuvPlanarImage,
+    YuvPlanarImageMut, YuvPlanarImageWithAlpha,
+};
+use std::fmt::Debug;
+
+/// Hash function used for frame hashing
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub enum FrameHashAlgorithm {
+    /// CRC-32 (IEEE 802.3), same as ffmpeg `framecrc` uses, value is stored in low 32 bits
+    #[default]
+    Crc32,
+    /// XXH64 with zero seed
+    XxHash64,
+}
+
+/// Sample type that can be hashed, samples are always hashed as little endian bytes
+/// so hashes don't depend on the host
+pub trait FrameHashSample: Copy + Debug + Default {
+    fn write_le(row: &[Self], scratch: &mut Vec<u8>);
+}
+
+impl FrameHashSample for u8 {
+    fn write_le(row: &[Self], scratch: &mut Vec<u8>) {
+        scratch.extend_from_slice(row);
+    }
+}
+
+impl FrameHashSample for u16 {
+    fn write_le(row: &[Self], scratch: &mut Vec<u8>) {
+        scratch.extend(row.iter().flat_map(|v| v.to_le_bytes()));
+    }
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+const XXH_PRIME64_1: u64 = 0x9E37_79B1_85EB_CA87;
+const XXH_PRIME64_2: u64 = 0xC2B2_AE3D_27D4_EB4F;
+const XXH_PRIME64_3: u64 = 0x1656_67B1_9E37_79F9;
+const XXH_PRIME64_4: u64 = 0x85EB_CA77_C2B2_AE63;
+const XXH_PRIME64_5: u64 = 0x27D4_EB2F_1656_67C5;
+
+#[inline]
+fn xxh64_round(acc: u64, input: u64) -> u64 {
+    acc.wrapping_add(input.wrapping_mul(XXH_PRIME64_2))
+        .rotate_left(31)
+        .wrapping_mul(XXH_PRIME64_1)
+}
+
+#[inline]
+fn xxh64_merge(acc: u64, value: u64) -> u64 {
+    (acc ^ xxh64_round(0, value))
+        .wrapping_mul(XXH_PRIME64_1)
+        .wrapping_add(XXH_PRIME64_4)
+}
+
+#[inline]
+fn read_u64(bytes: &[u8]) -> u64 {
+    u64::from_le_bytes(bytes[..8].try_into().unwrap())
+}
+
+/// Streaming XXH64 with zero seed
+struct XxHash64 {
+    lanes: [u64; 4],
+    buffer: [u8; 32],
+    buffered: usize,
+    total_length: u64,
+}
+
+impl XxHash64 {
+    fn new() -> XxHash64 {
+        XxHash64 {
+            lanes: [
+                XXH_PRIME64_1.wrapping_add(XXH_PRIME64_2),
+                XXH_PRIME64_2,
+                0,
+                0u64.wrapping_sub(XXH_PRIME64_1),
+            ],
+            buffer: [0u8; 32],
+            buffered: 0,
+            total_length: 0,
+        }
+    }
+
+    fn consume_stripe(lanes: &mut [u64; 4], stripe: &[u8]) {
+        for (lane, chunk) in lanes.iter_mut().zip(stripe.chunks_exact(8)) {
+            *lane = xxh64_round(*lane, read_u64(chunk));
+        }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        self.total_length += data.len() as u64;
+        if self.buffered > 0 {
+            let take = (32 - self.buffered).min(data.len());
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&data[..take]);
+            self.buffered += take;
+            data = &data[take..];
+            if self.buffered < 32 {
+                return;
+            }
+            let buffer = self.buffer;
+            Self::consume_stripe(&mut self.lanes, &buffer);
+            self.buffered = 0;
+        }
+        let mut stripes = data.chunks_exact(32);
+        for stripe in stripes.by_ref() {
+            Self::consume_stripe(&mut self.lanes, stripe);
+        }
+        let remainder = stripes.remainder();
+        self.buffer[..remainder.len()].copy_from_slice(remainder);
+        self.buffered = remainder.len();
+    }
+
+    fn finish(&self) -> u64 {
+        let [v1, v2, v3, v4] = self.lanes;
+        let mut hash = if self.total_length >= 32 {
+            let mut hash = v1
+                .rotate_left(1)
+                .wrapping_add(v2.rotate_left(7))
+                .wrapping_add(v3.rotate_left(12))
+                .wrapping_add(v4.rotate_left(18));
+            for lane in self.lanes {
+                hash = xxh64_merge(hash, lane);
+            }
+            hash
+        } else {
+            XXH_PRIME64_5
+        };
+        hash = hash.wrapping_add(self.total_length);
+
+        let mut tail = &self.buffer[..self.buffered];
+        while tail.len() >= 8 {
+            hash ^= xxh64_round(0, read_u64(tail));
+            hash = hash
+                .rotate_left(27)
+                .wrapping_mul(XXH_PRIME64_1)
+                .wrapping_add(XXH_PRIME64_4);
+            tail = &tail[8..];
+        }
+        if tail.len() >= 4 {
+            let value = u32::from_le_bytes(tail[..4].try_into().unwrap()) as u64;
+            hash ^= value.wrapping_mul(XXH_PRIME64_1);
+            hash = hash
+                .rotate_left(23)
+                .wrapping_mul(XXH_PRIME64_2)
+                .wrapping_add(XXH_PRIME64_3);
+            tail = &tail[4..];
+        }
+        for &byte in tail {
+            hash ^= (byte as u64).wrapping_mul(XXH_PRIME64_5);
+            hash = hash.rotate_left(11).wrapping_mul(XXH_PRIME64_1);
+        }
+
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(XXH_PRIME64_2);
+        hash ^= hash >> 29;
+        hash = hash.wrapping_mul(XXH_PRIME64_3);
+        hash ^= hash >> 32;
+        hash
+    }
+}
+
+enum FrameHasher {
+    Crc32(u32),
+    XxHash64(Box<XxHash64>),
+}
+
+impl FrameHasher {
+    fn new(algorithm: FrameHashAlgorithm) -> FrameHasher {
+        match algorithm {
+            FrameHashAlgorithm::Crc32 => FrameHasher::Crc32(!0),
+            FrameHashAlgorithm::XxHash64 => FrameHasher::XxHash64(Box::new(XxHash64::new())),
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            FrameHasher::Crc32(crc) => {
+                for &byte in data {
+                    *crc = CRC32_TABLE[((*crc ^ byte as u32) & 0xFF) as usize] ^ (*crc >> 8);
+                }
+            }
+            FrameHasher::XxHash64(hasher) => hasher.update(data),
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        match self {
+            FrameHasher::Crc32(crc) => !*crc as u64,
+            FrameHasher::XxHash64(hasher) => hasher.finish(),
+        }
+    }
+}
+
+/// Hashes `row_length` components of `height` rows, padding after each row is ignored.
+///
+/// Suitable for hashing RGB(A) buffers, where `row_length` is width multiplied by channels count.
+///
+/// # Arguments
+///
+/// * `plane` - Source plane or interleaved buffer.
+/// * `stride` - The stride (components per row).
+/// * `row_length` - Count of meaningful components in row.
+/// * `height` - Rows count.
+/// * `algorithm` - see [FrameHashAlgorithm] for more info.
+///
+pub fn hash_plane<V: FrameHashSample>(
+    plane: &[V],
+    stride: u32,
+    row_length: u32,
+    height: u32,
+    algorithm: FrameHashAlgorithm,
+) -> Result<u64, YuvError> {
+    check_rgba_destination(plane, stride, row_length, height, 1)?;
+    let mut hasher = FrameHasher::new(algorithm);
+    let mut scratch = Vec::with_capacity(row_length as usize * size_of::<V>());
+    for row in plane.chunks(stride as usize).take(height as usize) {
+        scratch.clear();
+        V::write_le(&row[..row_length as usize], &mut scratch);
+        hasher.update(&scratch);
+    }
+    Ok(hasher.finish())
+}
+
+impl<T: FrameHashSample> YuvPlanarImage<'_, T> {
+    /// Hashes Y, U, V planes ignoring row padding
+    pub fn hash_planes(
+        &self,
+        subsampling: YuvChromaSubsampling,
+        algorithm: FrameHashAlgorithm,
+    ) -> Result<[u64; 3], YuvError> {
+        self.check_constraints(subsampling)?;
+        let chroma_width = subsampling.chroma_width(self.width as usize) as u32;
+        let chroma_height = subsampling.chroma_height(self.height as usize) as u32;
+        Ok([
+            hash_plane(
+                self.y_plane,
+                self.y_stride,
+                self.width,
+                self.height,
+                algorithm,
+            )?,
+            hash_plane(
+                self.u_plane,
+                self.u_stride,
+                chroma_width,
+                chroma_height,
+                algorithm,
+            )?,
+            hash_plane(
+                self.v_plane,
+                self.v_stride,
+                chroma_width,
+                chroma_height,
+                algorithm,
+            )?,
+        ])
+    }
+}
+
+impl<T: FrameHashSample> YuvPlanarImageMut<'_, T> {
+    /// Hashes Y, U, V planes ignoring row padding
+    pub fn hash_planes(
+        &self,
+        subsampling: YuvChromaSubsampling,
+        algorithm: FrameHashAlgorithm,
+    ) -> Result<[u64; 3], YuvError> {
+        self.to_fixed().hash_planes(subsampling, algorithm)
+    }
+}
+
+impl<T: FrameHashSample> YuvPlanarImageWithAlpha<'_, T> {
+    /// Hashes Y, U, V and alpha planes ignoring row padding
+    pub fn hash_planes(
+        &self,
+        subsampling: YuvChromaSubsampling,
+        algorithm: FrameHashAlgorithm,
+    ) -> Result<[u64; 4], YuvError> {
+        self.check_constraints(subsampling)?;
+        let chroma_width = subsampling.chroma_width(self.width as usize) as u32;
+        let chroma_height = subsampling.chroma_height(self.height as usize) as u32;
+        Ok([
+            hash_plane(
+                self.y_plane,
+                self.y_stride,
+                self.width,
+                self.height,
+                algorithm,
+            )?,
+            hash_plane(
+                self.u_plane,
+                self.u_stride,
+                chroma_width,
+                chroma_height,
+                algorithm,
+            )?,
+            hash_plane(
+                self.v_plane,
+                self.v_stride,
+                chroma_width,
+                chroma_height,
+                algorithm,
+            )?,
+            hash_plane(
+                self.a_plane,
+                self.a_stride,
+                self.width,
+                self.height,
+                algorithm,
+            )?,
+        ])
+    }
+}
+
+impl<T: FrameHashSample> YuvBiPlanarImage<'_, T> {
+    /// Hashes Y and interleaved UV planes ignoring row padding
+    pub fn hash_planes(
+        &self,
+        subsampling: YuvChromaSubsampling,
+        algorithm: FrameHashAlgorithm,
+    ) -> Result<[u64; 2], YuvError> {
+        self.check_constraints(subsampling)?;
+        let chroma_width = subsampling.chroma_width(self.width as usize) as u32;
+        let chroma_height = subsampling.chroma_height(self.height as usize) as u32;
+        Ok([
+            hash_plane(
+                self.y_plane,
+                self.y_stride,
+                self.width,
+                self.height,
+                algorithm,
+            )?,
+            hash_plane(
+                self.uv_plane,
+                self.uv_stride,
+                chroma_width * 2,
+                chroma_height,
+                algorithm,
+            )?,
+        ])
+    }
+}
+
+impl<T: FrameHashSample> YuvBiPlanarImageMut<'_, T> {
+    /// Hashes Y and interleaved UV planes ignoring row padding
+    pub fn hash_planes(
+        &self,
+        subsampling: YuvChromaSubsampling,
+        algorithm: FrameHashAlgorithm,
+    ) -> Result<[u64; 2], YuvError> {
+        self.to_fixed().hash_planes(subsampling, algorithm)
+    }
+}
+
+impl<T: FrameHashSample> YuvGrayImage<'_, T> {
+    /// Hashes Y plane ignoring row padding
+    pub fn hash_planes(&self, algorithm: FrameHashAlgorithm) -> Result<[u64; 1], YuvError> {
+        self.check_constraints()?;
+        Ok([hash_plane(
+            self.y_plane,
+            self.y_stride,
+            self.width,
+            self.height,
+            algorithm,
+        )?])
+    }
+}
+
+impl<T: FrameHashSample> YuvGrayImageMut<'_, T> {
+    /// Hashes Y plane ignoring row padding
+    pub fn hash_planes(&self, algorithm: FrameHashAlgorithm) -> Result<[u64; 1], YuvError> {
+        self.to_fixed().hash_planes(algorithm)
+    }
+}
+
+impl<T: FrameHashSample> YuvGrayAlphaImage<'_, T> {
+    /// Hashes Y and alpha planes ignoring row padding
+    pub fn hash_planes(&self, algorithm: FrameHashAlgorithm) -> Result<[u64; 2], YuvError> {
+        self.check_constraints()?;
+        Ok([
+            hash_plane(
+                self.y_plane,
+                self.y_stride,
+                self.width,
+                self.height,
+                algorithm,
+            )?,
+            hash_plane(
+                self.a_plane,
+                self.a_stride,
+                self.width,
+                self.height,
+                algorithm,
+            )?,
+        ])
+    }
+}
+
+impl<T: FrameHashSample> YuvPackedImage<'_, T> {
+    /// Hashes packed 4:2:2 frame ignoring row padding
+    pub fn hash_planes(&self, algorithm: FrameHashAlgorithm) -> Result<[u64; 1], YuvError> {
+        self.check_constraints()?;
+        Ok([hash_plane(
+            self.yuy,
+            self.yuy_stride,
+            self.width.div_ceil(2) * 4,
+            self.height,
+            algorithm,
+        )?])
+    }
+}
+
+impl<T: FrameHashSample> YuvPackedImageMut<'_, T> {
+    /// Hashes packed 4:2:2 frame ignoring row padding
+    pub fn hash_planes(&self, algorithm: FrameHashAlgorithm) -> Result<[u64; 1], YuvError> {
+        self.to_fixed().hash_planes(algorithm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_vectors() {
+        let check = b"123456789";
+        let crc = hash_plane(check, 9, 9, 1, FrameHashAlgorithm::Crc32).unwrap();
+        assert_eq!(crc, 0xCBF4_3926);
+        let xxh = hash_plane(b"abc", 3, 3, 1, FrameHashAlgorithm::XxHash64).unwrap();
+        assert_eq!(xxh, 0x44BC_2CF5_AD77_0999);
+        let long: Vec<u8> = (0..100u8).collect();
+        let single = hash_plane(&long, 100, 100, 1, FrameHashAlgorithm::XxHash64).unwrap();
+        let rows = hash_plane(&long, 10, 10, 10, FrameHashAlgorithm::XxHash64).unwrap();
+        assert_eq!(single, rows);
+    }
+
+    #[test]
+    fn padding_is_ignored() {
+        let packed: Vec<u16> = (0..6 * 4).collect();
+        let mut padded = vec![0xFFFFu16; 9 * 4];
+        for (dst, src) in padded.chunks_exact_mut(9).zip(packed.chunks_exact(6)) {
+            dst[..6].copy_from_slice(src);
+        }
+        for algorithm in [FrameHashAlgorithm::Crc32, FrameHashAlgorithm::XxHash64] {
+            let gray = YuvGrayImage {
+                y_plane: &packed,
+                y_stride: 6,
+                width: 6,
+                height: 4,
+            };
+            let gray_padded = YuvGrayImage {
+                y_plane: &padded,
+                y_stride: 9,
+                width: 6,
+                height: 4,
+            };
+            assert_eq!(
+                gray.hash_planes(algorithm).unwrap(),
+                gray_padded.hash_planes(algorithm).unwrap()
+            );
+        }
+    }
+}
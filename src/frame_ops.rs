@@ -0,0 +1,1214 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+//! Fills, copies and edge padding of frames.
+//!
+//! These operations have no SIMD kernels on purpose: every row is a contiguous run written
+//! with `slice::fill`, `copy_from_slice` or `copy_within`, which lower to `memset`/`memcpy`
+//! or to loops the compiler vectorises, so they already run at memory bandwidth.
+//! Interleaved chroma is filled by doubling the already written prefix for the same reason.
+use crate::crop::check_region;
+use crate::yuv_error::check_bit_depth;
+use crate::yuv_support::{
+    get_yuv_range, search_forward_transform, YuvChromaSubsampling, YuvNVOrder, Yuy2Description,
+};
+use crate::{
+    YuvBiPlanarImage, YuvBiPlanarImageMut, YuvError, YuvGrayImage, YuvGrayImageMut, YuvPackedImage,
+    YuvPackedImageMut, YuvPlanarImage, YuvPlanarImageMut, YuvRange, YuvStandardMatrix,
+};
+use num_traits::AsPrimitive;
+use std::fmt::Debug;
+
+const PRECISION: i32 = 13;
+
+/// Converts RGB colour to Y, Cb, Cr with given bit-depth, range and matrix
+fn rgb_to_yuv_color(
+    rgb: [u16; 3],
+    bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<[i32; 3], YuvError> {
+    check_bit_depth(bit_depth, 8..=16)?;
+    let chroma_range = get_yuv_range(bit_depth, range);
+    let kr_kb = matrix.get_kr_kb();
+    let t = search_forward_transform(PRECISION, bit_depth, range, matrix, chroma_range, kr_kb);
+    let max = (1i64 << bit_depth) - 1;
+    let [r, g, b] = rgb.map(|x| x as i64);
+    let rounding = 1i64 << (PRECISION - 1);
+    let project = |c: (i32, i32, i32), bias: u32| -> i32 {
+        let v = ((bias as i64) << PRECISION) + r * c.0 as i64 + g * c.1 as i64 + b * c.2 as i64;
+        ((v + rounding) >> PRECISION).clamp(0, max) as i32
+    };
+    Ok([
+        project((t.yr, t.yg, t.yb), chroma_range.bias_y),
+        project((t.cb_r, t.cb_g, t.cb_b), chroma_range.bias_uv),
+        project((t.cr_r, t.cr_g, t.cr_b), chroma_range.bias_uv),
+    ])
+}
+
+/// Fills `width` x `height` block of `channels` interleaved samples with `value`
+fn fill_plane<T: Copy>(
+    plane: &mut [T],
+    stride: usize,
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    value: &[T],
+) {
+    let channels = value.len();
+    for row in plane.chunks_mut(stride).skip(y).take(height) {
+        let row = &mut row[x * channels..(x + width) * channels];
+        if channels == 1 {
+            row.fill(value[0]);
+        } else if !row.is_empty() {
+            // Doubles already filled prefix, so interleaved samples are written by bulk copies
+            row[..channels].copy_from_slice(value);
+            let mut filled = channels;
+            while filled < row.len() {
+                let count = filled.min(row.len() - filled);
+                row.copy_within(0..count, filled);
+                filled += count;
+            }
+        }
+    }
+}
+
+fn copy_plane<T: Copy>(
+    src: &[T],
+    src_stride: usize,
+    dst: &mut [T],
+    dst_stride: usize,
+    row_length: usize,
+    height: usize,
+) {
+    for (dst, src) in dst
+        .chunks_mut(dst_stride)
+        .zip(src.chunks(src_stride))
+        .take(height)
+    {
+        dst[..row_length].copy_from_slice(&src[..row_length]);
+    }
+}
+
+/// Replicates last content column and row of `channels` interleaved plane up to its full size
+fn pad_plane<T: Copy>(
+    plane: &mut [T],
+    stride: usize,
+    channels: usize,
+    content: (usize, usize),
+    size: (usize, usize),
+) {
+    let (content_width, content_height) = content;
+    let (width, height) = size;
+    if content_width < width {
+        for row in plane.chunks_mut(stride).take(content_height) {
+            let (head, tail) = row[..width * channels].split_at_mut(content_width * channels);
+            let edge = &head[(content_width - 1) * channels..];
+            for dst in tail.chunks_exact_mut(channels) {
+                dst.copy_from_slice(edge);
+            }
+        }
+    }
+    if content_height < height {
+        let (head, tail) = plane.split_at_mut(content_height * stride);
+        let edge =
+            &head[(content_height - 1) * stride..(content_height - 1) * stride + width * channels];
+        for row in tail.chunks_mut(stride).take(height - content_height) {
+            row[..width * channels].copy_from_slice(edge);
+        }
+    }
+}
+
+/// Chroma block range covering luma span `[start, start + length)`
+#[inline]
+fn chroma_span(start: u32, length: u32, factor: usize) -> (usize, usize) {
+    let start = start as usize;
+    let end = start + length as usize;
+    (start / factor, end.div_ceil(factor) - start / factor)
+}
+
+fn check_content(
+    width: u32,
+    height: u32,
+    content_width: u32,
+    content_height: u32,
+) -> Result<(), YuvError> {
+    check_region(width, height, 0, 0, content_width, content_height)
+}
+
+fn fill_planar_impl<T>(
+    image: &mut YuvPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    region: (u32, u32, u32, u32),
+    color: [i32; 3],
+) -> Result<(), YuvError>
+where
+    T: Copy + Debug + 'static,
+    i32: AsPrimitive<T>,
+{
+    image.check_constraints(subsampling)?;
+    let (x, y, width, height) = region;
+    check_region(image.width, image.height, x, y, width, height)?;
+    fill_plane(
+        image.y_plane.borrow_mut(),
+        image.y_stride as usize,
+        x as usize,
+        y as usize,
+        width as usize,
+        height as usize,
+        &[color[0].as_()],
+    );
+    let (cx, cw) = chroma_span(x, width, subsampling.horizontal_factor());
+    let (cy, ch) = chroma_span(y, height, subsampling.vertical_factor());
+    fill_plane(
+        image.u_plane.borrow_mut(),
+        image.u_stride as usize,
+        cx,
+        cy,
+        cw,
+        ch,
+        &[color[1].as_()],
+    );
+    fill_plane(
+        image.v_plane.borrow_mut(),
+        image.v_stride as usize,
+        cx,
+        cy,
+        cw,
+        ch,
+        &[color[2].as_()],
+    );
+    Ok(())
+}
+
+fn fill_bi_planar_impl<T>(
+    image: &mut YuvBiPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    order: YuvNVOrder,
+    region: (u32, u32, u32, u32),
+    color: [i32; 3],
+) -> Result<(), YuvError>
+where
+    T: Copy + Debug + 'static,
+    i32: AsPrimitive<T>,
+{
+    image.check_constraints(subsampling)?;
+    let (x, y, width, height) = region;
+    check_region(image.width, image.height, x, y, width, height)?;
+    fill_plane(
+        image.y_plane.borrow_mut(),
+        image.y_stride as usize,
+        x as usize,
+        y as usize,
+        width as usize,
+        height as usize,
+        &[color[0].as_()],
+    );
+    let (cx, cw) = chroma_span(x, width, subsampling.horizontal_factor());
+    let (cy, ch) = chroma_span(y, height, subsampling.vertical_factor());
+    let mut uv = [color[1].as_(); 2];
+    uv[order.get_u_position()] = color[1].as_();
+    uv[order.get_v_position()] = color[2].as_();
+    fill_plane(
+        image.uv_plane.borrow_mut(),
+        image.uv_stride as usize,
+        cx,
+        cy,
+        cw,
+        ch,
+        &uv,
+    );
+    Ok(())
+}
+
+fn fill_gray_impl<T>(
+    image: &mut YuvGrayImageMut<T>,
+    region: (u32, u32, u32, u32),
+    color: [i32; 3],
+) -> Result<(), YuvError>
+where
+    T: Copy + Debug + 'static,
+    i32: AsPrimitive<T>,
+{
+    image.check_constraints()?;
+    let (x, y, width, height) = region;
+    check_region(image.width, image.height, x, y, width, height)?;
+    fill_plane(
+        image.y_plane.borrow_mut(),
+        image.y_stride as usize,
+        x as usize,
+        y as usize,
+        width as usize,
+        height as usize,
+        &[color[0].as_()],
+    );
+    Ok(())
+}
+
+fn fill_packed_impl<T, const YUY2_TARGET: usize>(
+    image: &mut YuvPackedImageMut<T>,
+    region: (u32, u32, u32, u32),
+    color: [i32; 3],
+) -> Result<(), YuvError>
+where
+    T: Copy + Debug + 'static,
+    i32: AsPrimitive<T>,
+{
+    let yuy2_target: Yuy2Description = YUY2_TARGET.into();
+    image.check_constraints()?;
+    let (x, y, width, height) = region;
+    check_region(image.width, image.height, x, y, width, height)?;
+    let (x0, x1) = (x as usize, (x + width) as usize);
+    let luma: T = color[0].as_();
+    let (mx, mw) = chroma_span(x, width, 2);
+    let yuy_stride = image.yuy_stride as usize;
+    for row in image
+        .yuy
+        .borrow_mut()
+        .chunks_mut(yuy_stride)
+        .skip(y as usize)
+        .take(height as usize)
+    {
+        for (m, yuy2) in row[mx * 4..(mx + mw) * 4].chunks_exact_mut(4).enumerate() {
+            let first = (mx + m) * 2;
+            if first >= x0 {
+                yuy2[yuy2_target.get_first_y_position()] = luma;
+            }
+            if first + 1 < x1 {
+                yuy2[yuy2_target.get_second_y_position()] = luma;
+            }
+            yuy2[yuy2_target.get_u_position()] = color[1].as_();
+            yuy2[yuy2_target.get_v_position()] = color[2].as_();
+        }
+    }
+    Ok(())
+}
+
+fn pad_packed_impl<T: Copy + Debug, const YUY2_TARGET: usize>(
+    image: &mut YuvPackedImageMut<T>,
+    content_width: u32,
+    content_height: u32,
+) -> Result<(), YuvError> {
+    let yuy2_target: Yuy2Description = YUY2_TARGET.into();
+    image.check_constraints()?;
+    check_content(image.width, image.height, content_width, content_height)?;
+    let yuy_stride = image.yuy_stride as usize;
+    let macropixels = (image.width as usize).div_ceil(2);
+    let content_macropixels = (content_width as usize).div_ceil(2);
+    let odd = content_width % 2 != 0;
+    for row in image
+        .yuy
+        .borrow_mut()
+        .chunks_mut(yuy_stride)
+        .take(content_height as usize)
+    {
+        let row = &mut row[..macropixels * 4];
+        let last = (content_macropixels - 1) * 4;
+        let edge_y = if odd {
+            row[last + yuy2_target.get_first_y_position()]
+        } else {
+            row[last + yuy2_target.get_second_y_position()]
+        };
+        if odd {
+            row[last + yuy2_target.get_second_y_position()] = edge_y;
+        }
+        let mut edge = [edge_y; 4];
+        edge[yuy2_target.get_u_position()] = row[last + yuy2_target.get_u_position()];
+        edge[yuy2_target.get_v_position()] = row[last + yuy2_target.get_v_position()];
+        for dst in row[last + 4..].chunks_exact_mut(4) {
+            dst.copy_from_slice(&edge);
+        }
+    }
+    pad_plane(
+        image.yuy.borrow_mut(),
+        yuy_stride,
+        4,
+        (macropixels, content_height as usize),
+        (macropixels, image.height as usize),
+    );
+    Ok(())
+}
+
+macro_rules! d_fill_planar {
+    ($method: ident, $method_p16: ident, $sampling: expr, $yuv_name: expr) => {
+        #[doc = concat!("Fills rectangle of 8 bit-depth ", $yuv_name, " planar image with RGB colour.
+
+Colour is converted with given range and matrix. Every chroma sample
+whose block intersects the rectangle is filled.
+
+# Arguments
+
+* `image` - Target planar image.
+* `x`, `y`, `width`, `height` - Rectangle in luma coordinates.
+* `rgb` - Colour in RGB.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or rectangle is not inside the image.")]
+        pub fn $method(
+            image: &mut YuvPlanarImageMut<u8>,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            rgb: [u8; 3],
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            let color = rgb_to_yuv_color(rgb.map(|x| x as u16), 8, range, matrix)?;
+            fill_planar_impl(image, $sampling, (x, y, width, height), color)
+        }
+
+        #[doc = concat!("Fills rectangle of high bit-depth ", $yuv_name, " planar image with RGB colour.
+
+Colour is converted with given range and matrix, `rgb` has the same `bit_depth` as image.
+Every chroma sample whose block intersects the rectangle is filled.
+
+# Arguments
+
+* `image` - Target planar image.
+* `x`, `y`, `width`, `height` - Rectangle in luma coordinates.
+* `rgb` - Colour in RGB.
+* `bit_depth` - Bit-depth of image and colour, 8..=16.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or rectangle is not inside the image,
+or bit depth is not in 8..=16.")]
+        pub fn $method_p16(
+            image: &mut YuvPlanarImageMut<u16>,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            rgb: [u16; 3],
+            bit_depth: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            let color = rgb_to_yuv_color(rgb, bit_depth, range, matrix)?;
+            fill_planar_impl(image, $sampling, (x, y, width, height), color)
+        }
+    };
+}
+
+d_fill_planar!(
+    fill_rect_yuv420,
+    fill_rect_yuv420_p16,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420"
+);
+d_fill_planar!(
+    fill_rect_yuv422,
+    fill_rect_yuv422_p16,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422"
+);
+d_fill_planar!(
+    fill_rect_yuv444,
+    fill_rect_yuv444_p16,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444"
+);
+
+macro_rules! d_fill_bi_planar {
+    ($method: ident, $method_p16: ident, $sampling: expr, $order: expr, $yuv_name: expr) => {
+        #[doc = concat!("Fills rectangle of 8 bit-depth ", $yuv_name, " bi-planar image with RGB colour.
+
+Colour is converted with given range and matrix. Every chroma sample
+whose block intersects the rectangle is filled.
+
+# Arguments
+
+* `image` - Target bi-planar image.
+* `x`, `y`, `width`, `height` - Rectangle in luma coordinates.
+* `rgb` - Colour in RGB.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or rectangle is not inside the image.")]
+        pub fn $method(
+            image: &mut YuvBiPlanarImageMut<u8>,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            rgb: [u8; 3],
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            let color = rgb_to_yuv_color(rgb.map(|x| x as u16), 8, range, matrix)?;
+            fill_bi_planar_impl(image, $sampling, $order, (x, y, width, height), color)
+        }
+
+        #[doc = concat!("Fills rectangle of high bit-depth ", $yuv_name, " bi-planar image with RGB colour.
+
+Colour is converted with given range and matrix, `rgb` has the same `bit_depth` as image.
+Every chroma sample whose block intersects the rectangle is filled.
+
+# Arguments
+
+* `image` - Target bi-planar image.
+* `x`, `y`, `width`, `height` - Rectangle in luma coordinates.
+* `rgb` - Colour in RGB.
+* `bit_depth` - Bit-depth of image and colour, 8..=16.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or rectangle is not inside the image,
+or bit depth is not in 8..=16.")]
+        pub fn $method_p16(
+            image: &mut YuvBiPlanarImageMut<u16>,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            rgb: [u16; 3],
+            bit_depth: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            let color = rgb_to_yuv_color(rgb, bit_depth, range, matrix)?;
+            fill_bi_planar_impl(image, $sampling, $order, (x, y, width, height), color)
+        }
+    };
+}
+
+d_fill_bi_planar!(
+    fill_rect_nv12,
+    fill_rect_nv12_p16,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::UV,
+    "NV12"
+);
+d_fill_bi_planar!(
+    fill_rect_nv21,
+    fill_rect_nv21_p16,
+    YuvChromaSubsampling::Yuv420,
+    YuvNVOrder::VU,
+    "NV21"
+);
+d_fill_bi_planar!(
+    fill_rect_nv16,
+    fill_rect_nv16_p16,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::UV,
+    "NV16"
+);
+d_fill_bi_planar!(
+    fill_rect_nv61,
+    fill_rect_nv61_p16,
+    YuvChromaSubsampling::Yuv422,
+    YuvNVOrder::VU,
+    "NV61"
+);
+d_fill_bi_planar!(
+    fill_rect_nv24,
+    fill_rect_nv24_p16,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::UV,
+    "NV24"
+);
+d_fill_bi_planar!(
+    fill_rect_nv42,
+    fill_rect_nv42_p16,
+    YuvChromaSubsampling::Yuv444,
+    YuvNVOrder::VU,
+    "NV42"
+);
+
+/// Fills rectangle of 8 bit-depth gray image with luma of RGB colour.
+///
+/// # Arguments
+///
+/// * `image` - Target gray image.
+/// * `x`, `y`, `width`, `height` - Rectangle to fill.
+/// * `rgb` - Colour in RGB.
+/// * `range` - YUV values range. See [YuvRange] for more info.
+/// * `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the length of the plane is not valid based
+/// on the specified width, height, and stride, or rectangle is not inside the image.
+pub fn fill_rect_yuv400(
+    image: &mut YuvGrayImageMut<u8>,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    rgb: [u8; 3],
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let color = rgb_to_yuv_color(rgb.map(|x| x as u16), 8, range, matrix)?;
+    fill_gray_impl(image, (x, y, width, height), color)
+}
+
+/// Fills rectangle of high bit-depth gray image with luma of RGB colour.
+///
+/// `rgb` has the same `bit_depth` as image.
+///
+/// # Arguments
+///
+/// * `image` - Target gray image.
+/// * `x`, `y`, `width`, `height` - Rectangle to fill.
+/// * `rgb` - Colour in RGB.
+/// * `bit_depth` - Bit-depth of image and colour, 8..=16.
+/// * `range` - YUV values range. See [YuvRange] for more info.
+/// * `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the length of the plane is not valid based
+/// on the specified width, height, and stride, or rectangle is not inside the image,
+/// or bit depth is not in 8..=16.
+pub fn fill_rect_yuv400_p16(
+    image: &mut YuvGrayImageMut<u16>,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+    rgb: [u16; 3],
+    bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let color = rgb_to_yuv_color(rgb, bit_depth, range, matrix)?;
+    fill_gray_impl(image, (x, y, width, height), color)
+}
+
+macro_rules! d_packed {
+    ($fill: ident, $fill_p16: ident, $pad: ident, $pad_p16: ident, $yuy2: expr, $yuv_name: expr) => {
+        #[doc = concat!("Fills rectangle of 8 bit-depth ", $yuv_name, " packed image with RGB colour.
+
+Colour is converted with given range and matrix. Chroma of every macropixel
+intersecting the rectangle is filled.
+
+# Arguments
+
+* `image` - Target packed image.
+* `x`, `y`, `width`, `height` - Rectangle to fill.
+* `rgb` - Colour in RGB.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+
+# Errors
+
+This function returns an error if the length of packed data is not valid based
+on the specified width, height, and stride, or rectangle is not inside the image.")]
+        pub fn $fill(
+            image: &mut YuvPackedImageMut<u8>,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            rgb: [u8; 3],
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            let color = rgb_to_yuv_color(rgb.map(|x| x as u16), 8, range, matrix)?;
+            fill_packed_impl::<u8, { $yuy2 as usize }>(image, (x, y, width, height), color)
+        }
+
+        #[doc = concat!("Fills rectangle of high bit-depth ", $yuv_name, " packed image with RGB colour.
+
+Colour is converted with given range and matrix, `rgb` has the same `bit_depth` as image.
+Chroma of every macropixel intersecting the rectangle is filled.
+
+# Arguments
+
+* `image` - Target packed image.
+* `x`, `y`, `width`, `height` - Rectangle to fill.
+* `rgb` - Colour in RGB.
+* `bit_depth` - Bit-depth of image and colour, 8..=16.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+
+# Errors
+
+This function returns an error if the length of packed data is not valid based
+on the specified width, height, and stride, or rectangle is not inside the image,
+or bit depth is not in 8..=16.")]
+        pub fn $fill_p16(
+            image: &mut YuvPackedImageMut<u16>,
+            x: u32,
+            y: u32,
+            width: u32,
+            height: u32,
+            rgb: [u16; 3],
+            bit_depth: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            let color = rgb_to_yuv_color(rgb, bit_depth, range, matrix)?;
+            fill_packed_impl::<u16, { $yuy2 as usize }>(image, (x, y, width, height), color)
+        }
+
+        #[doc = concat!("Extends 8 bit-depth ", $yuv_name, " packed image content to the full image size by edge replication.
+
+Content occupies top left `content_width` x `content_height` corner of the image.
+
+# Errors
+
+This function returns an error if the length of packed data is not valid based
+on the specified width, height, and stride, or content is larger than the image.")]
+        pub fn $pad(
+            image: &mut YuvPackedImageMut<u8>,
+            content_width: u32,
+            content_height: u32,
+        ) -> Result<(), YuvError> {
+            pad_packed_impl::<u8, { $yuy2 as usize }>(image, content_width, content_height)
+        }
+
+        #[doc = concat!("Extends high bit-depth ", $yuv_name, " packed image content to the full image size by edge replication.
+
+Content occupies top left `content_width` x `content_height` corner of the image.
+
+# Errors
+
+This function returns an error if the length of packed data is not valid based
+on the specified width, height, and stride, or content is larger than the image.")]
+        pub fn $pad_p16(
+            image: &mut YuvPackedImageMut<u16>,
+            content_width: u32,
+            content_height: u32,
+        ) -> Result<(), YuvError> {
+            pad_packed_impl::<u16, { $yuy2 as usize }>(image, content_width, content_height)
+        }
+    };
+}
+
+d_packed!(
+    fill_rect_yuyv422,
+    fill_rect_yuyv422_p16,
+    pad_edges_yuyv422,
+    pad_edges_yuyv422_p16,
+    Yuy2Description::YUYV,
+    "YUYV"
+);
+d_packed!(
+    fill_rect_uyvy422,
+    fill_rect_uyvy422_p16,
+    pad_edges_uyvy422,
+    pad_edges_uyvy422_p16,
+    Yuy2Description::UYVY,
+    "UYVY"
+);
+d_packed!(
+    fill_rect_yvyu422,
+    fill_rect_yvyu422_p16,
+    pad_edges_yvyu422,
+    pad_edges_yvyu422_p16,
+    Yuy2Description::YVYU,
+    "YVYU"
+);
+d_packed!(
+    fill_rect_vyuy422,
+    fill_rect_vyuy422_p16,
+    pad_edges_vyuy422,
+    pad_edges_vyuy422_p16,
+    Yuy2Description::VYUY,
+    "VYUY"
+);
+
+fn check_same_size(src: (u32, u32), dst: (u32, u32)) -> Result<(), YuvError> {
+    if src != dst {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+    Ok(())
+}
+
+fn copy_planar_impl<T: Copy + Debug>(
+    src: &YuvPlanarImage<T>,
+    dst: &mut YuvPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+) -> Result<(), YuvError> {
+    src.check_constraints(subsampling)?;
+    dst.check_constraints(subsampling)?;
+    check_same_size((src.width, src.height), (dst.width, dst.height))?;
+    let (width, height) = (src.width as usize, src.height as usize);
+    let chroma_width = subsampling.chroma_width(width);
+    let chroma_height = subsampling.chroma_height(height);
+    copy_plane(
+        src.y_plane,
+        src.y_stride as usize,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride as usize,
+        width,
+        height,
+    );
+    copy_plane(
+        src.u_plane,
+        src.u_stride as usize,
+        dst.u_plane.borrow_mut(),
+        dst.u_stride as usize,
+        chroma_width,
+        chroma_height,
+    );
+    copy_plane(
+        src.v_plane,
+        src.v_stride as usize,
+        dst.v_plane.borrow_mut(),
+        dst.v_stride as usize,
+        chroma_width,
+        chroma_height,
+    );
+    Ok(())
+}
+
+fn copy_bi_planar_impl<T: Copy + Debug>(
+    src: &YuvBiPlanarImage<T>,
+    dst: &mut YuvBiPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+) -> Result<(), YuvError> {
+    src.check_constraints(subsampling)?;
+    dst.check_constraints(subsampling)?;
+    check_same_size((src.width, src.height), (dst.width, dst.height))?;
+    let (width, height) = (src.width as usize, src.height as usize);
+    copy_plane(
+        src.y_plane,
+        src.y_stride as usize,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride as usize,
+        width,
+        height,
+    );
+    copy_plane(
+        src.uv_plane,
+        src.uv_stride as usize,
+        dst.uv_plane.borrow_mut(),
+        dst.uv_stride as usize,
+        subsampling.chroma_width(width) * 2,
+        subsampling.chroma_height(height),
+    );
+    Ok(())
+}
+
+fn copy_gray_impl<T: Copy + Debug>(
+    src: &YuvGrayImage<T>,
+    dst: &mut YuvGrayImageMut<T>,
+) -> Result<(), YuvError> {
+    src.check_constraints()?;
+    dst.check_constraints()?;
+    check_same_size((src.width, src.height), (dst.width, dst.height))?;
+    copy_plane(
+        src.y_plane,
+        src.y_stride as usize,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride as usize,
+        src.width as usize,
+        src.height as usize,
+    );
+    Ok(())
+}
+
+fn copy_packed_impl<T: Copy + Debug>(
+    src: &YuvPackedImage<T>,
+    dst: &mut YuvPackedImageMut<T>,
+) -> Result<(), YuvError> {
+    src.check_constraints()?;
+    dst.check_constraints()?;
+    check_same_size((src.width, src.height), (dst.width, dst.height))?;
+    copy_plane(
+        src.yuy,
+        src.yuy_stride as usize,
+        dst.yuy.borrow_mut(),
+        dst.yuy_stride as usize,
+        (src.width as usize).div_ceil(2) * 4,
+        src.height as usize,
+    );
+    Ok(())
+}
+
+fn pad_planar_impl<T: Copy + Debug>(
+    image: &mut YuvPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    content_width: u32,
+    content_height: u32,
+) -> Result<(), YuvError> {
+    image.check_constraints(subsampling)?;
+    check_content(image.width, image.height, content_width, content_height)?;
+    let size = (image.width as usize, image.height as usize);
+    let content = (content_width as usize, content_height as usize);
+    let chroma_size = (
+        subsampling.chroma_width(size.0),
+        subsampling.chroma_height(size.1),
+    );
+    let chroma_content = (
+        subsampling.chroma_width(content.0),
+        subsampling.chroma_height(content.1),
+    );
+    pad_plane(
+        image.y_plane.borrow_mut(),
+        image.y_stride as usize,
+        1,
+        content,
+        size,
+    );
+    pad_plane(
+        image.u_plane.borrow_mut(),
+        image.u_stride as usize,
+        1,
+        chroma_content,
+        chroma_size,
+    );
+    pad_plane(
+        image.v_plane.borrow_mut(),
+        image.v_stride as usize,
+        1,
+        chroma_content,
+        chroma_size,
+    );
+    Ok(())
+}
+
+fn pad_bi_planar_impl<T: Copy + Debug>(
+    image: &mut YuvBiPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    content_width: u32,
+    content_height: u32,
+) -> Result<(), YuvError> {
+    image.check_constraints(subsampling)?;
+    check_content(image.width, image.height, content_width, content_height)?;
+    let size = (image.width as usize, image.height as usize);
+    let content = (content_width as usize, content_height as usize);
+    pad_plane(
+        image.y_plane.borrow_mut(),
+        image.y_stride as usize,
+        1,
+        content,
+        size,
+    );
+    pad_plane(
+        image.uv_plane.borrow_mut(),
+        image.uv_stride as usize,
+        2,
+        (
+            subsampling.chroma_width(content.0),
+            subsampling.chroma_height(content.1),
+        ),
+        (
+            subsampling.chroma_width(size.0),
+            subsampling.chroma_height(size.1),
+        ),
+    );
+    Ok(())
+}
+
+fn pad_gray_impl<T: Copy + Debug>(
+    image: &mut YuvGrayImageMut<T>,
+    content_width: u32,
+    content_height: u32,
+) -> Result<(), YuvError> {
+    image.check_constraints()?;
+    check_content(image.width, image.height, content_width, content_height)?;
+    let size = (image.width as usize, image.height as usize);
+    pad_plane(
+        image.y_plane.borrow_mut(),
+        image.y_stride as usize,
+        1,
+        (content_width as usize, content_height as usize),
+        size,
+    );
+    Ok(())
+}
+
+macro_rules! d_copy_pad {
+    ($copy: ident, $pad: ident, $v: ty, $depth: expr) => {
+        #[doc = concat!("Copies ", $depth, " planar image into another one with possibly different strides.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $copy(
+            src: &YuvPlanarImage<$v>,
+            dst: &mut YuvPlanarImageMut<$v>,
+            subsampling: YuvChromaSubsampling,
+        ) -> Result<(), YuvError> {
+            copy_planar_impl(src, dst, subsampling)
+        }
+
+        #[doc = concat!("Extends ", $depth, " planar image content to the full image size by edge replication.
+
+Content occupies top left `content_width` x `content_height` corner of the image,
+e.g. a frame allocated with dimensions aligned to 16 or 64.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or content is larger than the image.")]
+        pub fn $pad(
+            image: &mut YuvPlanarImageMut<$v>,
+            subsampling: YuvChromaSubsampling,
+            content_width: u32,
+            content_height: u32,
+        ) -> Result<(), YuvError> {
+            pad_planar_impl(image, subsampling, content_width, content_height)
+        }
+    };
+}
+
+d_copy_pad!(copy_yuv_planar, pad_edges_yuv_planar, u8, "8 bit-depth");
+d_copy_pad!(
+    copy_yuv_planar_p16,
+    pad_edges_yuv_planar_p16,
+    u16,
+    "high bit-depth"
+);
+
+macro_rules! d_copy_pad_bi_planar {
+    ($copy: ident, $pad: ident, $v: ty, $depth: expr) => {
+        #[doc = concat!("Copies ", $depth, " bi-planar image into another one with possibly different strides.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $copy(
+            src: &YuvBiPlanarImage<$v>,
+            dst: &mut YuvBiPlanarImageMut<$v>,
+            subsampling: YuvChromaSubsampling,
+        ) -> Result<(), YuvError> {
+            copy_bi_planar_impl(src, dst, subsampling)
+        }
+
+        #[doc = concat!("Extends ", $depth, " bi-planar image content to the full image size by edge replication.
+
+Content occupies top left `content_width` x `content_height` corner of the image,
+e.g. a frame allocated with dimensions aligned to 16 or 64.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or content is larger than the image.")]
+        pub fn $pad(
+            image: &mut YuvBiPlanarImageMut<$v>,
+            subsampling: YuvChromaSubsampling,
+            content_width: u32,
+            content_height: u32,
+        ) -> Result<(), YuvError> {
+            pad_bi_planar_impl(image, subsampling, content_width, content_height)
+        }
+    };
+}
+
+d_copy_pad_bi_planar!(
+    copy_yuv_bi_planar,
+    pad_edges_yuv_bi_planar,
+    u8,
+    "8 bit-depth"
+);
+d_copy_pad_bi_planar!(
+    copy_yuv_bi_planar_p16,
+    pad_edges_yuv_bi_planar_p16,
+    u16,
+    "high bit-depth"
+);
+
+macro_rules! d_copy_pad_gray {
+    ($copy: ident, $pad: ident, $copy_packed: ident, $v: ty, $depth: expr) => {
+        #[doc = concat!("Copies ", $depth, " gray image into another one with possibly different strides.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $copy(
+            src: &YuvGrayImage<$v>,
+            dst: &mut YuvGrayImageMut<$v>,
+        ) -> Result<(), YuvError> {
+            copy_gray_impl(src, dst)
+        }
+
+        #[doc = concat!("Extends ", $depth, " gray image content to the full image size by edge replication.
+
+Content occupies top left `content_width` x `content_height` corner of the image.
+
+# Errors
+
+This function returns an error if the length of the plane is not valid based
+on the specified width, height, and stride, or content is larger than the image.")]
+        pub fn $pad(
+            image: &mut YuvGrayImageMut<$v>,
+            content_width: u32,
+            content_height: u32,
+        ) -> Result<(), YuvError> {
+            pad_gray_impl(image, content_width, content_height)
+        }
+
+        #[doc = concat!("Copies ", $depth, " packed 4:2:2 image of any byte order into another one with possibly different strides.
+
+# Errors
+
+This function returns an error if the lengths of packed data is not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $copy_packed(
+            src: &YuvPackedImage<$v>,
+            dst: &mut YuvPackedImageMut<$v>,
+        ) -> Result<(), YuvError> {
+            copy_packed_impl(src, dst)
+        }
+    };
+}
+
+d_copy_pad_gray!(
+    copy_yuv_gray,
+    pad_edges_yuv_gray,
+    copy_yuv_packed,
+    u8,
+    "8 bit-depth"
+);
+d_copy_pad_gray!(
+    copy_yuv_gray_p16,
+    pad_edges_yuv_gray_p16,
+    copy_yuv_packed_p16,
+    u16,
+    "high bit-depth"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fill_and_pad_subsampled_frame() {
+        let mut image = YuvBiPlanarImageMut::<u8>::alloc(8, 6, YuvChromaSubsampling::Yuv420);
+        fill_rect_nv21(
+            &mut image,
+            1,
+            0,
+            3,
+            3,
+            [255, 255, 255],
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        let y_plane = image.y_plane.borrow();
+        assert_eq!(&y_plane[..5], &[0, 235, 235, 235, 0]);
+        assert_eq!(y_plane[3 * 8 + 1], 0);
+        // Chroma blocks 0 and 1 of rows 0 and 1 intersect the rectangle
+        let uv = image.uv_plane.borrow();
+        assert_eq!(&uv[..6], &[128, 128, 128, 128, 0, 0]);
+        assert_eq!(&uv[8..12], &[128, 128, 128, 128]);
+        assert_eq!(&uv[16..18], &[0, 0]);
+
+        pad_edges_yuv_bi_planar(&mut image, YuvChromaSubsampling::Yuv420, 3, 3).unwrap();
+        let y_plane = image.y_plane.borrow();
+        assert!(y_plane[..8].iter().skip(1).all(|&v| v == 235));
+        assert_eq!(&y_plane[5 * 8..5 * 8 + 3], &[0, 235, 235]);
+        assert!(image.uv_plane.borrow().iter().all(|&v| v == 128));
+
+        let mut packed = YuvPackedImageMut::<u16> {
+            yuy: crate::BufferStoreMut::Owned(vec![0u16; 4 * 4 * 2]),
+            yuy_stride: 16,
+            width: 7,
+            height: 2,
+        };
+        fill_rect_uyvy422_p16(
+            &mut packed,
+            0,
+            0,
+            3,
+            1,
+            [1023, 0, 0],
+            10,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        pad_edges_uyvy422_p16(&mut packed, 3, 1).unwrap();
+        let yuy = packed.yuy.borrow();
+        let first = &yuy[..4];
+        assert_eq!(&yuy[4..8], first);
+        assert_eq!(&yuy[8..12], &[first[0], first[1], first[2], first[1]]);
+        assert_eq!(&yuy[16..32], &yuy[..16]);
+
+        let mut copy = YuvPackedImageMut::<u16> {
+            yuy: crate::BufferStoreMut::Owned(vec![0u16; 4 * 4 * 2]),
+            yuy_stride: 16,
+            width: 7,
+            height: 2,
+        };
+        copy_yuv_packed_p16(&packed.to_fixed(), &mut copy).unwrap();
+        assert_eq!(copy.yuy.borrow(), packed.yuy.borrow());
+    }
+
+    #[test]
+    fn fill_interleaved_chroma_and_reject_bit_depth() {
+        let mut image = YuvBiPlanarImageMut::<u16>::alloc(14, 2, YuvChromaSubsampling::Yuv420);
+        fill_rect_nv12_p16(
+            &mut image,
+            2,
+            0,
+            10,
+            2,
+            [0, 0, 1023],
+            10,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        let uv = image.uv_plane.borrow();
+        let (u, v) = (uv[2], uv[3]);
+        assert!(u > 512 && v < 512);
+        assert_eq!(&uv[..2], &[0, 0]);
+        assert!(uv[2..12].chunks_exact(2).all(|x| x == [u, v]));
+        assert_eq!(&uv[12..], &[0, 0]);
+
+        assert!(matches!(
+            fill_rect_nv12_p16(
+                &mut image,
+                0,
+                0,
+                2,
+                2,
+                [0, 0, 0],
+                17,
+                YuvRange::Full,
+                YuvStandardMatrix::Bt709,
+            ),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+    }
+}
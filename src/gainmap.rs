@@ -0,0 +1,615 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::sharpyuv::pq_from_linear;
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::YuvSourceChannels;
+use crate::{SharpYuvGammaTransfer, YuvError, YuvGrayImage, YuvGrayImageMut, YuvStandardMatrix};
+#[cfg(feature = "nightly_f16")]
+use core::f16;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Gain map metadata as defined by Ultra HDR and ISO 21496-1.
+///
+/// Boosts and capacities are linear ratios against SDR white, not log2 values.
+#[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+pub struct GainMapMetadata {
+    /// Minimum boost applied where gain map is 0
+    pub min_content_boost: f32,
+    /// Maximum boost applied where gain map is 1
+    pub max_content_boost: f32,
+    /// Gamma stored gain map values are encoded with
+    pub gamma: f32,
+    /// Offset added to SDR linear values before ratio is taken
+    pub offset_sdr: f32,
+    /// Offset added to HDR linear values before ratio is taken
+    pub offset_hdr: f32,
+    /// Display boost where gain map starts to apply
+    pub hdr_capacity_min: f32,
+    /// Display boost where gain map is applied fully
+    pub hdr_capacity_max: f32,
+}
+
+impl Default for GainMapMetadata {
+    fn default() -> Self {
+        GainMapMetadata {
+            min_content_boost: 1f32,
+            max_content_boost: 4f32,
+            gamma: 1f32,
+            offset_sdr: 1f32 / 64f32,
+            offset_hdr: 1f32 / 64f32,
+            hdr_capacity_min: 1f32,
+            hdr_capacity_max: 4f32,
+        }
+    }
+}
+
+impl GainMapMetadata {
+    /// Computes weight of gain map for display with given HDR headroom
+    pub fn weight(&self, display_boost: f32) -> f32 {
+        let log_min = self.hdr_capacity_min.log2();
+        let log_max = self.hdr_capacity_max.log2();
+        if log_max <= log_min {
+            return if display_boost >= self.hdr_capacity_max {
+                1f32
+            } else {
+                0f32
+            };
+        }
+        ((display_boost.log2() - log_min) / (log_max - log_min))
+            .min(1f32)
+            .max(0f32)
+    }
+}
+
+const GAIN_LUT_SIZE: usize = 1024;
+
+/// Precomputes boost factor for normalized gain map values
+fn make_boost_lut(metadata: &GainMapMetadata, weight: f32) -> Vec<f32> {
+    let log_min = metadata.min_content_boost.log2();
+    let log_max = metadata.max_content_boost.log2();
+    let inverse_gamma = 1f32 / metadata.gamma;
+    (0..GAIN_LUT_SIZE)
+        .map(|x| {
+            let recovery = (x as f32 / (GAIN_LUT_SIZE - 1) as f32).powf(inverse_gamma);
+            let log_boost = log_min * (1f32 - recovery) + log_max * recovery;
+            (log_boost * weight).exp2()
+        })
+        .collect()
+}
+
+/// Bilinear sample of gain map in [0;1] for image position
+#[inline]
+fn sample_gain_map(gain_map: &YuvGrayImage<u8>, gx: f32, gy: f32) -> f32 {
+    let max_x = gain_map.width as usize - 1;
+    let max_y = gain_map.height as usize - 1;
+    let gx = gx.max(0f32);
+    let gy = gy.max(0f32);
+    let x0 = (gx as usize).min(max_x);
+    let y0 = (gy as usize).min(max_y);
+    let x1 = (x0 + 1).min(max_x);
+    let y1 = (y0 + 1).min(max_y);
+    let fx = (gx - x0 as f32).min(1f32);
+    let fy = (gy - y0 as f32).min(1f32);
+    let stride = gain_map.y_stride as usize;
+    let row0 = &gain_map.y_plane[y0 * stride..];
+    let row1 = &gain_map.y_plane[y1 * stride..];
+    let top = row0[x0] as f32 + (row0[x1] as f32 - row0[x0] as f32) * fx;
+    let bottom = row1[x0] as f32 + (row1[x1] as f32 - row1[x0] as f32) * fx;
+    (top + (bottom - top) * fy) * (1f32 / 255f32)
+}
+
+fn apply_gain_map_impl<V: Copy + Send, const CHANNELS: u8>(
+    sdr: &[u8],
+    sdr_stride: u32,
+    gain_map: &YuvGrayImage<u8>,
+    metadata: &GainMapMetadata,
+    sdr_transfer: SharpYuvGammaTransfer,
+    display_boost: f32,
+    dst: &mut [V],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    store: impl Fn(f32) -> V + Send + Sync,
+    store_alpha: impl Fn(u8) -> V + Send + Sync,
+) -> Result<(), YuvError> {
+    let channels: YuvSourceChannels = CHANNELS.into();
+    let cn = channels.get_channels_count();
+    check_rgba_destination(sdr, sdr_stride, width, height, cn)?;
+    check_rgba_destination(dst, dst_stride, width, height, cn)?;
+    gain_map.check_constraints()?;
+
+    let mut linear_lut = [0f32; 256];
+    for (i, dst) in linear_lut.iter_mut().enumerate() {
+        *dst = sdr_transfer.linearize(i as f32 * (1f32 / 255f32));
+    }
+    let boost_lut = make_boost_lut(metadata, metadata.weight(display_boost));
+    let scale_x = gain_map.width as f32 / width as f32;
+    let scale_y = gain_map.height as f32 / height as f32;
+    let width = width as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize)
+            .zip(sdr.par_chunks(sdr_stride as usize))
+            .take(height as usize)
+            .enumerate();
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize)
+            .zip(sdr.chunks(sdr_stride as usize))
+            .take(height as usize)
+            .enumerate();
+    }
+    iter.for_each(|(y, (dst, sdr))| {
+        let gy = (y as f32 + 0.5f32) * scale_y - 0.5f32;
+        for (x, (dst, sdr)) in dst[0..width * cn]
+            .chunks_exact_mut(cn)
+            .zip(sdr[0..width * cn].chunks_exact(cn))
+            .enumerate()
+        {
+            let gx = (x as f32 + 0.5f32) * scale_x - 0.5f32;
+            let gain = sample_gain_map(gain_map, gx, gy);
+            let boost = boost_lut[(gain * (GAIN_LUT_SIZE - 1) as f32).round() as usize];
+            for offset in [
+                channels.get_r_channel_offset(),
+                channels.get_g_channel_offset(),
+                channels.get_b_channel_offset(),
+            ] {
+                let linear = linear_lut[sdr[offset] as usize];
+                dst[offset] =
+                    store(((linear + metadata.offset_sdr) * boost - metadata.offset_hdr).max(0f32));
+            }
+            if channels.has_alpha() {
+                let a_offset = channels.get_a_channel_offset();
+                dst[a_offset] = store_alpha(sdr[a_offset]);
+            }
+        }
+    });
+
+    Ok(())
+}
+
+macro_rules! d_apply_f32 {
+    ($method: ident, $px_fmt: expr, $rgb_name: expr) => {
+        #[doc = concat!("Applies single channel gain map to SDR ", $rgb_name, " image and produces linear HDR ", $rgb_name, " f32 image.
+
+Output is linear light where 1.0 is SDR white, alpha is normalized to [0;1].
+Gain map may have lower resolution than the image, it is sampled with bilinear filter.
+
+# Arguments
+
+* `sdr` - Source SDR ", $rgb_name, " image.
+* `sdr_stride` - The stride (components per row) for SDR image.
+* `sdr_transfer` - Transfer function SDR image is encoded with.
+* `gain_map` - Single channel gain map, e.g. luma plane of YUV 400 image.
+* `metadata` - Gain map metadata.
+* `display_boost` - HDR headroom of target display, `metadata.hdr_capacity_max` applies gain map fully.
+* `dst` - A mutable slice to store linear HDR ", $rgb_name, " data.
+* `dst_stride` - The stride (components per row) for HDR image.
+* `width` - Image width.
+* `height` - Image height.
+
+# Errors
+
+This function returns an error if the lengths of images or gain map are not valid based
+on the specified width, height, and strides.
+")]
+        pub fn $method(
+            sdr: &[u8],
+            sdr_stride: u32,
+            sdr_transfer: SharpYuvGammaTransfer,
+            gain_map: &YuvGrayImage<u8>,
+            metadata: &GainMapMetadata,
+            display_boost: f32,
+            dst: &mut [f32],
+            dst_stride: u32,
+            width: u32,
+            height: u32,
+        ) -> Result<(), YuvError> {
+            apply_gain_map_impl::<f32, { $px_fmt as u8 }>(
+                sdr,
+                sdr_stride,
+                gain_map,
+                metadata,
+                sdr_transfer,
+                display_boost,
+                dst,
+                dst_stride,
+                width,
+                height,
+                |v| v,
+                |a| a as f32 * (1f32 / 255f32),
+            )
+        }
+    };
+}
+
+d_apply_f32!(apply_gain_map_rgb_f32, YuvSourceChannels::Rgb, "RGB");
+d_apply_f32!(apply_gain_map_rgba_f32, YuvSourceChannels::Rgba, "RGBA");
+
+#[cfg(feature = "nightly_f16")]
+macro_rules! d_apply_f16 {
+    ($method: ident, $px_fmt: expr, $rgb_name: expr) => {
+        #[doc = concat!("Applies single channel gain map to SDR ", $rgb_name, " image and produces linear HDR ", $rgb_name, " f16 image.
+
+Output is linear light where 1.0 is SDR white, alpha is normalized to [0;1].
+Gain map may have lower resolution than the image, it is sampled with bilinear filter.
+
+# Arguments
+
+* `sdr` - Source SDR ", $rgb_name, " image.
+* `sdr_stride` - The stride (components per row) for SDR image.
+* `sdr_transfer` - Transfer function SDR image is encoded with.
+* `gain_map` - Single channel gain map, e.g. luma plane of YUV 400 image.
+* `metadata` - Gain map metadata.
+* `display_boost` - HDR headroom of target display, `metadata.hdr_capacity_max` applies gain map fully.
+* `dst` - A mutable slice to store linear HDR ", $rgb_name, " data.
+* `dst_stride` - The stride (components per row) for HDR image.
+* `width` - Image width.
+* `height` - Image height.
+
+# Errors
+
+This function returns an error if the lengths of images or gain map are not valid based
+on the specified width, height, and strides.
+")]
+        pub fn $method(
+            sdr: &[u8],
+            sdr_stride: u32,
+            sdr_transfer: SharpYuvGammaTransfer,
+            gain_map: &YuvGrayImage<u8>,
+            metadata: &GainMapMetadata,
+            display_boost: f32,
+            dst: &mut [f16],
+            dst_stride: u32,
+            width: u32,
+            height: u32,
+        ) -> Result<(), YuvError> {
+            apply_gain_map_impl::<f16, { $px_fmt as u8 }>(
+                sdr,
+                sdr_stride,
+                gain_map,
+                metadata,
+                sdr_transfer,
+                display_boost,
+                dst,
+                dst_stride,
+                width,
+                height,
+                |v| v as f16,
+                |a| (a as f32 * (1f32 / 255f32)) as f16,
+            )
+        }
+    };
+}
+
+#[cfg(feature = "nightly_f16")]
+d_apply_f16!(apply_gain_map_rgb_f16, YuvSourceChannels::Rgb, "RGB");
+#[cfg(feature = "nightly_f16")]
+d_apply_f16!(apply_gain_map_rgba_f16, YuvSourceChannels::Rgba, "RGBA");
+
+macro_rules! d_apply_pq {
+    ($method: ident, $px_fmt: expr, $rgb_name: expr, $bit_depth: expr) => {
+        #[doc = concat!("Applies single channel gain map to SDR ", $rgb_name, " image and produces PQ encoded ", $rgb_name, " image with ", $bit_depth, " bit depth.
+
+Linear HDR values are scaled by `sdr_white_nits` and encoded with SMPTE ST 2084 transfer.
+Gain map may have lower resolution than the image, it is sampled with bilinear filter.
+
+# Arguments
+
+* `sdr` - Source SDR ", $rgb_name, " image.
+* `sdr_stride` - The stride (components per row) for SDR image.
+* `sdr_transfer` - Transfer function SDR image is encoded with.
+* `gain_map` - Single channel gain map, e.g. luma plane of YUV 400 image.
+* `metadata` - Gain map metadata.
+* `display_boost` - HDR headroom of target display, `metadata.hdr_capacity_max` applies gain map fully.
+* `sdr_white_nits` - Luminance of SDR white in nits, 203 is recommended by ITU-R BT.2408.
+* `dst` - A mutable slice to store PQ encoded ", $rgb_name, " data.
+* `dst_stride` - The stride (components per row) for HDR image.
+* `width` - Image width.
+* `height` - Image height.
+
+# Errors
+
+This function returns an error if the lengths of images or gain map are not valid based
+on the specified width, height, and strides.
+")]
+        pub fn $method(
+            sdr: &[u8],
+            sdr_stride: u32,
+            sdr_transfer: SharpYuvGammaTransfer,
+            gain_map: &YuvGrayImage<u8>,
+            metadata: &GainMapMetadata,
+            display_boost: f32,
+            sdr_white_nits: f32,
+            dst: &mut [u16],
+            dst_stride: u32,
+            width: u32,
+            height: u32,
+        ) -> Result<(), YuvError> {
+            let max_value = ((1u32 << $bit_depth) - 1) as f32;
+            let nits_scale = sdr_white_nits / 10000f32;
+            apply_gain_map_impl::<u16, { $px_fmt as u8 }>(
+                sdr,
+                sdr_stride,
+                gain_map,
+                metadata,
+                sdr_transfer,
+                display_boost,
+                dst,
+                dst_stride,
+                width,
+                height,
+                |v| (pq_from_linear(v * nits_scale) * max_value).round() as u16,
+                |a| (a as f32 * (max_value / 255f32)).round() as u16,
+            )
+        }
+    };
+}
+
+d_apply_pq!(apply_gain_map_rgb_pq10, YuvSourceChannels::Rgb, "RGB", 10);
+d_apply_pq!(
+    apply_gain_map_rgba_pq10,
+    YuvSourceChannels::Rgba,
+    "RGBA",
+    10
+);
+d_apply_pq!(apply_gain_map_rgb_pq12, YuvSourceChannels::Rgb, "RGB", 12);
+d_apply_pq!(
+    apply_gain_map_rgba_pq12,
+    YuvSourceChannels::Rgba,
+    "RGBA",
+    12
+);
+d_apply_pq!(apply_gain_map_rgb_pq16, YuvSourceChannels::Rgb, "RGB", 16);
+d_apply_pq!(
+    apply_gain_map_rgba_pq16,
+    YuvSourceChannels::Rgba,
+    "RGBA",
+    16
+);
+
+fn generate_gain_map_impl<const CHANNELS: u8>(
+    sdr: &[u8],
+    sdr_stride: u32,
+    sdr_transfer: SharpYuvGammaTransfer,
+    hdr: &[f32],
+    hdr_stride: u32,
+    width: u32,
+    height: u32,
+    matrix: YuvStandardMatrix,
+    metadata: &GainMapMetadata,
+    gain_map: &mut YuvGrayImageMut<u8>,
+) -> Result<(), YuvError> {
+    let channels: YuvSourceChannels = CHANNELS.into();
+    let cn = channels.get_channels_count();
+    check_rgba_destination(sdr, sdr_stride, width, height, cn)?;
+    check_rgba_destination(hdr, hdr_stride, width, height, cn)?;
+    gain_map.check_constraints()?;
+    if gain_map.width > width || gain_map.height > height {
+        return Err(YuvError::ImageDimensionsNotMatch);
+    }
+
+    let mut linear_lut = [0f32; 256];
+    for (i, dst) in linear_lut.iter_mut().enumerate() {
+        *dst = sdr_transfer.linearize(i as f32 * (1f32 / 255f32));
+    }
+    let bias = matrix.get_kr_kb();
+    let kg = 1f32 - bias.kr - bias.kb;
+    let log_min = metadata.min_content_boost.log2();
+    let log_max = metadata.max_content_boost.log2();
+    let log_range = (log_max - log_min).max(f32::EPSILON);
+
+    let map_width = gain_map.width as usize;
+    let map_height = gain_map.height as usize;
+    let map_stride = gain_map.y_stride as usize;
+    let width = width as usize;
+    let height = height as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = gain_map
+            .y_plane
+            .borrow_mut()
+            .par_chunks_mut(map_stride)
+            .take(map_height)
+            .enumerate();
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = gain_map
+            .y_plane
+            .borrow_mut()
+            .chunks_mut(map_stride)
+            .take(map_height)
+            .enumerate();
+    }
+    iter.for_each(|(gy, map_row)| {
+        // Every gain map pixel averages log gain of the source area it covers
+        let y_start = gy * height / map_height;
+        let y_end = ((gy + 1) * height / map_height).max(y_start + 1);
+        for (gx, dst) in map_row[0..map_width].iter_mut().enumerate() {
+            let x_start = gx * width / map_width;
+            let x_end = ((gx + 1) * width / map_width).max(x_start + 1);
+            let mut log_gain = 0f32;
+            for y in y_start..y_end {
+                let sdr_row = &sdr[y * sdr_stride as usize..];
+                let hdr_row = &hdr[y * hdr_stride as usize..];
+                for x in x_start..x_end {
+                    let px = x * cn;
+                    let sdr_luma = bias.kr
+                        * linear_lut[sdr_row[px + channels.get_r_channel_offset()] as usize]
+                        + kg * linear_lut[sdr_row[px + channels.get_g_channel_offset()] as usize]
+                        + bias.kb
+                            * linear_lut[sdr_row[px + channels.get_b_channel_offset()] as usize];
+                    let hdr_luma = bias.kr * hdr_row[px + channels.get_r_channel_offset()]
+                        + kg * hdr_row[px + channels.get_g_channel_offset()]
+                        + bias.kb * hdr_row[px + channels.get_b_channel_offset()];
+                    log_gain += ((hdr_luma.max(0f32) + metadata.offset_hdr)
+                        / (sdr_luma + metadata.offset_sdr))
+                        .log2();
+                }
+            }
+            log_gain /= ((y_end - y_start) * (x_end - x_start)) as f32;
+            let recovery = ((log_gain - log_min) / log_range).min(1f32).max(0f32);
+            *dst = (recovery.powf(metadata.gamma) * 255f32).round() as u8;
+        }
+    });
+
+    Ok(())
+}
+
+macro_rules! d_generate {
+    ($method: ident, $px_fmt: expr, $rgb_name: expr) => {
+        #[doc = concat!("Computes single channel gain map from SDR ", $rgb_name, " image and linear HDR ", $rgb_name, " f32 image.
+
+Gain is computed from luminance ratio and quantized with metadata min/max boost and gamma.
+Gain map may have lower resolution than the image, then each gain map pixel averages log gain of the area it covers.
+
+# Arguments
+
+* `sdr` - Source SDR ", $rgb_name, " image.
+* `sdr_stride` - The stride (components per row) for SDR image.
+* `sdr_transfer` - Transfer function SDR image is encoded with.
+* `hdr` - Source linear HDR ", $rgb_name, " image, where 1.0 is SDR white.
+* `hdr_stride` - The stride (components per row) for HDR image.
+* `width` - Image width.
+* `height` - Image height.
+* `matrix` - Matrix which luminance coefficients are taken from.
+* `metadata` - Gain map metadata.
+* `gain_map` - Target single channel gain map.
+
+# Errors
+
+This function returns an error if the lengths of images or gain map are not valid based
+on the specified width, height, and strides, or gain map is bigger than images.
+")]
+        pub fn $method(
+            sdr: &[u8],
+            sdr_stride: u32,
+            sdr_transfer: SharpYuvGammaTransfer,
+            hdr: &[f32],
+            hdr_stride: u32,
+            width: u32,
+            height: u32,
+            matrix: YuvStandardMatrix,
+            metadata: &GainMapMetadata,
+            gain_map: &mut YuvGrayImageMut<u8>,
+        ) -> Result<(), YuvError> {
+            generate_gain_map_impl::<{ $px_fmt as u8 }>(
+                sdr,
+                sdr_stride,
+                sdr_transfer,
+                hdr,
+                hdr_stride,
+                width,
+                height,
+                matrix,
+                metadata,
+                gain_map,
+            )
+        }
+    };
+}
+
+d_generate!(generate_gain_map_rgb_f32, YuvSourceChannels::Rgb, "RGB");
+d_generate!(generate_gain_map_rgba_f32, YuvSourceChannels::Rgba, "RGBA");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BufferStoreMut;
+
+    #[test]
+    fn test_gain_map_round_trip() {
+        let width = 16usize;
+        let height = 8usize;
+        let sdr = [200u8, 120u8, 60u8].repeat(width * height);
+        let metadata = GainMapMetadata::default();
+        let hdr = sdr
+            .iter()
+            .map(|&v| {
+                let linear = SharpYuvGammaTransfer::Srgb.linearize(v as f32 / 255f32);
+                (linear + metadata.offset_sdr) * 2f32 - metadata.offset_hdr
+            })
+            .collect::<Vec<f32>>();
+
+        let mut gain_map = YuvGrayImageMut::<u8> {
+            y_plane: BufferStoreMut::Owned(vec![0u8; width * height / 4]),
+            y_stride: width as u32 / 2,
+            width: width as u32 / 2,
+            height: height as u32 / 2,
+        };
+        generate_gain_map_rgb_f32(
+            &sdr,
+            width as u32 * 3,
+            SharpYuvGammaTransfer::Srgb,
+            &hdr,
+            width as u32 * 3,
+            width as u32,
+            height as u32,
+            YuvStandardMatrix::Bt709,
+            &metadata,
+            &mut gain_map,
+        )
+        .unwrap();
+        assert!(gain_map
+            .y_plane
+            .borrow()
+            .iter()
+            .all(|&v| (127..=128).contains(&v)));
+
+        let mut restored = vec![0f32; width * height * 3];
+        apply_gain_map_rgb_f32(
+            &sdr,
+            width as u32 * 3,
+            SharpYuvGammaTransfer::Srgb,
+            &gain_map.to_fixed(),
+            &metadata,
+            metadata.hdr_capacity_max,
+            &mut restored,
+            width as u32 * 3,
+            width as u32,
+            height as u32,
+        )
+        .unwrap();
+        for (&src, &dst) in hdr.iter().zip(restored.iter()) {
+            assert!((src - dst).abs() < 0.02f32, "Expected {src}, got {dst}");
+        }
+    }
+}
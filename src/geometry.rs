@@ -26,7 +26,11 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-use crate::YuvError;
+use crate::scale::{max_value, resample_plane, ScaleSample, ScalingFilter};
+use crate::{
+    YuvBiPlanarImage, YuvBiPlanarImageMut, YuvChromaSubsampling, YuvError, YuvPlanarImage,
+    YuvPlanarImageMut,
+};
 use fast_transpose::{
     rotate180_plane, rotate180_plane16, rotate180_plane16_with_alpha, rotate180_plane_with_alpha,
     rotate180_rgb, rotate180_rgb16, rotate180_rgba, rotate180_rgba16, transpose_plane,
@@ -34,12 +38,24 @@ use fast_transpose::{
     transpose_rgb16, transpose_rgba, transpose_rgba16, FlipMode, FlopMode, TransposeError,
 };
 
-/// Declares rotation mode, 90, 180, 270
+/// Declares rotation mode, 90, 180, 270, or reflection over one of the diagonals
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[non_exhaustive]
 pub enum RotationMode {
     Rotate90,
     Rotate180,
     Rotate270,
+    /// Reflection over the main diagonal, top-left corner stays in place
+    Transpose,
+    /// Reflection over the anti-diagonal, top-left corner moves to bottom-right
+    Transverse,
+}
+
+impl RotationMode {
+    /// Whether width and height are exchanged by this mode
+    pub const fn swaps_dimensions(self) -> bool {
+        !matches!(self, RotationMode::Rotate180)
+    }
 }
 
 #[inline]
@@ -53,6 +69,24 @@ pub(crate) fn map_ft_result(result: Result<(), TransposeError>) -> Result<(), Yu
     }
 }
 
+/// `fast_transpose` SIMD kernels always write the columns in flopped order,
+/// so `Rotate90` and `Transverse` are transposed with flop and rows are reversed afterwards.
+fn flip_reflected_rows<T>(
+    dst: &mut [T],
+    dst_stride: usize,
+    row_length: usize,
+    rows: usize,
+    mode: RotationMode,
+) {
+    if !matches!(mode, RotationMode::Rotate90 | RotationMode::Transverse) {
+        return;
+    }
+    for y in 0..rows / 2 {
+        let (top, bottom) = dst.split_at_mut((rows - 1 - y) * dst_stride);
+        top[y * dst_stride..][..row_length].swap_with_slice(&mut bottom[..row_length]);
+    }
+}
+
 /// Rotates RGBA 8 bit image.
 ///
 /// This rotates any 4 channels image, channel order does not matter.
@@ -87,7 +121,7 @@ pub fn rotate_rgba(
             width,
             height,
             FlipMode::NoFlip,
-            FlopMode::NoFlop,
+            FlopMode::Flop,
         ),
         RotationMode::Rotate180 => rotate180_rgba(src, src_stride, dst, dst_stride, width, height),
         RotationMode::Rotate270 => transpose_rgba(
@@ -100,8 +134,30 @@ pub fn rotate_rgba(
             FlipMode::Flip,
             FlopMode::Flop,
         ),
+        RotationMode::Transpose => transpose_rgba(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        RotationMode::Transverse => transpose_rgba(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::Flip,
+            FlopMode::Flop,
+        ),
     };
-    map_ft_result(rs)
+    map_ft_result(rs)?;
+    flip_reflected_rows(dst, dst_stride, height * 4, width, mode);
+    Ok(())
 }
 
 /// Rotates RGB 8 bit image.
@@ -138,7 +194,7 @@ pub fn rotate_rgb(
             width,
             height,
             FlipMode::NoFlip,
-            FlopMode::NoFlop,
+            FlopMode::Flop,
         ),
         RotationMode::Rotate180 => rotate180_rgb(src, src_stride, dst, dst_stride, width, height),
         RotationMode::Rotate270 => transpose_rgb(
@@ -151,8 +207,30 @@ pub fn rotate_rgb(
             FlipMode::Flip,
             FlopMode::Flop,
         ),
+        RotationMode::Transpose => transpose_rgb(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        RotationMode::Transverse => transpose_rgb(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::Flip,
+            FlopMode::Flop,
+        ),
     };
-    map_ft_result(rs)
+    map_ft_result(rs)?;
+    flip_reflected_rows(dst, dst_stride, height * 3, width, mode);
+    Ok(())
 }
 
 /// Rotates CbCr 8 bit image.
@@ -189,7 +267,7 @@ pub fn rotate_cbcr(
             width,
             height,
             FlipMode::NoFlip,
-            FlopMode::NoFlop,
+            FlopMode::Flop,
         ),
         RotationMode::Rotate180 => {
             rotate180_plane_with_alpha(src, src_stride, dst, dst_stride, width, height)
@@ -204,8 +282,30 @@ pub fn rotate_cbcr(
             FlipMode::Flip,
             FlopMode::Flop,
         ),
+        RotationMode::Transpose => transpose_plane_with_alpha(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        RotationMode::Transverse => transpose_plane_with_alpha(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::Flip,
+            FlopMode::Flop,
+        ),
     };
-    map_ft_result(rs)
+    map_ft_result(rs)?;
+    flip_reflected_rows(dst, dst_stride, height * 2, width, mode);
+    Ok(())
 }
 
 /// Rotates Planar 8 bit image.
@@ -242,7 +342,7 @@ pub fn rotate_plane(
             width,
             height,
             FlipMode::NoFlip,
-            FlopMode::NoFlop,
+            FlopMode::Flop,
         ),
         RotationMode::Rotate180 => rotate180_plane(src, src_stride, dst, dst_stride, width, height),
         RotationMode::Rotate270 => transpose_plane(
@@ -255,8 +355,30 @@ pub fn rotate_plane(
             FlipMode::Flip,
             FlopMode::Flop,
         ),
+        RotationMode::Transpose => transpose_plane(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        RotationMode::Transverse => transpose_plane(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::Flip,
+            FlopMode::Flop,
+        ),
     };
-    map_ft_result(rs)
+    map_ft_result(rs)?;
+    flip_reflected_rows(dst, dst_stride, height, width, mode);
+    Ok(())
 }
 
 /// Rotates RGBA 8+ bit image.
@@ -293,7 +415,7 @@ pub fn rotate_rgba16(
             width,
             height,
             FlipMode::NoFlip,
-            FlopMode::NoFlop,
+            FlopMode::Flop,
         ),
         RotationMode::Rotate180 => {
             rotate180_rgba16(src, src_stride, dst, dst_stride, width, height)
@@ -308,8 +430,30 @@ pub fn rotate_rgba16(
             FlipMode::Flip,
             FlopMode::Flop,
         ),
+        RotationMode::Transpose => transpose_rgba16(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        RotationMode::Transverse => transpose_rgba16(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::Flip,
+            FlopMode::Flop,
+        ),
     };
-    map_ft_result(rs)
+    map_ft_result(rs)?;
+    flip_reflected_rows(dst, dst_stride, height * 4, width, mode);
+    Ok(())
 }
 
 /// Rotates RGB 8+ bit image.
@@ -346,7 +490,7 @@ pub fn rotate_rgb16(
             width,
             height,
             FlipMode::NoFlip,
-            FlopMode::NoFlop,
+            FlopMode::Flop,
         ),
         RotationMode::Rotate180 => rotate180_rgb16(src, src_stride, dst, dst_stride, width, height),
         RotationMode::Rotate270 => transpose_rgb16(
@@ -359,8 +503,30 @@ pub fn rotate_rgb16(
             FlipMode::Flip,
             FlopMode::Flop,
         ),
+        RotationMode::Transpose => transpose_rgb16(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        RotationMode::Transverse => transpose_rgb16(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::Flip,
+            FlopMode::Flop,
+        ),
     };
-    map_ft_result(rs)
+    map_ft_result(rs)?;
+    flip_reflected_rows(dst, dst_stride, height * 3, width, mode);
+    Ok(())
 }
 
 /// Rotates CbCr 8+ bit image.
@@ -397,7 +563,7 @@ pub fn rotate_cbcr16(
             width,
             height,
             FlipMode::NoFlip,
-            FlopMode::NoFlop,
+            FlopMode::Flop,
         ),
         RotationMode::Rotate180 => {
             rotate180_plane16_with_alpha(src, src_stride, dst, dst_stride, width, height)
@@ -412,8 +578,30 @@ pub fn rotate_cbcr16(
             FlipMode::Flip,
             FlopMode::Flop,
         ),
+        RotationMode::Transpose => transpose_plane16_with_alpha(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        RotationMode::Transverse => transpose_plane16_with_alpha(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::Flip,
+            FlopMode::Flop,
+        ),
     };
-    map_ft_result(rs)
+    map_ft_result(rs)?;
+    flip_reflected_rows(dst, dst_stride, height * 2, width, mode);
+    Ok(())
 }
 
 /// Rotates Planar 8+ bit image.
@@ -450,7 +638,7 @@ pub fn rotate_plane16(
             width,
             height,
             FlipMode::NoFlip,
-            FlopMode::NoFlop,
+            FlopMode::Flop,
         ),
         RotationMode::Rotate180 => {
             rotate180_plane16(src, src_stride, dst, dst_stride, width, height)
@@ -465,6 +653,550 @@ pub fn rotate_plane16(
             FlipMode::Flip,
             FlopMode::Flop,
         ),
+        RotationMode::Transpose => transpose_plane16(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::NoFlip,
+            FlopMode::Flop,
+        ),
+        RotationMode::Transverse => transpose_plane16(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            FlipMode::Flip,
+            FlopMode::Flop,
+        ),
+    };
+    map_ft_result(rs)?;
+    flip_reflected_rows(dst, dst_stride, height, width, mode);
+    Ok(())
+}
+
+type PlaneRotation<T> =
+    fn(&[T], usize, &mut [T], usize, usize, usize, RotationMode) -> Result<(), YuvError>;
+
+/// Exact `stride * height` view, as required by rotation kernels
+#[inline]
+pub(crate) fn exact_plane<T>(plane: &[T], stride: u32, height: usize) -> &[T] {
+    plane.get(..stride as usize * height).unwrap_or(plane)
+}
+
+#[inline]
+pub(crate) fn exact_plane_mut<T>(plane: &mut [T], stride: u32, height: usize) -> &mut [T] {
+    let length = (stride as usize * height).min(plane.len());
+    &mut plane[..length]
+}
+
+/// Rotates chroma plane with interleaved `channels`.
+///
+/// 4:2:2 chroma turned by 90 degrees becomes 4:4:0,
+/// so it is rotated into temporary plane and resampled back to 4:2:2 of rotated image.
+fn rotate_chroma<T: ScaleSample>(
+    src: &[T],
+    src_stride: u32,
+    dst: &mut [T],
+    dst_stride: u32,
+    channels: usize,
+    src_size: (usize, usize),
+    subsampling: YuvChromaSubsampling,
+    mode: RotationMode,
+    rotate: PlaneRotation<T>,
+    max: i32,
+) -> Result<(), YuvError> {
+    let chroma_width = subsampling.chroma_width(src_size.0);
+    let chroma_height = subsampling.chroma_height(src_size.1);
+    let src = exact_plane(src, src_stride, chroma_height);
+    if subsampling != YuvChromaSubsampling::Yuv422 || !mode.swaps_dimensions() {
+        let dst_height = if mode.swaps_dimensions() {
+            chroma_width
+        } else {
+            chroma_height
+        };
+        return rotate(
+            src,
+            src_stride as usize,
+            exact_plane_mut(dst, dst_stride, dst_height),
+            dst_stride as usize,
+            chroma_width,
+            chroma_height,
+            mode,
+        );
+    }
+    let tmp_stride = chroma_height * channels;
+    let mut tmp = vec![T::default(); tmp_stride * chroma_width];
+    rotate(
+        src,
+        src_stride as usize,
+        &mut tmp,
+        tmp_stride,
+        chroma_width,
+        chroma_height,
+        mode,
+    )?;
+    let (dst_width, dst_height) = (src_size.1, src_size.0);
+    resample_plane(
+        &tmp,
+        tmp_stride as u32,
+        dst,
+        dst_stride,
+        channels,
+        (chroma_height, chroma_width),
+        (subsampling.chroma_width(dst_width), dst_height),
+        ScalingFilter::Bilinear,
+        max,
+    );
+    Ok(())
+}
+
+fn check_rotated_size(
+    src_size: (u32, u32),
+    dst_size: (u32, u32),
+    mode: RotationMode,
+) -> Result<(), YuvError> {
+    let expected = if mode.swaps_dimensions() {
+        (src_size.1, src_size.0)
+    } else {
+        src_size
     };
-    map_ft_result(rs)
+    if expected != dst_size {
+        return Err(YuvError::ImageDimensionsNotMatch);
+    }
+    Ok(())
+}
+
+fn rotate_yuv_planar_impl<T: ScaleSample>(
+    src: &YuvPlanarImage<T>,
+    dst: &mut YuvPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    mode: RotationMode,
+    rotate: PlaneRotation<T>,
+    max: i32,
+) -> Result<(), YuvError> {
+    src.check_constraints(subsampling)?;
+    dst.check_constraints(subsampling)?;
+    check_rotated_size((src.width, src.height), (dst.width, dst.height), mode)?;
+    let src_size = (src.width as usize, src.height as usize);
+    rotate(
+        exact_plane(src.y_plane, src.y_stride, src_size.1),
+        src.y_stride as usize,
+        exact_plane_mut(dst.y_plane.borrow_mut(), dst.y_stride, dst.height as usize),
+        dst.y_stride as usize,
+        src_size.0,
+        src_size.1,
+        mode,
+    )?;
+    rotate_chroma(
+        src.u_plane,
+        src.u_stride,
+        dst.u_plane.borrow_mut(),
+        dst.u_stride,
+        1,
+        src_size,
+        subsampling,
+        mode,
+        rotate,
+        max,
+    )?;
+    rotate_chroma(
+        src.v_plane,
+        src.v_stride,
+        dst.v_plane.borrow_mut(),
+        dst.v_stride,
+        1,
+        src_size,
+        subsampling,
+        mode,
+        rotate,
+        max,
+    )
+}
+
+fn rotate_yuv_bi_planar_impl<T: ScaleSample>(
+    src: &YuvBiPlanarImage<T>,
+    dst: &mut YuvBiPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    mode: RotationMode,
+    rotate: PlaneRotation<T>,
+    rotate_interleaved: PlaneRotation<T>,
+    max: i32,
+) -> Result<(), YuvError> {
+    src.check_constraints(subsampling)?;
+    dst.check_constraints(subsampling)?;
+    check_rotated_size((src.width, src.height), (dst.width, dst.height), mode)?;
+    let src_size = (src.width as usize, src.height as usize);
+    rotate(
+        exact_plane(src.y_plane, src.y_stride, src_size.1),
+        src.y_stride as usize,
+        exact_plane_mut(dst.y_plane.borrow_mut(), dst.y_stride, dst.height as usize),
+        dst.y_stride as usize,
+        src_size.0,
+        src_size.1,
+        mode,
+    )?;
+    rotate_chroma(
+        src.uv_plane,
+        src.uv_stride,
+        dst.uv_plane.borrow_mut(),
+        dst.uv_stride,
+        2,
+        src_size,
+        subsampling,
+        mode,
+        rotate_interleaved,
+        max,
+    )
+}
+
+/// Rotates whole 8 bit planar YUV image.
+///
+/// Destination keeps source subsampling, when 4:2:2 is rotated by 90 degrees,
+/// or transposed, chroma is resampled back to 4:2:2 layout.
+///
+/// # Arguments
+///
+/// * `src`: Source image
+/// * `dst`: Destination image, width and height must be swapped when mode exchanges dimensions
+/// * `subsampling`: Chroma subsampling of both images
+/// * `mode`: Refer to [RotationMode] for mode info
+///
+/// returns: Result<(), [YuvError]>
+///
+pub fn rotate_yuv_planar(
+    src: &YuvPlanarImage<u8>,
+    dst: &mut YuvPlanarImageMut<u8>,
+    subsampling: YuvChromaSubsampling,
+    mode: RotationMode,
+) -> Result<(), YuvError> {
+    rotate_yuv_planar_impl(src, dst, subsampling, mode, rotate_plane, 255)
+}
+
+/// Rotates whole 8+ bit planar YUV image.
+///
+/// Destination keeps source subsampling, when 4:2:2 is rotated by 90 degrees,
+/// or transposed, chroma is resampled back to 4:2:2 layout.
+/// Samples are expected in host endianness, for MSB packed formats pass 16 as `bit_depth`.
+///
+/// # Arguments
+///
+/// * `src`: Source image
+/// * `dst`: Destination image, width and height must be swapped when mode exchanges dimensions
+/// * `subsampling`: Chroma subsampling of both images
+/// * `bit_depth`: Bit-depth of samples, 8..=16
+/// * `mode`: Refer to [RotationMode] for mode info
+///
+/// returns: Result<(), [YuvError]>
+///
+pub fn rotate_yuv_planar16(
+    src: &YuvPlanarImage<u16>,
+    dst: &mut YuvPlanarImageMut<u16>,
+    subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    mode: RotationMode,
+) -> Result<(), YuvError> {
+    let max = max_value(bit_depth)?;
+    rotate_yuv_planar_impl(src, dst, subsampling, mode, rotate_plane16, max)
+}
+
+/// Rotates whole 8 bit bi-planar YUV image.
+///
+/// Destination keeps source subsampling, when 4:2:2 is rotated by 90 degrees,
+/// or transposed, chroma is resampled back to 4:2:2 layout.
+///
+/// # Arguments
+///
+/// * `src`: Source image
+/// * `dst`: Destination image, width and height must be swapped when mode exchanges dimensions
+/// * `subsampling`: Chroma subsampling of both images
+/// * `mode`: Refer to [RotationMode] for mode info
+///
+/// returns: Result<(), [YuvError]>
+///
+pub fn rotate_yuv_biplanar(
+    src: &YuvBiPlanarImage<u8>,
+    dst: &mut YuvBiPlanarImageMut<u8>,
+    subsampling: YuvChromaSubsampling,
+    mode: RotationMode,
+) -> Result<(), YuvError> {
+    rotate_yuv_bi_planar_impl(src, dst, subsampling, mode, rotate_plane, rotate_cbcr, 255)
+}
+
+/// Rotates whole 8+ bit bi-planar YUV image.
+///
+/// Destination keeps source subsampling, when 4:2:2 is rotated by 90 degrees,
+/// or transposed, chroma is resampled back to 4:2:2 layout.
+/// Samples are expected in host endianness, for MSB packed formats pass 16 as `bit_depth`.
+///
+/// # Arguments
+///
+/// * `src`: Source image
+/// * `dst`: Destination image, width and height must be swapped when mode exchanges dimensions
+/// * `subsampling`: Chroma subsampling of both images
+/// * `bit_depth`: Bit-depth of samples, 8..=16
+/// * `mode`: Refer to [RotationMode] for mode info
+///
+/// returns: Result<(), [YuvError]>
+///
+pub fn rotate_yuv_biplanar16(
+    src: &YuvBiPlanarImage<u16>,
+    dst: &mut YuvBiPlanarImageMut<u16>,
+    subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    mode: RotationMode,
+) -> Result<(), YuvError> {
+    let max = max_value(bit_depth)?;
+    rotate_yuv_bi_planar_impl(
+        src,
+        dst,
+        subsampling,
+        mode,
+        rotate_plane16,
+        rotate_cbcr16,
+        max,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{yuv422_to_rgb, YuvRange, YuvStandardMatrix};
+
+    #[test]
+    fn whole_frame_rotations_round_trip() {
+        let (width, height) = (6u32, 4u32);
+        let mut nv12 =
+            YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in nv12.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = i as u8;
+        }
+        for (i, v) in nv12.uv_plane.borrow_mut().iter_mut().enumerate() {
+            *v = 100 + i as u8;
+        }
+        let mut rotated =
+            YuvBiPlanarImageMut::<u8>::alloc(height, width, YuvChromaSubsampling::Yuv420);
+        rotate_yuv_biplanar(
+            &nv12.to_fixed(),
+            &mut rotated,
+            YuvChromaSubsampling::Yuv420,
+            RotationMode::Transpose,
+        )
+        .unwrap();
+        // Row 1 of transposed luma is column 1 of source
+        assert_eq!(&rotated.y_plane.borrow()[4..8], &[1, 7, 13, 19]);
+        // UV pairs are kept together
+        assert_eq!(&rotated.uv_plane.borrow()[..4], &[100, 101, 106, 107]);
+
+        let mut back =
+            YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        rotate_yuv_biplanar(
+            &rotated.to_fixed(),
+            &mut back,
+            YuvChromaSubsampling::Yuv420,
+            RotationMode::Transpose,
+        )
+        .unwrap();
+        assert_eq!(back.y_plane.borrow(), nv12.y_plane.borrow());
+        assert_eq!(back.uv_plane.borrow(), nv12.uv_plane.borrow());
+    }
+
+    #[test]
+    fn rotated_422_decodes_as_rotated_rgb() {
+        let (width, height) = (16usize, 10usize);
+        let subsampling = YuvChromaSubsampling::Yuv422;
+        let mut i422 = YuvPlanarImageMut::<u8>::alloc(width as u32, height as u32, subsampling);
+        let y_stride = i422.y_stride as usize;
+        for (i, v) in i422.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (40 + (i % y_stride) * 6 + (i / y_stride) * 4) as u8;
+        }
+        let chroma_stride = i422.u_stride as usize;
+        for (i, v) in i422.u_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (100 + (i % chroma_stride) * 2 + i / chroma_stride) as u8;
+        }
+        for (i, v) in i422.v_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (160 - (i % chroma_stride) * 2 - i / chroma_stride) as u8;
+        }
+        let i422 = i422.to_fixed();
+
+        let mut rgb = vec![0u8; width * height * 3];
+        yuv422_to_rgb(
+            &i422,
+            &mut rgb,
+            width as u32 * 3,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        // Rotated by 90 degrees counter-clockwise, source column `width - 1 - y` becomes row `y`
+        let mut expected = vec![0u8; width * height * 3];
+        for y in 0..width {
+            for x in 0..height {
+                let src = &rgb[(x * width + width - 1 - y) * 3..][..3];
+                expected[(y * height + x) * 3..][..3].copy_from_slice(src);
+            }
+        }
+
+        let mut rotated = YuvPlanarImageMut::<u8>::alloc(height as u32, width as u32, subsampling);
+        rotate_yuv_planar(&i422, &mut rotated, subsampling, RotationMode::Rotate90).unwrap();
+        // Luma is moved without resampling
+        for y in 0..width {
+            for x in 0..height {
+                assert_eq!(
+                    rotated.y_plane.borrow()[y * height + x],
+                    i422.y_plane[x * width + width - 1 - y],
+                    "luma at {x}, {y}"
+                );
+            }
+        }
+        let mut actual = vec![0u8; width * height * 3];
+        yuv422_to_rgb(
+            &rotated.to_fixed(),
+            &mut actual,
+            height as u32 * 3,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        // Decoder replicates chroma, so resampled chroma of gradient stepping by 2
+        // may be one step away, which is less than 3 RGB levels per chroma level
+        let max_error = actual
+            .iter()
+            .zip(expected.iter())
+            .map(|(&a, &b)| (a as i32 - b as i32).abs())
+            .max()
+            .unwrap();
+        assert!(max_error <= 6, "max error {max_error}");
+
+        assert!(
+            rotate_yuv_planar(&i422, &mut rotated, subsampling, RotationMode::Rotate180).is_err()
+        );
+    }
+
+    #[test]
+    fn rotations_match_index_mapping_past_simd_blocks() {
+        let (width, height) = (21usize, 19usize);
+        // Destination (column, row) of source pixel
+        let place = |mode: RotationMode, x: usize, y: usize| match mode {
+            RotationMode::Rotate90 => (y, width - 1 - x),
+            RotationMode::Rotate180 => (width - 1 - x, height - 1 - y),
+            RotationMode::Rotate270 => (height - 1 - y, x),
+            RotationMode::Transpose => (y, x),
+            RotationMode::Transverse => (height - 1 - y, width - 1 - x),
+        };
+        let plane: Vec<u8> = (0..width * height).map(|i| (i % 251) as u8).collect();
+        let rgba16: Vec<u16> = (0..width * height * 4).map(|i| i as u16).collect();
+        for mode in [
+            RotationMode::Rotate90,
+            RotationMode::Rotate180,
+            RotationMode::Rotate270,
+            RotationMode::Transpose,
+            RotationMode::Transverse,
+        ] {
+            let dst_width = if mode.swaps_dimensions() {
+                height
+            } else {
+                width
+            };
+            let mut rotated = vec![0u8; width * height];
+            rotate_plane(&plane, width, &mut rotated, dst_width, width, height, mode).unwrap();
+            let mut rotated16 = vec![0u16; width * height * 4];
+            rotate_rgba16(
+                &rgba16,
+                width * 4,
+                &mut rotated16,
+                dst_width * 4,
+                width,
+                height,
+                mode,
+            )
+            .unwrap();
+            for y in 0..height {
+                for x in 0..width {
+                    let (dx, dy) = place(mode, x, y);
+                    assert_eq!(
+                        rotated[dy * dst_width + dx],
+                        plane[y * width + x],
+                        "{mode:?} at {x}, {y}"
+                    );
+                    assert_eq!(
+                        rotated16[(dy * dst_width + dx) * 4..][..4],
+                        rgba16[(y * width + x) * 4..][..4],
+                        "{mode:?} at {x}, {y}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transverse_places_pixels_over_anti_diagonal() {
+        let (width, height) = (6usize, 4usize);
+        let mut i420 = YuvPlanarImageMut::<u16>::alloc(
+            width as u32,
+            height as u32,
+            YuvChromaSubsampling::Yuv420,
+        );
+        for (i, v) in i420.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = i as u16;
+        }
+        for (i, v) in i420.u_plane.borrow_mut().iter_mut().enumerate() {
+            *v = 100 + i as u16;
+        }
+        for (i, v) in i420.v_plane.borrow_mut().iter_mut().enumerate() {
+            *v = 200 + i as u16;
+        }
+        let src = i420.to_fixed();
+        let mut rotated = YuvPlanarImageMut::<u16>::alloc(
+            height as u32,
+            width as u32,
+            YuvChromaSubsampling::Yuv420,
+        );
+        rotate_yuv_planar16(
+            &src,
+            &mut rotated,
+            YuvChromaSubsampling::Yuv420,
+            10,
+            RotationMode::Transverse,
+        )
+        .unwrap();
+        for y in 0..width {
+            for x in 0..height {
+                assert_eq!(
+                    rotated.y_plane.borrow()[y * height + x],
+                    src.y_plane[(height - 1 - x) * width + (width - 1 - y)],
+                    "luma at {x}, {y}"
+                );
+            }
+        }
+        let (chroma_width, chroma_height) = (width / 2, height / 2);
+        for y in 0..chroma_width {
+            for x in 0..chroma_height {
+                let src_offset = (chroma_height - 1 - x) * chroma_width + (chroma_width - 1 - y);
+                assert_eq!(
+                    rotated.u_plane.borrow()[y * chroma_height + x],
+                    src.u_plane[src_offset]
+                );
+                assert_eq!(
+                    rotated.v_plane.borrow()[y * chroma_height + x],
+                    src.v_plane[src_offset]
+                );
+            }
+        }
+
+        assert!(matches!(
+            rotate_yuv_planar16(
+                &src,
+                &mut rotated,
+                YuvChromaSubsampling::Yuv420,
+                17,
+                RotationMode::Transverse,
+            ),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+    }
 }
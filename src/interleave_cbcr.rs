@@ -0,0 +1,956 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::MismatchedSize;
+use crate::yuv_support::{YuvChromaSubsampling, YuvNVOrder};
+use crate::{YuvBiPlanarImage, YuvBiPlanarImageMut, YuvError, YuvPlanarImage, YuvPlanarImageMut};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+use std::fmt::Debug;
+
+type Deinterleave8Row = unsafe fn(&[u8], &mut [u8], &mut [u8], usize) -> usize;
+type Interleave8Row = unsafe fn(&[u8], &[u8], &mut [u8], usize) -> usize;
+type Deinterleave16Row = unsafe fn(&[u16], &mut [u16], &mut [u16], u32, u32, usize) -> usize;
+type Interleave16Row = unsafe fn(&[u16], &[u16], &mut [u16], u32, u32, usize) -> usize;
+
+/// Runtime selected SIMD row kernels, each returns count of processed chroma pairs
+#[derive(Copy, Clone)]
+struct CbCrRowHandler {
+    deinterleave_u8: Deinterleave8Row,
+    interleave_u8: Interleave8Row,
+    deinterleave_u16: Deinterleave16Row,
+    interleave_u16: Interleave16Row,
+}
+
+fn deinterleave_u8_none(_: &[u8], _: &mut [u8], _: &mut [u8], _: usize) -> usize {
+    0
+}
+
+fn interleave_u8_none(_: &[u8], _: &[u8], _: &mut [u8], _: usize) -> usize {
+    0
+}
+
+fn deinterleave_u16_none(
+    _: &[u16],
+    _: &mut [u16],
+    _: &mut [u16],
+    _: u32,
+    _: u32,
+    _: usize,
+) -> usize {
+    0
+}
+
+fn interleave_u16_none(_: &[u16], _: &[u16], _: &mut [u16], _: u32, _: u32, _: usize) -> usize {
+    0
+}
+
+impl Default for CbCrRowHandler {
+    fn default() -> Self {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            use crate::neon::{
+                neon_deinterleave_cbcr_u16, neon_deinterleave_cbcr_u8, neon_interleave_cbcr_u16,
+                neon_interleave_cbcr_u8,
+            };
+            return CbCrRowHandler {
+                deinterleave_u8: neon_deinterleave_cbcr_u8,
+                interleave_u8: neon_interleave_cbcr_u8,
+                deinterleave_u16: neon_deinterleave_cbcr_u16,
+                interleave_u16: neon_interleave_cbcr_u16,
+            };
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                use crate::avx2::{
+                    avx2_deinterleave_cbcr_u16, avx2_deinterleave_cbcr_u8,
+                    avx2_interleave_cbcr_u16, avx2_interleave_cbcr_u8,
+                };
+                return CbCrRowHandler {
+                    deinterleave_u8: avx2_deinterleave_cbcr_u8,
+                    interleave_u8: avx2_interleave_cbcr_u8,
+                    deinterleave_u16: avx2_deinterleave_cbcr_u16,
+                    interleave_u16: avx2_interleave_cbcr_u16,
+                };
+            }
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.1") {
+                use crate::sse::{
+                    sse_deinterleave_cbcr_u16, sse_deinterleave_cbcr_u8, sse_interleave_cbcr_u16,
+                    sse_interleave_cbcr_u8,
+                };
+                return CbCrRowHandler {
+                    deinterleave_u8: sse_deinterleave_cbcr_u8,
+                    interleave_u8: sse_interleave_cbcr_u8,
+                    deinterleave_u16: sse_deinterleave_cbcr_u16,
+                    interleave_u16: sse_interleave_cbcr_u16,
+                };
+            }
+        }
+        #[allow(unreachable_code)]
+        CbCrRowHandler {
+            deinterleave_u8: deinterleave_u8_none,
+            interleave_u8: interleave_u8_none,
+            deinterleave_u16: deinterleave_u16_none,
+            interleave_u16: interleave_u16_none,
+        }
+    }
+}
+
+/// Sample which may be moved between planar and interleaved layouts.
+///
+/// Samples are transformed as `(v >> shift.0) << shift.1`, this moves
+/// high bit-depth values between LSB and MSB packing, 8-bit samples are never shifted.
+trait CbCrSample: Copy + Default + Debug + Send + Sync {
+    fn copy_row(src: &[Self], dst: &mut [Self], shift: (u32, u32));
+
+    fn deinterleave_row(
+        handler: &CbCrRowHandler,
+        uv: &[Self],
+        u: &mut [Self],
+        v: &mut [Self],
+        shift: (u32, u32),
+    );
+
+    fn interleave_row(
+        handler: &CbCrRowHandler,
+        u: &[Self],
+        v: &[Self],
+        uv: &mut [Self],
+        shift: (u32, u32),
+    );
+}
+
+impl CbCrSample for u8 {
+    fn copy_row(src: &[Self], dst: &mut [Self], _: (u32, u32)) {
+        dst.copy_from_slice(src);
+    }
+
+    fn deinterleave_row(
+        handler: &CbCrRowHandler,
+        uv: &[Self],
+        u: &mut [Self],
+        v: &mut [Self],
+        _: (u32, u32),
+    ) {
+        let width = u.len();
+        let processed = unsafe { (handler.deinterleave_u8)(uv, u, v, width) };
+        for ((u, v), uv) in u
+            .iter_mut()
+            .zip(v.iter_mut())
+            .zip(uv.chunks_exact(2))
+            .skip(processed)
+        {
+            *u = uv[0];
+            *v = uv[1];
+        }
+    }
+
+    fn interleave_row(
+        handler: &CbCrRowHandler,
+        u: &[Self],
+        v: &[Self],
+        uv: &mut [Self],
+        _: (u32, u32),
+    ) {
+        let width = u.len();
+        let processed = unsafe { (handler.interleave_u8)(u, v, uv, width) };
+        for ((&u, &v), uv) in u
+            .iter()
+            .zip(v.iter())
+            .zip(uv.chunks_exact_mut(2))
+            .skip(processed)
+        {
+            uv[0] = u;
+            uv[1] = v;
+        }
+    }
+}
+
+impl CbCrSample for u16 {
+    fn copy_row(src: &[Self], dst: &mut [Self], shift: (u32, u32)) {
+        if shift == (0, 0) {
+            dst.copy_from_slice(src);
+            return;
+        }
+        for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+            *dst = (src >> shift.0) << shift.1;
+        }
+    }
+
+    fn deinterleave_row(
+        handler: &CbCrRowHandler,
+        uv: &[Self],
+        u: &mut [Self],
+        v: &mut [Self],
+        shift: (u32, u32),
+    ) {
+        let width = u.len();
+        let processed = unsafe { (handler.deinterleave_u16)(uv, u, v, shift.0, shift.1, width) };
+        for ((u, v), uv) in u
+            .iter_mut()
+            .zip(v.iter_mut())
+            .zip(uv.chunks_exact(2))
+            .skip(processed)
+        {
+            *u = (uv[0] >> shift.0) << shift.1;
+            *v = (uv[1] >> shift.0) << shift.1;
+        }
+    }
+
+    fn interleave_row(
+        handler: &CbCrRowHandler,
+        u: &[Self],
+        v: &[Self],
+        uv: &mut [Self],
+        shift: (u32, u32),
+    ) {
+        let width = u.len();
+        let processed = unsafe { (handler.interleave_u16)(u, v, uv, shift.0, shift.1, width) };
+        for ((&u, &v), uv) in u
+            .iter()
+            .zip(v.iter())
+            .zip(uv.chunks_exact_mut(2))
+            .skip(processed)
+        {
+            uv[0] = (u >> shift.0) << shift.1;
+            uv[1] = (v >> shift.0) << shift.1;
+        }
+    }
+}
+
+fn check_plane<T>(
+    plane: &[T],
+    stride: usize,
+    row_length: usize,
+    height: usize,
+) -> Result<(), YuvError> {
+    if stride < row_length {
+        return Err(YuvError::MinimumStrideSizeMismatch(MismatchedSize {
+            expected: row_length,
+            received: stride,
+        }));
+    }
+    let required = stride * (height - 1) + row_length;
+    if plane.len() < required {
+        return Err(YuvError::ChromaPlaneMinimumSizeMismatch(MismatchedSize {
+            expected: required,
+            received: plane.len(),
+        }));
+    }
+    Ok(())
+}
+
+fn copy_plane<T: CbCrSample>(
+    src: &[T],
+    src_stride: usize,
+    dst: &mut [T],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+    shift: (u32, u32),
+) {
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride)
+            .zip(src.par_chunks(src_stride))
+            .take(height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride)
+            .zip(src.chunks(src_stride))
+            .take(height);
+    }
+    iter.for_each(|(dst, src)| {
+        T::copy_row(&src[..width], &mut dst[..width], shift);
+    });
+}
+
+fn deinterleave_plane<T: CbCrSample>(
+    uv: &[T],
+    uv_stride: usize,
+    u: &mut [T],
+    u_stride: usize,
+    v: &mut [T],
+    v_stride: usize,
+    width: usize,
+    height: usize,
+    shift: (u32, u32),
+) {
+    let handler = CbCrRowHandler::default();
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = u
+            .par_chunks_mut(u_stride)
+            .zip(v.par_chunks_mut(v_stride))
+            .zip(uv.par_chunks(uv_stride))
+            .take(height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = u
+            .chunks_mut(u_stride)
+            .zip(v.chunks_mut(v_stride))
+            .zip(uv.chunks(uv_stride))
+            .take(height);
+    }
+    iter.for_each(|((u, v), uv)| {
+        T::deinterleave_row(
+            &handler,
+            &uv[..width * 2],
+            &mut u[..width],
+            &mut v[..width],
+            shift,
+        );
+    });
+}
+
+fn interleave_plane<T: CbCrSample>(
+    u: &[T],
+    u_stride: usize,
+    v: &[T],
+    v_stride: usize,
+    uv: &mut [T],
+    uv_stride: usize,
+    width: usize,
+    height: usize,
+    shift: (u32, u32),
+) {
+    let handler = CbCrRowHandler::default();
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = uv
+            .par_chunks_mut(uv_stride)
+            .zip(u.par_chunks(u_stride))
+            .zip(v.par_chunks(v_stride))
+            .take(height);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = uv
+            .chunks_mut(uv_stride)
+            .zip(u.chunks(u_stride))
+            .zip(v.chunks(v_stride))
+            .take(height);
+    }
+    iter.for_each(|((uv, u), v)| {
+        T::interleave_row(
+            &handler,
+            &u[..width],
+            &v[..width],
+            &mut uv[..width * 2],
+            shift,
+        );
+    });
+}
+
+fn deinterleave_cbcr_impl<T: CbCrSample>(
+    src: &[T],
+    src_stride: usize,
+    u_plane: &mut [T],
+    u_stride: usize,
+    v_plane: &mut [T],
+    v_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), YuvError> {
+    if width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    check_plane(src, src_stride, width * 2, height)?;
+    check_plane(u_plane, u_stride, width, height)?;
+    check_plane(v_plane, v_stride, width, height)?;
+    deinterleave_plane(
+        src,
+        src_stride,
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        width,
+        height,
+        (0, 0),
+    );
+    Ok(())
+}
+
+fn interleave_cbcr_impl<T: CbCrSample>(
+    u_plane: &[T],
+    u_stride: usize,
+    v_plane: &[T],
+    v_stride: usize,
+    dst: &mut [T],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), YuvError> {
+    if width == 0 || height == 0 {
+        return Err(YuvError::ZeroBaseSize);
+    }
+    check_plane(u_plane, u_stride, width, height)?;
+    check_plane(v_plane, v_stride, width, height)?;
+    check_plane(dst, dst_stride, width * 2, height)?;
+    interleave_plane(
+        u_plane,
+        u_stride,
+        v_plane,
+        v_stride,
+        dst,
+        dst_stride,
+        width,
+        height,
+        (0, 0),
+    );
+    Ok(())
+}
+
+/// Splits interleaved 8-bit two channel plane into two planes.
+///
+/// This works with any interleaved chroma plane, e.g. output of [crate::rotate_cbcr],
+/// first channel goes to `u_plane`, second to `v_plane`.
+///
+/// # Arguments
+///
+/// * `src`: Source interleaved plane
+/// * `src_stride`: Source stride in elements
+/// * `u_plane`: Destination plane for the first channel
+/// * `u_stride`: First channel stride
+/// * `v_plane`: Destination plane for the second channel
+/// * `v_stride`: Second channel stride
+/// * `width`: Plane width in pairs
+/// * `height`: Plane height
+///
+pub fn deinterleave_cbcr(
+    src: &[u8],
+    src_stride: usize,
+    u_plane: &mut [u8],
+    u_stride: usize,
+    v_plane: &mut [u8],
+    v_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), YuvError> {
+    deinterleave_cbcr_impl(
+        src, src_stride, u_plane, u_stride, v_plane, v_stride, width, height,
+    )
+}
+
+/// Splits interleaved high bit-depth two channel plane into two planes, samples are copied as is.
+///
+/// See [deinterleave_cbcr] for more info.
+pub fn deinterleave_cbcr16(
+    src: &[u16],
+    src_stride: usize,
+    u_plane: &mut [u16],
+    u_stride: usize,
+    v_plane: &mut [u16],
+    v_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), YuvError> {
+    deinterleave_cbcr_impl(
+        src, src_stride, u_plane, u_stride, v_plane, v_stride, width, height,
+    )
+}
+
+/// Merges two 8-bit planes into interleaved two channel plane.
+///
+/// Result may be passed to [crate::rotate_cbcr] or used as UV plane of bi-planar image.
+///
+/// # Arguments
+///
+/// * `u_plane`: First channel plane
+/// * `u_stride`: First channel stride
+/// * `v_plane`: Second channel plane
+/// * `v_stride`: Second channel stride
+/// * `dst`: Destination interleaved plane
+/// * `dst_stride`: Destination stride in elements
+/// * `width`: Plane width in pairs
+/// * `height`: Plane height
+///
+pub fn interleave_cbcr(
+    u_plane: &[u8],
+    u_stride: usize,
+    v_plane: &[u8],
+    v_stride: usize,
+    dst: &mut [u8],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), YuvError> {
+    interleave_cbcr_impl(
+        u_plane, u_stride, v_plane, v_stride, dst, dst_stride, width, height,
+    )
+}
+
+/// Merges two high bit-depth planes into interleaved two channel plane, samples are copied as is.
+///
+/// See [interleave_cbcr] for more info.
+pub fn interleave_cbcr16(
+    u_plane: &[u16],
+    u_stride: usize,
+    v_plane: &[u16],
+    v_stride: usize,
+    dst: &mut [u16],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+) -> Result<(), YuvError> {
+    interleave_cbcr_impl(
+        u_plane, u_stride, v_plane, v_stride, dst, dst_stride, width, height,
+    )
+}
+
+fn bi_planar_to_planar_impl<T: CbCrSample, const UV_ORDER: u8>(
+    src: &YuvBiPlanarImage<T>,
+    dst: &mut YuvPlanarImageMut<T>,
+    sampling: YuvChromaSubsampling,
+    shift: (u32, u32),
+) -> Result<(), YuvError> {
+    src.check_constraints(sampling)?;
+    dst.check_constraints(sampling)?;
+    if src.width != dst.width || src.height != dst.height {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+    let order: YuvNVOrder = UV_ORDER.into();
+    let width = src.width as usize;
+    let height = src.height as usize;
+    copy_plane(
+        src.y_plane,
+        src.y_stride as usize,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride as usize,
+        width,
+        height,
+        shift,
+    );
+    let (first, first_stride, second, second_stride) = match order {
+        YuvNVOrder::UV => (
+            dst.u_plane.borrow_mut(),
+            dst.u_stride as usize,
+            dst.v_plane.borrow_mut(),
+            dst.v_stride as usize,
+        ),
+        YuvNVOrder::VU => (
+            dst.v_plane.borrow_mut(),
+            dst.v_stride as usize,
+            dst.u_plane.borrow_mut(),
+            dst.u_stride as usize,
+        ),
+    };
+    deinterleave_plane(
+        src.uv_plane,
+        src.uv_stride as usize,
+        first,
+        first_stride,
+        second,
+        second_stride,
+        sampling.chroma_width(width),
+        sampling.chroma_height(height),
+        shift,
+    );
+    Ok(())
+}
+
+fn planar_to_bi_planar_impl<T: CbCrSample, const UV_ORDER: u8>(
+    src: &YuvPlanarImage<T>,
+    dst: &mut YuvBiPlanarImageMut<T>,
+    sampling: YuvChromaSubsampling,
+    shift: (u32, u32),
+) -> Result<(), YuvError> {
+    src.check_constraints(sampling)?;
+    dst.check_constraints(sampling)?;
+    if src.width != dst.width || src.height != dst.height {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+    let order: YuvNVOrder = UV_ORDER.into();
+    let width = src.width as usize;
+    let height = src.height as usize;
+    copy_plane(
+        src.y_plane,
+        src.y_stride as usize,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride as usize,
+        width,
+        height,
+        shift,
+    );
+    let (first, first_stride, second, second_stride) = match order {
+        YuvNVOrder::UV => (src.u_plane, src.u_stride, src.v_plane, src.v_stride),
+        YuvNVOrder::VU => (src.v_plane, src.v_stride, src.u_plane, src.u_stride),
+    };
+    let uv_stride = dst.uv_stride as usize;
+    interleave_plane(
+        first,
+        first_stride as usize,
+        second,
+        second_stride as usize,
+        dst.uv_plane.borrow_mut(),
+        uv_stride,
+        sampling.chroma_width(width),
+        sampling.chroma_height(height),
+        shift,
+    );
+    Ok(())
+}
+
+macro_rules! d_bi_planar_to_planar {
+    ($method: ident, $order: expr, $sampling: expr, $src_name: expr, $dst_name: expr) => {
+        #[doc = concat!("Converts ", $src_name, " bi-planar image to ", $dst_name, " planar image.
+
+# Arguments
+
+* `src` - Source ", $src_name, " image.
+* `dst` - Destination ", $dst_name, " image, must have the same dimensions as source.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $method(
+            src: &YuvBiPlanarImage<u8>,
+            dst: &mut YuvPlanarImageMut<u8>,
+        ) -> Result<(), YuvError> {
+            bi_planar_to_planar_impl::<u8, { $order as u8 }>(src, dst, $sampling, (0, 0))
+        }
+    };
+}
+
+d_bi_planar_to_planar!(
+    nv12_to_i420,
+    YuvNVOrder::UV,
+    YuvChromaSubsampling::Yuv420,
+    "NV12",
+    "I420"
+);
+d_bi_planar_to_planar!(
+    nv21_to_i420,
+    YuvNVOrder::VU,
+    YuvChromaSubsampling::Yuv420,
+    "NV21",
+    "I420"
+);
+d_bi_planar_to_planar!(
+    nv16_to_i422,
+    YuvNVOrder::UV,
+    YuvChromaSubsampling::Yuv422,
+    "NV16",
+    "I422"
+);
+d_bi_planar_to_planar!(
+    nv61_to_i422,
+    YuvNVOrder::VU,
+    YuvChromaSubsampling::Yuv422,
+    "NV61",
+    "I422"
+);
+d_bi_planar_to_planar!(
+    nv24_to_i444,
+    YuvNVOrder::UV,
+    YuvChromaSubsampling::Yuv444,
+    "NV24",
+    "I444"
+);
+d_bi_planar_to_planar!(
+    nv42_to_i444,
+    YuvNVOrder::VU,
+    YuvChromaSubsampling::Yuv444,
+    "NV42",
+    "I444"
+);
+
+macro_rules! d_planar_to_bi_planar {
+    ($method: ident, $order: expr, $sampling: expr, $src_name: expr, $dst_name: expr) => {
+        #[doc = concat!("Converts ", $src_name, " planar image to ", $dst_name, " bi-planar image.
+
+# Arguments
+
+* `src` - Source ", $src_name, " image.
+* `dst` - Destination ", $dst_name, " image, must have the same dimensions as source.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $method(
+            src: &YuvPlanarImage<u8>,
+            dst: &mut YuvBiPlanarImageMut<u8>,
+        ) -> Result<(), YuvError> {
+            planar_to_bi_planar_impl::<u8, { $order as u8 }>(src, dst, $sampling, (0, 0))
+        }
+    };
+}
+
+d_planar_to_bi_planar!(
+    i420_to_nv12,
+    YuvNVOrder::UV,
+    YuvChromaSubsampling::Yuv420,
+    "I420",
+    "NV12"
+);
+d_planar_to_bi_planar!(
+    i420_to_nv21,
+    YuvNVOrder::VU,
+    YuvChromaSubsampling::Yuv420,
+    "I420",
+    "NV21"
+);
+d_planar_to_bi_planar!(
+    i422_to_nv16,
+    YuvNVOrder::UV,
+    YuvChromaSubsampling::Yuv422,
+    "I422",
+    "NV16"
+);
+d_planar_to_bi_planar!(
+    i422_to_nv61,
+    YuvNVOrder::VU,
+    YuvChromaSubsampling::Yuv422,
+    "I422",
+    "NV61"
+);
+d_planar_to_bi_planar!(
+    i444_to_nv24,
+    YuvNVOrder::UV,
+    YuvChromaSubsampling::Yuv444,
+    "I444",
+    "NV24"
+);
+d_planar_to_bi_planar!(
+    i444_to_nv42,
+    YuvNVOrder::VU,
+    YuvChromaSubsampling::Yuv444,
+    "I444",
+    "NV42"
+);
+
+macro_rules! d_bi_planar_to_planar16 {
+    ($method: ident, $sampling: expr, $bit_depth: expr, $src_name: expr, $dst_name: expr) => {
+        #[doc = concat!("Converts ", $src_name, " bi-planar image to ", $dst_name, " planar image.
+
+Samples are moved from most significant bits to least significant bits, host endianness is expected.
+
+# Arguments
+
+* `src` - Source ", $src_name, " image.
+* `dst` - Destination ", $dst_name, " image, must have the same dimensions as source.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $method(
+            src: &YuvBiPlanarImage<u16>,
+            dst: &mut YuvPlanarImageMut<u16>,
+        ) -> Result<(), YuvError> {
+            bi_planar_to_planar_impl::<u16, { YuvNVOrder::UV as u8 }>(
+                src,
+                dst,
+                $sampling,
+                (16 - $bit_depth, 0),
+            )
+        }
+    };
+}
+
+d_bi_planar_to_planar16!(
+    p010_to_i010,
+    YuvChromaSubsampling::Yuv420,
+    10,
+    "P010",
+    "I010"
+);
+d_bi_planar_to_planar16!(
+    p012_to_i012,
+    YuvChromaSubsampling::Yuv420,
+    12,
+    "P012",
+    "I012"
+);
+d_bi_planar_to_planar16!(
+    p210_to_i210,
+    YuvChromaSubsampling::Yuv422,
+    10,
+    "P210",
+    "I210"
+);
+d_bi_planar_to_planar16!(
+    p212_to_i212,
+    YuvChromaSubsampling::Yuv422,
+    12,
+    "P212",
+    "I212"
+);
+d_bi_planar_to_planar16!(
+    p410_to_i410,
+    YuvChromaSubsampling::Yuv444,
+    10,
+    "P410",
+    "I410"
+);
+d_bi_planar_to_planar16!(
+    p412_to_i412,
+    YuvChromaSubsampling::Yuv444,
+    12,
+    "P412",
+    "I412"
+);
+
+macro_rules! d_planar_to_bi_planar16 {
+    ($method: ident, $sampling: expr, $bit_depth: expr, $src_name: expr, $dst_name: expr) => {
+        #[doc = concat!("Converts ", $src_name, " planar image to ", $dst_name, " bi-planar image.
+
+Samples are moved from least significant bits to most significant bits, host endianness is expected.
+
+# Arguments
+
+* `src` - Source ", $src_name, " image.
+* `dst` - Destination ", $dst_name, " image, must have the same dimensions as source.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $method(
+            src: &YuvPlanarImage<u16>,
+            dst: &mut YuvBiPlanarImageMut<u16>,
+        ) -> Result<(), YuvError> {
+            planar_to_bi_planar_impl::<u16, { YuvNVOrder::UV as u8 }>(
+                src,
+                dst,
+                $sampling,
+                (0, 16 - $bit_depth),
+            )
+        }
+    };
+}
+
+d_planar_to_bi_planar16!(
+    i010_to_p010,
+    YuvChromaSubsampling::Yuv420,
+    10,
+    "I010",
+    "P010"
+);
+d_planar_to_bi_planar16!(
+    i012_to_p012,
+    YuvChromaSubsampling::Yuv420,
+    12,
+    "I012",
+    "P012"
+);
+d_planar_to_bi_planar16!(
+    i210_to_p210,
+    YuvChromaSubsampling::Yuv422,
+    10,
+    "I210",
+    "P210"
+);
+d_planar_to_bi_planar16!(
+    i212_to_p212,
+    YuvChromaSubsampling::Yuv422,
+    12,
+    "I212",
+    "P212"
+);
+d_planar_to_bi_planar16!(
+    i410_to_p410,
+    YuvChromaSubsampling::Yuv444,
+    10,
+    "I410",
+    "P410"
+);
+d_planar_to_bi_planar16!(
+    i412_to_p412,
+    YuvChromaSubsampling::Yuv444,
+    12,
+    "I412",
+    "P412"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nv_planar_round_trip() {
+        let width = 67u32;
+        let height = 9u32;
+        let mut nv21 =
+            YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in nv21.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (i * 7) as u8;
+        }
+        for (i, v) in nv21.uv_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (i * 13) as u8;
+        }
+        let mut i420 = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        nv21_to_i420(&nv21.to_fixed(), &mut i420).unwrap();
+        assert_eq!(i420.y_plane.borrow(), nv21.y_plane.borrow());
+        for (i, uv) in nv21.uv_plane.borrow().chunks_exact(2).enumerate() {
+            assert_eq!(i420.v_plane.borrow()[i], uv[0]);
+            assert_eq!(i420.u_plane.borrow()[i], uv[1]);
+        }
+        let mut back =
+            YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        i420_to_nv21(&i420.to_fixed(), &mut back).unwrap();
+        assert_eq!(back.uv_plane.borrow(), nv21.uv_plane.borrow());
+
+        let mut p010 =
+            YuvBiPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv422);
+        for (i, v) in p010.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = ((i * 37) as u16 & 0x3ff) << 6;
+        }
+        for (i, v) in p010.uv_plane.borrow_mut().iter_mut().enumerate() {
+            *v = ((i * 11) as u16 & 0x3ff) << 6;
+        }
+        let mut i210 = YuvPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv422);
+        p210_to_i210(&p010.to_fixed(), &mut i210).unwrap();
+        for (i, uv) in p010.uv_plane.borrow().chunks_exact(2).enumerate() {
+            assert_eq!(i210.u_plane.borrow()[i], uv[0] >> 6);
+            assert_eq!(i210.v_plane.borrow()[i], uv[1] >> 6);
+        }
+        assert!(i210.y_plane.borrow().iter().all(|&v| v <= 0x3ff));
+        let mut back =
+            YuvBiPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv422);
+        i210_to_p210(&i210.to_fixed(), &mut back).unwrap();
+        assert_eq!(back.y_plane.borrow(), p010.y_plane.borrow());
+        assert_eq!(back.uv_plane.borrow(), p010.uv_plane.borrow());
+    }
+}
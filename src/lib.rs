@@ -67,26 +67,42 @@ mod avx2;
 ))]
 mod avx512bw;
 mod ayuv_to_rgb;
+mod blend;
+mod bt2020_cl;
 mod built_coefficients;
+mod cicp;
+mod color_adjust;
+mod crop;
+mod dither;
 #[cfg(feature = "nightly_f16")]
 mod f16_converter;
+mod frame_hash;
+mod frame_ops;
 mod from_identity;
 mod from_identity_alpha;
 #[cfg(feature = "nightly_f16")]
 mod from_identity_alpha_f16;
 #[cfg(feature = "nightly_f16")]
 mod from_identity_f16;
+mod gainmap;
 #[cfg(feature = "geometry")]
 mod geometry;
+mod ictcp;
 mod images;
+mod interleave_cbcr;
 mod internals;
+mod lut;
+mod metrics;
 #[cfg(feature = "geometry")]
 mod mirroring;
 #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
 mod neon;
 mod numerics;
+mod premultiply;
 #[cfg(feature = "rdp")]
 mod rdp;
+mod reference;
+mod resample_chroma;
 mod rgb16_to_yuv_p16;
 mod rgb_ar30;
 mod rgb_to_nv_p16;
@@ -94,10 +110,12 @@ mod rgb_to_y;
 mod rgb_to_ycgco;
 mod rgba_to_nv;
 mod rgba_to_yuv;
+mod scale;
 mod sharpyuv;
 mod shuffle;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
 mod sse;
+mod stats;
 mod to_identity;
 #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
 mod wasm32;
@@ -108,33 +126,41 @@ mod y_with_alpha_to_rgb;
 mod ycgco_to_rgb;
 mod ycgco_to_rgb_alpha;
 mod ycgcor_support;
+mod yuv_cross_depth;
+mod yuv_depth;
 mod yuv_error;
+mod yuv_matrix_range;
 mod yuv_nv_p10_to_ar30;
 mod yuv_nv_p10_to_rgb;
 mod yuv_nv_p16_to_rgb16;
 mod yuv_nv_to_rgba;
 mod yuv_p10_rgba;
 mod yuv_p16_ar30;
+mod yuv_p16_rgb8_dither;
 mod yuv_p16_rgba16_alpha;
 mod yuv_p16_rgba_alpha;
 #[cfg(feature = "nightly_f16")]
 mod yuv_p16_rgba_f16;
 mod yuv_p16_rgba_p16;
 mod yuv_support;
+mod yuv_thumbnail;
 mod yuv_to_rgba;
 mod yuv_to_rgba_alpha;
+#[cfg(feature = "geometry")]
+mod yuv_to_rgba_rotated;
 mod yuv_to_yuy2;
 mod yuv_to_yuy2_p16;
 #[cfg(feature = "nightly_f16")]
 mod yuva_p16_rgba_f16;
+mod yuy2_nv;
 mod yuy2_to_rgb;
 mod yuy2_to_rgb_p16;
 mod yuy2_to_yuv;
 mod yuy2_to_yuv_p16;
 
 pub use yuv_support::{
-    Rgb30ByteOrder, YuvBytesPacking, YuvChromaSubsampling, YuvConversionMode, YuvEndianness,
-    YuvRange, YuvStandardMatrix,
+    Rgb30ByteOrder, YuvBias, YuvBytesPacking, YuvChromaSubsampling, YuvConversionMode,
+    YuvEndianness, YuvRange, YuvStandardMatrix, Yuy2Description,
 };
 
 pub use yuv_nv_p10_to_rgb::{
@@ -496,12 +522,14 @@ pub use f16_converter::{
 #[cfg(feature = "geometry")]
 pub use geometry::{
     rotate_cbcr, rotate_cbcr16, rotate_plane, rotate_plane16, rotate_rgb, rotate_rgb16,
-    rotate_rgba, rotate_rgba16, RotationMode,
+    rotate_rgba, rotate_rgba16, rotate_yuv_biplanar, rotate_yuv_biplanar16, rotate_yuv_planar,
+    rotate_yuv_planar16, RotationMode,
 };
 #[cfg(feature = "geometry")]
 pub use mirroring::{
     mirror_cbcr, mirror_cbcr16, mirror_plane, mirror_plane16, mirror_rgb, mirror_rgb16,
-    mirror_rgba, mirror_rgba16, MirrorMode,
+    mirror_rgba, mirror_rgba16, mirror_yuv_biplanar, mirror_yuv_biplanar16, mirror_yuv_planar,
+    mirror_yuv_planar16, MirrorMode,
 };
 
 pub use rgb_ar30::{rgb8_to_ar30, rgb8_to_ra30, rgba8_to_ar30, rgba8_to_ra30};
@@ -537,3 +565,239 @@ pub use yuv_p16_rgba_f16::{
 };
 
 pub use ayuv_to_rgb::{ayuv_to_rgb, ayuv_to_rgba, vyua_to_rgb, vyua_to_rgba};
+
+pub use ictcp::{
+    ictcp010_to_rgb10, ictcp010_to_rgba10, ictcp012_to_rgb12, ictcp012_to_rgba12,
+    ictcp210_to_rgb10, ictcp210_to_rgba10, ictcp212_to_rgb12, ictcp212_to_rgba12,
+    ictcp410_to_rgb10, ictcp410_to_rgba10, ictcp412_to_rgb12, ictcp412_to_rgba12,
+    rgb10_to_ictcp010, rgb10_to_ictcp210, rgb10_to_ictcp410, rgb12_to_ictcp012, rgb12_to_ictcp212,
+    rgb12_to_ictcp412, rgba10_to_ictcp010, rgba10_to_ictcp210, rgba10_to_ictcp410,
+    rgba12_to_ictcp012, rgba12_to_ictcp212, rgba12_to_ictcp412, IctCpTransfer,
+};
+
+pub use bt2020_cl::{
+    i010_cl_to_rgb10, i010_cl_to_rgba10, i012_cl_to_rgb12, i012_cl_to_rgba12, p010_cl_to_rgb10,
+    p010_cl_to_rgba10, rgb10_to_i010_cl, rgb10_to_p010_cl, rgb12_to_i012_cl, rgba10_to_i010_cl,
+    rgba10_to_p010_cl, rgba12_to_i012_cl,
+};
+
+pub use cicp::{
+    Chromaticity, ColorDescription, ColorDescriptionError, ColorPrimaries,
+    ColorPrimariesChromaticities, MatrixCoefficients, TransferCharacteristics,
+};
+
+#[cfg(feature = "nightly_f16")]
+pub use gainmap::{apply_gain_map_rgb_f16, apply_gain_map_rgba_f16};
+pub use gainmap::{
+    apply_gain_map_rgb_f32, apply_gain_map_rgb_pq10, apply_gain_map_rgb_pq12,
+    apply_gain_map_rgb_pq16, apply_gain_map_rgba_f32, apply_gain_map_rgba_pq10,
+    apply_gain_map_rgba_pq12, apply_gain_map_rgba_pq16, generate_gain_map_rgb_f32,
+    generate_gain_map_rgba_f32, GainMapMetadata,
+};
+
+pub use yuv_matrix_range::{
+    p010_convert_matrix_range, p210_convert_matrix_range, p410_convert_matrix_range,
+    uyvy422_convert_matrix_range, uyvy422_p16_convert_matrix_range, vyuy422_convert_matrix_range,
+    vyuy422_p16_convert_matrix_range, yuv420_convert_matrix_range, yuv420_p16_convert_matrix_range,
+    yuv422_convert_matrix_range, yuv422_p16_convert_matrix_range, yuv444_convert_matrix_range,
+    yuv444_p16_convert_matrix_range, yuv_convert_matrix_range, yuv_nv12_convert_matrix_range,
+    yuv_nv16_convert_matrix_range, yuv_nv21_convert_matrix_range, yuv_nv24_convert_matrix_range,
+    yuv_nv42_convert_matrix_range, yuv_nv61_convert_matrix_range, yuyv422_convert_matrix_range,
+    yuyv422_p16_convert_matrix_range, yvyu422_convert_matrix_range,
+    yvyu422_p16_convert_matrix_range,
+};
+
+pub use blend::{
+    blend_rgba10_on_p010, blend_rgba10_on_p210, blend_rgba16_on_nv12_p16, blend_rgba16_on_nv16_p16,
+    blend_rgba16_on_nv21_p16, blend_rgba16_on_nv24_p16, blend_rgba16_on_nv42_p16,
+    blend_rgba16_on_nv61_p16, blend_rgba16_on_yuv420_p16, blend_rgba16_on_yuv422_p16,
+    blend_rgba16_on_yuv444_p16, blend_rgba_on_nv12, blend_rgba_on_nv16, blend_rgba_on_nv21,
+    blend_rgba_on_nv24, blend_rgba_on_nv42, blend_rgba_on_nv61, blend_rgba_on_yuv420,
+    blend_rgba_on_yuv422, blend_rgba_on_yuv444,
+};
+pub use color_adjust::{
+    adjust_nv12, adjust_nv12_p16, adjust_nv16, adjust_nv16_p16, adjust_nv21, adjust_nv21_p16,
+    adjust_nv24, adjust_nv24_p16, adjust_nv42, adjust_nv42_p16, adjust_nv61, adjust_nv61_p16,
+    adjust_yuv420, adjust_yuv420_p16, adjust_yuv422, adjust_yuv422_p16, adjust_yuv444,
+    adjust_yuv444_p16, bgr16_color_matrix, bgr_color_matrix, bgra16_color_matrix,
+    bgra_color_matrix, rgb16_color_matrix, rgb_color_matrix, rgba16_color_matrix,
+    rgba_color_matrix, yuv420_to_bgr_adjusted, yuv420_to_bgra_adjusted, yuv420_to_rgb_adjusted,
+    yuv420_to_rgba_adjusted, yuv422_to_bgr_adjusted, yuv422_to_bgra_adjusted,
+    yuv422_to_rgb_adjusted, yuv422_to_rgba_adjusted, yuv444_to_bgr_adjusted,
+    yuv444_to_bgra_adjusted, yuv444_to_rgb_adjusted, yuv444_to_rgba_adjusted,
+    yuv_nv12_to_bgr_adjusted, yuv_nv12_to_bgra_adjusted, yuv_nv12_to_rgb_adjusted,
+    yuv_nv12_to_rgba_adjusted, yuv_nv16_to_bgr_adjusted, yuv_nv16_to_bgra_adjusted,
+    yuv_nv16_to_rgb_adjusted, yuv_nv16_to_rgba_adjusted, yuv_nv21_to_bgr_adjusted,
+    yuv_nv21_to_bgra_adjusted, yuv_nv21_to_rgb_adjusted, yuv_nv21_to_rgba_adjusted,
+    yuv_nv24_to_bgr_adjusted, yuv_nv24_to_bgra_adjusted, yuv_nv24_to_rgb_adjusted,
+    yuv_nv24_to_rgba_adjusted, yuv_nv42_to_bgr_adjusted, yuv_nv42_to_bgra_adjusted,
+    yuv_nv42_to_rgb_adjusted, yuv_nv42_to_rgba_adjusted, yuv_nv61_to_bgr_adjusted,
+    yuv_nv61_to_bgra_adjusted, yuv_nv61_to_rgb_adjusted, yuv_nv61_to_rgba_adjusted, RgbColorMatrix,
+    YuvAdjustment,
+};
+pub use dither::YuvDitherMode;
+pub use frame_hash::{hash_plane, FrameHashAlgorithm, FrameHashSample};
+pub use frame_ops::{
+    copy_yuv_bi_planar, copy_yuv_bi_planar_p16, copy_yuv_gray, copy_yuv_gray_p16, copy_yuv_packed,
+    copy_yuv_packed_p16, copy_yuv_planar, copy_yuv_planar_p16, fill_rect_nv12, fill_rect_nv12_p16,
+    fill_rect_nv16, fill_rect_nv16_p16, fill_rect_nv21, fill_rect_nv21_p16, fill_rect_nv24,
+    fill_rect_nv24_p16, fill_rect_nv42, fill_rect_nv42_p16, fill_rect_nv61, fill_rect_nv61_p16,
+    fill_rect_uyvy422, fill_rect_uyvy422_p16, fill_rect_vyuy422, fill_rect_vyuy422_p16,
+    fill_rect_yuv400, fill_rect_yuv400_p16, fill_rect_yuv420, fill_rect_yuv420_p16,
+    fill_rect_yuv422, fill_rect_yuv422_p16, fill_rect_yuv444, fill_rect_yuv444_p16,
+    fill_rect_yuyv422, fill_rect_yuyv422_p16, fill_rect_yvyu422, fill_rect_yvyu422_p16,
+    pad_edges_uyvy422, pad_edges_uyvy422_p16, pad_edges_vyuy422, pad_edges_vyuy422_p16,
+    pad_edges_yuv_bi_planar, pad_edges_yuv_bi_planar_p16, pad_edges_yuv_gray,
+    pad_edges_yuv_gray_p16, pad_edges_yuv_planar, pad_edges_yuv_planar_p16, pad_edges_yuyv422,
+    pad_edges_yuyv422_p16, pad_edges_yvyu422, pad_edges_yvyu422_p16,
+};
+pub use interleave_cbcr::{
+    deinterleave_cbcr, deinterleave_cbcr16, i010_to_p010, i012_to_p012, i210_to_p210, i212_to_p212,
+    i410_to_p410, i412_to_p412, i420_to_nv12, i420_to_nv21, i422_to_nv16, i422_to_nv61,
+    i444_to_nv24, i444_to_nv42, interleave_cbcr, interleave_cbcr16, nv12_to_i420, nv16_to_i422,
+    nv21_to_i420, nv24_to_i444, nv42_to_i444, nv61_to_i422, p010_to_i010, p012_to_i012,
+    p210_to_i210, p212_to_i212, p410_to_i410, p412_to_i412,
+};
+pub use lut::{
+    bgr16_apply_lut, bgr_apply_lut, bgra16_apply_lut, bgra_apply_lut, i010_to_rgb10_lut,
+    i010_to_rgba10_lut, i012_to_rgb12_lut, i012_to_rgba12_lut, i210_to_rgb10_lut,
+    i210_to_rgba10_lut, i212_to_rgb12_lut, i212_to_rgba12_lut, i410_to_rgb10_lut,
+    i410_to_rgba10_lut, i412_to_rgb12_lut, i412_to_rgba12_lut, p010_to_rgb10_lut,
+    p010_to_rgba10_lut, p012_to_rgb12_lut, p012_to_rgba12_lut, p210_to_rgb10_lut,
+    p210_to_rgba10_lut, p212_to_rgb12_lut, p212_to_rgba12_lut, p410_to_rgb10_lut,
+    p410_to_rgba10_lut, p412_to_rgb12_lut, p412_to_rgba12_lut, rgb16_apply_lut, rgb_apply_lut,
+    rgba16_apply_lut, rgba_apply_lut, CubeLut, LutInterpolation,
+};
+#[cfg(feature = "nightly_f16")]
+pub use lut::{bgr_f16_apply_lut, bgra_f16_apply_lut, rgb_f16_apply_lut, rgba_f16_apply_lut};
+pub use metrics::{
+    rgb16_quality, rgb_quality, rgba16_quality, rgba_quality, yuv_bi_planar_p16_quality,
+    yuv_bi_planar_quality, yuv_gray_p16_quality, yuv_gray_quality, yuv_planar_p16_quality,
+    yuv_planar_quality, ImageQuality, PlaneQuality,
+};
+pub use premultiply::{
+    bgra_to_yuv420_alpha_premultiplied, bgra_to_yuv422_alpha_premultiplied,
+    bgra_to_yuv444_alpha_premultiplied, bgra_to_yuv_nv12_alpha_premultiplied,
+    bgra_to_yuv_nv16_alpha_premultiplied, bgra_to_yuv_nv21_alpha_premultiplied,
+    bgra_to_yuv_nv24_alpha_premultiplied, bgra_to_yuv_nv42_alpha_premultiplied,
+    bgra_to_yuv_nv61_alpha_premultiplied, premultiply_abgr, premultiply_abgr16,
+    premultiply_abgr16_in_place, premultiply_abgr_in_place, premultiply_argb, premultiply_argb16,
+    premultiply_argb16_in_place, premultiply_argb_in_place, premultiply_bgra, premultiply_bgra16,
+    premultiply_bgra16_in_place, premultiply_bgra_in_place, premultiply_rgba, premultiply_rgba16,
+    premultiply_rgba16_in_place, premultiply_rgba_in_place, rgba10_to_i010_alpha_premultiplied,
+    rgba10_to_i210_alpha_premultiplied, rgba10_to_i410_alpha_premultiplied,
+    rgba12_to_i012_alpha_premultiplied, rgba12_to_i212_alpha_premultiplied,
+    rgba12_to_i412_alpha_premultiplied, rgba14_to_i014_alpha_premultiplied,
+    rgba14_to_i214_alpha_premultiplied, rgba14_to_i414_alpha_premultiplied,
+    rgba16_to_i016_alpha_premultiplied, rgba16_to_i216_alpha_premultiplied,
+    rgba16_to_i416_alpha_premultiplied, rgba_to_yuv420_alpha_premultiplied,
+    rgba_to_yuv422_alpha_premultiplied, rgba_to_yuv444_alpha_premultiplied,
+    rgba_to_yuv_nv12_alpha_premultiplied, rgba_to_yuv_nv16_alpha_premultiplied,
+    rgba_to_yuv_nv21_alpha_premultiplied, rgba_to_yuv_nv24_alpha_premultiplied,
+    rgba_to_yuv_nv42_alpha_premultiplied, rgba_to_yuv_nv61_alpha_premultiplied, unpremultiply_abgr,
+    unpremultiply_abgr16, unpremultiply_abgr16_in_place, unpremultiply_abgr_in_place,
+    unpremultiply_argb, unpremultiply_argb16, unpremultiply_argb16_in_place,
+    unpremultiply_argb_in_place, unpremultiply_bgra, unpremultiply_bgra16,
+    unpremultiply_bgra16_in_place, unpremultiply_bgra_in_place, unpremultiply_rgba,
+    unpremultiply_rgba16, unpremultiply_rgba16_in_place, unpremultiply_rgba_in_place,
+};
+#[cfg(feature = "nightly_f16")]
+pub use premultiply::{
+    premultiply_abgr_f16, premultiply_abgr_f16_in_place, premultiply_argb_f16,
+    premultiply_argb_f16_in_place, premultiply_bgra_f16, premultiply_bgra_f16_in_place,
+    premultiply_rgba_f16, premultiply_rgba_f16_in_place, unpremultiply_abgr_f16,
+    unpremultiply_abgr_f16_in_place, unpremultiply_argb_f16, unpremultiply_argb_f16_in_place,
+    unpremultiply_bgra_f16, unpremultiply_bgra_f16_in_place, unpremultiply_rgba_f16,
+    unpremultiply_rgba_f16_in_place,
+};
+pub use reference::{
+    reference_convert_depth, reference_convert_matrix_range, reference_rgb_to_yuv,
+    reference_yuv_bi_planar_to_rgb, reference_yuv_packed_to_rgb, reference_yuv_to_rgb,
+    ReferenceError, ReferenceRgb, ReferenceSample, ReferenceYuv,
+};
+pub use resample_chroma::{
+    resample_chroma_bi_planar, resample_chroma_bi_planar_p16, resample_chroma_planar,
+    resample_chroma_planar_p16,
+};
+pub use scale::{
+    scale_rgb, scale_rgb16, scale_rgba, scale_rgba16, scale_uyvy422, scale_uyvy422_p16,
+    scale_vyuy422, scale_vyuy422_p16, scale_yuv_bi_planar, scale_yuv_bi_planar_p16, scale_yuv_gray,
+    scale_yuv_gray_p16, scale_yuv_planar, scale_yuv_planar_p16, scale_yuyv422, scale_yuyv422_p16,
+    scale_yvyu422, scale_yvyu422_p16, ScalingFilter, YuvChromaLocation,
+};
+pub use stats::{
+    rgba16_is_opaque, rgba_is_opaque, yuv_bi_planar_p16_stats, yuv_bi_planar_stats,
+    yuv_gray_alpha_p16_stats, yuv_gray_alpha_stats, yuv_gray_p16_stats, yuv_gray_stats,
+    yuv_planar_p16_stats, yuv_planar_stats, yuv_planar_with_alpha_p16_stats,
+    yuv_planar_with_alpha_stats, PlaneStats, YuvStats,
+};
+pub use yuv_cross_depth::{
+    rgb16_to_yuv420, rgb16_to_yuv422, rgb16_to_yuv444, rgba16_to_yuv420, rgba16_to_yuv422,
+    rgba16_to_yuv444, yuv420_to_rgb16, yuv420_to_rgba16, yuv422_to_rgb16, yuv422_to_rgba16,
+    yuv444_to_rgb16, yuv444_to_rgba16,
+};
+pub use yuv_depth::{
+    convert_bi_planar_depth_16_to_16, convert_bi_planar_depth_16_to_8,
+    convert_bi_planar_depth_8_to_16, convert_packed_depth_16_to_16, convert_packed_depth_16_to_8,
+    convert_packed_depth_8_to_16, convert_planar_depth_16_to_16, convert_planar_depth_16_to_8,
+    convert_planar_depth_8_to_16, i010_to_i420, i420_to_i010, nv12_to_p010, p010_to_nv12,
+    YuvBitDepth,
+};
+pub use yuv_p16_rgb8_dither::{
+    i010_to_rgb_dithered, i010_to_rgba_dithered, i012_to_rgb_dithered, i012_to_rgba_dithered,
+    i014_to_rgb_dithered, i014_to_rgba_dithered, i016_to_rgb_dithered, i016_to_rgba_dithered,
+    i210_to_rgb_dithered, i210_to_rgba_dithered, i212_to_rgb_dithered, i212_to_rgba_dithered,
+    i214_to_rgb_dithered, i214_to_rgba_dithered, i216_to_rgb_dithered, i216_to_rgba_dithered,
+    i410_to_rgb_dithered, i410_to_rgba_dithered, i412_to_rgb_dithered, i412_to_rgba_dithered,
+    i414_to_rgb_dithered, i414_to_rgba_dithered, i416_to_rgb_dithered, i416_to_rgba_dithered,
+    p010_to_rgb_dithered, p010_to_rgba_dithered, p012_to_rgb_dithered, p012_to_rgba_dithered,
+    p014_to_rgb_dithered, p014_to_rgba_dithered, p016_to_rgb_dithered, p016_to_rgba_dithered,
+    p210_to_rgb_dithered, p210_to_rgba_dithered, p212_to_rgb_dithered, p212_to_rgba_dithered,
+    p214_to_rgb_dithered, p214_to_rgba_dithered, p216_to_rgb_dithered, p216_to_rgba_dithered,
+    p410_to_rgb_dithered, p410_to_rgba_dithered, p412_to_rgb_dithered, p412_to_rgba_dithered,
+    p414_to_rgb_dithered, p414_to_rgba_dithered, p416_to_rgb_dithered, p416_to_rgba_dithered,
+};
+pub use yuv_thumbnail::{
+    p010_to_bgr_thumbnail, p010_to_bgra_thumbnail, p010_to_rgb_thumbnail, p010_to_rgba_thumbnail,
+    yuv420_to_bgr_thumbnail, yuv420_to_bgra_thumbnail, yuv420_to_rgb_thumbnail,
+    yuv420_to_rgba_thumbnail, yuv_nv12_to_bgr_thumbnail, yuv_nv12_to_bgra_thumbnail,
+    yuv_nv12_to_rgb_thumbnail, yuv_nv12_to_rgba_thumbnail, yuv_nv21_to_bgr_thumbnail,
+    yuv_nv21_to_bgra_thumbnail, yuv_nv21_to_rgb_thumbnail, yuv_nv21_to_rgba_thumbnail,
+    ThumbnailMode,
+};
+#[cfg(feature = "geometry")]
+pub use yuv_to_rgba_rotated::{
+    uyvy422_to_bgr_rotated, uyvy422_to_bgra_rotated, uyvy422_to_rgb_rotated,
+    uyvy422_to_rgba_rotated, vyuy422_to_bgr_rotated, vyuy422_to_bgra_rotated,
+    vyuy422_to_rgb_rotated, vyuy422_to_rgba_rotated, yuv420_to_bgr_rotated, yuv420_to_bgra_rotated,
+    yuv420_to_rgb_rotated, yuv420_to_rgba_rotated, yuv_nv12_to_bgr_rotated,
+    yuv_nv12_to_bgra_rotated, yuv_nv12_to_rgb_rotated, yuv_nv12_to_rgba_rotated,
+    yuv_nv16_to_bgr_rotated, yuv_nv16_to_bgra_rotated, yuv_nv16_to_rgb_rotated,
+    yuv_nv16_to_rgba_rotated, yuv_nv21_to_bgr_rotated, yuv_nv21_to_bgra_rotated,
+    yuv_nv21_to_rgb_rotated, yuv_nv21_to_rgba_rotated, yuv_nv24_to_bgr_rotated,
+    yuv_nv24_to_bgra_rotated, yuv_nv24_to_rgb_rotated, yuv_nv24_to_rgba_rotated,
+    yuv_nv42_to_bgr_rotated, yuv_nv42_to_bgra_rotated, yuv_nv42_to_rgb_rotated,
+    yuv_nv42_to_rgba_rotated, yuv_nv61_to_bgr_rotated, yuv_nv61_to_bgra_rotated,
+    yuv_nv61_to_rgb_rotated, yuv_nv61_to_rgba_rotated, yuyv422_to_bgr_rotated,
+    yuyv422_to_bgra_rotated, yuyv422_to_rgb_rotated, yuyv422_to_rgba_rotated,
+    yvyu422_to_bgr_rotated, yvyu422_to_bgra_rotated, yvyu422_to_rgb_rotated,
+    yvyu422_to_rgba_rotated,
+};
+pub use yuy2_nv::{
+    nv12_to_uyvy422, nv12_to_uyvy422_p16, nv12_to_vyuy422, nv12_to_vyuy422_p16, nv12_to_yuyv422,
+    nv12_to_yuyv422_p16, nv12_to_yvyu422, nv12_to_yvyu422_p16, nv16_to_uyvy422,
+    nv16_to_uyvy422_p16, nv16_to_vyuy422, nv16_to_vyuy422_p16, nv16_to_yuyv422,
+    nv16_to_yuyv422_p16, nv16_to_yvyu422, nv16_to_yvyu422_p16, nv21_to_uyvy422,
+    nv21_to_uyvy422_p16, nv21_to_vyuy422, nv21_to_vyuy422_p16, nv21_to_yuyv422,
+    nv21_to_yuyv422_p16, nv21_to_yvyu422, nv21_to_yvyu422_p16, nv61_to_uyvy422,
+    nv61_to_uyvy422_p16, nv61_to_vyuy422, nv61_to_vyuy422_p16, nv61_to_yuyv422,
+    nv61_to_yuyv422_p16, nv61_to_yvyu422, nv61_to_yvyu422_p16, uyvy422_to_nv12,
+    uyvy422_to_nv12_p16, uyvy422_to_nv16, uyvy422_to_nv16_p16, uyvy422_to_nv21,
+    uyvy422_to_nv21_p16, uyvy422_to_nv61, uyvy422_to_nv61_p16, vyuy422_to_nv12,
+    vyuy422_to_nv12_p16, vyuy422_to_nv16, vyuy422_to_nv16_p16, vyuy422_to_nv21,
+    vyuy422_to_nv21_p16, vyuy422_to_nv61, vyuy422_to_nv61_p16, yuyv422_to_nv12,
+    yuyv422_to_nv12_p16, yuyv422_to_nv16, yuyv422_to_nv16_p16, yuyv422_to_nv21,
+    yuyv422_to_nv21_p16, yuyv422_to_nv61, yuyv422_to_nv61_p16, yvyu422_to_nv12,
+    yvyu422_to_nv12_p16, yvyu422_to_nv16, yvyu422_to_nv16_p16, yvyu422_to_nv21,
+    yvyu422_to_nv21_p16, yvyu422_to_nv61, yvyu422_to_nv61_p16,
+};
@@ -0,0 +1,1280 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::{check_bit_depth, check_rgba_destination};
+use crate::yuv_support::{YuvChromaSubsampling, YuvSourceChannels};
+use crate::{
+    i010_to_rgb10, i010_to_rgba10, i012_to_rgb12, i012_to_rgba12, i210_to_rgb10, i210_to_rgba10,
+    i212_to_rgb12, i212_to_rgba12, i410_to_rgb10, i410_to_rgba10, i412_to_rgb12, i412_to_rgba12,
+    p010_to_rgb10, p010_to_rgba10, p012_to_rgb12, p012_to_rgba12, p210_to_rgb10, p210_to_rgba10,
+    p212_to_rgb12, p212_to_rgba12, p410_to_rgb10, p410_to_rgba10, p412_to_rgb12, p412_to_rgba12,
+    YuvBiPlanarImage, YuvError, YuvPlanarImage, YuvRange, YuvStandardMatrix,
+};
+#[cfg(feature = "nightly_f16")]
+use core::f16;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use std::io::{BufRead, BufReader, Read};
+
+/// Rows decoded at once by fused decoders before LUT is applied to them
+const STRIP_HEIGHT: usize = 32;
+
+/// Pixels gathered into scratch before handed to interpolation kernel
+const BLOCK_SIZE: usize = 64;
+
+/// Interpolation used to sample 3D LUT between grid nodes
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum LutInterpolation {
+    /// Blends 8 surrounding nodes
+    Trilinear,
+    /// Blends 4 nodes of the tetrahedron containing the sample, preserves neutral axis exactly
+    #[default]
+    Tetrahedral,
+}
+
+#[derive(Debug, Clone)]
+struct Lut1d {
+    table: Vec<[f32; 3]>,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+}
+
+#[derive(Debug, Clone)]
+struct Lut3d {
+    /// Nodes with red changing fastest, each padded to 4 lanes for SIMD loads
+    table: Vec<f32>,
+    size: usize,
+    domain_min: [f32; 3],
+    domain_max: [f32; 3],
+}
+
+/// LUT parsed from `.cube` file.
+///
+/// Supports 1D, 3D and 1D shaper followed by 3D LUT as written by Resolve.
+/// `DOMAIN_MIN`, `DOMAIN_MAX`, `LUT_1D_INPUT_RANGE` and `LUT_3D_INPUT_RANGE` are honored.
+#[derive(Debug, Clone)]
+pub struct CubeLut {
+    title: Option<String>,
+    shaper: Option<Lut1d>,
+    cube: Option<Lut3d>,
+}
+
+fn parse_floats<const N: usize>(tokens: &[&str], line: usize) -> Result<[f32; N], YuvError> {
+    if tokens.len() != N {
+        return Err(YuvError::InvalidLut(format!(
+            "line {line}: expected {N} values, got {}",
+            tokens.len()
+        )));
+    }
+    let mut values = [0f32; N];
+    for (dst, token) in values.iter_mut().zip(tokens) {
+        *dst = token
+            .parse::<f32>()
+            .map_err(|_| YuvError::InvalidLut(format!("line {line}: invalid number {token}")))?;
+    }
+    Ok(values)
+}
+
+fn parse_size(tokens: &[&str], line: usize, max: usize) -> Result<usize, YuvError> {
+    let [size] = tokens else {
+        return Err(YuvError::InvalidLut(format!(
+            "line {line}: size must be a single value"
+        )));
+    };
+    let size = size
+        .parse::<usize>()
+        .map_err(|_| YuvError::InvalidLut(format!("line {line}: invalid size {size}")))?;
+    if !(2..=max).contains(&size) {
+        return Err(YuvError::InvalidLut(format!(
+            "line {line}: size must be in 2..={max}, got {size}"
+        )));
+    }
+    Ok(size)
+}
+
+fn check_domain(min: [f32; 3], max: [f32; 3]) -> Result<(), YuvError> {
+    if !min.iter().zip(max.iter()).all(|(a, b)| a < b) {
+        return Err(YuvError::InvalidLut(
+            "domain maximum must be greater than minimum".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+impl CubeLut {
+    /// Parses `.cube` file from reader
+    pub fn from_reader<R: Read>(reader: R) -> Result<CubeLut, YuvError> {
+        let mut title = None;
+        let mut size_1d = None;
+        let mut size_3d = None;
+        let mut domain_min = [0f32; 3];
+        let mut domain_max = [1f32; 3];
+        let mut range_1d = None;
+        let mut range_3d = None;
+        let mut entries: Vec<[f32; 3]> = Vec::new();
+
+        for (index, line) in BufReader::new(reader).lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.map_err(|e| YuvError::InvalidLut(e.to_string()))?;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let tokens = line.split_whitespace().collect::<Vec<_>>();
+            let (keyword, values) = (tokens[0], &tokens[1..]);
+            if !keyword.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                entries.push(parse_floats::<3>(&tokens, line_number)?);
+                continue;
+            }
+            if !entries.is_empty() {
+                return Err(YuvError::InvalidLut(format!(
+                    "line {line_number}: keyword {keyword} after table data"
+                )));
+            }
+            match keyword {
+                "TITLE" => {
+                    title = Some(line["TITLE".len()..].trim().trim_matches('"').to_string());
+                }
+                "LUT_1D_SIZE" => size_1d = Some(parse_size(values, line_number, 65536)?),
+                "LUT_3D_SIZE" => size_3d = Some(parse_size(values, line_number, 256)?),
+                "DOMAIN_MIN" => domain_min = parse_floats::<3>(values, line_number)?,
+                "DOMAIN_MAX" => domain_max = parse_floats::<3>(values, line_number)?,
+                "LUT_1D_INPUT_RANGE" => range_1d = Some(parse_floats::<2>(values, line_number)?),
+                "LUT_3D_INPUT_RANGE" => range_3d = Some(parse_floats::<2>(values, line_number)?),
+                // Vendor specific keywords don't affect the table
+                _ => {}
+            }
+        }
+
+        if size_1d.is_none() && size_3d.is_none() {
+            return Err(YuvError::InvalidLut(
+                "neither LUT_1D_SIZE nor LUT_3D_SIZE is present".to_string(),
+            ));
+        }
+        let count_1d = size_1d.unwrap_or(0);
+        let count_3d = size_3d.map(|x| x * x * x).unwrap_or(0);
+        if entries.len() != count_1d + count_3d {
+            return Err(YuvError::InvalidLut(format!(
+                "expected {} table entries, got {}",
+                count_1d + count_3d,
+                entries.len()
+            )));
+        }
+
+        let shaper = match size_1d {
+            Some(size) => {
+                let (min, max) = match range_1d {
+                    Some([min, max]) => ([min; 3], [max; 3]),
+                    None => (domain_min, domain_max),
+                };
+                check_domain(min, max)?;
+                Some(Lut1d {
+                    table: entries[..size].to_vec(),
+                    domain_min: min,
+                    domain_max: max,
+                })
+            }
+            None => None,
+        };
+        let cube = match size_3d {
+            Some(size) => {
+                let (min, max) = match range_3d {
+                    Some([min, max]) => ([min; 3], [max; 3]),
+                    None => (domain_min, domain_max),
+                };
+                check_domain(min, max)?;
+                let mut table = Vec::with_capacity(count_3d * 4);
+                for node in entries[count_1d..].iter() {
+                    table.extend_from_slice(&[node[0], node[1], node[2], 0f32]);
+                }
+                Some(Lut3d {
+                    table,
+                    size,
+                    domain_min: min,
+                    domain_max: max,
+                })
+            }
+            None => None,
+        };
+
+        Ok(CubeLut {
+            title,
+            shaper,
+            cube,
+        })
+    }
+
+    /// Title of the LUT if file has one
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Size of 1D LUT or shaper if present
+    pub fn size_1d(&self) -> Option<usize> {
+        self.shaper.as_ref().map(|x| x.table.len())
+    }
+
+    /// Size of 3D LUT edge if present
+    pub fn size_3d(&self) -> Option<usize> {
+        self.cube.as_ref().map(|x| x.size)
+    }
+
+    /// Applies 1D LUT if present and maps result onto 3D grid coordinates
+    #[inline]
+    fn prepare(&self, mut rgb: [f32; 3]) -> [f32; 3] {
+        if let Some(shaper) = &self.shaper {
+            let last = shaper.table.len() - 1;
+            for (c, value) in rgb.iter_mut().enumerate() {
+                let position = (*value - shaper.domain_min[c])
+                    / (shaper.domain_max[c] - shaper.domain_min[c])
+                    * last as f32;
+                let (i, f) = lut_cell(last + 1, position.max(0f32).min(last as f32));
+                let a = shaper.table[i][c];
+                *value = a + (shaper.table[i + 1][c] - a) * f;
+            }
+        }
+        if let Some(cube) = &self.cube {
+            let last = (cube.size - 1) as f32;
+            for (c, value) in rgb.iter_mut().enumerate() {
+                let position = (*value - cube.domain_min[c])
+                    / (cube.domain_max[c] - cube.domain_min[c])
+                    * last;
+                *value = position.max(0f32).min(last);
+            }
+        }
+        rgb
+    }
+}
+
+/// Cell origin and fractional position of grid coordinate in `[0, size - 1]`
+#[inline(always)]
+pub(crate) fn lut_cell(size: usize, position: f32) -> (usize, f32) {
+    let i = (position as usize).min(size - 2);
+    (i, position - i as f32)
+}
+
+/// Offsets of 4 tetrahedron nodes in the padded table and their weights
+#[inline(always)]
+pub(crate) fn tetrahedral_nodes(size: usize, px: &[f32]) -> ([usize; 4], [f32; 4]) {
+    let (r, fr) = lut_cell(size, px[0]);
+    let (g, fg) = lut_cell(size, px[1]);
+    let (b, fb) = lut_cell(size, px[2]);
+    let (dr, dg, db) = (4, size * 4, size * size * 4);
+    let base = (b * size * size + g * size + r) * 4;
+    let (w1, w2, w3, first, second) = if fr > fg {
+        if fg > fb {
+            (fr, fg, fb, dr, dr + dg)
+        } else if fr > fb {
+            (fr, fb, fg, dr, dr + db)
+        } else {
+            (fb, fr, fg, db, db + dr)
+        }
+    } else if fb > fg {
+        (fb, fg, fr, db, db + dg)
+    } else if fb > fr {
+        (fg, fb, fr, dg, dg + db)
+    } else {
+        (fg, fr, fb, dg, dg + dr)
+    };
+    (
+        [base, base + first, base + second, base + dr + dg + db],
+        [1f32 - w1, w1 - w2, w2 - w3, w3],
+    )
+}
+
+/// Offset of cell origin node in the padded table, offsets to neighbours along
+/// red, green and blue and fractional position inside the cell
+#[inline(always)]
+pub(crate) fn trilinear_cell(size: usize, px: &[f32]) -> (usize, [usize; 3], [f32; 3]) {
+    let (r, fr) = lut_cell(size, px[0]);
+    let (g, fg) = lut_cell(size, px[1]);
+    let (b, fb) = lut_cell(size, px[2]);
+    (
+        (b * size * size + g * size + r) * 4,
+        [4, size * 4, size * size * 4],
+        [fr, fg, fb],
+    )
+}
+
+fn tetrahedral_scalar(table: &[f32], size: usize, px: &mut [f32]) {
+    let (nodes, weights) = tetrahedral_nodes(size, px);
+    for c in 0..3 {
+        px[c] = table[nodes[0] + c] * weights[0]
+            + table[nodes[1] + c] * weights[1]
+            + table[nodes[2] + c] * weights[2]
+            + table[nodes[3] + c] * weights[3];
+    }
+}
+
+fn trilinear_scalar(table: &[f32], size: usize, px: &mut [f32]) {
+    let (base, [dr, dg, db], [fr, fg, fb]) = trilinear_cell(size, px);
+    let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+    for c in 0..3 {
+        let node = |offset: usize| table[base + offset + c];
+        let c00 = lerp(node(0), node(dr), fr);
+        let c10 = lerp(node(dg), node(dg + dr), fr);
+        let c01 = lerp(node(db), node(db + dr), fr);
+        let c11 = lerp(node(db + dg), node(db + dg + dr), fr);
+        px[c] = lerp(lerp(c00, c10, fg), lerp(c01, c11, fg), fb);
+    }
+}
+
+/// Interpolates `count` pixels of 4 lanes holding grid coordinates in place, returns processed count
+type Lut3dRow = unsafe fn(&[f32], usize, &mut [f32], usize) -> usize;
+
+/// Runtime selected SIMD interpolation kernels
+#[derive(Copy, Clone)]
+struct Lut3dRowHandler {
+    tetrahedral: Lut3dRow,
+    trilinear: Lut3dRow,
+}
+
+fn lut3d_row_none(_: &[f32], _: usize, _: &mut [f32], _: usize) -> usize {
+    0
+}
+
+impl Default for Lut3dRowHandler {
+    fn default() -> Self {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            use crate::neon::{neon_lut3d_tetrahedral_row, neon_lut3d_trilinear_row};
+            return Lut3dRowHandler {
+                tetrahedral: neon_lut3d_tetrahedral_row,
+                trilinear: neon_lut3d_trilinear_row,
+            };
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                use crate::avx2::{avx2_lut3d_tetrahedral_row, avx2_lut3d_trilinear_row};
+                return Lut3dRowHandler {
+                    tetrahedral: avx2_lut3d_tetrahedral_row,
+                    trilinear: avx2_lut3d_trilinear_row,
+                };
+            }
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.1") {
+                use crate::sse::{sse_lut3d_tetrahedral_row, sse_lut3d_trilinear_row};
+                return Lut3dRowHandler {
+                    tetrahedral: sse_lut3d_tetrahedral_row,
+                    trilinear: sse_lut3d_trilinear_row,
+                };
+            }
+        }
+        #[allow(unreachable_code)]
+        Lut3dRowHandler {
+            tetrahedral: lut3d_row_none,
+            trilinear: lut3d_row_none,
+        }
+    }
+}
+
+trait LutSample: Copy + Send + Sync {
+    fn to_f32(self) -> f32;
+    /// Converts value already scaled to `[0, max]`
+    fn from_f32(value: f32, max: f32) -> Self;
+}
+
+impl LutSample for u8 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    #[inline(always)]
+    fn from_f32(value: f32, max: f32) -> Self {
+        value.round().max(0f32).min(max) as u8
+    }
+}
+
+impl LutSample for u16 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    #[inline(always)]
+    fn from_f32(value: f32, max: f32) -> Self {
+        value.round().max(0f32).min(max) as u16
+    }
+}
+
+#[cfg(feature = "nightly_f16")]
+impl LutSample for f16 {
+    #[inline(always)]
+    fn to_f32(self) -> f32 {
+        self as f32
+    }
+
+    #[inline(always)]
+    fn from_f32(value: f32, _: f32) -> Self {
+        value as f16
+    }
+}
+
+fn apply_lut_row<T: LutSample, const CHANNELS: u8>(
+    lut: &CubeLut,
+    kernel: Lut3dRow,
+    scalar: fn(&[f32], usize, &mut [f32]),
+    row: &mut [T],
+    max: f32,
+) {
+    let source_channels: YuvSourceChannels = CHANNELS.into();
+    let channels = source_channels.get_channels_count();
+    let offsets = [
+        source_channels.get_r_channel_offset(),
+        source_channels.get_g_channel_offset(),
+        source_channels.get_b_channel_offset(),
+    ];
+    let inverse_max = 1f32 / max;
+    let mut scratch = [0f32; BLOCK_SIZE * 4];
+
+    for chunk in row.chunks_mut(BLOCK_SIZE * channels) {
+        let count = chunk.len() / channels;
+        for (px, dst) in chunk
+            .chunks_exact(channels)
+            .zip(scratch.chunks_exact_mut(4))
+        {
+            let rgb = lut.prepare(offsets.map(|o| px[o].to_f32() * inverse_max));
+            dst[..3].copy_from_slice(&rgb);
+        }
+        if let Some(cube) = &lut.cube {
+            let processed = unsafe { kernel(&cube.table, cube.size, &mut scratch, count) };
+            for px in scratch[processed * 4..count * 4].chunks_exact_mut(4) {
+                scalar(&cube.table, cube.size, px);
+            }
+        }
+        for (px, src) in chunk
+            .chunks_exact_mut(channels)
+            .zip(scratch.chunks_exact(4))
+        {
+            for (c, &o) in offsets.iter().enumerate() {
+                px[o] = T::from_f32(src[c] * max, max);
+            }
+        }
+    }
+}
+
+fn apply_lut_impl<T: LutSample, const CHANNELS: u8>(
+    rgba: &mut [T],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    max: f32,
+    lut: &CubeLut,
+    interpolation: LutInterpolation,
+) -> Result<(), YuvError> {
+    let source_channels: YuvSourceChannels = CHANNELS.into();
+    let channels = source_channels.get_channels_count();
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    let handler = Lut3dRowHandler::default();
+    let (kernel, scalar): (Lut3dRow, fn(&[f32], usize, &mut [f32])) = match interpolation {
+        LutInterpolation::Trilinear => (handler.trilinear, trilinear_scalar),
+        LutInterpolation::Tetrahedral => (handler.tetrahedral, tetrahedral_scalar),
+    };
+    let row_length = width as usize * channels;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba.par_chunks_mut(rgba_stride as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba.chunks_mut(rgba_stride as usize);
+    }
+    iter.take(height as usize).for_each(|row| {
+        apply_lut_row::<T, CHANNELS>(lut, kernel, scalar, &mut row[..row_length], max)
+    });
+    Ok(())
+}
+
+macro_rules! d_apply_lut {
+    ($method: ident, $method_p16: ident, $channels: expr, $rgb_name: expr) => {
+        #[doc = concat!("Applies LUT to 8 bit-depth ", $rgb_name, " image in place.
+
+# Arguments
+
+* `rgba` - ", $rgb_name, " image data.
+* `rgba_stride` - The stride (components per row) for ", $rgb_name, " data.
+* `width` - Image width.
+* `height` - Image height.
+* `lut` - LUT to apply. See [CubeLut] for more info.
+* `interpolation` - 3D LUT interpolation. See [LutInterpolation] for more info.
+
+# Errors
+
+This function returns an error if the length of ", $rgb_name, " data is not valid based
+on the specified width, height, and stride.")]
+        pub fn $method(
+            rgba: &mut [u8],
+            rgba_stride: u32,
+            width: u32,
+            height: u32,
+            lut: &CubeLut,
+            interpolation: LutInterpolation,
+        ) -> Result<(), YuvError> {
+            apply_lut_impl::<u8, { $channels as u8 }>(
+                rgba,
+                rgba_stride,
+                width,
+                height,
+                255f32,
+                lut,
+                interpolation,
+            )
+        }
+
+        #[doc = concat!("Applies LUT to high bit-depth ", $rgb_name, " image in place.
+
+# Arguments
+
+* `rgba` - ", $rgb_name, " image data.
+* `rgba_stride` - The stride (components per row) for ", $rgb_name, " data.
+* `width` - Image width.
+* `height` - Image height.
+* `bit_depth` - Bit-depth of the image, 8..=16.
+* `lut` - LUT to apply. See [CubeLut] for more info.
+* `interpolation` - 3D LUT interpolation. See [LutInterpolation] for more info.
+
+# Errors
+
+This function returns an error if the length of ", $rgb_name, " data is not valid based
+on the specified width, height, and stride, or bit depth is not in 8..=16.")]
+        pub fn $method_p16(
+            rgba: &mut [u16],
+            rgba_stride: u32,
+            width: u32,
+            height: u32,
+            bit_depth: u32,
+            lut: &CubeLut,
+            interpolation: LutInterpolation,
+        ) -> Result<(), YuvError> {
+            check_bit_depth(bit_depth, 8..=16)?;
+            apply_lut_impl::<u16, { $channels as u8 }>(
+                rgba,
+                rgba_stride,
+                width,
+                height,
+                ((1u32 << bit_depth) - 1) as f32,
+                lut,
+                interpolation,
+            )
+        }
+    };
+}
+
+d_apply_lut!(
+    rgb_apply_lut,
+    rgb16_apply_lut,
+    YuvSourceChannels::Rgb,
+    "RGB"
+);
+d_apply_lut!(
+    bgr_apply_lut,
+    bgr16_apply_lut,
+    YuvSourceChannels::Bgr,
+    "BGR"
+);
+d_apply_lut!(
+    rgba_apply_lut,
+    rgba16_apply_lut,
+    YuvSourceChannels::Rgba,
+    "RGBA"
+);
+d_apply_lut!(
+    bgra_apply_lut,
+    bgra16_apply_lut,
+    YuvSourceChannels::Bgra,
+    "BGRA"
+);
+
+#[cfg(feature = "nightly_f16")]
+macro_rules! d_apply_lut_f16 {
+    ($method: ident, $channels: expr, $rgb_name: expr) => {
+        #[doc = concat!("Applies LUT to ", $rgb_name, " f16 image in place.
+
+Values are expected to be normalized so LUT domain maps onto them,
+results are not clamped.
+
+# Arguments
+
+* `rgba` - ", $rgb_name, " image data.
+* `rgba_stride` - The stride (components per row) for ", $rgb_name, " data.
+* `width` - Image width.
+* `height` - Image height.
+* `lut` - LUT to apply. See [CubeLut] for more info.
+* `interpolation` - 3D LUT interpolation. See [LutInterpolation] for more info.
+
+# Errors
+
+This function returns an error if the length of ", $rgb_name, " data is not valid based
+on the specified width, height, and stride.")]
+        pub fn $method(
+            rgba: &mut [f16],
+            rgba_stride: u32,
+            width: u32,
+            height: u32,
+            lut: &CubeLut,
+            interpolation: LutInterpolation,
+        ) -> Result<(), YuvError> {
+            apply_lut_impl::<f16, { $channels as u8 }>(
+                rgba,
+                rgba_stride,
+                width,
+                height,
+                1f32,
+                lut,
+                interpolation,
+            )
+        }
+    };
+}
+
+#[cfg(feature = "nightly_f16")]
+d_apply_lut_f16!(rgb_f16_apply_lut, YuvSourceChannels::Rgb, "RGB");
+#[cfg(feature = "nightly_f16")]
+d_apply_lut_f16!(bgr_f16_apply_lut, YuvSourceChannels::Bgr, "BGR");
+#[cfg(feature = "nightly_f16")]
+d_apply_lut_f16!(rgba_f16_apply_lut, YuvSourceChannels::Rgba, "RGBA");
+#[cfg(feature = "nightly_f16")]
+d_apply_lut_f16!(bgra_f16_apply_lut, YuvSourceChannels::Bgra, "BGRA");
+
+/// Slice of `rows` rows starting at `row`, last row may be shorter than stride
+#[inline]
+fn rows_of<T>(plane: &[T], stride: usize, row: usize, rows: usize) -> &[T] {
+    let start = row * stride;
+    &plane[start..(start + rows * stride).min(plane.len())]
+}
+
+/// Decodes planar image strip by strip straight into destination and applies LUT
+/// to every strip while it's still in cache
+fn decode_planar_lut<const CHANNELS: u8>(
+    image: &YuvPlanarImage<u16>,
+    subsampling: YuvChromaSubsampling,
+    rgba: &mut [u16],
+    rgba_stride: u32,
+    bit_depth: u32,
+    lut: &CubeLut,
+    interpolation: LutInterpolation,
+    decoder: impl Fn(&YuvPlanarImage<u16>, &mut [u16]) -> Result<(), YuvError>,
+) -> Result<(), YuvError> {
+    let source_channels: YuvSourceChannels = CHANNELS.into();
+    image.check_constraints(subsampling)?;
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        image.width,
+        image.height,
+        source_channels.get_channels_count(),
+    )?;
+    let height = image.height as usize;
+    let rgba_stride_u = rgba_stride as usize;
+    let max = ((1u32 << bit_depth) - 1) as f32;
+    for row in (0..height).step_by(STRIP_HEIGHT) {
+        let rows = STRIP_HEIGHT.min(height - row);
+        let chroma_row = row / subsampling.vertical_factor();
+        let chroma_rows = subsampling.chroma_height(rows);
+        let strip = YuvPlanarImage {
+            y_plane: rows_of(image.y_plane, image.y_stride as usize, row, rows),
+            y_stride: image.y_stride,
+            u_plane: rows_of(
+                image.u_plane,
+                image.u_stride as usize,
+                chroma_row,
+                chroma_rows,
+            ),
+            u_stride: image.u_stride,
+            v_plane: rows_of(
+                image.v_plane,
+                image.v_stride as usize,
+                chroma_row,
+                chroma_rows,
+            ),
+            v_stride: image.v_stride,
+            width: image.width,
+            height: rows as u32,
+        };
+        let start = row * rgba_stride_u;
+        let end = (start + rows * rgba_stride_u).min(rgba.len());
+        let dst = &mut rgba[start..end];
+        decoder(&strip, dst)?;
+        apply_lut_impl::<u16, CHANNELS>(
+            dst,
+            rgba_stride,
+            image.width,
+            rows as u32,
+            max,
+            lut,
+            interpolation,
+        )?;
+    }
+    Ok(())
+}
+
+/// Bi-planar counterpart of [decode_planar_lut]
+fn decode_bi_planar_lut<const CHANNELS: u8>(
+    image: &YuvBiPlanarImage<u16>,
+    subsampling: YuvChromaSubsampling,
+    rgba: &mut [u16],
+    rgba_stride: u32,
+    bit_depth: u32,
+    lut: &CubeLut,
+    interpolation: LutInterpolation,
+    decoder: impl Fn(&YuvBiPlanarImage<u16>, &mut [u16]) -> Result<(), YuvError>,
+) -> Result<(), YuvError> {
+    let source_channels: YuvSourceChannels = CHANNELS.into();
+    image.check_constraints(subsampling)?;
+    check_rgba_destination(
+        rgba,
+        rgba_stride,
+        image.width,
+        image.height,
+        source_channels.get_channels_count(),
+    )?;
+    let height = image.height as usize;
+    let rgba_stride_u = rgba_stride as usize;
+    let max = ((1u32 << bit_depth) - 1) as f32;
+    for row in (0..height).step_by(STRIP_HEIGHT) {
+        let rows = STRIP_HEIGHT.min(height - row);
+        let chroma_row = row / subsampling.vertical_factor();
+        let chroma_rows = subsampling.chroma_height(rows);
+        let strip = YuvBiPlanarImage {
+            y_plane: rows_of(image.y_plane, image.y_stride as usize, row, rows),
+            y_stride: image.y_stride,
+            uv_plane: rows_of(
+                image.uv_plane,
+                image.uv_stride as usize,
+                chroma_row,
+                chroma_rows,
+            ),
+            uv_stride: image.uv_stride,
+            width: image.width,
+            height: rows as u32,
+        };
+        let start = row * rgba_stride_u;
+        let end = (start + rows * rgba_stride_u).min(rgba.len());
+        let dst = &mut rgba[start..end];
+        decoder(&strip, dst)?;
+        apply_lut_impl::<u16, CHANNELS>(
+            dst,
+            rgba_stride,
+            image.width,
+            rows as u32,
+            max,
+            lut,
+            interpolation,
+        )?;
+    }
+    Ok(())
+}
+
+macro_rules! d_fused_lut {
+    ($method: ident, $decoder: ident, $driver: ident, $image: ident, $image_arg: expr, $sampling: expr, $channels: expr, $bit_depth: expr, $yuv_name: expr, $rgb_name: expr) => {
+        #[doc = concat!("Converts ", $yuv_name, " image to ", $rgb_name, " ", stringify!($bit_depth), " bit-depth and applies LUT to it.
+
+Same as [", stringify!($decoder), "] followed by LUT application, but LUT is applied
+to small strips of rows right after they are decoded so no intermediate
+", $rgb_name, " image is walked over twice.
+
+# Arguments
+
+* `", $image_arg, "` - Source image.
+* `rgba` - A mutable slice to store the converted ", $rgb_name, " data.
+* `rgba_stride` - The stride (components per row) for ", $rgb_name, " data.
+* `range` - YUV values range. See [YuvRange] for more info.
+* `matrix` - YUV matrix coefficients. See [YuvStandardMatrix] for more info.
+* `lut` - LUT to apply. See [CubeLut] for more info.
+* `interpolation` - 3D LUT interpolation. See [LutInterpolation] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $rgb_name, " data are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            image: &$image<u16>,
+            rgba: &mut [u16],
+            rgba_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            lut: &CubeLut,
+            interpolation: LutInterpolation,
+        ) -> Result<(), YuvError> {
+            $driver::<{ $channels as u8 }>(
+                image,
+                $sampling,
+                rgba,
+                rgba_stride,
+                $bit_depth,
+                lut,
+                interpolation,
+                |strip, dst| $decoder(strip, dst, rgba_stride, range, matrix),
+            )
+        }
+    };
+}
+
+macro_rules! d_fused_planar {
+    ($method: ident, $decoder: ident, $sampling: expr, $channels: expr, $bit_depth: expr, $yuv_name: expr, $rgb_name: expr) => {
+        d_fused_lut!(
+            $method,
+            $decoder,
+            decode_planar_lut,
+            YuvPlanarImage,
+            "image",
+            $sampling,
+            $channels,
+            $bit_depth,
+            $yuv_name,
+            $rgb_name
+        );
+    };
+}
+
+macro_rules! d_fused_bi_planar {
+    ($method: ident, $decoder: ident, $sampling: expr, $channels: expr, $bit_depth: expr, $yuv_name: expr, $rgb_name: expr) => {
+        d_fused_lut!(
+            $method,
+            $decoder,
+            decode_bi_planar_lut,
+            YuvBiPlanarImage,
+            "image",
+            $sampling,
+            $channels,
+            $bit_depth,
+            $yuv_name,
+            $rgb_name
+        );
+    };
+}
+
+d_fused_planar!(
+    i010_to_rgb10_lut,
+    i010_to_rgb10,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    10,
+    "I010",
+    "RGB"
+);
+d_fused_planar!(
+    i010_to_rgba10_lut,
+    i010_to_rgba10,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    10,
+    "I010",
+    "RGBA"
+);
+d_fused_planar!(
+    i012_to_rgb12_lut,
+    i012_to_rgb12,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    12,
+    "I012",
+    "RGB"
+);
+d_fused_planar!(
+    i012_to_rgba12_lut,
+    i012_to_rgba12,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    12,
+    "I012",
+    "RGBA"
+);
+d_fused_planar!(
+    i210_to_rgb10_lut,
+    i210_to_rgb10,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    10,
+    "I210",
+    "RGB"
+);
+d_fused_planar!(
+    i210_to_rgba10_lut,
+    i210_to_rgba10,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    10,
+    "I210",
+    "RGBA"
+);
+d_fused_planar!(
+    i212_to_rgb12_lut,
+    i212_to_rgb12,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    12,
+    "I212",
+    "RGB"
+);
+d_fused_planar!(
+    i212_to_rgba12_lut,
+    i212_to_rgba12,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    12,
+    "I212",
+    "RGBA"
+);
+d_fused_planar!(
+    i410_to_rgb10_lut,
+    i410_to_rgb10,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    10,
+    "I410",
+    "RGB"
+);
+d_fused_planar!(
+    i410_to_rgba10_lut,
+    i410_to_rgba10,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    10,
+    "I410",
+    "RGBA"
+);
+d_fused_planar!(
+    i412_to_rgb12_lut,
+    i412_to_rgb12,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    12,
+    "I412",
+    "RGB"
+);
+d_fused_planar!(
+    i412_to_rgba12_lut,
+    i412_to_rgba12,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    12,
+    "I412",
+    "RGBA"
+);
+
+d_fused_bi_planar!(
+    p010_to_rgb10_lut,
+    p010_to_rgb10,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    10,
+    "P010",
+    "RGB"
+);
+d_fused_bi_planar!(
+    p010_to_rgba10_lut,
+    p010_to_rgba10,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    10,
+    "P010",
+    "RGBA"
+);
+d_fused_bi_planar!(
+    p012_to_rgb12_lut,
+    p012_to_rgb12,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    12,
+    "P012",
+    "RGB"
+);
+d_fused_bi_planar!(
+    p012_to_rgba12_lut,
+    p012_to_rgba12,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    12,
+    "P012",
+    "RGBA"
+);
+d_fused_bi_planar!(
+    p210_to_rgb10_lut,
+    p210_to_rgb10,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    10,
+    "P210",
+    "RGB"
+);
+d_fused_bi_planar!(
+    p210_to_rgba10_lut,
+    p210_to_rgba10,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    10,
+    "P210",
+    "RGBA"
+);
+d_fused_bi_planar!(
+    p212_to_rgb12_lut,
+    p212_to_rgb12,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    12,
+    "P212",
+    "RGB"
+);
+d_fused_bi_planar!(
+    p212_to_rgba12_lut,
+    p212_to_rgba12,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    12,
+    "P212",
+    "RGBA"
+);
+d_fused_bi_planar!(
+    p410_to_rgb10_lut,
+    p410_to_rgb10,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    10,
+    "P410",
+    "RGB"
+);
+d_fused_bi_planar!(
+    p410_to_rgba10_lut,
+    p410_to_rgba10,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    10,
+    "P410",
+    "RGBA"
+);
+d_fused_bi_planar!(
+    p412_to_rgb12_lut,
+    p412_to_rgb12,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    12,
+    "P412",
+    "RGB"
+);
+d_fused_bi_planar!(
+    p412_to_rgba12_lut,
+    p412_to_rgba12,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    12,
+    "P412",
+    "RGBA"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::YuvPlanarImageMut;
+
+    /// 5^3 LUT which swaps red and blue and squares green
+    fn make_cube() -> String {
+        let size = 5;
+        let mut cube = String::from("# test\nTITLE \"Swap\"\nLUT_3D_SIZE 5\n\n");
+        for b in 0..size {
+            for g in 0..size {
+                for r in 0..size {
+                    let [r, g, b] = [r, g, b].map(|x| x as f32 / (size - 1) as f32);
+                    cube.push_str(&format!("{b} {} {r}\n", g * g));
+                }
+            }
+        }
+        cube
+    }
+
+    #[test]
+    fn cube_lut_interpolations() {
+        let lut = CubeLut::from_reader(make_cube().as_bytes()).unwrap();
+        assert_eq!(lut.title(), Some("Swap"));
+        assert_eq!(lut.size_3d(), Some(5));
+        assert!(CubeLut::from_reader("LUT_3D_SIZE 2\n0 0 0\n".as_bytes()).is_err());
+
+        let width = 67usize;
+        let source = (0..width * 4)
+            .map(|i| ((i * 37) % 256) as u8)
+            .collect::<Vec<_>>();
+        for interpolation in [LutInterpolation::Trilinear, LutInterpolation::Tetrahedral] {
+            let mut rgba = source.clone();
+            rgba_apply_lut(
+                &mut rgba,
+                width as u32 * 4,
+                width as u32,
+                1,
+                &lut,
+                interpolation,
+            )
+            .unwrap();
+            for (dst, src) in rgba.chunks_exact(4).zip(source.chunks_exact(4)) {
+                assert_eq!(dst[0], src[2]);
+                assert_eq!(dst[2], src[0]);
+                assert_eq!(dst[3], src[3]);
+                // Green is piecewise linear approximation of square
+                let expected = (src[1] as f32 / 255f32).powi(2) * 255f32;
+                assert!((dst[1] as f32 - expected).abs() <= 5f32);
+            }
+        }
+
+        let mut image = YuvPlanarImageMut::<u16>::alloc(35, 37, YuvChromaSubsampling::Yuv420);
+        for (i, v) in image.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (64 + i * 7 % 876) as u16;
+        }
+        for (i, v) in image.u_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (100 + i * 13 % 800) as u16;
+        }
+        for (i, v) in image.v_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (80 + i * 11 % 850) as u16;
+        }
+        let stride = 35 * 4;
+        let mut fused = vec![0u16; stride * 37];
+        i010_to_rgba10_lut(
+            &image.to_fixed(),
+            &mut fused,
+            stride as u32,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt2020,
+            &lut,
+            LutInterpolation::Tetrahedral,
+        )
+        .unwrap();
+        let mut reference = vec![0u16; stride * 37];
+        i010_to_rgba10(
+            &image.to_fixed(),
+            &mut reference,
+            stride as u32,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt2020,
+        )
+        .unwrap();
+        rgba16_apply_lut(
+            &mut reference,
+            stride as u32,
+            35,
+            37,
+            10,
+            &lut,
+            LutInterpolation::Tetrahedral,
+        )
+        .unwrap();
+        assert_eq!(fused, reference);
+    }
+
+    /// 2^3 LUT with nodes at corners of the domain, red and blue are swapped when `swap` is set
+    fn make_corners(header: &str, swap: bool) -> String {
+        let mut cube = String::from(header);
+        for b in 0..2 {
+            for g in 0..2 {
+                for r in 0..2 {
+                    let (r, b) = if swap { (b, r) } else { (r, b) };
+                    cube.push_str(&format!("{r} {g} {b}\n"));
+                }
+            }
+        }
+        cube
+    }
+
+    /// Applies LUT to RGB gradient and returns source with result
+    fn apply_to_gradient(lut: &CubeLut) -> (Vec<u8>, Vec<u8>) {
+        let width = 85usize;
+        let source = (0..width * 3)
+            .map(|i| ((i * 3 + i / 3 * 7) % 256) as u8)
+            .collect::<Vec<_>>();
+        let mut rgb = source.clone();
+        rgb_apply_lut(
+            &mut rgb,
+            width as u32 * 3,
+            width as u32,
+            1,
+            lut,
+            LutInterpolation::Tetrahedral,
+        )
+        .unwrap();
+        (source, rgb)
+    }
+
+    #[test]
+    fn one_dimensional_lut_and_shaper() {
+        let lut =
+            CubeLut::from_reader("LUT_1D_SIZE 3\n1 1 1\n0.5 0.5 0.5\n0 0 0\n".as_bytes()).unwrap();
+        assert_eq!(lut.size_1d(), Some(3));
+        assert_eq!(lut.size_3d(), None);
+        let (source, rgb) = apply_to_gradient(&lut);
+        for (dst, src) in rgb.iter().zip(source.iter()) {
+            assert!((*dst as i32 - (255 - *src as i32)).abs() <= 1);
+        }
+
+        // Shaper doubles input over [0, 2], 3D LUT over [0, 2] swaps red and blue back
+        let header = "LUT_1D_SIZE 2\nLUT_3D_SIZE 2\n\
+            LUT_1D_INPUT_RANGE 0 2\nLUT_3D_INPUT_RANGE 0 2\n0 0 0\n4 4 4\n";
+        let lut = CubeLut::from_reader(make_corners(header, true).as_bytes()).unwrap();
+        assert_eq!(lut.size_1d(), Some(2));
+        assert_eq!(lut.size_3d(), Some(2));
+        let (source, rgb) = apply_to_gradient(&lut);
+        for (dst, src) in rgb.chunks_exact(3).zip(source.chunks_exact(3)) {
+            for (d, s) in dst.iter().zip([src[2], src[1], src[0]]) {
+                assert!((*d as i32 - s as i32).abs() <= 1, "{dst:?} of {src:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn domain_bounds_scale_lookups() {
+        let header = "LUT_3D_SIZE 2\nDOMAIN_MIN 0 0 0\nDOMAIN_MAX 1 2 4\n";
+        let lut = CubeLut::from_reader(make_corners(header, false).as_bytes()).unwrap();
+        let (source, rgb) = apply_to_gradient(&lut);
+        for (dst, src) in rgb.chunks_exact(3).zip(source.chunks_exact(3)) {
+            let expected = [src[0] as f32, src[1] as f32 / 2., src[2] as f32 / 4.];
+            for (d, e) in dst.iter().zip(expected) {
+                assert!((*d as f32 - e).abs() <= 1., "{dst:?} of {src:?}");
+            }
+        }
+
+        let inverted = "LUT_3D_SIZE 2\nDOMAIN_MIN 0 1 0\nDOMAIN_MAX 1 0 1\n";
+        assert!(matches!(
+            CubeLut::from_reader(make_corners(inverted, false).as_bytes()),
+            Err(YuvError::InvalidLut(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_unsupported_bit_depth() {
+        let lut = CubeLut::from_reader(make_cube().as_bytes()).unwrap();
+        let mut rgb = vec![0u16; 4 * 3];
+        assert!(matches!(
+            rgb16_apply_lut(&mut rgb, 12, 4, 1, 17, &lut, LutInterpolation::Trilinear),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+    }
+}
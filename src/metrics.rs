@@ -0,0 +1,879 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::{check_bit_depth, check_rgba_destination};
+use crate::{
+    YuvBiPlanarImage, YuvBitDepth, YuvChromaSubsampling, YuvError, YuvGrayImage, YuvPlanarImage,
+};
+
+/// Quality of a single plane or channel against the reference
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlaneQuality {
+    /// Sum of squared errors
+    pub sse: u64,
+    /// Peak signal-to-noise ratio in dB, infinite for identical planes
+    pub psnr: f64,
+    /// Structural similarity over 8x8 windows
+    pub ssim: f64,
+    /// Multi-scale structural similarity over up to 5 scales
+    pub ms_ssim: f64,
+}
+
+/// Quality of an image against the reference.
+///
+/// Weighted values use 6:1:1 for YUV, and equal weights for R, G, B.
+/// Alpha is reported in `planes` but doesn't contribute to weighted values.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageQuality {
+    /// Per plane quality in Y, U, V or in source channels order
+    pub planes: Vec<PlaneQuality>,
+    /// Sum of squared errors of weighted planes
+    pub sse: u64,
+    /// PSNR in dB of weighted mean of per plane MSE, infinite if every weighted plane is identical
+    pub psnr: f64,
+    /// Weighted mean of per plane SSIM
+    pub ssim: f64,
+    /// Weighted mean of per plane MS-SSIM
+    pub ms_ssim: f64,
+}
+
+type SquaredErrorRow = unsafe fn(&[u16], &[u16]) -> (u64, usize);
+
+fn squared_error_row_none(_: &[u16], _: &[u16]) -> (u64, usize) {
+    (0, 0)
+}
+
+fn make_squared_error_row() -> SquaredErrorRow {
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        use crate::neon::neon_squared_error_row;
+        return neon_squared_error_row;
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            use crate::avx2::avx2_squared_error_row;
+            return avx2_squared_error_row;
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            use crate::sse::sse_squared_error_row;
+            return sse_squared_error_row;
+        }
+    }
+    #[allow(unreachable_code)]
+    squared_error_row_none
+}
+
+/// Plane samples gathered as host order values in least significant bits
+struct Plane {
+    data: Vec<u16>,
+    width: usize,
+    height: usize,
+}
+
+impl Plane {
+    fn gather<V: Copy>(
+        src: &[V],
+        stride: u32,
+        width: usize,
+        height: usize,
+        step: usize,
+        offset: usize,
+        load: impl Fn(V) -> u16,
+    ) -> Plane {
+        let mut data = Vec::with_capacity(width * height);
+        for row in src.chunks(stride as usize).take(height) {
+            data.extend(
+                row[offset..]
+                    .iter()
+                    .step_by(step)
+                    .take(width)
+                    .map(|&v| load(v)),
+            );
+        }
+        Plane {
+            data,
+            width,
+            height,
+        }
+    }
+
+    fn to_f64(&self) -> Vec<f64> {
+        self.data.iter().map(|&v| v as f64).collect()
+    }
+}
+
+fn sum_squared_error(handler: SquaredErrorRow, a: &Plane, b: &Plane) -> u64 {
+    let mut sse = 0u64;
+    for (a, b) in a
+        .data
+        .chunks_exact(a.width)
+        .zip(b.data.chunks_exact(b.width))
+    {
+        let (sum, processed) = unsafe { handler(a, b) };
+        sse += sum;
+        for (&a, &b) in a.iter().zip(b.iter()).skip(processed) {
+            let diff = a as i64 - b as i64;
+            sse += (diff * diff) as u64;
+        }
+    }
+    sse
+}
+
+/// Accumulated sums of window as `[a, b, a*a, b*b, a*b]`
+type WindowSums = [f64; 5];
+
+/// Block sums are kept lane-wise as 5 quantities by 4 columns until all block rows are added
+const BLOCK_SUMS: usize = 20;
+
+type SsimBlockRow = unsafe fn(&[f64], &[f64], &mut [f64]) -> usize;
+
+fn ssim_block_row_none(_: &[f64], _: &[f64], _: &mut [f64]) -> usize {
+    0
+}
+
+fn make_ssim_block_row() -> SsimBlockRow {
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        use crate::neon::neon_ssim_block_row;
+        return neon_ssim_block_row;
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            use crate::avx2::avx2_ssim_block_row;
+            return avx2_ssim_block_row;
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            use crate::sse::sse_ssim_block_row;
+            return sse_ssim_block_row;
+        }
+    }
+    #[allow(unreachable_code)]
+    ssim_block_row_none
+}
+
+/// Adds one row of samples into lane-wise sums of 4 pixels wide blocks
+fn accumulate_block_row(handler: SsimBlockRow, a: &[f64], b: &[f64], sums: &mut [f64]) {
+    let processed = unsafe { handler(a, b, sums) };
+    for ((a, b), sums) in a
+        .chunks_exact(4)
+        .zip(b.chunks_exact(4))
+        .zip(sums.chunks_exact_mut(BLOCK_SUMS))
+        .skip(processed)
+    {
+        for lane in 0..4 {
+            let (a, b) = (a[lane], b[lane]);
+            sums[lane] += a;
+            sums[4 + lane] += b;
+            sums[8 + lane] += a * a;
+            sums[12 + lane] += b * b;
+            sums[16 + lane] += a * b;
+        }
+    }
+}
+
+fn accumulate(sums: &mut WindowSums, a: f64, b: f64) {
+    sums[0] += a;
+    sums[1] += b;
+    sums[2] += a * a;
+    sums[3] += b * b;
+    sums[4] += a * b;
+}
+
+/// Returns SSIM and contrast-structure term of the window
+fn window_ssim(sums: &WindowSums, count: f64, c1: f64, c2: f64) -> (f64, f64) {
+    let mu_a = sums[0] / count;
+    let mu_b = sums[1] / count;
+    let var_a = sums[2] / count - mu_a * mu_a;
+    let var_b = sums[3] / count - mu_b * mu_b;
+    let cov = sums[4] / count - mu_a * mu_b;
+    let luminance = (2. * mu_a * mu_b + c1) / (mu_a * mu_a + mu_b * mu_b + c1);
+    let contrast_structure = (2. * cov + c2) / (var_a + var_b + c2);
+    (luminance * contrast_structure, contrast_structure)
+}
+
+/// Mean SSIM and contrast-structure over 8x8 windows stepping by 4 pixels
+fn ssim_stats(
+    handler: SsimBlockRow,
+    a: &[f64],
+    b: &[f64],
+    width: usize,
+    height: usize,
+    c1: f64,
+    c2: f64,
+) -> (f64, f64) {
+    if width < 8 || height < 8 {
+        let mut sums = WindowSums::default();
+        for (&a, &b) in a.iter().zip(b.iter()) {
+            accumulate(&mut sums, a, b);
+        }
+        return window_ssim(&sums, (width * height) as f64, c1, c2);
+    }
+
+    let blocks_w = width / 4;
+    let blocks_h = height / 4;
+    let mut lanes = vec![0f64; blocks_w * BLOCK_SUMS];
+    let mut blocks = Vec::with_capacity(blocks_w * blocks_h);
+    for (a, b) in a
+        .chunks_exact(width * 4)
+        .zip(b.chunks_exact(width * 4))
+        .take(blocks_h)
+    {
+        lanes.fill(0.);
+        for (a, b) in a.chunks_exact(width).zip(b.chunks_exact(width)) {
+            accumulate_block_row(handler, &a[..blocks_w * 4], &b[..blocks_w * 4], &mut lanes);
+        }
+        blocks.extend(lanes.chunks_exact(BLOCK_SUMS).map(|lanes| {
+            let mut sums = WindowSums::default();
+            for (dst, quantity) in sums.iter_mut().zip(lanes.chunks_exact(4)) {
+                *dst = quantity.iter().sum();
+            }
+            sums
+        }));
+    }
+
+    let mut ssim = 0f64;
+    let mut cs = 0f64;
+    for y in 0..blocks_h - 1 {
+        for x in 0..blocks_w - 1 {
+            let mut sums = WindowSums::default();
+            for block in [
+                &blocks[y * blocks_w + x],
+                &blocks[y * blocks_w + x + 1],
+                &blocks[(y + 1) * blocks_w + x],
+                &blocks[(y + 1) * blocks_w + x + 1],
+            ] {
+                for (dst, &src) in sums.iter_mut().zip(block.iter()) {
+                    *dst += src;
+                }
+            }
+            let (window_ssim, window_cs) = window_ssim(&sums, 64., c1, c2);
+            ssim += window_ssim;
+            cs += window_cs;
+        }
+    }
+    let windows = ((blocks_w - 1) * (blocks_h - 1)) as f64;
+    (ssim / windows, cs / windows)
+}
+
+fn downsample(src: &[f64], width: usize, height: usize) -> Vec<f64> {
+    let (new_width, new_height) = (width / 2, height / 2);
+    let mut dst = Vec::with_capacity(new_width * new_height);
+    for rows in src.chunks_exact(width * 2).take(new_height) {
+        let (row0, row1) = rows.split_at(width);
+        dst.extend(
+            row0.chunks_exact(2)
+                .zip(row1.chunks_exact(2))
+                .take(new_width)
+                .map(|(r0, r1)| (r0[0] + r0[1] + r1[0] + r1[1]) * 0.25),
+        );
+    }
+    dst
+}
+
+const MS_SSIM_WEIGHTS: [f64; 5] = [0.0448, 0.2856, 0.3001, 0.2363, 0.1333];
+
+/// Returns SSIM at full scale and MS-SSIM
+fn ssim_and_ms_ssim(handler: SsimBlockRow, a: &Plane, b: &Plane, max_value: f64) -> (f64, f64) {
+    let c1 = (0.01 * max_value) * (0.01 * max_value);
+    let c2 = (0.03 * max_value) * (0.03 * max_value);
+
+    let (mut width, mut height) = (a.width, a.height);
+    let mut a = a.to_f64();
+    let mut b = b.to_f64();
+    let mut scales = Vec::with_capacity(MS_SSIM_WEIGHTS.len());
+    loop {
+        scales.push(ssim_stats(handler, &a, &b, width, height, c1, c2));
+        if scales.len() == MS_SSIM_WEIGHTS.len() || width / 2 < 8 || height / 2 < 8 {
+            break;
+        }
+        a = downsample(&a, width, height);
+        b = downsample(&b, width, height);
+        width /= 2;
+        height /= 2;
+    }
+
+    let weights = &MS_SSIM_WEIGHTS[..scales.len()];
+    let weights_sum: f64 = weights.iter().sum();
+    let last = scales.len() - 1;
+    let ms_ssim = scales
+        .iter()
+        .zip(weights.iter())
+        .enumerate()
+        .map(|(i, (&(ssim, cs), &weight))| {
+            let value = if i == last { ssim } else { cs };
+            value.max(0.).powf(weight / weights_sum)
+        })
+        .product();
+    (scales[0].0, ms_ssim)
+}
+
+/// Runtime selected SIMD row kernels
+#[derive(Copy, Clone)]
+struct MetricsHandler {
+    squared_error: SquaredErrorRow,
+    ssim_block: SsimBlockRow,
+}
+
+fn plane_quality(handler: MetricsHandler, a: &Plane, b: &Plane, bit_depth: u32) -> PlaneQuality {
+    let max_value = ((1u32 << bit_depth) - 1) as f64;
+    let sse = sum_squared_error(handler.squared_error, a, b);
+    let psnr = if sse == 0 {
+        f64::INFINITY
+    } else {
+        let mse = sse as f64 / (a.width * a.height) as f64;
+        10. * (max_value * max_value / mse).log10()
+    };
+    let (ssim, ms_ssim) = ssim_and_ms_ssim(handler.ssim_block, a, b, max_value);
+    PlaneQuality {
+        sse,
+        psnr,
+        ssim,
+        ms_ssim,
+    }
+}
+
+fn image_quality(pairs: Vec<(Plane, Plane)>, weights: &[f64], bit_depth: u32) -> ImageQuality {
+    let handler = MetricsHandler {
+        squared_error: make_squared_error_row(),
+        ssim_block: make_ssim_block_row(),
+    };
+    let planes: Vec<PlaneQuality> = pairs
+        .iter()
+        .map(|(a, b)| plane_quality(handler, a, b, bit_depth))
+        .collect();
+    let weighted = |f: &dyn Fn(usize, &PlaneQuality) -> f64| -> f64 {
+        let mut sum = 0f64;
+        let mut weights_sum = 0f64;
+        for (i, (plane, &weight)) in planes.iter().zip(weights.iter()).enumerate() {
+            if weight != 0. {
+                sum += f(i, plane) * weight;
+                weights_sum += weight;
+            }
+        }
+        sum / weights_sum
+    };
+    // PSNR is taken of weighted MSE, so a single identical plane doesn't make it infinite
+    let mse = weighted(&|i, p| {
+        let (a, _) = &pairs[i];
+        p.sse as f64 / (a.width * a.height) as f64
+    });
+    let max_value = ((1u32 << bit_depth) - 1) as f64;
+    let psnr = if mse == 0. {
+        f64::INFINITY
+    } else {
+        10. * (max_value * max_value / mse).log10()
+    };
+    ImageQuality {
+        sse: planes
+            .iter()
+            .zip(weights.iter())
+            .filter(|(_, &weight)| weight != 0.)
+            .map(|(plane, _)| plane.sse)
+            .sum(),
+        psnr,
+        ssim: weighted(&|_, p| p.ssim),
+        ms_ssim: weighted(&|_, p| p.ms_ssim),
+        planes,
+    }
+}
+
+const YUV_WEIGHTS: [f64; 3] = [6., 1., 1.];
+
+fn check_sizes(reference: (u32, u32), distorted: (u32, u32)) -> Result<(), YuvError> {
+    if reference != distorted {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+    Ok(())
+}
+
+fn planar_pairs<V: Copy + std::fmt::Debug>(
+    reference: &YuvPlanarImage<V>,
+    distorted: &YuvPlanarImage<V>,
+    subsampling: YuvChromaSubsampling,
+    load: impl Fn(V) -> u16 + Copy,
+) -> Result<Vec<(Plane, Plane)>, YuvError> {
+    reference.check_constraints(subsampling)?;
+    distorted.check_constraints(subsampling)?;
+    check_sizes(
+        (reference.width, reference.height),
+        (distorted.width, distorted.height),
+    )?;
+    let width = reference.width as usize;
+    let height = reference.height as usize;
+    let chroma_width = subsampling.chroma_width(width);
+    let chroma_height = subsampling.chroma_height(height);
+    let gather = |image: &YuvPlanarImage<V>| {
+        (
+            Plane::gather(image.y_plane, image.y_stride, width, height, 1, 0, load),
+            Plane::gather(
+                image.u_plane,
+                image.u_stride,
+                chroma_width,
+                chroma_height,
+                1,
+                0,
+                load,
+            ),
+            Plane::gather(
+                image.v_plane,
+                image.v_stride,
+                chroma_width,
+                chroma_height,
+                1,
+                0,
+                load,
+            ),
+        )
+    };
+    let (ry, ru, rv) = gather(reference);
+    let (dy, du, dv) = gather(distorted);
+    Ok(vec![(ry, dy), (ru, du), (rv, dv)])
+}
+
+fn bi_planar_pairs<V: Copy + std::fmt::Debug>(
+    reference: &YuvBiPlanarImage<V>,
+    distorted: &YuvBiPlanarImage<V>,
+    subsampling: YuvChromaSubsampling,
+    load: impl Fn(V) -> u16 + Copy,
+) -> Result<Vec<(Plane, Plane)>, YuvError> {
+    reference.check_constraints(subsampling)?;
+    distorted.check_constraints(subsampling)?;
+    check_sizes(
+        (reference.width, reference.height),
+        (distorted.width, distorted.height),
+    )?;
+    let width = reference.width as usize;
+    let height = reference.height as usize;
+    let chroma_width = subsampling.chroma_width(width);
+    let chroma_height = subsampling.chroma_height(height);
+    let gather = |image: &YuvBiPlanarImage<V>| {
+        (
+            Plane::gather(image.y_plane, image.y_stride, width, height, 1, 0, load),
+            Plane::gather(
+                image.uv_plane,
+                image.uv_stride,
+                chroma_width,
+                chroma_height,
+                2,
+                0,
+                load,
+            ),
+            Plane::gather(
+                image.uv_plane,
+                image.uv_stride,
+                chroma_width,
+                chroma_height,
+                2,
+                1,
+                load,
+            ),
+        )
+    };
+    let (ry, ru, rv) = gather(reference);
+    let (dy, du, dv) = gather(distorted);
+    Ok(vec![(ry, dy), (ru, du), (rv, dv)])
+}
+
+fn gray_pairs<V: Copy + std::fmt::Debug>(
+    reference: &YuvGrayImage<V>,
+    distorted: &YuvGrayImage<V>,
+    load: impl Fn(V) -> u16 + Copy,
+) -> Result<Vec<(Plane, Plane)>, YuvError> {
+    reference.check_constraints()?;
+    distorted.check_constraints()?;
+    check_sizes(
+        (reference.width, reference.height),
+        (distorted.width, distorted.height),
+    )?;
+    let width = reference.width as usize;
+    let height = reference.height as usize;
+    Ok(vec![(
+        Plane::gather(
+            reference.y_plane,
+            reference.y_stride,
+            width,
+            height,
+            1,
+            0,
+            load,
+        ),
+        Plane::gather(
+            distorted.y_plane,
+            distorted.y_stride,
+            width,
+            height,
+            1,
+            0,
+            load,
+        ),
+    )])
+}
+
+fn rgba_quality_impl<V: Copy>(
+    reference: &[V],
+    reference_stride: u32,
+    distorted: &[V],
+    distorted_stride: u32,
+    width: u32,
+    height: u32,
+    channels: usize,
+    bit_depth: u32,
+    load: impl Fn(V) -> u16 + Copy,
+) -> Result<ImageQuality, YuvError> {
+    check_rgba_destination(reference, reference_stride, width, height, channels)?;
+    check_rgba_destination(distorted, distorted_stride, width, height, channels)?;
+    let (w, h) = (width as usize, height as usize);
+    let pairs = (0..channels)
+        .map(|c| {
+            (
+                Plane::gather(reference, reference_stride, w, h, channels, c, load),
+                Plane::gather(distorted, distorted_stride, w, h, channels, c, load),
+            )
+        })
+        .collect();
+    Ok(image_quality(pairs, &[1., 1., 1., 0.], bit_depth))
+}
+
+/// Computes PSNR, SSIM and MS-SSIM of 8 bit-depth planar YUV image against the reference.
+///
+/// # Arguments
+///
+/// * `reference` - Reference planar image.
+/// * `distorted` - Planar image to evaluate.
+/// * `subsampling` - Chroma subsampling of both images.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or images sizes do not match.
+pub fn yuv_planar_quality(
+    reference: &YuvPlanarImage<u8>,
+    distorted: &YuvPlanarImage<u8>,
+    subsampling: YuvChromaSubsampling,
+) -> Result<ImageQuality, YuvError> {
+    let pairs = planar_pairs(reference, distorted, subsampling, |v| v as u16)?;
+    Ok(image_quality(pairs, &YUV_WEIGHTS, 8))
+}
+
+/// Computes PSNR, SSIM and MS-SSIM of high bit-depth planar YUV image against the reference.
+///
+/// # Arguments
+///
+/// * `reference` - Reference planar image.
+/// * `distorted` - Planar image to evaluate.
+/// * `subsampling` - Chroma subsampling of both images.
+/// * `depth` - Storage of samples, see [YuvBitDepth] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, images sizes do not match
+/// or bit depth is not in 8..=16.
+pub fn yuv_planar_p16_quality(
+    reference: &YuvPlanarImage<u16>,
+    distorted: &YuvPlanarImage<u16>,
+    subsampling: YuvChromaSubsampling,
+    depth: YuvBitDepth,
+) -> Result<ImageQuality, YuvError> {
+    check_bit_depth(depth.bit_depth, 8..=16)?;
+    let pairs = planar_pairs(reference, distorted, subsampling, |v| depth.native(v))?;
+    Ok(image_quality(pairs, &YUV_WEIGHTS, depth.bit_depth))
+}
+
+/// Computes PSNR, SSIM and MS-SSIM of 8 bit-depth bi-planar YUV image against the reference.
+///
+/// Chroma planes are reported in stored order, so for NV21 the second plane is V.
+///
+/// # Arguments
+///
+/// * `reference` - Reference bi-planar image.
+/// * `distorted` - Bi-planar image to evaluate.
+/// * `subsampling` - Chroma subsampling of both images.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or images sizes do not match.
+pub fn yuv_bi_planar_quality(
+    reference: &YuvBiPlanarImage<u8>,
+    distorted: &YuvBiPlanarImage<u8>,
+    subsampling: YuvChromaSubsampling,
+) -> Result<ImageQuality, YuvError> {
+    let pairs = bi_planar_pairs(reference, distorted, subsampling, |v| v as u16)?;
+    Ok(image_quality(pairs, &YUV_WEIGHTS, 8))
+}
+
+/// Computes PSNR, SSIM and MS-SSIM of high bit-depth bi-planar YUV image against the reference.
+///
+/// # Arguments
+///
+/// * `reference` - Reference bi-planar image.
+/// * `distorted` - Bi-planar image to evaluate.
+/// * `subsampling` - Chroma subsampling of both images.
+/// * `depth` - Storage of samples, for P010 use [YuvBitDepth::msb].
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, images sizes do not match
+/// or bit depth is not in 8..=16.
+pub fn yuv_bi_planar_p16_quality(
+    reference: &YuvBiPlanarImage<u16>,
+    distorted: &YuvBiPlanarImage<u16>,
+    subsampling: YuvChromaSubsampling,
+    depth: YuvBitDepth,
+) -> Result<ImageQuality, YuvError> {
+    check_bit_depth(depth.bit_depth, 8..=16)?;
+    let pairs = bi_planar_pairs(reference, distorted, subsampling, |v| depth.native(v))?;
+    Ok(image_quality(pairs, &YUV_WEIGHTS, depth.bit_depth))
+}
+
+/// Computes PSNR, SSIM and MS-SSIM of 8 bit-depth gray image against the reference.
+///
+/// # Arguments
+///
+/// * `reference` - Reference gray image.
+/// * `distorted` - Gray image to evaluate.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or images sizes do not match.
+pub fn yuv_gray_quality(
+    reference: &YuvGrayImage<u8>,
+    distorted: &YuvGrayImage<u8>,
+) -> Result<ImageQuality, YuvError> {
+    let pairs = gray_pairs(reference, distorted, |v| v as u16)?;
+    Ok(image_quality(pairs, &[1.], 8))
+}
+
+/// Computes PSNR, SSIM and MS-SSIM of high bit-depth gray image against the reference.
+///
+/// # Arguments
+///
+/// * `reference` - Reference gray image.
+/// * `distorted` - Gray image to evaluate.
+/// * `depth` - Storage of samples, see [YuvBitDepth] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, images sizes do not match
+/// or bit depth is not in 8..=16.
+pub fn yuv_gray_p16_quality(
+    reference: &YuvGrayImage<u16>,
+    distorted: &YuvGrayImage<u16>,
+    depth: YuvBitDepth,
+) -> Result<ImageQuality, YuvError> {
+    check_bit_depth(depth.bit_depth, 8..=16)?;
+    let pairs = gray_pairs(reference, distorted, |v| depth.native(v))?;
+    Ok(image_quality(pairs, &[1.], depth.bit_depth))
+}
+
+macro_rules! d_quality_rgba8 {
+    ($method: ident, $channels: expr, $px_name: expr) => {
+        #[doc = concat!("Computes per channel and weighted PSNR, SSIM and MS-SSIM of ", $px_name, " image against the reference.
+
+# Arguments
+
+* `reference` - Reference ", $px_name, " data.
+* `reference_stride` - The stride (components per row) of reference.
+* `distorted` - ", $px_name, " data to evaluate.
+* `distorted_stride` - The stride (components per row) of distorted data.
+* `width` - Image width.
+* `height` - Image height.
+
+# Errors
+
+This function returns an error if the lengths of the input ", $px_name, " data are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            reference: &[u8],
+            reference_stride: u32,
+            distorted: &[u8],
+            distorted_stride: u32,
+            width: u32,
+            height: u32,
+        ) -> Result<ImageQuality, YuvError> {
+            rgba_quality_impl(
+                reference,
+                reference_stride,
+                distorted,
+                distorted_stride,
+                width,
+                height,
+                $channels,
+                8,
+                |v| v as u16,
+            )
+        }
+    };
+}
+
+d_quality_rgba8!(rgb_quality, 3, "RGB");
+d_quality_rgba8!(rgba_quality, 4, "RGBA");
+
+macro_rules! d_quality_rgba16 {
+    ($method: ident, $channels: expr, $px_name: expr) => {
+        #[doc = concat!("Computes per channel and weighted PSNR, SSIM and MS-SSIM of high bit-depth ", $px_name, " image against the reference.
+
+# Arguments
+
+* `reference` - Reference ", $px_name, " data.
+* `reference_stride` - The stride (components per row) of reference.
+* `distorted` - ", $px_name, " data to evaluate.
+* `distorted_stride` - The stride (components per row) of distorted data.
+* `width` - Image width.
+* `height` - Image height.
+* `bit_depth` - Bit-depth of ", $px_name, " data, 8..=16.
+
+# Errors
+
+This function returns an error if the lengths of the input ", $px_name, " data are not valid based
+on the specified width, height, and strides, or bit depth is not in 8..=16.")]
+        pub fn $method(
+            reference: &[u16],
+            reference_stride: u32,
+            distorted: &[u16],
+            distorted_stride: u32,
+            width: u32,
+            height: u32,
+            bit_depth: u32,
+        ) -> Result<ImageQuality, YuvError> {
+            check_bit_depth(bit_depth, 8..=16)?;
+            rgba_quality_impl(
+                reference,
+                reference_stride,
+                distorted,
+                distorted_stride,
+                width,
+                height,
+                $channels,
+                bit_depth,
+                |v| v,
+            )
+        }
+    };
+}
+
+d_quality_rgba16!(rgb16_quality, 3, "RGB");
+d_quality_rgba16!(rgba16_quality, 4, "RGBA");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::YuvPlanarImageMut;
+
+    #[test]
+    fn identical_images_are_perfect() {
+        let (width, height) = (37u32, 29u32);
+        let rgba: Vec<u8> = (0..width * height * 4)
+            .map(|i| (i * 7 % 251) as u8)
+            .collect();
+        let quality = rgba_quality(&rgba, width * 4, &rgba, width * 4, width, height).unwrap();
+        assert_eq!(quality.sse, 0);
+        assert!(quality.psnr.is_infinite());
+        assert!((quality.ssim - 1.).abs() < 1e-9);
+        assert!((quality.ms_ssim - 1.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn psnr_of_constant_offset() {
+        let (width, height) = (64u32, 48u32);
+        let reference: Vec<u16> = (0..width * height * 3).map(|i| (i % 900) as u16).collect();
+        let distorted: Vec<u16> = reference.iter().map(|&v| v + 4).collect();
+        let quality = rgb16_quality(
+            &reference,
+            width * 3,
+            &distorted,
+            width * 3,
+            width,
+            height,
+            10,
+        )
+        .unwrap();
+        assert_eq!(quality.sse, 16 * (width * height * 3) as u64);
+        let expected = 10. * (1023f64 * 1023. / 16.).log10();
+        assert!((quality.psnr - expected).abs() < 1e-9);
+        assert!(quality.ssim < 1. && quality.ssim > 0.9);
+    }
+
+    #[test]
+    fn simd_window_sums_match_scalar() {
+        let (width, height) = (45usize, 29usize);
+        let a: Vec<f64> = (0..width * height).map(|i| (i * 37 % 251) as f64).collect();
+        let b: Vec<f64> = a
+            .iter()
+            .enumerate()
+            .map(|(i, &v)| v + (i % 7) as f64 - 3.)
+            .collect();
+        let (c1, c2) = (6.5025, 58.5225);
+        let simd = ssim_stats(make_ssim_block_row(), &a, &b, width, height, c1, c2);
+        let scalar = ssim_stats(ssim_block_row_none, &a, &b, width, height, c1, c2);
+        assert_eq!(simd, scalar);
+        assert!(simd.0 < 1. && simd.0 > 0.9, "SSIM {}", simd.0);
+    }
+
+    #[test]
+    fn psnr_stays_finite_with_identical_luma() {
+        let (width, height) = (16u32, 16u32);
+        let mut reference =
+            YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in reference.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (i * 7 % 220 + 16) as u8;
+        }
+        reference.u_plane.borrow_mut().fill(100);
+        reference.v_plane.borrow_mut().fill(150);
+        let mut distorted =
+            YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        distorted
+            .y_plane
+            .borrow_mut()
+            .copy_from_slice(reference.y_plane.borrow());
+        distorted.u_plane.borrow_mut().fill(102);
+        distorted.v_plane.borrow_mut().fill(150);
+        let quality = yuv_planar_quality(
+            &reference.to_fixed(),
+            &distorted.to_fixed(),
+            YuvChromaSubsampling::Yuv420,
+        )
+        .unwrap();
+        assert!(quality.planes[0].psnr.is_infinite());
+        // MSE of 4 in U weighted 1 of 8
+        let expected = 10. * (255f64 * 255. / 0.5).log10();
+        assert!((quality.psnr - expected).abs() < 1e-9, "{}", quality.psnr);
+    }
+}
@@ -26,13 +26,17 @@
  * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
-use crate::geometry::map_ft_result;
-use crate::YuvError;
+use crate::geometry::{exact_plane, exact_plane_mut, map_ft_result};
+use crate::{
+    YuvBiPlanarImage, YuvBiPlanarImageMut, YuvChromaSubsampling, YuvError, YuvPlanarImage,
+    YuvPlanarImageMut,
+};
 use fast_transpose::{
     flip_plane, flip_plane16, flip_plane16_with_alpha, flip_plane_with_alpha, flip_rgb, flip_rgb16,
     flip_rgba, flip_rgba16, flop_plane, flop_plane16, flop_plane16_with_alpha,
     flop_plane_with_alpha, flop_rgb, flop_rgb16, flop_rgba, flop_rgba16,
 };
+use std::fmt::Debug;
 
 /// Declares mirroring mode: vertical or horizontal
 #[derive(Copy, Clone, Debug, Ord, PartialOrd, Eq, PartialEq, Hash)]
@@ -304,3 +308,192 @@ pub fn mirror_plane16(
     };
     map_ft_result(rs)
 }
+
+type PlaneMirroring<T> =
+    fn(&[T], usize, &mut [T], usize, usize, usize, MirrorMode) -> Result<(), YuvError>;
+
+fn mirror_whole_plane<T>(
+    src: &[T],
+    src_stride: u32,
+    dst: &mut [T],
+    dst_stride: u32,
+    width: usize,
+    height: usize,
+    mode: MirrorMode,
+    mirror: PlaneMirroring<T>,
+) -> Result<(), YuvError> {
+    mirror(
+        exact_plane(src, src_stride, height),
+        src_stride as usize,
+        exact_plane_mut(dst, dst_stride, height),
+        dst_stride as usize,
+        width,
+        height,
+        mode,
+    )
+}
+
+fn mirror_yuv_planar_impl<T: Copy + Debug>(
+    src: &YuvPlanarImage<T>,
+    dst: &mut YuvPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    mode: MirrorMode,
+    mirror: PlaneMirroring<T>,
+) -> Result<(), YuvError> {
+    src.check_constraints(subsampling)?;
+    dst.check_constraints(subsampling)?;
+    if src.width != dst.width || src.height != dst.height {
+        return Err(YuvError::ImageDimensionsNotMatch);
+    }
+    let width = src.width as usize;
+    let height = src.height as usize;
+    let chroma_width = subsampling.chroma_width(width);
+    let chroma_height = subsampling.chroma_height(height);
+    mirror_whole_plane(
+        src.y_plane,
+        src.y_stride,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride,
+        width,
+        height,
+        mode,
+        mirror,
+    )?;
+    mirror_whole_plane(
+        src.u_plane,
+        src.u_stride,
+        dst.u_plane.borrow_mut(),
+        dst.u_stride,
+        chroma_width,
+        chroma_height,
+        mode,
+        mirror,
+    )?;
+    mirror_whole_plane(
+        src.v_plane,
+        src.v_stride,
+        dst.v_plane.borrow_mut(),
+        dst.v_stride,
+        chroma_width,
+        chroma_height,
+        mode,
+        mirror,
+    )
+}
+
+fn mirror_yuv_bi_planar_impl<T: Copy + Debug>(
+    src: &YuvBiPlanarImage<T>,
+    dst: &mut YuvBiPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    mode: MirrorMode,
+    mirror: PlaneMirroring<T>,
+    mirror_interleaved: PlaneMirroring<T>,
+) -> Result<(), YuvError> {
+    src.check_constraints(subsampling)?;
+    dst.check_constraints(subsampling)?;
+    if src.width != dst.width || src.height != dst.height {
+        return Err(YuvError::ImageDimensionsNotMatch);
+    }
+    let width = src.width as usize;
+    let height = src.height as usize;
+    mirror_whole_plane(
+        src.y_plane,
+        src.y_stride,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride,
+        width,
+        height,
+        mode,
+        mirror,
+    )?;
+    mirror_whole_plane(
+        src.uv_plane,
+        src.uv_stride,
+        dst.uv_plane.borrow_mut(),
+        dst.uv_stride,
+        subsampling.chroma_width(width),
+        subsampling.chroma_height(height),
+        mode,
+        mirror_interleaved,
+    )
+}
+
+/// Mirrors whole 8 bit planar YUV image.
+///
+/// # Arguments
+///
+/// * `src`: Source image
+/// * `dst`: Destination image of the same size
+/// * `subsampling`: Chroma subsampling of both images
+/// * `mode`: Refer to [MirrorMode] for mode info
+///
+/// returns: Result<(), [YuvError]>
+///
+pub fn mirror_yuv_planar(
+    src: &YuvPlanarImage<u8>,
+    dst: &mut YuvPlanarImageMut<u8>,
+    subsampling: YuvChromaSubsampling,
+    mode: MirrorMode,
+) -> Result<(), YuvError> {
+    mirror_yuv_planar_impl(src, dst, subsampling, mode, mirror_plane)
+}
+
+/// Mirrors whole 8+ bit planar YUV image.
+///
+/// # Arguments
+///
+/// * `src`: Source image
+/// * `dst`: Destination image of the same size
+/// * `subsampling`: Chroma subsampling of both images
+/// * `mode`: Refer to [MirrorMode] for mode info
+///
+/// returns: Result<(), [YuvError]>
+///
+pub fn mirror_yuv_planar16(
+    src: &YuvPlanarImage<u16>,
+    dst: &mut YuvPlanarImageMut<u16>,
+    subsampling: YuvChromaSubsampling,
+    mode: MirrorMode,
+) -> Result<(), YuvError> {
+    mirror_yuv_planar_impl(src, dst, subsampling, mode, mirror_plane16)
+}
+
+/// Mirrors whole 8 bit bi-planar YUV image.
+///
+/// # Arguments
+///
+/// * `src`: Source image
+/// * `dst`: Destination image of the same size
+/// * `subsampling`: Chroma subsampling of both images
+/// * `mode`: Refer to [MirrorMode] for mode info
+///
+/// returns: Result<(), [YuvError]>
+///
+pub fn mirror_yuv_biplanar(
+    src: &YuvBiPlanarImage<u8>,
+    dst: &mut YuvBiPlanarImageMut<u8>,
+    subsampling: YuvChromaSubsampling,
+    mode: MirrorMode,
+) -> Result<(), YuvError> {
+    mirror_yuv_bi_planar_impl(src, dst, subsampling, mode, mirror_plane, mirror_cbcr)
+}
+
+/// Mirrors whole 8+ bit bi-planar YUV image.
+///
+/// # Arguments
+///
+/// * `src`: Source image
+/// * `dst`: Destination image of the same size
+/// * `subsampling`: Chroma subsampling of both images
+/// * `mode`: Refer to [MirrorMode] for mode info
+///
+/// returns: Result<(), [YuvError]>
+///
+pub fn mirror_yuv_biplanar16(
+    src: &YuvBiPlanarImage<u16>,
+    dst: &mut YuvBiPlanarImageMut<u16>,
+    subsampling: YuvChromaSubsampling,
+    mode: MirrorMode,
+) -> Result<(), YuvError> {
+    mirror_yuv_bi_planar_impl(src, dst, subsampling, mode, mirror_plane16, mirror_cbcr16)
+}
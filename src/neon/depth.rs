@@ -0,0 +1,104 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Computes `min((src + threshold) >> r_shift, max) << l_shift`, returns processed count
+pub(crate) unsafe fn neon_depth_row_16_to_16(
+    src: &[u16],
+    dst: &mut [u16],
+    thresholds: &[u16],
+    r_shift: u32,
+    l_shift: u32,
+    max: u16,
+    width: usize,
+) -> usize {
+    let v_r_shift = vdupq_n_s16(-(r_shift as i16));
+    let v_l_shift = vdupq_n_s16(l_shift as i16);
+    let v_max = vdupq_n_u16(max);
+
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let v_src = vld1q_u16(src.get_unchecked(cx..).as_ptr());
+        let v_t = vld1q_u16(thresholds.get_unchecked(cx..).as_ptr());
+        let v = vshlq_u16(vqaddq_u16(v_src, v_t), v_r_shift);
+        let v = vshlq_u16(vminq_u16(v, v_max), v_l_shift);
+        vst1q_u16(dst.get_unchecked_mut(cx..).as_mut_ptr(), v);
+        cx += 8;
+    }
+    cx
+}
+
+/// Computes `min((src + threshold) >> r_shift, 255)`, returns processed count
+pub(crate) unsafe fn neon_depth_row_16_to_8(
+    src: &[u16],
+    dst: &mut [u8],
+    thresholds: &[u16],
+    r_shift: u32,
+    width: usize,
+) -> usize {
+    let v_r_shift = vdupq_n_s16(-(r_shift as i16));
+
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let s_ptr = src.get_unchecked(cx..).as_ptr();
+        let t_ptr = thresholds.get_unchecked(cx..).as_ptr();
+        let v0 = vshlq_u16(vqaddq_u16(vld1q_u16(s_ptr), vld1q_u16(t_ptr)), v_r_shift);
+        let v1 = vshlq_u16(
+            vqaddq_u16(vld1q_u16(s_ptr.add(8)), vld1q_u16(t_ptr.add(8))),
+            v_r_shift,
+        );
+        vst1q_u8(
+            dst.get_unchecked_mut(cx..).as_mut_ptr(),
+            vcombine_u8(vqmovn_u16(v0), vqmovn_u16(v1)),
+        );
+        cx += 16;
+    }
+    cx
+}
+
+/// Computes `src << l_shift`, returns processed count
+pub(crate) unsafe fn neon_depth_row_8_to_16(
+    src: &[u8],
+    dst: &mut [u16],
+    l_shift: u32,
+    width: usize,
+) -> usize {
+    let v_l_shift = vdupq_n_s16(l_shift as i16);
+
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let v_src = vmovl_u8(vld1_u8(src.get_unchecked(cx..).as_ptr()));
+        vst1q_u16(
+            dst.get_unchecked_mut(cx..).as_mut_ptr(),
+            vshlq_u16(v_src, v_l_shift),
+        );
+        cx += 8;
+    }
+    cx
+}
@@ -0,0 +1,119 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Splits interleaved `width` chroma pairs into two planes, returns processed count
+pub(crate) unsafe fn neon_deinterleave_cbcr_u8(
+    uv: &[u8],
+    u: &mut [u8],
+    v: &mut [u8],
+    width: usize,
+) -> usize {
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let v_src = vld2q_u8(uv.get_unchecked(cx * 2..).as_ptr());
+        vst1q_u8(u.get_unchecked_mut(cx..).as_mut_ptr(), v_src.0);
+        vst1q_u8(v.get_unchecked_mut(cx..).as_mut_ptr(), v_src.1);
+        cx += 16;
+    }
+    cx
+}
+
+/// Merges two chroma planes into `width` interleaved pairs, returns processed count
+pub(crate) unsafe fn neon_interleave_cbcr_u8(
+    u: &[u8],
+    v: &[u8],
+    uv: &mut [u8],
+    width: usize,
+) -> usize {
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let v_u = vld1q_u8(u.get_unchecked(cx..).as_ptr());
+        let v_v = vld1q_u8(v.get_unchecked(cx..).as_ptr());
+        vst2q_u8(
+            uv.get_unchecked_mut(cx * 2..).as_mut_ptr(),
+            uint8x16x2_t(v_u, v_v),
+        );
+        cx += 16;
+    }
+    cx
+}
+
+/// Splits interleaved `width` chroma pairs into two planes as `(src >> r_shift) << l_shift`,
+/// returns processed count
+pub(crate) unsafe fn neon_deinterleave_cbcr_u16(
+    uv: &[u16],
+    u: &mut [u16],
+    v: &mut [u16],
+    r_shift: u32,
+    l_shift: u32,
+    width: usize,
+) -> usize {
+    let v_r_shift = vdupq_n_s16(-(r_shift as i16));
+    let v_l_shift = vdupq_n_s16(l_shift as i16);
+
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let v_src = vld2q_u16(uv.get_unchecked(cx * 2..).as_ptr());
+        let v_u = vshlq_u16(vshlq_u16(v_src.0, v_r_shift), v_l_shift);
+        let v_v = vshlq_u16(vshlq_u16(v_src.1, v_r_shift), v_l_shift);
+        vst1q_u16(u.get_unchecked_mut(cx..).as_mut_ptr(), v_u);
+        vst1q_u16(v.get_unchecked_mut(cx..).as_mut_ptr(), v_v);
+        cx += 8;
+    }
+    cx
+}
+
+/// Merges two chroma planes into `width` interleaved pairs as `(src >> r_shift) << l_shift`,
+/// returns processed count
+pub(crate) unsafe fn neon_interleave_cbcr_u16(
+    u: &[u16],
+    v: &[u16],
+    uv: &mut [u16],
+    r_shift: u32,
+    l_shift: u32,
+    width: usize,
+) -> usize {
+    let v_r_shift = vdupq_n_s16(-(r_shift as i16));
+    let v_l_shift = vdupq_n_s16(l_shift as i16);
+
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let v_u = vld1q_u16(u.get_unchecked(cx..).as_ptr());
+        let v_v = vld1q_u16(v.get_unchecked(cx..).as_ptr());
+        let v_u = vshlq_u16(vshlq_u16(v_u, v_r_shift), v_l_shift);
+        let v_v = vshlq_u16(vshlq_u16(v_v, v_r_shift), v_l_shift);
+        vst2q_u16(
+            uv.get_unchecked_mut(cx * 2..).as_mut_ptr(),
+            uint16x8x2_t(v_u, v_v),
+        );
+        cx += 8;
+    }
+    cx
+}
@@ -0,0 +1,81 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::lut::{tetrahedral_nodes, trilinear_cell};
+use std::arch::aarch64::*;
+
+/// Tetrahedral interpolation of `count` pixels holding 3D LUT grid coordinates
+/// in 4 lanes each, returns processed count
+pub(crate) unsafe fn neon_lut3d_tetrahedral_row(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    let t_ptr = table.as_ptr();
+    for px in row.chunks_exact_mut(4).take(count) {
+        let (nodes, weights) = tetrahedral_nodes(size, px);
+        let mut acc = vmulq_n_f32(vld1q_f32(t_ptr.add(nodes[0])), weights[0]);
+        acc = vfmaq_n_f32(acc, vld1q_f32(t_ptr.add(nodes[1])), weights[1]);
+        acc = vfmaq_n_f32(acc, vld1q_f32(t_ptr.add(nodes[2])), weights[2]);
+        acc = vfmaq_n_f32(acc, vld1q_f32(t_ptr.add(nodes[3])), weights[3]);
+        vst1q_f32(px.as_mut_ptr(), acc);
+    }
+    count
+}
+
+#[inline(always)]
+unsafe fn lerp_f32(a: float32x4_t, b: float32x4_t, t: f32) -> float32x4_t {
+    vfmaq_n_f32(a, vsubq_f32(b, a), t)
+}
+
+/// Trilinear interpolation of `count` pixels holding 3D LUT grid coordinates
+/// in 4 lanes each, returns processed count
+pub(crate) unsafe fn neon_lut3d_trilinear_row(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    for px in row.chunks_exact_mut(4).take(count) {
+        let (base, [dr, dg, db], [fr, fg, fb]) = trilinear_cell(size, px);
+        let t_ptr = table.as_ptr().add(base);
+        let c00 = lerp_f32(vld1q_f32(t_ptr), vld1q_f32(t_ptr.add(dr)), fr);
+        let c10 = lerp_f32(vld1q_f32(t_ptr.add(dg)), vld1q_f32(t_ptr.add(dg + dr)), fr);
+        let c01 = lerp_f32(vld1q_f32(t_ptr.add(db)), vld1q_f32(t_ptr.add(db + dr)), fr);
+        let c11 = lerp_f32(
+            vld1q_f32(t_ptr.add(db + dg)),
+            vld1q_f32(t_ptr.add(db + dg + dr)),
+            fr,
+        );
+        let c0 = lerp_f32(c00, c10, fg);
+        let c1 = lerp_f32(c01, c11, fg);
+        vst1q_f32(px.as_mut_ptr(), lerp_f32(c0, c1, fb));
+    }
+    count
+}
@@ -29,11 +29,17 @@
 #![deny(unreachable_code, unreachable_pub)]
 mod ar30_utils;
 mod ayuv_to_rgba;
+mod depth;
 #[cfg(feature = "nightly_f16")]
 mod f16_converter;
 #[cfg(feature = "nightly_f16")]
 mod f16_utils;
 mod gbr_to_rgb;
+mod interleave_cbcr;
+mod lut;
+mod masked_equals;
+mod plane_stats;
+mod premultiply;
 #[cfg(feature = "professional_mode")]
 mod rgb_to_nv420_prof;
 #[cfg(feature = "professional_mode")]
@@ -65,7 +71,11 @@ mod rgbx_to_nv_fast420;
 mod rgbx_to_yuv_fast;
 #[cfg(feature = "fast_mode")]
 mod rgbx_to_yuv_fast420;
+mod scale_horizontal;
+mod scale_vertical;
 mod shuffle;
+mod squared_error;
+mod ssim;
 mod utils;
 mod y_p16_to_rgba16;
 mod y_to_rgb;
@@ -104,6 +114,7 @@ mod yuy2_to_yuv;
 pub(crate) use ayuv_to_rgba::neon_ayuv_to_rgba;
 #[cfg(feature = "rdm")]
 pub(crate) use ayuv_to_rgba::neon_ayuv_to_rgba_rdm;
+pub(crate) use depth::{neon_depth_row_16_to_16, neon_depth_row_16_to_8, neon_depth_row_8_to_16};
 #[cfg(feature = "nightly_f16")]
 pub(crate) use f16_converter::{
     SurfaceF16ToUnsigned16Neon, SurfaceF16ToUnsigned16NeonFallback, SurfaceF16ToUnsigned8Neon,
@@ -113,6 +124,17 @@ pub(crate) use f16_converter::{
 #[cfg(feature = "rdm")]
 pub(crate) use gbr_to_rgb::yuv_to_rgba_row_limited_rdm;
 pub(crate) use gbr_to_rgb::{yuv_to_rgba_row_full, yuv_to_rgba_row_limited};
+pub(crate) use interleave_cbcr::{
+    neon_deinterleave_cbcr_u16, neon_deinterleave_cbcr_u8, neon_interleave_cbcr_u16,
+    neon_interleave_cbcr_u8,
+};
+pub(crate) use lut::{neon_lut3d_tetrahedral_row, neon_lut3d_trilinear_row};
+pub(crate) use masked_equals::neon_masked_equals_row;
+pub(crate) use plane_stats::neon_plane_stats_row;
+pub(crate) use premultiply::{
+    neon_premultiply_row_u16, neon_premultiply_row_u8, neon_unpremultiply_row_u16,
+    neon_unpremultiply_row_u8,
+};
 #[cfg(feature = "professional_mode")]
 pub(crate) use rgb_to_nv420_prof::neon_rgba_to_nv_prof420;
 #[cfg(feature = "professional_mode")]
@@ -158,7 +180,11 @@ pub(crate) use rgbx_to_nv_fast420::neon_rgbx_to_nv_fast420;
 pub(crate) use rgbx_to_yuv_fast::neon_rgbx_to_yuv_fast;
 #[cfg(feature = "fast_mode")]
 pub(crate) use rgbx_to_yuv_fast420::neon_rgbx_to_yuv_fast420;
+pub(crate) use scale_horizontal::{neon_scale_horizontal_row_u16, neon_scale_horizontal_row_u8};
+pub(crate) use scale_vertical::{neon_scale_vertical_row_u16, neon_scale_vertical_row_u8};
 pub(crate) use shuffle::ShuffleConverterNeon;
+pub(crate) use squared_error::neon_squared_error_row;
+pub(crate) use ssim::neon_ssim_block_row;
 pub(crate) use y_p16_to_rgba16::neon_y_p16_to_rgba16_row;
 pub(crate) use y_to_rgb::neon_y_to_rgb_row;
 #[cfg(feature = "rdm")]
@@ -0,0 +1,49 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Finds min, max and sum of the row, returns them with processed count
+pub(crate) unsafe fn neon_plane_stats_row(src: &[u16]) -> (u16, u16, u64, usize) {
+    let mut v_min = vdupq_n_u16(u16::MAX);
+    let mut v_max = vdupq_n_u16(0);
+    let mut v_sum = vdupq_n_u64(0);
+
+    let mut cx = 0usize;
+    while cx + 8 <= src.len() {
+        let v_src = vld1q_u16(src.get_unchecked(cx..).as_ptr());
+        v_min = vminq_u16(v_min, v_src);
+        v_max = vmaxq_u16(v_max, v_src);
+        v_sum = vpadalq_u32(v_sum, vpaddlq_u16(v_src));
+        cx += 8;
+    }
+    if cx == 0 {
+        return (u16::MAX, 0, 0, 0);
+    }
+    (vminvq_u16(v_min), vmaxvq_u16(v_max), vaddvq_u64(v_sum), cx)
+}
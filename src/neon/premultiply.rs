@@ -0,0 +1,190 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Rounding `c * a / 255` for 16 lanes
+#[inline(always)]
+unsafe fn premultiply_u8x16(c: uint8x16_t, a: uint8x16_t) -> uint8x16_t {
+    let rounding = vdupq_n_u16(0x80);
+    let lo = vaddq_u16(vmull_u8(vget_low_u8(c), vget_low_u8(a)), rounding);
+    let hi = vaddq_u16(vmull_high_u8(c, a), rounding);
+    vcombine_u8(
+        vshrn_n_u16::<8>(vsraq_n_u16::<8>(lo, lo)),
+        vshrn_n_u16::<8>(vsraq_n_u16::<8>(hi, hi)),
+    )
+}
+
+/// `c * max / a` rounded, 0 where alpha is 0
+#[inline(always)]
+unsafe fn unpremultiply_f32(c: uint32x4_t, a: uint32x4_t, v_max: float32x4_t) -> uint32x4_t {
+    let a = vcvtq_f32_u32(a);
+    let q = vaddq_f32(
+        vdivq_f32(vmulq_f32(vcvtq_f32_u32(c), v_max), a),
+        vdupq_n_f32(0.5f32),
+    );
+    vbicq_u32(vcvtq_u32_f32(vminq_f32(q, v_max)), vceqzq_f32(a))
+}
+
+/// `c * a / max` rounded
+#[inline(always)]
+unsafe fn premultiply_f32(c: uint32x4_t, a: uint32x4_t, v_max: float32x4_t) -> uint32x4_t {
+    let q = vaddq_f32(
+        vdivq_f32(vmulq_f32(vcvtq_f32_u32(c), vcvtq_f32_u32(a)), v_max),
+        vdupq_n_f32(0.5f32),
+    );
+    vcvtq_u32_f32(vminq_f32(q, v_max))
+}
+
+#[inline(always)]
+unsafe fn unpremultiply_u16x8(c: uint16x8_t, a: uint16x8_t, v_max: float32x4_t) -> uint16x8_t {
+    let lo = unpremultiply_f32(
+        vmovl_u16(vget_low_u16(c)),
+        vmovl_u16(vget_low_u16(a)),
+        v_max,
+    );
+    let hi = unpremultiply_f32(vmovl_high_u16(c), vmovl_high_u16(a), v_max);
+    vcombine_u16(vmovn_u32(lo), vmovn_u32(hi))
+}
+
+#[inline(always)]
+unsafe fn unpremultiply_u8x8(c: uint8x8_t, a: uint8x8_t, v_max: float32x4_t) -> uint8x8_t {
+    vqmovn_u16(unpremultiply_u16x8(vmovl_u8(c), vmovl_u8(a), v_max))
+}
+
+#[inline(always)]
+unsafe fn premultiply_u16x8(c: uint16x8_t, a: uint16x8_t, v_max: float32x4_t) -> uint16x8_t {
+    let lo = premultiply_f32(
+        vmovl_u16(vget_low_u16(c)),
+        vmovl_u16(vget_low_u16(a)),
+        v_max,
+    );
+    let hi = premultiply_f32(vmovl_high_u16(c), vmovl_high_u16(a), v_max);
+    vcombine_u16(vmovn_u32(lo), vmovn_u32(hi))
+}
+
+/// Premultiplies `width` 4 channel pixels with alpha at `A_POS`, returns processed count
+pub(crate) unsafe fn neon_premultiply_row_u8<const A_POS: usize>(
+    row: &mut [u8],
+    width: usize,
+) -> usize {
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let ptr = row.get_unchecked_mut(cx * 4..).as_mut_ptr();
+        let mut v = vld4q_u8(ptr);
+        if A_POS == 0 {
+            v.1 = premultiply_u8x16(v.1, v.0);
+            v.2 = premultiply_u8x16(v.2, v.0);
+            v.3 = premultiply_u8x16(v.3, v.0);
+        } else {
+            v.0 = premultiply_u8x16(v.0, v.3);
+            v.1 = premultiply_u8x16(v.1, v.3);
+            v.2 = premultiply_u8x16(v.2, v.3);
+        }
+        vst4q_u8(ptr, v);
+        cx += 16;
+    }
+    cx
+}
+
+/// Unpremultiplies `width` 4 channel pixels with alpha at `A_POS`, returns processed count
+pub(crate) unsafe fn neon_unpremultiply_row_u8<const A_POS: usize>(
+    row: &mut [u8],
+    width: usize,
+) -> usize {
+    let v_max = vdupq_n_f32(255f32);
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let ptr = row.get_unchecked_mut(cx * 4..).as_mut_ptr();
+        let mut v = vld4_u8(ptr);
+        if A_POS == 0 {
+            v.1 = unpremultiply_u8x8(v.1, v.0, v_max);
+            v.2 = unpremultiply_u8x8(v.2, v.0, v_max);
+            v.3 = unpremultiply_u8x8(v.3, v.0, v_max);
+        } else {
+            v.0 = unpremultiply_u8x8(v.0, v.3, v_max);
+            v.1 = unpremultiply_u8x8(v.1, v.3, v_max);
+            v.2 = unpremultiply_u8x8(v.2, v.3, v_max);
+        }
+        vst4_u8(ptr, v);
+        cx += 8;
+    }
+    cx
+}
+
+/// Premultiplies `width` 4 channel pixels with alpha at `A_POS`, returns processed count
+pub(crate) unsafe fn neon_premultiply_row_u16<const A_POS: usize>(
+    row: &mut [u16],
+    width: usize,
+    max: f32,
+) -> usize {
+    let v_max = vdupq_n_f32(max);
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let ptr = row.get_unchecked_mut(cx * 4..).as_mut_ptr();
+        let mut v = vld4q_u16(ptr);
+        if A_POS == 0 {
+            v.1 = premultiply_u16x8(v.1, v.0, v_max);
+            v.2 = premultiply_u16x8(v.2, v.0, v_max);
+            v.3 = premultiply_u16x8(v.3, v.0, v_max);
+        } else {
+            v.0 = premultiply_u16x8(v.0, v.3, v_max);
+            v.1 = premultiply_u16x8(v.1, v.3, v_max);
+            v.2 = premultiply_u16x8(v.2, v.3, v_max);
+        }
+        vst4q_u16(ptr, v);
+        cx += 8;
+    }
+    cx
+}
+
+/// Unpremultiplies `width` 4 channel pixels with alpha at `A_POS`, returns processed count
+pub(crate) unsafe fn neon_unpremultiply_row_u16<const A_POS: usize>(
+    row: &mut [u16],
+    width: usize,
+    max: f32,
+) -> usize {
+    let v_max = vdupq_n_f32(max);
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let ptr = row.get_unchecked_mut(cx * 4..).as_mut_ptr();
+        let mut v = vld4q_u16(ptr);
+        if A_POS == 0 {
+            v.1 = unpremultiply_u16x8(v.1, v.0, v_max);
+            v.2 = unpremultiply_u16x8(v.2, v.0, v_max);
+            v.3 = unpremultiply_u16x8(v.3, v.0, v_max);
+        } else {
+            v.0 = unpremultiply_u16x8(v.0, v.3, v_max);
+            v.1 = unpremultiply_u16x8(v.1, v.3, v_max);
+            v.2 = unpremultiply_u16x8(v.2, v.3, v_max);
+        }
+        vst4q_u16(ptr, v);
+        cx += 8;
+    }
+    cx
+}
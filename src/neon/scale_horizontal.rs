@@ -0,0 +1,120 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Accumulates 4 consecutive destination components starting at `n`,
+/// each lane gathers its own pixel taps
+#[inline(always)]
+unsafe fn neon_horizontal_lanes<T: Copy + Into<i32>>(
+    src: &[T],
+    n: usize,
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> uint32x4_t {
+    let mut src_idx = [0usize; 4];
+    let mut w_idx = [0usize; 4];
+    for (l, (src_idx, w_idx)) in src_idx.iter_mut().zip(w_idx.iter_mut()).enumerate() {
+        let px = (n + l) / channels;
+        *src_idx = *bounds.get_unchecked(px) * channels + (n + l) % channels;
+        *w_idx = px * taps;
+    }
+    let mut acc = vdupq_n_s32(0);
+    for k in 0..taps {
+        let offset = k * channels;
+        let v_src: [i32; 4] = [
+            (*src.get_unchecked(src_idx[0] + offset)).into(),
+            (*src.get_unchecked(src_idx[1] + offset)).into(),
+            (*src.get_unchecked(src_idx[2] + offset)).into(),
+            (*src.get_unchecked(src_idx[3] + offset)).into(),
+        ];
+        let v_weight: [i32; 4] = [
+            *weights.get_unchecked(w_idx[0] + k),
+            *weights.get_unchecked(w_idx[1] + k),
+            *weights.get_unchecked(w_idx[2] + k),
+            *weights.get_unchecked(w_idx[3] + k),
+        ];
+        acc = vmlaq_s32(acc, vld1q_s32(v_src.as_ptr()), vld1q_s32(v_weight.as_ptr()));
+    }
+    vminq_u32(
+        vmovl_u16(vqrshrun_n_s32::<14>(acc)),
+        vdupq_n_u32(max as u32),
+    )
+}
+
+/// Filters row with interleaved `channels`, destination pixel `i` reads `taps` pixels
+/// from `bounds[i]` with weights in 14 bits fixed point, returns processed components count
+pub(crate) unsafe fn neon_scale_horizontal_row_u8(
+    src: &[u8],
+    dst: &mut [u8],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    let length = dst.len();
+
+    let mut n = 0usize;
+    while n + 4 <= length {
+        let v = neon_horizontal_lanes(src, n, channels, bounds, weights, taps, max);
+        let v = vqmovn_u16(vcombine_u16(vmovn_u32(v), vdup_n_u16(0)));
+        vst1_lane_u32::<0>(
+            dst.get_unchecked_mut(n..).as_mut_ptr() as *mut u32,
+            vreinterpret_u32_u8(v),
+        );
+        n += 4;
+    }
+    n
+}
+
+/// Filters row with interleaved `channels`, destination pixel `i` reads `taps` pixels
+/// from `bounds[i]` with weights in 14 bits fixed point clamped to `max`,
+/// returns processed components count
+pub(crate) unsafe fn neon_scale_horizontal_row_u16(
+    src: &[u16],
+    dst: &mut [u16],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    let length = dst.len();
+
+    let mut n = 0usize;
+    while n + 4 <= length {
+        let v = neon_horizontal_lanes(src, n, channels, bounds, weights, taps, max);
+        vst1_u16(dst.get_unchecked_mut(n..).as_mut_ptr(), vmovn_u32(v));
+        n += 4;
+    }
+    n
+}
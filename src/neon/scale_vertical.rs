@@ -0,0 +1,101 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Weighted sum of `weights.len()` source rows spaced by `stride` with weights in 14 bits fixed point,
+/// returns processed count
+pub(crate) unsafe fn neon_scale_vertical_row_u8(
+    src: &[u8],
+    stride: usize,
+    weights: &[i32],
+    dst: &mut [u8],
+    _: i32,
+) -> usize {
+    let width = dst.len();
+
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let mut acc0 = vdupq_n_s32(0);
+        let mut acc1 = vdupq_n_s32(0);
+        let mut acc2 = vdupq_n_s32(0);
+        let mut acc3 = vdupq_n_s32(0);
+        for (k, &weight) in weights.iter().enumerate() {
+            let row = src.get_unchecked(k * stride..);
+            let v = vld1q_u8(row.get_unchecked(cx..).as_ptr());
+            let lo = vreinterpretq_s16_u16(vmovl_u8(vget_low_u8(v)));
+            let hi = vreinterpretq_s16_u16(vmovl_high_u8(v));
+            acc0 = vmlaq_n_s32(acc0, vmovl_s16(vget_low_s16(lo)), weight);
+            acc1 = vmlaq_n_s32(acc1, vmovl_high_s16(lo), weight);
+            acc2 = vmlaq_n_s32(acc2, vmovl_s16(vget_low_s16(hi)), weight);
+            acc3 = vmlaq_n_s32(acc3, vmovl_high_s16(hi), weight);
+        }
+        let lo = vcombine_u16(vqrshrun_n_s32::<14>(acc0), vqrshrun_n_s32::<14>(acc1));
+        let hi = vcombine_u16(vqrshrun_n_s32::<14>(acc2), vqrshrun_n_s32::<14>(acc3));
+        vst1q_u8(
+            dst.get_unchecked_mut(cx..).as_mut_ptr(),
+            vcombine_u8(vqmovn_u16(lo), vqmovn_u16(hi)),
+        );
+        cx += 16;
+    }
+    cx
+}
+
+/// Weighted sum of `weights.len()` source rows spaced by `stride` with weights in 14 bits fixed point clamped to `max`,
+/// returns processed count
+pub(crate) unsafe fn neon_scale_vertical_row_u16(
+    src: &[u16],
+    stride: usize,
+    weights: &[i32],
+    dst: &mut [u16],
+    max: i32,
+) -> usize {
+    let width = dst.len();
+    let v_max = vdupq_n_u16(max as u16);
+
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let mut acc0 = vdupq_n_s32(0);
+        let mut acc1 = vdupq_n_s32(0);
+        for (k, &weight) in weights.iter().enumerate() {
+            let row = src.get_unchecked(k * stride..);
+            let v = vld1q_u16(row.get_unchecked(cx..).as_ptr());
+            let lo = vreinterpretq_s32_u32(vmovl_u16(vget_low_u16(v)));
+            let hi = vreinterpretq_s32_u32(vmovl_high_u16(v));
+            acc0 = vmlaq_n_s32(acc0, lo, weight);
+            acc1 = vmlaq_n_s32(acc1, hi, weight);
+        }
+        let v = vcombine_u16(vqrshrun_n_s32::<14>(acc0), vqrshrun_n_s32::<14>(acc1));
+        vst1q_u16(
+            dst.get_unchecked_mut(cx..).as_mut_ptr(),
+            vminq_u16(v, v_max),
+        );
+        cx += 16 / 2;
+    }
+    cx
+}
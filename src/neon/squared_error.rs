@@ -0,0 +1,48 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Sums `(a - b)^2` over the row, returns sum and processed count
+pub(crate) unsafe fn neon_squared_error_row(a: &[u16], b: &[u16]) -> (u64, usize) {
+    let width = a.len().min(b.len());
+    let mut v_acc = vdupq_n_u64(0);
+
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let v_a = vld1q_u16(a.get_unchecked(cx..).as_ptr());
+        let v_b = vld1q_u16(b.get_unchecked(cx..).as_ptr());
+        let diff = vabdq_u16(v_a, v_b);
+        let sq_lo = vmull_u16(vget_low_u16(diff), vget_low_u16(diff));
+        let sq_hi = vmull_high_u16(diff, diff);
+        v_acc = vpadalq_u32(v_acc, sq_lo);
+        v_acc = vpadalq_u32(v_acc, sq_hi);
+        cx += 8;
+    }
+    (vaddvq_u64(v_acc), cx)
+}
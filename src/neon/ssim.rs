@@ -0,0 +1,60 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use std::arch::aarch64::*;
+
+/// Adds `[a, b, a*a, b*b, a*b]` of every 4 samples of the row lane-wise into
+/// 20 sums of corresponding block, returns count of processed blocks
+pub(crate) unsafe fn neon_ssim_block_row(a: &[f64], b: &[f64], sums: &mut [f64]) -> usize {
+    let blocks = (a.len().min(b.len()) / 4).min(sums.len() / 20);
+    for ((a, b), sums) in a
+        .chunks_exact(4)
+        .zip(b.chunks_exact(4))
+        .zip(sums.chunks_exact_mut(20))
+        .take(blocks)
+    {
+        for half in 0..2 {
+            let v_a = vld1q_f64(a.get_unchecked(half * 2..).as_ptr());
+            let v_b = vld1q_f64(b.get_unchecked(half * 2..).as_ptr());
+            for (q, value) in [
+                v_a,
+                v_b,
+                vmulq_f64(v_a, v_a),
+                vmulq_f64(v_b, v_b),
+                vmulq_f64(v_a, v_b),
+            ]
+            .into_iter()
+            .enumerate()
+            {
+                let dst = sums.get_unchecked_mut(q * 4 + half * 2..).as_mut_ptr();
+                vst1q_f64(dst, vaddq_f64(vld1q_f64(dst), value));
+            }
+        }
+    }
+    blocks
+}
@@ -60,6 +60,22 @@ pub(crate) fn to_ne<const ENDIANNESS: u8, const BYTES_POSITION: u8>(v: u16, msb:
     }
 }
 
+#[inline(always)]
+/// Converts native value to MSB, if needed, and to target endianness
+pub(crate) fn from_ne<const ENDIANNESS: u8, const BYTES_POSITION: u8>(v: u16, msb: i32) -> u16 {
+    let endianness: YuvEndianness = ENDIANNESS.into();
+    let bytes_position: YuvBytesPacking = BYTES_POSITION.into();
+    let packed = match bytes_position {
+        YuvBytesPacking::MostSignificantBytes => v << msb,
+        YuvBytesPacking::LeastSignificantBytes => v,
+    };
+    match endianness {
+        #[cfg(feature = "big_endian")]
+        YuvEndianness::BigEndian => packed.to_be(),
+        YuvEndianness::LittleEndian => packed.to_le(),
+    }
+}
+
 #[inline(always)]
 /// Saturating rounding shift right against bit depth
 pub(crate) fn qrshr_n<const PRECISION: i32>(val: i32, max: i32) -> i32 {
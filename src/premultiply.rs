@@ -0,0 +1,1214 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::numerics::div_by_255;
+use crate::yuv_error::{check_bit_depth, check_rgba_destination};
+use crate::yuv_support::YuvChromaSubsampling;
+use crate::{
+    bgra_to_yuv420, bgra_to_yuv422, bgra_to_yuv444, bgra_to_yuv_nv12, bgra_to_yuv_nv16,
+    bgra_to_yuv_nv21, bgra_to_yuv_nv24, bgra_to_yuv_nv42, bgra_to_yuv_nv61, rgba10_to_i010,
+    rgba10_to_i210, rgba10_to_i410, rgba12_to_i012, rgba12_to_i212, rgba12_to_i412, rgba14_to_i014,
+    rgba14_to_i214, rgba14_to_i414, rgba16_to_i016, rgba16_to_i216, rgba16_to_i416, rgba_to_yuv420,
+    rgba_to_yuv422, rgba_to_yuv444, rgba_to_yuv_nv12, rgba_to_yuv_nv16, rgba_to_yuv_nv21,
+    rgba_to_yuv_nv24, rgba_to_yuv_nv42, rgba_to_yuv_nv61, BufferStoreMut, YuvBiPlanarImageMut,
+    YuvConversionMode, YuvError, YuvPlanarImageMut, YuvRange, YuvStandardMatrix,
+};
+#[cfg(feature = "nightly_f16")]
+use core::f16;
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+use std::fmt::Debug;
+
+/// Rows unpremultiplied at once by encoders before they're handed to conversion
+const STRIP_HEIGHT: usize = 16;
+
+type AlphaRow8 = unsafe fn(&mut [u8], usize) -> usize;
+type AlphaRow16 = unsafe fn(&mut [u16], usize, f32) -> usize;
+
+/// Runtime selected SIMD row kernels for 4 channel pixels with alpha at `A_POS`,
+/// each returns count of processed pixels
+#[derive(Copy, Clone)]
+struct AlphaRowHandler<const A_POS: usize> {
+    premultiply_u8: AlphaRow8,
+    unpremultiply_u8: AlphaRow8,
+    premultiply_u16: AlphaRow16,
+    unpremultiply_u16: AlphaRow16,
+}
+
+fn alpha_row_u8_none(_: &mut [u8], _: usize) -> usize {
+    0
+}
+
+fn alpha_row_u16_none(_: &mut [u16], _: usize, _: f32) -> usize {
+    0
+}
+
+impl<const A_POS: usize> Default for AlphaRowHandler<A_POS> {
+    fn default() -> Self {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            use crate::neon::{
+                neon_premultiply_row_u16, neon_premultiply_row_u8, neon_unpremultiply_row_u16,
+                neon_unpremultiply_row_u8,
+            };
+            return AlphaRowHandler {
+                premultiply_u8: neon_premultiply_row_u8::<A_POS>,
+                unpremultiply_u8: neon_unpremultiply_row_u8::<A_POS>,
+                premultiply_u16: neon_premultiply_row_u16::<A_POS>,
+                unpremultiply_u16: neon_unpremultiply_row_u16::<A_POS>,
+            };
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                use crate::avx2::{
+                    avx2_premultiply_row_u16, avx2_premultiply_row_u8, avx2_unpremultiply_row_u16,
+                    avx2_unpremultiply_row_u8,
+                };
+                return AlphaRowHandler {
+                    premultiply_u8: avx2_premultiply_row_u8::<A_POS>,
+                    unpremultiply_u8: avx2_unpremultiply_row_u8::<A_POS>,
+                    premultiply_u16: avx2_premultiply_row_u16::<A_POS>,
+                    unpremultiply_u16: avx2_unpremultiply_row_u16::<A_POS>,
+                };
+            }
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.1") {
+                use crate::sse::{
+                    sse_premultiply_row_u16, sse_premultiply_row_u8, sse_unpremultiply_row_u16,
+                    sse_unpremultiply_row_u8,
+                };
+                return AlphaRowHandler {
+                    premultiply_u8: sse_premultiply_row_u8::<A_POS>,
+                    unpremultiply_u8: sse_unpremultiply_row_u8::<A_POS>,
+                    premultiply_u16: sse_premultiply_row_u16::<A_POS>,
+                    unpremultiply_u16: sse_unpremultiply_row_u16::<A_POS>,
+                };
+            }
+        }
+        #[allow(unreachable_code)]
+        AlphaRowHandler {
+            premultiply_u8: alpha_row_u8_none,
+            unpremultiply_u8: alpha_row_u8_none,
+            premultiply_u16: alpha_row_u16_none,
+            unpremultiply_u16: alpha_row_u16_none,
+        }
+    }
+}
+
+/// Positions of colour components in pixel with alpha at `a_pos`
+const fn colour_positions(a_pos: usize) -> [usize; 3] {
+    if a_pos == 0 {
+        [1, 2, 3]
+    } else {
+        [0, 1, 2]
+    }
+}
+
+/// Sample which can be premultiplied by SIMD row handler or scalar fallback
+trait AlphaSample: Copy + Debug + Default + Send + Sync {
+    fn premultiply_row<const A_POS: usize>(
+        handler: &AlphaRowHandler<A_POS>,
+        row: &mut [Self],
+        max: f32,
+    );
+
+    fn unpremultiply_row<const A_POS: usize>(
+        handler: &AlphaRowHandler<A_POS>,
+        row: &mut [Self],
+        max: f32,
+    );
+}
+
+/// `c * a / max` rounded, same arithmetic as SIMD kernels
+#[inline(always)]
+fn premultiply_f32(c: f32, a: f32, max: f32) -> f32 {
+    ((c * a / max) + 0.5f32).min(max)
+}
+
+/// `c * max / a` rounded, same arithmetic as SIMD kernels
+#[inline(always)]
+fn unpremultiply_f32(c: f32, a: f32, max: f32) -> f32 {
+    if a == 0f32 {
+        return 0f32;
+    }
+    ((c * max / a) + 0.5f32).min(max)
+}
+
+impl AlphaSample for u8 {
+    fn premultiply_row<const A_POS: usize>(
+        handler: &AlphaRowHandler<A_POS>,
+        row: &mut [u8],
+        _: f32,
+    ) {
+        let cx = unsafe { (handler.premultiply_u8)(row, row.len() / 4) };
+        for px in row.chunks_exact_mut(4).skip(cx) {
+            let a = px[A_POS] as u16;
+            for c in colour_positions(A_POS) {
+                px[c] = div_by_255(px[c] as u16 * a);
+            }
+        }
+    }
+
+    fn unpremultiply_row<const A_POS: usize>(
+        handler: &AlphaRowHandler<A_POS>,
+        row: &mut [u8],
+        _: f32,
+    ) {
+        let cx = unsafe { (handler.unpremultiply_u8)(row, row.len() / 4) };
+        for px in row.chunks_exact_mut(4).skip(cx) {
+            let a = px[A_POS] as f32;
+            for c in colour_positions(A_POS) {
+                px[c] = unpremultiply_f32(px[c] as f32, a, 255f32) as u8;
+            }
+        }
+    }
+}
+
+impl AlphaSample for u16 {
+    fn premultiply_row<const A_POS: usize>(
+        handler: &AlphaRowHandler<A_POS>,
+        row: &mut [u16],
+        max: f32,
+    ) {
+        let cx = unsafe { (handler.premultiply_u16)(row, row.len() / 4, max) };
+        for px in row.chunks_exact_mut(4).skip(cx) {
+            let a = px[A_POS] as f32;
+            for c in colour_positions(A_POS) {
+                px[c] = premultiply_f32(px[c] as f32, a, max) as u16;
+            }
+        }
+    }
+
+    fn unpremultiply_row<const A_POS: usize>(
+        handler: &AlphaRowHandler<A_POS>,
+        row: &mut [u16],
+        max: f32,
+    ) {
+        let cx = unsafe { (handler.unpremultiply_u16)(row, row.len() / 4, max) };
+        for px in row.chunks_exact_mut(4).skip(cx) {
+            let a = px[A_POS] as f32;
+            for c in colour_positions(A_POS) {
+                px[c] = unpremultiply_f32(px[c] as f32, a, max) as u16;
+            }
+        }
+    }
+}
+
+/// There are no f16 SIMD kernels yet, so f16 rows are always processed by this scalar loop.
+/// Unlike integers, f16 is neither rounded nor clamped, so alpha above 1 or negative passes through.
+#[cfg(feature = "nightly_f16")]
+impl AlphaSample for f16 {
+    fn premultiply_row<const A_POS: usize>(_: &AlphaRowHandler<A_POS>, row: &mut [f16], _: f32) {
+        for px in row.chunks_exact_mut(4) {
+            let a = px[A_POS] as f32;
+            for c in colour_positions(A_POS) {
+                px[c] = (px[c] as f32 * a) as f16;
+            }
+        }
+    }
+
+    fn unpremultiply_row<const A_POS: usize>(_: &AlphaRowHandler<A_POS>, row: &mut [f16], _: f32) {
+        for px in row.chunks_exact_mut(4) {
+            let a = px[A_POS] as f32;
+            for c in colour_positions(A_POS) {
+                px[c] = if a == 0f32 {
+                    0f32 as f16
+                } else {
+                    (px[c] as f32 / a) as f16
+                };
+            }
+        }
+    }
+}
+
+fn alpha_in_place<T: AlphaSample, const A_POS: usize, const UNPREMULTIPLY: bool>(
+    rgba: &mut [T],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    max: f32,
+) -> Result<(), YuvError> {
+    check_rgba_destination(rgba, rgba_stride, width, height, 4)?;
+    let handler = AlphaRowHandler::<A_POS>::default();
+    let row_length = width as usize * 4;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba.par_chunks_mut(rgba_stride as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba.chunks_mut(rgba_stride as usize);
+    }
+    iter.take(height as usize).for_each(|row| {
+        let row = &mut row[..row_length];
+        if UNPREMULTIPLY {
+            T::unpremultiply_row(&handler, row, max);
+        } else {
+            T::premultiply_row(&handler, row, max);
+        }
+    });
+    Ok(())
+}
+
+fn alpha_out_of_place<T: AlphaSample, const A_POS: usize, const UNPREMULTIPLY: bool>(
+    src: &[T],
+    src_stride: u32,
+    dst: &mut [T],
+    dst_stride: u32,
+    width: u32,
+    height: u32,
+    max: f32,
+) -> Result<(), YuvError> {
+    check_rgba_destination(src, src_stride, width, height, 4)?;
+    check_rgba_destination(dst, dst_stride, width, height, 4)?;
+    let handler = AlphaRowHandler::<A_POS>::default();
+    let row_length = width as usize * 4;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride as usize)
+            .zip(src.par_chunks(src_stride as usize));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride as usize)
+            .zip(src.chunks(src_stride as usize));
+    }
+    iter.take(height as usize).for_each(|(dst, src)| {
+        let dst = &mut dst[..row_length];
+        dst.copy_from_slice(&src[..row_length]);
+        if UNPREMULTIPLY {
+            T::unpremultiply_row(&handler, dst, max);
+        } else {
+            T::premultiply_row(&handler, dst, max);
+        }
+    });
+    Ok(())
+}
+
+macro_rules! d_alpha {
+    ($premultiply: ident, $premultiply_in_place: ident, $unpremultiply: ident, $unpremultiply_in_place: ident, $v: ty, $a_pos: expr, $rgba_name: expr, $depth_doc: expr, $depth: expr, $depth_error: expr, $max: expr $(, $bit_depth: ident)?) => {
+        #[doc = concat!("Premultiplies colour of ", $depth_doc, " ", $rgba_name, " image by its alpha into another buffer.
+
+# Arguments
+
+* `src` - Source ", $rgba_name, " image data.
+* `src_stride` - The stride (components per row) for source data.
+* `dst` - Destination for premultiplied ", $rgba_name, " data.
+* `dst_stride` - The stride (components per row) for destination data.
+* `width` - Image width.
+* `height` - Image height.", $depth, "
+
+# Errors
+
+This function returns an error if the lengths of the buffers are not valid based
+on the specified width, height, and strides", $depth_error, ".")]
+        pub fn $premultiply(
+            src: &[$v],
+            src_stride: u32,
+            dst: &mut [$v],
+            dst_stride: u32,
+            width: u32,
+            height: u32,
+            $($bit_depth: u32,)?
+        ) -> Result<(), YuvError> {
+            alpha_out_of_place::<$v, $a_pos, false>(
+                src, src_stride, dst, dst_stride, width, height, $max,
+            )
+        }
+
+        #[doc = concat!("Premultiplies colour of ", $depth_doc, " ", $rgba_name, " image by its alpha in place.
+
+# Arguments
+
+* `rgba` - ", $rgba_name, " image data.
+* `rgba_stride` - The stride (components per row) for ", $rgba_name, " data.
+* `width` - Image width.
+* `height` - Image height.", $depth, "
+
+# Errors
+
+This function returns an error if the length of the buffer is not valid based
+on the specified width, height, and stride", $depth_error, ".")]
+        pub fn $premultiply_in_place(
+            rgba: &mut [$v],
+            rgba_stride: u32,
+            width: u32,
+            height: u32,
+            $($bit_depth: u32,)?
+        ) -> Result<(), YuvError> {
+            alpha_in_place::<$v, $a_pos, false>(rgba, rgba_stride, width, height, $max)
+        }
+
+        #[doc = concat!("Divides colour of premultiplied ", $depth_doc, " ", $rgba_name, " image by its alpha into another buffer.
+
+Fully transparent pixels get zero colour.
+
+# Arguments
+
+* `src` - Source premultiplied ", $rgba_name, " image data.
+* `src_stride` - The stride (components per row) for source data.
+* `dst` - Destination for straight alpha ", $rgba_name, " data.
+* `dst_stride` - The stride (components per row) for destination data.
+* `width` - Image width.
+* `height` - Image height.", $depth, "
+
+# Errors
+
+This function returns an error if the lengths of the buffers are not valid based
+on the specified width, height, and strides", $depth_error, ".")]
+        pub fn $unpremultiply(
+            src: &[$v],
+            src_stride: u32,
+            dst: &mut [$v],
+            dst_stride: u32,
+            width: u32,
+            height: u32,
+            $($bit_depth: u32,)?
+        ) -> Result<(), YuvError> {
+            alpha_out_of_place::<$v, $a_pos, true>(
+                src, src_stride, dst, dst_stride, width, height, $max,
+            )
+        }
+
+        #[doc = concat!("Divides colour of premultiplied ", $depth_doc, " ", $rgba_name, " image by its alpha in place.
+
+Fully transparent pixels get zero colour.
+
+# Arguments
+
+* `rgba` - Premultiplied ", $rgba_name, " image data.
+* `rgba_stride` - The stride (components per row) for ", $rgba_name, " data.
+* `width` - Image width.
+* `height` - Image height.", $depth, "
+
+# Errors
+
+This function returns an error if the length of the buffer is not valid based
+on the specified width, height, and stride", $depth_error, ".")]
+        pub fn $unpremultiply_in_place(
+            rgba: &mut [$v],
+            rgba_stride: u32,
+            width: u32,
+            height: u32,
+            $($bit_depth: u32,)?
+        ) -> Result<(), YuvError> {
+            alpha_in_place::<$v, $a_pos, true>(rgba, rgba_stride, width, height, $max)
+        }
+    };
+}
+
+/// Maximum sample value for bit-depth
+#[inline]
+fn max_value(bit_depth: u32) -> Result<f32, YuvError> {
+    check_bit_depth(bit_depth, 8..=16)?;
+    Ok(((1u32 << bit_depth) - 1) as f32)
+}
+
+d_alpha!(
+    premultiply_rgba,
+    premultiply_rgba_in_place,
+    unpremultiply_rgba,
+    unpremultiply_rgba_in_place,
+    u8,
+    3,
+    "RGBA",
+    "8 bit-depth",
+    "",
+    "",
+    255f32
+);
+d_alpha!(
+    premultiply_bgra,
+    premultiply_bgra_in_place,
+    unpremultiply_bgra,
+    unpremultiply_bgra_in_place,
+    u8,
+    3,
+    "BGRA",
+    "8 bit-depth",
+    "",
+    "",
+    255f32
+);
+d_alpha!(
+    premultiply_argb,
+    premultiply_argb_in_place,
+    unpremultiply_argb,
+    unpremultiply_argb_in_place,
+    u8,
+    0,
+    "ARGB",
+    "8 bit-depth",
+    "",
+    "",
+    255f32
+);
+d_alpha!(
+    premultiply_abgr,
+    premultiply_abgr_in_place,
+    unpremultiply_abgr,
+    unpremultiply_abgr_in_place,
+    u8,
+    0,
+    "ABGR",
+    "8 bit-depth",
+    "",
+    "",
+    255f32
+);
+d_alpha!(
+    premultiply_rgba16,
+    premultiply_rgba16_in_place,
+    unpremultiply_rgba16,
+    unpremultiply_rgba16_in_place,
+    u16,
+    3,
+    "RGBA",
+    "high bit-depth",
+    "\n* `bit_depth` - Bit-depth of the image, 8..=16.",
+    ", or bit depth is not in 8..=16",
+    max_value(bit_depth)?,
+    bit_depth
+);
+d_alpha!(
+    premultiply_bgra16,
+    premultiply_bgra16_in_place,
+    unpremultiply_bgra16,
+    unpremultiply_bgra16_in_place,
+    u16,
+    3,
+    "BGRA",
+    "high bit-depth",
+    "\n* `bit_depth` - Bit-depth of the image, 8..=16.",
+    ", or bit depth is not in 8..=16",
+    max_value(bit_depth)?,
+    bit_depth
+);
+d_alpha!(
+    premultiply_argb16,
+    premultiply_argb16_in_place,
+    unpremultiply_argb16,
+    unpremultiply_argb16_in_place,
+    u16,
+    0,
+    "ARGB",
+    "high bit-depth",
+    "\n* `bit_depth` - Bit-depth of the image, 8..=16.",
+    ", or bit depth is not in 8..=16",
+    max_value(bit_depth)?,
+    bit_depth
+);
+d_alpha!(
+    premultiply_abgr16,
+    premultiply_abgr16_in_place,
+    unpremultiply_abgr16,
+    unpremultiply_abgr16_in_place,
+    u16,
+    0,
+    "ABGR",
+    "high bit-depth",
+    "\n* `bit_depth` - Bit-depth of the image, 8..=16.",
+    ", or bit depth is not in 8..=16",
+    max_value(bit_depth)?,
+    bit_depth
+);
+#[cfg(feature = "nightly_f16")]
+d_alpha!(
+    premultiply_rgba_f16,
+    premultiply_rgba_f16_in_place,
+    unpremultiply_rgba_f16,
+    unpremultiply_rgba_f16_in_place,
+    f16,
+    3,
+    "RGBA",
+    "f16",
+    "",
+    "",
+    1f32
+);
+#[cfg(feature = "nightly_f16")]
+d_alpha!(
+    premultiply_bgra_f16,
+    premultiply_bgra_f16_in_place,
+    unpremultiply_bgra_f16,
+    unpremultiply_bgra_f16_in_place,
+    f16,
+    3,
+    "BGRA",
+    "f16",
+    "",
+    "",
+    1f32
+);
+#[cfg(feature = "nightly_f16")]
+d_alpha!(
+    premultiply_argb_f16,
+    premultiply_argb_f16_in_place,
+    unpremultiply_argb_f16,
+    unpremultiply_argb_f16_in_place,
+    f16,
+    0,
+    "ARGB",
+    "f16",
+    "",
+    "",
+    1f32
+);
+#[cfg(feature = "nightly_f16")]
+d_alpha!(
+    premultiply_abgr_f16,
+    premultiply_abgr_f16_in_place,
+    unpremultiply_abgr_f16,
+    unpremultiply_abgr_f16_in_place,
+    f16,
+    0,
+    "ABGR",
+    "f16",
+    "",
+    "",
+    1f32
+);
+
+/// Rows `row..row + rows` of a plane, last row may be shorter than stride
+#[inline]
+fn rows_of_mut<T>(plane: &mut [T], stride: usize, row: usize, rows: usize) -> &mut [T] {
+    let start = row * stride;
+    let end = (start + rows * stride).min(plane.len());
+    &mut plane[start..end]
+}
+
+/// Copies strip of source rows into tightly packed scratch and unpremultiplies it
+fn unpremultiplied_strip<'a, T: AlphaSample>(
+    rgba: &[T],
+    rgba_stride: usize,
+    row: usize,
+    rows: usize,
+    width: usize,
+    scratch: &'a mut [T],
+    max: f32,
+) -> &'a [T] {
+    let handler = AlphaRowHandler::<3>::default();
+    let row_length = width * 4;
+    let scratch = &mut scratch[..row_length * rows];
+    for (dst, src) in scratch
+        .chunks_exact_mut(row_length)
+        .zip(rgba.chunks(rgba_stride).skip(row))
+    {
+        dst.copy_from_slice(&src[..row_length]);
+        T::unpremultiply_row(&handler, dst, max);
+    }
+    scratch
+}
+
+/// Encodes planar image strip by strip, unpremultiplying every strip in scratch
+/// right before it's handed to `encoder`
+fn encode_planar_premultiplied<T: AlphaSample>(
+    image: &mut YuvPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    rgba: &[T],
+    rgba_stride: u32,
+    max: f32,
+    encoder: impl Fn(&mut YuvPlanarImageMut<T>, &[T], u32) -> Result<(), YuvError>,
+) -> Result<(), YuvError> {
+    image.check_constraints(subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, image.width, image.height, 4)?;
+    let width = image.width as usize;
+    let height = image.height as usize;
+    let mut scratch = vec![T::default(); width * 4 * STRIP_HEIGHT];
+    for row in (0..height).step_by(STRIP_HEIGHT) {
+        let rows = STRIP_HEIGHT.min(height - row);
+        let chroma_row = row / subsampling.vertical_factor();
+        let chroma_rows = subsampling.chroma_height(rows);
+        let strip_rgba = unpremultiplied_strip(
+            rgba,
+            rgba_stride as usize,
+            row,
+            rows,
+            width,
+            &mut scratch,
+            max,
+        );
+        let (y_stride, u_stride, v_stride) = (image.y_stride, image.u_stride, image.v_stride);
+        let mut strip = YuvPlanarImageMut {
+            y_plane: BufferStoreMut::Borrowed(rows_of_mut(
+                image.y_plane.borrow_mut(),
+                y_stride as usize,
+                row,
+                rows,
+            )),
+            y_stride,
+            u_plane: BufferStoreMut::Borrowed(rows_of_mut(
+                image.u_plane.borrow_mut(),
+                u_stride as usize,
+                chroma_row,
+                chroma_rows,
+            )),
+            u_stride,
+            v_plane: BufferStoreMut::Borrowed(rows_of_mut(
+                image.v_plane.borrow_mut(),
+                v_stride as usize,
+                chroma_row,
+                chroma_rows,
+            )),
+            v_stride,
+            width: image.width,
+            height: rows as u32,
+        };
+        encoder(&mut strip, strip_rgba, image.width * 4)?;
+    }
+    Ok(())
+}
+
+/// Bi-planar counterpart of [encode_planar_premultiplied]
+fn encode_bi_planar_premultiplied(
+    image: &mut YuvBiPlanarImageMut<u8>,
+    subsampling: YuvChromaSubsampling,
+    rgba: &[u8],
+    rgba_stride: u32,
+    encoder: impl Fn(&mut YuvBiPlanarImageMut<u8>, &[u8], u32) -> Result<(), YuvError>,
+) -> Result<(), YuvError> {
+    image.check_constraints(subsampling)?;
+    check_rgba_destination(rgba, rgba_stride, image.width, image.height, 4)?;
+    let width = image.width as usize;
+    let height = image.height as usize;
+    let mut scratch = vec![0u8; width * 4 * STRIP_HEIGHT];
+    for row in (0..height).step_by(STRIP_HEIGHT) {
+        let rows = STRIP_HEIGHT.min(height - row);
+        let chroma_row = row / subsampling.vertical_factor();
+        let chroma_rows = subsampling.chroma_height(rows);
+        let strip_rgba = unpremultiplied_strip(
+            rgba,
+            rgba_stride as usize,
+            row,
+            rows,
+            width,
+            &mut scratch,
+            255f32,
+        );
+        let (y_stride, uv_stride) = (image.y_stride, image.uv_stride);
+        let mut strip = YuvBiPlanarImageMut {
+            y_plane: BufferStoreMut::Borrowed(rows_of_mut(
+                image.y_plane.borrow_mut(),
+                y_stride as usize,
+                row,
+                rows,
+            )),
+            y_stride,
+            uv_plane: BufferStoreMut::Borrowed(rows_of_mut(
+                image.uv_plane.borrow_mut(),
+                uv_stride as usize,
+                chroma_row,
+                chroma_rows,
+            )),
+            uv_stride,
+            width: image.width,
+            height: rows as u32,
+        };
+        encoder(&mut strip, strip_rgba, image.width * 4)?;
+    }
+    Ok(())
+}
+
+macro_rules! d_encode_planar {
+    ($method: ident, $encoder: ident, $sampling: expr, $rgba_name: expr, $yuv_name: expr) => {
+        #[doc = concat!("Converts ", $rgba_name, " 8 bit-depth image which may have premultiplied alpha to ", $yuv_name, " planar format.
+
+When `alpha_premultiplied` is set colour is divided by alpha before encoding,
+strip by strip in a small scratch buffer, otherwise this is the same as [", stringify!($encoder), "].
+
+# Arguments
+
+* `planar_image` - Target planar image.
+* `rgba` - The input ", $rgba_name, " image data slice.
+* `rgba_stride` - The stride (components per row) for the ", $rgba_name, " image data.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `mode` - See [YuvConversionMode] for more info.
+* `alpha_premultiplied` - Whether colour of the source is premultiplied by alpha.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $rgba_name, " data are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            planar_image: &mut YuvPlanarImageMut<u8>,
+            rgba: &[u8],
+            rgba_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            mode: YuvConversionMode,
+            alpha_premultiplied: bool,
+        ) -> Result<(), YuvError> {
+            if !alpha_premultiplied {
+                return $encoder(planar_image, rgba, rgba_stride, range, matrix, mode);
+            }
+            encode_planar_premultiplied(
+                planar_image,
+                $sampling,
+                rgba,
+                rgba_stride,
+                255f32,
+                |strip, src, stride| $encoder(strip, src, stride, range, matrix, mode),
+            )
+        }
+    };
+}
+
+d_encode_planar!(
+    rgba_to_yuv420_alpha_premultiplied,
+    rgba_to_yuv420,
+    YuvChromaSubsampling::Yuv420,
+    "RGBA",
+    "YUV 420"
+);
+d_encode_planar!(
+    rgba_to_yuv422_alpha_premultiplied,
+    rgba_to_yuv422,
+    YuvChromaSubsampling::Yuv422,
+    "RGBA",
+    "YUV 422"
+);
+d_encode_planar!(
+    rgba_to_yuv444_alpha_premultiplied,
+    rgba_to_yuv444,
+    YuvChromaSubsampling::Yuv444,
+    "RGBA",
+    "YUV 444"
+);
+d_encode_planar!(
+    bgra_to_yuv420_alpha_premultiplied,
+    bgra_to_yuv420,
+    YuvChromaSubsampling::Yuv420,
+    "BGRA",
+    "YUV 420"
+);
+d_encode_planar!(
+    bgra_to_yuv422_alpha_premultiplied,
+    bgra_to_yuv422,
+    YuvChromaSubsampling::Yuv422,
+    "BGRA",
+    "YUV 422"
+);
+d_encode_planar!(
+    bgra_to_yuv444_alpha_premultiplied,
+    bgra_to_yuv444,
+    YuvChromaSubsampling::Yuv444,
+    "BGRA",
+    "YUV 444"
+);
+
+macro_rules! d_encode_bi_planar {
+    ($method: ident, $encoder: ident, $sampling: expr, $rgba_name: expr, $yuv_name: expr) => {
+        #[doc = concat!("Converts ", $rgba_name, " 8 bit-depth image which may have premultiplied alpha to ", $yuv_name, " bi-planar format.
+
+When `alpha_premultiplied` is set colour is divided by alpha before encoding,
+strip by strip in a small scratch buffer, otherwise this is the same as [", stringify!($encoder), "].
+
+# Arguments
+
+* `bi_planar_image` - Target bi-planar image.
+* `rgba` - The input ", $rgba_name, " image data slice.
+* `rgba_stride` - The stride (components per row) for the ", $rgba_name, " image data.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `mode` - See [YuvConversionMode] for more info.
+* `alpha_premultiplied` - Whether colour of the source is premultiplied by alpha.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $rgba_name, " data are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            bi_planar_image: &mut YuvBiPlanarImageMut<u8>,
+            rgba: &[u8],
+            rgba_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            mode: YuvConversionMode,
+            alpha_premultiplied: bool,
+        ) -> Result<(), YuvError> {
+            if !alpha_premultiplied {
+                return $encoder(bi_planar_image, rgba, rgba_stride, range, matrix, mode);
+            }
+            encode_bi_planar_premultiplied(
+                bi_planar_image,
+                $sampling,
+                rgba,
+                rgba_stride,
+                |strip, src, stride| $encoder(strip, src, stride, range, matrix, mode),
+            )
+        }
+    };
+}
+
+d_encode_bi_planar!(
+    rgba_to_yuv_nv12_alpha_premultiplied,
+    rgba_to_yuv_nv12,
+    YuvChromaSubsampling::Yuv420,
+    "RGBA",
+    "NV12"
+);
+d_encode_bi_planar!(
+    rgba_to_yuv_nv21_alpha_premultiplied,
+    rgba_to_yuv_nv21,
+    YuvChromaSubsampling::Yuv420,
+    "RGBA",
+    "NV21"
+);
+d_encode_bi_planar!(
+    rgba_to_yuv_nv16_alpha_premultiplied,
+    rgba_to_yuv_nv16,
+    YuvChromaSubsampling::Yuv422,
+    "RGBA",
+    "NV16"
+);
+d_encode_bi_planar!(
+    rgba_to_yuv_nv61_alpha_premultiplied,
+    rgba_to_yuv_nv61,
+    YuvChromaSubsampling::Yuv422,
+    "RGBA",
+    "NV61"
+);
+d_encode_bi_planar!(
+    rgba_to_yuv_nv24_alpha_premultiplied,
+    rgba_to_yuv_nv24,
+    YuvChromaSubsampling::Yuv444,
+    "RGBA",
+    "NV24"
+);
+d_encode_bi_planar!(
+    rgba_to_yuv_nv42_alpha_premultiplied,
+    rgba_to_yuv_nv42,
+    YuvChromaSubsampling::Yuv444,
+    "RGBA",
+    "NV42"
+);
+d_encode_bi_planar!(
+    bgra_to_yuv_nv12_alpha_premultiplied,
+    bgra_to_yuv_nv12,
+    YuvChromaSubsampling::Yuv420,
+    "BGRA",
+    "NV12"
+);
+d_encode_bi_planar!(
+    bgra_to_yuv_nv21_alpha_premultiplied,
+    bgra_to_yuv_nv21,
+    YuvChromaSubsampling::Yuv420,
+    "BGRA",
+    "NV21"
+);
+d_encode_bi_planar!(
+    bgra_to_yuv_nv16_alpha_premultiplied,
+    bgra_to_yuv_nv16,
+    YuvChromaSubsampling::Yuv422,
+    "BGRA",
+    "NV16"
+);
+d_encode_bi_planar!(
+    bgra_to_yuv_nv61_alpha_premultiplied,
+    bgra_to_yuv_nv61,
+    YuvChromaSubsampling::Yuv422,
+    "BGRA",
+    "NV61"
+);
+d_encode_bi_planar!(
+    bgra_to_yuv_nv24_alpha_premultiplied,
+    bgra_to_yuv_nv24,
+    YuvChromaSubsampling::Yuv444,
+    "BGRA",
+    "NV24"
+);
+d_encode_bi_planar!(
+    bgra_to_yuv_nv42_alpha_premultiplied,
+    bgra_to_yuv_nv42,
+    YuvChromaSubsampling::Yuv444,
+    "BGRA",
+    "NV42"
+);
+
+macro_rules! d_encode_planar16 {
+    ($method: ident, $encoder: ident, $sampling: expr, $bit_depth: expr, $yuv_name: expr) => {
+        #[doc = concat!("Converts RGBA ", stringify!($bit_depth), " bit-depth image which may have premultiplied alpha to ", $yuv_name, " planar format.
+
+When `alpha_premultiplied` is set colour is divided by alpha before encoding,
+strip by strip in a small scratch buffer, otherwise this is the same as [", stringify!($encoder), "].
+
+# Arguments
+
+* `planar_image` - Target planar image.
+* `rgba` - The input RGBA image data slice.
+* `rgba_stride` - The stride (components per row) for the RGBA image data.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `alpha_premultiplied` - Whether colour of the source is premultiplied by alpha.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input RGBA data are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            planar_image: &mut YuvPlanarImageMut<u16>,
+            rgba: &[u16],
+            rgba_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            alpha_premultiplied: bool,
+        ) -> Result<(), YuvError> {
+            if !alpha_premultiplied {
+                return $encoder(planar_image, rgba, rgba_stride, range, matrix);
+            }
+            encode_planar_premultiplied(
+                planar_image,
+                $sampling,
+                rgba,
+                rgba_stride,
+                max_value($bit_depth)?,
+                |strip, src, stride| $encoder(strip, src, stride, range, matrix),
+            )
+        }
+    };
+}
+
+d_encode_planar16!(
+    rgba10_to_i010_alpha_premultiplied,
+    rgba10_to_i010,
+    YuvChromaSubsampling::Yuv420,
+    10,
+    "I010"
+);
+d_encode_planar16!(
+    rgba10_to_i210_alpha_premultiplied,
+    rgba10_to_i210,
+    YuvChromaSubsampling::Yuv422,
+    10,
+    "I210"
+);
+d_encode_planar16!(
+    rgba10_to_i410_alpha_premultiplied,
+    rgba10_to_i410,
+    YuvChromaSubsampling::Yuv444,
+    10,
+    "I410"
+);
+d_encode_planar16!(
+    rgba12_to_i012_alpha_premultiplied,
+    rgba12_to_i012,
+    YuvChromaSubsampling::Yuv420,
+    12,
+    "I012"
+);
+d_encode_planar16!(
+    rgba12_to_i212_alpha_premultiplied,
+    rgba12_to_i212,
+    YuvChromaSubsampling::Yuv422,
+    12,
+    "I212"
+);
+d_encode_planar16!(
+    rgba12_to_i412_alpha_premultiplied,
+    rgba12_to_i412,
+    YuvChromaSubsampling::Yuv444,
+    12,
+    "I412"
+);
+d_encode_planar16!(
+    rgba14_to_i014_alpha_premultiplied,
+    rgba14_to_i014,
+    YuvChromaSubsampling::Yuv420,
+    14,
+    "I014"
+);
+d_encode_planar16!(
+    rgba14_to_i214_alpha_premultiplied,
+    rgba14_to_i214,
+    YuvChromaSubsampling::Yuv422,
+    14,
+    "I214"
+);
+d_encode_planar16!(
+    rgba14_to_i414_alpha_premultiplied,
+    rgba14_to_i414,
+    YuvChromaSubsampling::Yuv444,
+    14,
+    "I414"
+);
+d_encode_planar16!(
+    rgba16_to_i016_alpha_premultiplied,
+    rgba16_to_i016,
+    YuvChromaSubsampling::Yuv420,
+    16,
+    "I016"
+);
+d_encode_planar16!(
+    rgba16_to_i216_alpha_premultiplied,
+    rgba16_to_i216,
+    YuvChromaSubsampling::Yuv422,
+    16,
+    "I216"
+);
+d_encode_planar16!(
+    rgba16_to_i416_alpha_premultiplied,
+    rgba16_to_i416,
+    YuvChromaSubsampling::Yuv444,
+    16,
+    "I416"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn premultiply_round_trip_matches_scalar() {
+        let width = 37usize;
+        let source = (0..width * 4)
+            .map(|i| ((i * 53 + 11) % 256) as u8)
+            .collect::<Vec<_>>();
+        let mut premultiplied = vec![0u8; width * 4];
+        premultiply_argb(
+            &source,
+            width as u32 * 4,
+            &mut premultiplied,
+            width as u32 * 4,
+            width as u32,
+            1,
+        )
+        .unwrap();
+        for (dst, src) in premultiplied.chunks_exact(4).zip(source.chunks_exact(4)) {
+            assert_eq!(dst[0], src[0]);
+            for c in 1..4 {
+                let expected = (src[c] as f32 * src[0] as f32 / 255f32).round() as u8;
+                assert_eq!(dst[c], expected);
+            }
+        }
+        let mut straight = premultiplied.clone();
+        unpremultiply_argb_in_place(&mut straight, width as u32 * 4, width as u32, 1).unwrap();
+        for (dst, src) in straight.chunks_exact(4).zip(premultiplied.chunks_exact(4)) {
+            for c in 1..4 {
+                let expected = unpremultiply_f32(src[c] as f32, src[0] as f32, 255f32) as u8;
+                assert_eq!(dst[c], expected);
+            }
+        }
+
+        let rgba16 = (0..width * 4)
+            .map(|i| ((i * 397 + 5) % 1024) as u16)
+            .collect::<Vec<_>>();
+        let mut simd = rgba16.clone();
+        unpremultiply_rgba16_in_place(&mut simd, width as u32 * 4, width as u32, 1, 10).unwrap();
+        for (dst, src) in simd.chunks_exact(4).zip(rgba16.chunks_exact(4)) {
+            for c in 0..3 {
+                assert_eq!(
+                    dst[c],
+                    unpremultiply_f32(src[c] as f32, src[3] as f32, 1023f32) as u16
+                );
+            }
+            assert_eq!(dst[3], src[3]);
+        }
+
+        for bit_depth in [10u32, 12, 16] {
+            let max = ((1u32 << bit_depth) - 1) as f32;
+            let rgba16 = (0..width * 4)
+                .map(|i| ((i * 7919 + 13) % (1usize << bit_depth)) as u16)
+                .collect::<Vec<_>>();
+            let mut premultiplied = vec![0u16; width * 4];
+            premultiply_rgba16(
+                &rgba16,
+                width as u32 * 4,
+                &mut premultiplied,
+                width as u32 * 4,
+                width as u32,
+                1,
+                bit_depth,
+            )
+            .unwrap();
+            for (dst, src) in premultiplied.chunks_exact(4).zip(rgba16.chunks_exact(4)) {
+                for c in 0..3 {
+                    let expected = premultiply_f32(src[c] as f32, src[3] as f32, max) as u16;
+                    assert_eq!(dst[c], expected, "{bit_depth} bit {src:?}");
+                }
+                assert_eq!(dst[3], src[3]);
+            }
+        }
+
+        // Encoding premultiplied surface equals encoding it after unpremultiplication
+        let (w, h) = (19u32, 21u32);
+        let surface = (0..(w * h * 4) as usize)
+            .map(|i| ((i * 31 + 7) % 256) as u8)
+            .collect::<Vec<_>>();
+        let mut fused = YuvPlanarImageMut::<u8>::alloc(w, h, YuvChromaSubsampling::Yuv420);
+        rgba_to_yuv420_alpha_premultiplied(
+            &mut fused,
+            &surface,
+            w * 4,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt601,
+            YuvConversionMode::Balanced,
+            true,
+        )
+        .unwrap();
+        let mut straight = surface.clone();
+        unpremultiply_rgba_in_place(&mut straight, w * 4, w, h).unwrap();
+        let mut reference = YuvPlanarImageMut::<u8>::alloc(w, h, YuvChromaSubsampling::Yuv420);
+        rgba_to_yuv420(
+            &mut reference,
+            &straight,
+            w * 4,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt601,
+            YuvConversionMode::Balanced,
+        )
+        .unwrap();
+        assert_eq!(fused.y_plane.borrow(), reference.y_plane.borrow());
+        assert_eq!(fused.u_plane.borrow(), reference.u_plane.borrow());
+        assert_eq!(fused.v_plane.borrow(), reference.v_plane.borrow());
+    }
+
+    #[test]
+    fn rejects_unsupported_bit_depth() {
+        let mut rgba = vec![0u16; 4 * 4];
+        assert!(matches!(
+            premultiply_rgba16_in_place(&mut rgba, 16, 4, 1, 17),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+        assert!(matches!(
+            unpremultiply_rgba16_in_place(&mut rgba, 16, 4, 1, 7),
+            Err(YuvError::UnsupportedBitDepth(7))
+        ));
+    }
+}
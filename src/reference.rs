@@ -0,0 +1,1011 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::{check_bit_depth, check_rgba_destination};
+use crate::yuv_support::get_yuv_range;
+use crate::{
+    YuvBiPlanarImage, YuvBitDepth, YuvChromaSubsampling, YuvError, YuvPackedImage, YuvPlanarImage,
+    YuvRange, YuvStandardMatrix, Yuy2Description,
+};
+use std::fmt::Debug;
+
+/// Sample type readable by reference converter
+pub trait ReferenceSample: Copy + Debug {
+    /// Reads sample as value in least significant bits
+    fn to_reference(self, depth: YuvBitDepth) -> f64;
+}
+
+impl ReferenceSample for u8 {
+    fn to_reference(self, _: YuvBitDepth) -> f64 {
+        self as f64
+    }
+}
+
+impl ReferenceSample for u16 {
+    fn to_reference(self, depth: YuvBitDepth) -> f64 {
+        depth.native(self) as f64
+    }
+}
+
+/// Unrounded RGB result of reference converter, 3 channels per pixel
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceRgb {
+    /// Tightly packed RGB values in range of RGB bit-depth, `width * 3` per row
+    pub data: Vec<f64>,
+    /// Image width
+    pub width: u32,
+    /// Image height
+    pub height: u32,
+}
+
+/// Unrounded planar YUV result of reference converter
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceYuv {
+    /// Tightly packed luma values, `width` per row
+    pub y_plane: Vec<f64>,
+    /// Tightly packed U values, `chroma_width` per row
+    pub u_plane: Vec<f64>,
+    /// Tightly packed V values, `chroma_width` per row
+    pub v_plane: Vec<f64>,
+    /// Image width
+    pub width: u32,
+    /// Image height
+    pub height: u32,
+    /// Width of chroma planes for target subsampling
+    pub chroma_width: u32,
+    /// Height of chroma planes for target subsampling
+    pub chroma_height: u32,
+}
+
+/// Deviation of a conversion result from the reference in LSB of target depth
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct ReferenceError {
+    /// Largest absolute deviation
+    pub max: f64,
+    /// Mean absolute deviation
+    pub mean: f64,
+    /// Count of compared samples
+    pub samples: usize,
+}
+
+impl ReferenceError {
+    fn measure(pairs: impl Iterator<Item = (f64, f64)>) -> ReferenceError {
+        let mut max = 0f64;
+        let mut sum = 0f64;
+        let mut samples = 0usize;
+        for (reference, actual) in pairs {
+            let diff = (reference - actual).abs();
+            max = max.max(diff);
+            sum += diff;
+            samples += 1;
+        }
+        ReferenceError {
+            max,
+            mean: if samples == 0 {
+                0.
+            } else {
+                sum / samples as f64
+            },
+            samples,
+        }
+    }
+
+    /// Combines errors of several planes or channels
+    pub fn merge(&self, other: &ReferenceError) -> ReferenceError {
+        let samples = self.samples + other.samples;
+        ReferenceError {
+            max: self.max.max(other.max),
+            mean: if samples == 0 {
+                0.
+            } else {
+                (self.mean * self.samples as f64 + other.mean * other.samples as f64)
+                    / samples as f64
+            },
+            samples,
+        }
+    }
+}
+
+/// Exact YCbCr model for given matrix, range and depths
+struct ReferenceModel {
+    kr: f64,
+    kb: f64,
+    kg: f64,
+    bias_y: f64,
+    bias_uv: f64,
+    range_y: f64,
+    range_uv: f64,
+    yuv_max: f64,
+    rgb_max: f64,
+}
+
+impl ReferenceModel {
+    fn new(
+        yuv_bit_depth: u32,
+        rgb_bit_depth: u32,
+        range: YuvRange,
+        matrix: YuvStandardMatrix,
+    ) -> Result<ReferenceModel, YuvError> {
+        check_bit_depth(yuv_bit_depth, 8..=16)?;
+        check_bit_depth(rgb_bit_depth, 8..=16)?;
+        let kr_kb = matrix.get_kr_kb();
+        let chroma_range = get_yuv_range(yuv_bit_depth, range);
+        let kr = kr_kb.kr as f64;
+        let kb = kr_kb.kb as f64;
+        Ok(ReferenceModel {
+            kr,
+            kb,
+            kg: 1. - kr - kb,
+            bias_y: chroma_range.bias_y as f64,
+            bias_uv: chroma_range.bias_uv as f64,
+            range_y: chroma_range.range_y as f64,
+            range_uv: chroma_range.range_uv as f64,
+            yuv_max: ((1u32 << yuv_bit_depth) - 1) as f64,
+            rgb_max: ((1u32 << rgb_bit_depth) - 1) as f64,
+        })
+    }
+
+    /// Unclamped R, G, B in 0..=1 nominal range
+    fn decode_normalized(&self, y: f64, u: f64, v: f64) -> [f64; 3] {
+        let y = (y - self.bias_y) / self.range_y;
+        let cb = (u - self.bias_uv) / self.range_uv;
+        let cr = (v - self.bias_uv) / self.range_uv;
+        let r = y + 2. * (1. - self.kr) * cr;
+        let b = y + 2. * (1. - self.kb) * cb;
+        let g = (y - self.kr * r - self.kb * b) / self.kg;
+        [r, g, b]
+    }
+
+    /// Y, Cb, Cr of R, G, B in 0..=1 nominal range
+    fn encode_normalized(&self, rgb: [f64; 3]) -> [f64; 3] {
+        let [r, g, b] = rgb;
+        let y = self.kr * r + self.kg * g + self.kb * b;
+        let cb = (b - y) / (2. * (1. - self.kb));
+        let cr = (r - y) / (2. * (1. - self.kr));
+        [
+            (self.bias_y + y * self.range_y).clamp(0., self.yuv_max),
+            (self.bias_uv + cb * self.range_uv).clamp(0., self.yuv_max),
+            (self.bias_uv + cr * self.range_uv).clamp(0., self.yuv_max),
+        ]
+    }
+
+    fn to_rgb(&self, y: f64, u: f64, v: f64) -> [f64; 3] {
+        self.decode_normalized(y, u, v)
+            .map(|v| (v * self.rgb_max).clamp(0., self.rgb_max))
+    }
+
+    fn luma(&self, rgb: [f64; 3]) -> f64 {
+        self.encode_normalized(rgb.map(|v| v / self.rgb_max))[0]
+    }
+
+    fn chroma(&self, rgb: [f64; 3]) -> (f64, f64) {
+        let [_, u, v] = self.encode_normalized(rgb.map(|v| v / self.rgb_max));
+        (u, v)
+    }
+}
+
+fn check_channels(channels: usize) -> Result<(), YuvError> {
+    if channels != 3 && channels != 4 {
+        return Err(YuvError::UnsupportedChannelsCount(channels));
+    }
+    Ok(())
+}
+
+/// Decodes image where chroma sample of pixel is read by `chroma(x, y)`,
+/// chroma is replicated over subsampled block as in fixed point decoders
+fn decode_reference(
+    width: u32,
+    height: u32,
+    model: &ReferenceModel,
+    luma: impl Fn(usize, usize) -> f64,
+    chroma: impl Fn(usize, usize) -> (f64, f64),
+) -> ReferenceRgb {
+    let mut data = Vec::with_capacity(width as usize * height as usize * 3);
+    for y in 0..height as usize {
+        for x in 0..width as usize {
+            let (u, v) = chroma(x, y);
+            data.extend_from_slice(&model.to_rgb(luma(x, y), u, v));
+        }
+    }
+    ReferenceRgb {
+        data,
+        width,
+        height,
+    }
+}
+
+/// Reference f64 decoding of planar YUV to RGB.
+///
+/// Expected deviation of 8 bit-depth fixed point decoders from this reference:
+/// scalar path stays within 1 LSB, x86 SSE4.1 and AVX2 paths compute with reduced
+/// precision and deviate up to 3 LSB, mostly in green channel.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image, `u8` or `u16`.
+/// * `subsampling` - Chroma subsampling of the image.
+/// * `depth` - Storage of YUV samples, see [YuvBitDepth] for more info.
+/// * `rgb_bit_depth` - Bit-depth of RGB result.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or any bit depth is not in 8..=16.
+pub fn reference_yuv_to_rgb<V: ReferenceSample>(
+    image: &YuvPlanarImage<V>,
+    subsampling: YuvChromaSubsampling,
+    depth: YuvBitDepth,
+    rgb_bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<ReferenceRgb, YuvError> {
+    image.check_constraints(subsampling)?;
+    let model = ReferenceModel::new(depth.bit_depth, rgb_bit_depth, range, matrix)?;
+    let (hf, vf) = (
+        subsampling.horizontal_factor(),
+        subsampling.vertical_factor(),
+    );
+    Ok(decode_reference(
+        image.width,
+        image.height,
+        &model,
+        |x, y| image.y_plane[y * image.y_stride as usize + x].to_reference(depth),
+        |x, y| {
+            let u = image.u_plane[(y / vf) * image.u_stride as usize + x / hf];
+            let v = image.v_plane[(y / vf) * image.v_stride as usize + x / hf];
+            (u.to_reference(depth), v.to_reference(depth))
+        },
+    ))
+}
+
+/// Reference f64 decoding of bi-planar YUV with U stored first, as NV12 or P010, to RGB.
+///
+/// # Arguments
+///
+/// * `image` - Source bi-planar image, `u8` or `u16`.
+/// * `subsampling` - Chroma subsampling of the image.
+/// * `depth` - Storage of YUV samples, for P010 use [YuvBitDepth::msb].
+/// * `rgb_bit_depth` - Bit-depth of RGB result.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or any bit depth is not in 8..=16.
+pub fn reference_yuv_bi_planar_to_rgb<V: ReferenceSample>(
+    image: &YuvBiPlanarImage<V>,
+    subsampling: YuvChromaSubsampling,
+    depth: YuvBitDepth,
+    rgb_bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<ReferenceRgb, YuvError> {
+    image.check_constraints(subsampling)?;
+    let model = ReferenceModel::new(depth.bit_depth, rgb_bit_depth, range, matrix)?;
+    let (hf, vf) = (
+        subsampling.horizontal_factor(),
+        subsampling.vertical_factor(),
+    );
+    Ok(decode_reference(
+        image.width,
+        image.height,
+        &model,
+        |x, y| image.y_plane[y * image.y_stride as usize + x].to_reference(depth),
+        |x, y| {
+            let offset = (y / vf) * image.uv_stride as usize + (x / hf) * 2;
+            (
+                image.uv_plane[offset].to_reference(depth),
+                image.uv_plane[offset + 1].to_reference(depth),
+            )
+        },
+    ))
+}
+
+/// Reference f64 encoding of RGB or RGBA to planar YUV.
+///
+/// Chroma is computed from RGB averaged over subsampled block.
+/// 8 bit-depth fixed point encoders stay within 1 LSB of this reference on scalar and x86 SIMD paths.
+///
+/// # Arguments
+///
+/// * `rgba` - Source RGB or RGBA data, `u8` or `u16`.
+/// * `rgba_stride` - The stride (components per row) for the source data.
+/// * `channels` - 3 for RGB, 4 for RGBA.
+/// * `width` - Image width.
+/// * `height` - Image height.
+/// * `rgb_depth` - Storage of RGB samples, see [YuvBitDepth] for more info.
+/// * `subsampling` - Target chroma subsampling.
+/// * `yuv_bit_depth` - Target YUV bit-depth.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+/// # Errors
+///
+/// This function returns an error if `channels` is not 3 or 4, the length of the source
+/// is not valid based on the specified width, height, and stride, or any bit depth is not in 8..=16.
+pub fn reference_rgb_to_yuv<V: ReferenceSample>(
+    rgba: &[V],
+    rgba_stride: u32,
+    channels: usize,
+    width: u32,
+    height: u32,
+    rgb_depth: YuvBitDepth,
+    subsampling: YuvChromaSubsampling,
+    yuv_bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<ReferenceYuv, YuvError> {
+    check_channels(channels)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, channels)?;
+    let model = ReferenceModel::new(yuv_bit_depth, rgb_depth.bit_depth, range, matrix)?;
+    let (w, h) = (width as usize, height as usize);
+    let pixel = |x: usize, y: usize| -> [f64; 3] {
+        let px = &rgba[y * rgba_stride as usize + x * channels..][..3];
+        [px[0], px[1], px[2]].map(|v| v.to_reference(rgb_depth))
+    };
+
+    let mut y_plane = Vec::with_capacity(w * h);
+    for y in 0..h {
+        for x in 0..w {
+            y_plane.push(model.luma(pixel(x, y)));
+        }
+    }
+
+    let chroma_width = subsampling.chroma_width(w);
+    let chroma_height = subsampling.chroma_height(h);
+    let (hf, vf) = (
+        subsampling.horizontal_factor(),
+        subsampling.vertical_factor(),
+    );
+    let mut u_plane = Vec::with_capacity(chroma_width * chroma_height);
+    let mut v_plane = Vec::with_capacity(chroma_width * chroma_height);
+    for cy in 0..chroma_height {
+        for cx in 0..chroma_width {
+            let mut sum = [0f64; 3];
+            let mut count = 0f64;
+            for y in cy * vf..((cy + 1) * vf).min(h) {
+                for x in cx * hf..((cx + 1) * hf).min(w) {
+                    for (dst, src) in sum.iter_mut().zip(pixel(x, y)) {
+                        *dst += src;
+                    }
+                    count += 1.;
+                }
+            }
+            let (u, v) = model.chroma(sum.map(|v| v / count));
+            u_plane.push(u);
+            v_plane.push(v);
+        }
+    }
+
+    Ok(ReferenceYuv {
+        y_plane,
+        u_plane,
+        v_plane,
+        width,
+        height,
+        chroma_width: chroma_width as u32,
+        chroma_height: chroma_height as u32,
+    })
+}
+
+/// Reference f64 decoding of packed 4:2:2 YUV of any byte order to RGB.
+///
+/// # Arguments
+///
+/// * `image` - Source packed image, `u8` or `u16`.
+/// * `packing` - Order of components inside macropixel.
+/// * `depth` - Storage of YUV samples, see [YuvBitDepth] for more info.
+/// * `rgb_bit_depth` - Bit-depth of RGB result.
+/// * `range` - The YUV range (limited or full).
+/// * `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+///
+/// # Errors
+///
+/// This function returns an error if the length of packed data is not valid based
+/// on the specified width, height, and stride, or any bit depth is not in 8..=16.
+pub fn reference_yuv_packed_to_rgb<V: ReferenceSample>(
+    image: &YuvPackedImage<V>,
+    packing: Yuy2Description,
+    depth: YuvBitDepth,
+    rgb_bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<ReferenceRgb, YuvError> {
+    image.check_constraints()?;
+    let model = ReferenceModel::new(depth.bit_depth, rgb_bit_depth, range, matrix)?;
+    let stride = image.yuy_stride as usize;
+    let macropixel = |x: usize, y: usize| &image.yuy[y * stride + (x / 2) * 4..][..4];
+    Ok(decode_reference(
+        image.width,
+        image.height,
+        &model,
+        |x, y| {
+            let position = if x % 2 == 0 {
+                packing.get_first_y_position()
+            } else {
+                packing.get_second_y_position()
+            };
+            macropixel(x, y)[position].to_reference(depth)
+        },
+        |x, y| {
+            let px = macropixel(x, y);
+            (
+                px[packing.get_u_position()].to_reference(depth),
+                px[packing.get_v_position()].to_reference(depth),
+            )
+        },
+    ))
+}
+
+/// Collects reference planes computed sample by sample
+fn map_planes(
+    width: u32,
+    height: u32,
+    subsampling: YuvChromaSubsampling,
+    luma: impl Fn(usize, usize) -> f64,
+    chroma: impl Fn(usize, usize) -> (f64, f64),
+) -> ReferenceYuv {
+    let (w, h) = (width as usize, height as usize);
+    let chroma_width = subsampling.chroma_width(w);
+    let chroma_height = subsampling.chroma_height(h);
+    let mut y_plane = Vec::with_capacity(w * h);
+    for y in 0..h {
+        for x in 0..w {
+            y_plane.push(luma(x, y));
+        }
+    }
+    let mut u_plane = Vec::with_capacity(chroma_width * chroma_height);
+    let mut v_plane = Vec::with_capacity(chroma_width * chroma_height);
+    for cy in 0..chroma_height {
+        for cx in 0..chroma_width {
+            let (u, v) = chroma(cx, cy);
+            u_plane.push(u);
+            v_plane.push(v);
+        }
+    }
+    ReferenceYuv {
+        y_plane,
+        u_plane,
+        v_plane,
+        width,
+        height,
+        chroma_width: chroma_width as u32,
+        chroma_height: chroma_height as u32,
+    }
+}
+
+/// Reference f64 bit depth conversion of planar YUV.
+///
+/// Samples are scaled by power of two as BT.2100 defines different bit depths of the same signal,
+/// so limited range stays limited range. Widening fixed point converters match the reference exactly,
+/// narrowing ones are within 0.5 LSB when rounding and within 1 LSB when truncating or dithering.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image, `u8` or `u16`.
+/// * `subsampling` - Chroma subsampling of the image.
+/// * `depth` - Storage of source samples, see [YuvBitDepth] for more info.
+/// * `dst_bit_depth` - Target bit-depth.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or any bit depth is not in 8..=16.
+pub fn reference_convert_depth<V: ReferenceSample>(
+    image: &YuvPlanarImage<V>,
+    subsampling: YuvChromaSubsampling,
+    depth: YuvBitDepth,
+    dst_bit_depth: u32,
+) -> Result<ReferenceYuv, YuvError> {
+    image.check_constraints(subsampling)?;
+    check_bit_depth(depth.bit_depth, 8..=16)?;
+    check_bit_depth(dst_bit_depth, 8..=16)?;
+    let scale = 2f64.powi(dst_bit_depth as i32 - depth.bit_depth as i32);
+    Ok(map_planes(
+        image.width,
+        image.height,
+        subsampling,
+        |x, y| image.y_plane[y * image.y_stride as usize + x].to_reference(depth) * scale,
+        |x, y| {
+            let u = image.u_plane[y * image.u_stride as usize + x].to_reference(depth);
+            let v = image.v_plane[y * image.v_stride as usize + x].to_reference(depth);
+            (u * scale, v * scale)
+        },
+    ))
+}
+
+/// Reference f64 conversion of planar YUV between matrices and ranges.
+///
+/// Samples are decoded to unclamped RGB with source matrix and range and encoded back
+/// with target ones. When chroma is subsampled, luma takes chroma of its block, and chroma
+/// takes the mean luma of the block it covers, as the fixed point converters do.
+/// Fixed point converters stay within 1 LSB of this reference.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image, `u8` or `u16`.
+/// * `subsampling` - Chroma subsampling of the image.
+/// * `depth` - Storage of YUV samples, see [YuvBitDepth] for more info.
+/// * `src_range` - The YUV range (limited or full) of source image.
+/// * `src_matrix` - The YUV standard matrix of source image.
+/// * `dst_range` - The YUV range (limited or full) of target image.
+/// * `dst_matrix` - The YUV standard matrix of target image.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn reference_convert_matrix_range<V: ReferenceSample>(
+    image: &YuvPlanarImage<V>,
+    subsampling: YuvChromaSubsampling,
+    depth: YuvBitDepth,
+    src_range: YuvRange,
+    src_matrix: YuvStandardMatrix,
+    dst_range: YuvRange,
+    dst_matrix: YuvStandardMatrix,
+) -> Result<ReferenceYuv, YuvError> {
+    image.check_constraints(subsampling)?;
+    let source = ReferenceModel::new(depth.bit_depth, depth.bit_depth, src_range, src_matrix)?;
+    let target = ReferenceModel::new(depth.bit_depth, depth.bit_depth, dst_range, dst_matrix)?;
+    let (w, h) = (image.width as usize, image.height as usize);
+    let (hf, vf) = (
+        subsampling.horizontal_factor(),
+        subsampling.vertical_factor(),
+    );
+    let luma =
+        |x: usize, y: usize| image.y_plane[y * image.y_stride as usize + x].to_reference(depth);
+    let chroma = |cx: usize, cy: usize| {
+        (
+            image.u_plane[cy * image.u_stride as usize + cx].to_reference(depth),
+            image.v_plane[cy * image.v_stride as usize + cx].to_reference(depth),
+        )
+    };
+    let convert =
+        |y: f64, (u, v): (f64, f64)| target.encode_normalized(source.decode_normalized(y, u, v));
+    Ok(map_planes(
+        image.width,
+        image.height,
+        subsampling,
+        |x, y| convert(luma(x, y), chroma(x / hf, y / vf))[0],
+        |cx, cy| {
+            let mut sum = 0f64;
+            let mut count = 0f64;
+            for y in cy * vf..((cy + 1) * vf).min(h) {
+                for x in cx * hf..((cx + 1) * hf).min(w) {
+                    sum += luma(x, y);
+                    count += 1.;
+                }
+            }
+            let [_, u, v] = convert(sum / count, chroma(cx, cy));
+            (u, v)
+        },
+    ))
+}
+
+impl ReferenceRgb {
+    /// Measures error of RGB or RGBA conversion result against the reference.
+    ///
+    /// # Arguments
+    ///
+    /// * `actual` - Converted RGB or RGBA data, `u8` or `u16`.
+    /// * `stride` - The stride (components per row) for the converted data.
+    /// * `channels` - 3 for RGB, 4 for RGBA, alpha isn't compared.
+    /// * `depth` - Storage of converted samples.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if `channels` is not 3 or 4, or the length of the converted
+    /// data is not valid based on the reference size and the specified stride.
+    pub fn error<V: ReferenceSample>(
+        &self,
+        actual: &[V],
+        stride: u32,
+        channels: usize,
+        depth: YuvBitDepth,
+    ) -> Result<ReferenceError, YuvError> {
+        check_channels(channels)?;
+        check_rgba_destination(actual, stride, self.width, self.height, channels)?;
+        let width = self.width as usize;
+        Ok(ReferenceError::measure(
+            self.data
+                .chunks_exact(width * 3)
+                .zip(actual.chunks(stride as usize))
+                .flat_map(|(reference, actual)| {
+                    reference
+                        .chunks_exact(3)
+                        .zip(actual.chunks_exact(channels))
+                        .flat_map(move |(reference, actual)| {
+                            (0..3).map(move |c| (reference[c], actual[c].to_reference(depth)))
+                        })
+                }),
+        ))
+    }
+}
+
+fn plane_error<V: ReferenceSample>(
+    reference: &[f64],
+    actual: &[V],
+    stride: u32,
+    width: usize,
+    depth: YuvBitDepth,
+    step: usize,
+    offset: usize,
+) -> ReferenceError {
+    ReferenceError::measure(
+        reference
+            .chunks_exact(width)
+            .zip(actual.chunks(stride as usize))
+            .flat_map(|(reference, actual)| {
+                reference
+                    .iter()
+                    .zip(actual[offset..].iter().step_by(step))
+                    .map(|(&reference, &actual)| (reference, actual.to_reference(depth)))
+            }),
+    )
+}
+
+impl ReferenceYuv {
+    /// Measures error of planar YUV conversion result against the reference, returns errors of Y, U, V planes.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the image size differs from the reference or the lengths
+    /// of the planes are not valid based on the specified width, height, and strides.
+    pub fn error_planar<V: ReferenceSample>(
+        &self,
+        actual: &YuvPlanarImage<V>,
+        subsampling: YuvChromaSubsampling,
+        depth: YuvBitDepth,
+    ) -> Result<[ReferenceError; 3], YuvError> {
+        self.check_image(actual.width, actual.height)?;
+        actual.check_constraints(subsampling)?;
+        let (w, cw) = (self.width as usize, self.chroma_width as usize);
+        Ok([
+            plane_error(
+                &self.y_plane,
+                actual.y_plane,
+                actual.y_stride,
+                w,
+                depth,
+                1,
+                0,
+            ),
+            plane_error(
+                &self.u_plane,
+                actual.u_plane,
+                actual.u_stride,
+                cw,
+                depth,
+                1,
+                0,
+            ),
+            plane_error(
+                &self.v_plane,
+                actual.v_plane,
+                actual.v_stride,
+                cw,
+                depth,
+                1,
+                0,
+            ),
+        ])
+    }
+
+    /// Measures error of bi-planar YUV with U stored first against the reference, returns errors of Y, U, V planes.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the image size differs from the reference or the lengths
+    /// of the planes are not valid based on the specified width, height, and strides.
+    pub fn error_bi_planar<V: ReferenceSample>(
+        &self,
+        actual: &YuvBiPlanarImage<V>,
+        subsampling: YuvChromaSubsampling,
+        depth: YuvBitDepth,
+    ) -> Result<[ReferenceError; 3], YuvError> {
+        self.check_image(actual.width, actual.height)?;
+        actual.check_constraints(subsampling)?;
+        let (w, cw) = (self.width as usize, self.chroma_width as usize);
+        Ok([
+            plane_error(
+                &self.y_plane,
+                actual.y_plane,
+                actual.y_stride,
+                w,
+                depth,
+                1,
+                0,
+            ),
+            plane_error(
+                &self.u_plane,
+                actual.uv_plane,
+                actual.uv_stride,
+                cw,
+                depth,
+                2,
+                0,
+            ),
+            plane_error(
+                &self.v_plane,
+                actual.uv_plane,
+                actual.uv_stride,
+                cw,
+                depth,
+                2,
+                1,
+            ),
+        ])
+    }
+
+    fn check_image(&self, width: u32, height: u32) -> Result<(), YuvError> {
+        if self.width != width || self.height != height {
+            return Err(YuvError::ImagesSizesNotMatch);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        convert_planar_depth_16_to_16, rgb_to_yuv420, yuv420_convert_matrix_range, yuv420_to_rgb,
+        yuyv422_to_rgb, YuvConversionMode, YuvDitherMode, YuvPlanarImageMut,
+    };
+
+    /// Documented deviation of 8 bit-depth decoder selected at runtime
+    fn decoder_bound() -> f64 {
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        if std::arch::is_x86_feature_detected!("avx2") {
+            return 3.;
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            return 3.;
+        }
+        1.
+    }
+
+    #[test]
+    fn fixed_point_paths_match_reference() {
+        let (width, height) = (67u32, 41u32);
+        let rgb: Vec<u8> = (0..width * height * 3)
+            .map(|i| ((i * 29 + i / 7) % 256) as u8)
+            .collect();
+        let depth = YuvBitDepth::lsb(8);
+
+        let mut planar =
+            YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        rgb_to_yuv420(
+            &mut planar,
+            &rgb,
+            width * 3,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+            YuvConversionMode::Balanced,
+        )
+        .unwrap();
+        let encoded = reference_rgb_to_yuv(
+            &rgb,
+            width * 3,
+            3,
+            width,
+            height,
+            depth,
+            YuvChromaSubsampling::Yuv420,
+            8,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        let planar = planar.to_fixed();
+        let [y_error, u_error, v_error] = encoded
+            .error_planar(&planar, YuvChromaSubsampling::Yuv420, depth)
+            .unwrap();
+        assert!(y_error.max <= 1., "Y error {:?}", y_error);
+        assert!(u_error.merge(&v_error).max <= 1., "UV error {:?}", u_error);
+
+        let decoded = reference_yuv_to_rgb(
+            &planar,
+            YuvChromaSubsampling::Yuv420,
+            depth,
+            8,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        let mut rgb = vec![0u8; rgb.len()];
+        yuv420_to_rgb(
+            &planar,
+            &mut rgb,
+            width * 3,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        let error = decoded.error(&rgb, width * 3, 3, depth).unwrap();
+        assert_eq!(error.samples, (width * height * 3) as usize);
+        assert!(error.max <= decoder_bound(), "RGB error {:?}", error);
+        assert!(error.mean < 1., "RGB error {:?}", error);
+    }
+
+    #[test]
+    fn packed_decoder_matches_reference() {
+        let (width, height) = (37u32, 9u32);
+        let stride = width.div_ceil(2) * 4;
+        let yuy: Vec<u8> = (0..stride * height)
+            .map(|i| (16 + (i * 37 + i / 5) % 220) as u8)
+            .collect();
+        let packed = YuvPackedImage {
+            yuy: &yuy,
+            yuy_stride: stride,
+            width,
+            height,
+        };
+        let reference = reference_yuv_packed_to_rgb(
+            &packed,
+            Yuy2Description::YUYV,
+            YuvBitDepth::lsb(8),
+            8,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        let mut rgb = vec![0u8; (width * height * 3) as usize];
+        yuyv422_to_rgb(
+            &packed,
+            &mut rgb,
+            width * 3,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        let error = reference
+            .error(&rgb, width * 3, 3, YuvBitDepth::lsb(8))
+            .unwrap();
+        assert!(error.max <= decoder_bound(), "RGB error {:?}", error);
+        assert!(error.mean < 1., "RGB error {:?}", error);
+    }
+
+    #[test]
+    fn depth_conversion_matches_reference() {
+        let (width, height) = (29u32, 7u32);
+        let subsampling = YuvChromaSubsampling::Yuv420;
+        let mut source = YuvPlanarImageMut::<u16>::alloc(width, height, subsampling);
+        for (i, v) in source.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (64 + i * 53 % 876) as u16;
+        }
+        for (i, v) in source.u_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (64 + i * 71 % 896) as u16;
+        }
+        for (i, v) in source.v_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (64 + i * 89 % 896) as u16;
+        }
+        let source = source.to_fixed();
+
+        let convert = |src_depth: YuvBitDepth, dst_depth: YuvBitDepth| {
+            let mut target = YuvPlanarImageMut::<u16>::alloc(width, height, subsampling);
+            convert_planar_depth_16_to_16(
+                &source,
+                src_depth,
+                &mut target,
+                dst_depth,
+                subsampling,
+                YuvDitherMode::Round,
+            )
+            .unwrap();
+            let reference =
+                reference_convert_depth(&source, subsampling, src_depth, dst_depth.bit_depth)
+                    .unwrap();
+            let errors = reference
+                .error_planar(&target.to_fixed(), subsampling, dst_depth)
+                .unwrap();
+            errors[0].merge(&errors[1]).merge(&errors[2])
+        };
+
+        let widening = convert(YuvBitDepth::lsb(10), YuvBitDepth::lsb(12));
+        assert_eq!(widening.max, 0., "10 -> 12 error {:?}", widening);
+        let narrowing = convert(YuvBitDepth::lsb(10), YuvBitDepth::lsb(8));
+        assert!(narrowing.max <= 0.5, "10 -> 8 error {:?}", narrowing);
+    }
+
+    #[test]
+    fn matrix_range_conversion_matches_reference() {
+        let (width, height) = (31u32, 11u32);
+        let subsampling = YuvChromaSubsampling::Yuv420;
+        let mut source = YuvPlanarImageMut::<u8>::alloc(width, height, subsampling);
+        for (i, v) in source.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (16 + i * 13 % 220) as u8;
+        }
+        for (i, v) in source.u_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (16 + i * 23 % 225) as u8;
+        }
+        for (i, v) in source.v_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (16 + i * 31 % 225) as u8;
+        }
+        let source = source.to_fixed();
+        let depth = YuvBitDepth::lsb(8);
+
+        let mut target = YuvPlanarImageMut::<u8>::alloc(width, height, subsampling);
+        yuv420_convert_matrix_range(
+            &source,
+            &mut target,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        let reference = reference_convert_matrix_range(
+            &source,
+            subsampling,
+            depth,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        let [y_error, u_error, v_error] = reference
+            .error_planar(&target.to_fixed(), subsampling, depth)
+            .unwrap();
+        assert!(y_error.max <= 1., "Y error {:?}", y_error);
+        assert!(u_error.merge(&v_error).max <= 1., "UV error {:?}", u_error);
+    }
+
+    #[test]
+    fn rejects_unsupported_arguments() {
+        let rgb = vec![0u8; 4 * 2 * 3];
+        let convert = |channels: usize, yuv_bit_depth: u32| {
+            reference_rgb_to_yuv(
+                &rgb,
+                4 * 3,
+                channels,
+                4,
+                2,
+                YuvBitDepth::lsb(8),
+                YuvChromaSubsampling::Yuv420,
+                yuv_bit_depth,
+                YuvRange::Limited,
+                YuvStandardMatrix::Bt709,
+            )
+        };
+        assert!(convert(3, 10).is_ok());
+        assert!(matches!(
+            convert(2, 10),
+            Err(YuvError::UnsupportedChannelsCount(2))
+        ));
+        assert!(matches!(
+            convert(3, 17),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+    }
+}
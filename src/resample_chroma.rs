@@ -0,0 +1,493 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::scale::{
+    build_weights, chroma_phase, max_value, scale_plane_impl, PlaneGeometry, ScaleSample,
+};
+use crate::yuv_support::YuvChromaSubsampling;
+use crate::{
+    ScalingFilter, YuvBiPlanarImage, YuvBiPlanarImageMut, YuvChromaLocation, YuvError,
+    YuvPlanarImage, YuvPlanarImageMut,
+};
+
+/// Picks filter for one axis, unchanged axis always maps samples one to one
+fn axis_filter(
+    src_factor: usize,
+    dst_factor: usize,
+    up_filter: ScalingFilter,
+    down_filter: ScalingFilter,
+) -> ScalingFilter {
+    match dst_factor.cmp(&src_factor) {
+        std::cmp::Ordering::Less => up_filter,
+        std::cmp::Ordering::Equal => ScalingFilter::Nearest,
+        std::cmp::Ordering::Greater => down_filter,
+    }
+}
+
+fn copy_luma<T: Copy>(
+    src: &[T],
+    src_stride: u32,
+    dst: &mut [T],
+    dst_stride: u32,
+    width: usize,
+    height: usize,
+) {
+    for (dst, src) in dst
+        .chunks_mut(dst_stride as usize)
+        .zip(src.chunks(src_stride as usize))
+        .take(height)
+    {
+        dst[..width].copy_from_slice(&src[..width]);
+    }
+}
+
+/// Resamples chroma plane with interleaved `channels` from one subsampling to another
+fn resample_chroma_plane<T: ScaleSample>(
+    src: &[T],
+    src_stride: u32,
+    dst: &mut [T],
+    dst_stride: u32,
+    channels: usize,
+    size: (usize, usize),
+    subsampling: (YuvChromaSubsampling, YuvChromaSubsampling),
+    up_filter: ScalingFilter,
+    down_filter: ScalingFilter,
+    location: YuvChromaLocation,
+    max: i32,
+) {
+    let (src_subsampling, dst_subsampling) = subsampling;
+    let (width, height) = size;
+    let src_geometry = PlaneGeometry {
+        stride: src_stride as usize,
+        width: src_subsampling.chroma_width(width),
+        height: src_subsampling.chroma_height(height),
+    };
+    let dst_geometry = PlaneGeometry {
+        stride: dst_stride as usize,
+        width: dst_subsampling.chroma_width(width),
+        height: dst_subsampling.chroma_height(height),
+    };
+    let src_phase = chroma_phase(src_subsampling, location);
+    let dst_phase = chroma_phase(dst_subsampling, location);
+    let horizontal = build_weights(
+        axis_filter(
+            src_subsampling.horizontal_factor(),
+            dst_subsampling.horizontal_factor(),
+            up_filter,
+            down_filter,
+        ),
+        src_geometry.width,
+        dst_geometry.width,
+        width,
+        width,
+        (src_subsampling.horizontal_factor(), src_phase.0),
+        (dst_subsampling.horizontal_factor(), dst_phase.0),
+    );
+    let vertical = build_weights(
+        axis_filter(
+            src_subsampling.vertical_factor(),
+            dst_subsampling.vertical_factor(),
+            up_filter,
+            down_filter,
+        ),
+        src_geometry.height,
+        dst_geometry.height,
+        height,
+        height,
+        (src_subsampling.vertical_factor(), src_phase.1),
+        (dst_subsampling.vertical_factor(), dst_phase.1),
+    );
+    scale_plane_impl(
+        src,
+        src_geometry,
+        dst,
+        dst_geometry,
+        channels,
+        &horizontal,
+        &vertical,
+        max,
+    );
+}
+
+fn resample_chroma_planar_impl<T: ScaleSample>(
+    src: &YuvPlanarImage<T>,
+    src_subsampling: YuvChromaSubsampling,
+    dst: &mut YuvPlanarImageMut<T>,
+    dst_subsampling: YuvChromaSubsampling,
+    up_filter: ScalingFilter,
+    down_filter: ScalingFilter,
+    location: YuvChromaLocation,
+    max: i32,
+) -> Result<(), YuvError> {
+    src.check_constraints(src_subsampling)?;
+    dst.check_constraints(dst_subsampling)?;
+    if src.width != dst.width || src.height != dst.height {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+    let size = (src.width as usize, src.height as usize);
+    copy_luma(
+        src.y_plane,
+        src.y_stride,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride,
+        size.0,
+        size.1,
+    );
+    resample_chroma_plane(
+        src.u_plane,
+        src.u_stride,
+        dst.u_plane.borrow_mut(),
+        dst.u_stride,
+        1,
+        size,
+        (src_subsampling, dst_subsampling),
+        up_filter,
+        down_filter,
+        location,
+        max,
+    );
+    resample_chroma_plane(
+        src.v_plane,
+        src.v_stride,
+        dst.v_plane.borrow_mut(),
+        dst.v_stride,
+        1,
+        size,
+        (src_subsampling, dst_subsampling),
+        up_filter,
+        down_filter,
+        location,
+        max,
+    );
+    Ok(())
+}
+
+fn resample_chroma_bi_planar_impl<T: ScaleSample>(
+    src: &YuvBiPlanarImage<T>,
+    src_subsampling: YuvChromaSubsampling,
+    dst: &mut YuvBiPlanarImageMut<T>,
+    dst_subsampling: YuvChromaSubsampling,
+    up_filter: ScalingFilter,
+    down_filter: ScalingFilter,
+    location: YuvChromaLocation,
+    max: i32,
+) -> Result<(), YuvError> {
+    src.check_constraints(src_subsampling)?;
+    dst.check_constraints(dst_subsampling)?;
+    if src.width != dst.width || src.height != dst.height {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+    let size = (src.width as usize, src.height as usize);
+    copy_luma(
+        src.y_plane,
+        src.y_stride,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride,
+        size.0,
+        size.1,
+    );
+    resample_chroma_plane(
+        src.uv_plane,
+        src.uv_stride,
+        dst.uv_plane.borrow_mut(),
+        dst.uv_stride,
+        2,
+        size,
+        (src_subsampling, dst_subsampling),
+        up_filter,
+        down_filter,
+        location,
+        max,
+    );
+    Ok(())
+}
+
+/// Converts 8 bit-depth planar YUV image between chroma subsamplings, e.g. I422 to I420 or I420 to I444.
+///
+/// Luma is copied as is, chroma is resampled taking its siting into account.
+/// Axes where subsampling doesn't change are copied without filtering.
+///
+/// # Arguments
+///
+/// * `src` - Source planar image.
+/// * `src_subsampling` - Chroma subsampling of source image.
+/// * `dst` - Destination planar image, must have the same size as source.
+/// * `dst_subsampling` - Chroma subsampling of destination image.
+/// * `up_filter` - Filter used on axes where chroma resolution increases.
+/// * `down_filter` - Filter used on axes where chroma resolution decreases.
+/// * `location` - Siting of chroma samples, see [YuvChromaLocation] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn resample_chroma_planar(
+    src: &YuvPlanarImage<u8>,
+    src_subsampling: YuvChromaSubsampling,
+    dst: &mut YuvPlanarImageMut<u8>,
+    dst_subsampling: YuvChromaSubsampling,
+    up_filter: ScalingFilter,
+    down_filter: ScalingFilter,
+    location: YuvChromaLocation,
+) -> Result<(), YuvError> {
+    resample_chroma_planar_impl(
+        src,
+        src_subsampling,
+        dst,
+        dst_subsampling,
+        up_filter,
+        down_filter,
+        location,
+        255,
+    )
+}
+
+/// Converts high bit-depth planar YUV image between chroma subsamplings, e.g. I210 to I010.
+///
+/// Luma is copied as is, chroma is resampled taking its siting into account.
+/// Samples are expected in host endianness, for MSB packed formats pass 16 as `bit_depth`.
+///
+/// # Arguments
+///
+/// * `src` - Source planar image.
+/// * `src_subsampling` - Chroma subsampling of source image.
+/// * `dst` - Destination planar image, must have the same size as source.
+/// * `dst_subsampling` - Chroma subsampling of destination image.
+/// * `bit_depth` - Bit-depth of samples, 8..=16.
+/// * `up_filter` - Filter used on axes where chroma resolution increases.
+/// * `down_filter` - Filter used on axes where chroma resolution decreases.
+/// * `location` - Siting of chroma samples, see [YuvChromaLocation] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn resample_chroma_planar_p16(
+    src: &YuvPlanarImage<u16>,
+    src_subsampling: YuvChromaSubsampling,
+    dst: &mut YuvPlanarImageMut<u16>,
+    dst_subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    up_filter: ScalingFilter,
+    down_filter: ScalingFilter,
+    location: YuvChromaLocation,
+) -> Result<(), YuvError> {
+    let max = max_value(bit_depth)?;
+    resample_chroma_planar_impl(
+        src,
+        src_subsampling,
+        dst,
+        dst_subsampling,
+        up_filter,
+        down_filter,
+        location,
+        max,
+    )
+}
+
+/// Converts 8 bit-depth bi-planar YUV image between chroma subsamplings, e.g. NV16 to NV12 or NV12 to NV24.
+///
+/// Luma is copied as is, chroma is resampled taking its siting into account.
+/// Axes where subsampling doesn't change are copied without filtering.
+///
+/// # Arguments
+///
+/// * `src` - Source bi-planar image.
+/// * `src_subsampling` - Chroma subsampling of source image.
+/// * `dst` - Destination bi-planar image, must have the same size as source.
+/// * `dst_subsampling` - Chroma subsampling of destination image.
+/// * `up_filter` - Filter used on axes where chroma resolution increases.
+/// * `down_filter` - Filter used on axes where chroma resolution decreases.
+/// * `location` - Siting of chroma samples, see [YuvChromaLocation] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn resample_chroma_bi_planar(
+    src: &YuvBiPlanarImage<u8>,
+    src_subsampling: YuvChromaSubsampling,
+    dst: &mut YuvBiPlanarImageMut<u8>,
+    dst_subsampling: YuvChromaSubsampling,
+    up_filter: ScalingFilter,
+    down_filter: ScalingFilter,
+    location: YuvChromaLocation,
+) -> Result<(), YuvError> {
+    resample_chroma_bi_planar_impl(
+        src,
+        src_subsampling,
+        dst,
+        dst_subsampling,
+        up_filter,
+        down_filter,
+        location,
+        255,
+    )
+}
+
+/// Converts high bit-depth bi-planar YUV image between chroma subsamplings, e.g. P210 to P010.
+///
+/// Luma is copied as is, chroma is resampled taking its siting into account.
+/// Samples are expected in host endianness, for MSB packed formats as P010 pass 16 as `bit_depth`.
+///
+/// # Arguments
+///
+/// * `src` - Source bi-planar image.
+/// * `src_subsampling` - Chroma subsampling of source image.
+/// * `dst` - Destination bi-planar image, must have the same size as source.
+/// * `dst_subsampling` - Chroma subsampling of destination image.
+/// * `bit_depth` - Bit-depth of samples, 8..=16.
+/// * `up_filter` - Filter used on axes where chroma resolution increases.
+/// * `down_filter` - Filter used on axes where chroma resolution decreases.
+/// * `location` - Siting of chroma samples, see [YuvChromaLocation] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn resample_chroma_bi_planar_p16(
+    src: &YuvBiPlanarImage<u16>,
+    src_subsampling: YuvChromaSubsampling,
+    dst: &mut YuvBiPlanarImageMut<u16>,
+    dst_subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    up_filter: ScalingFilter,
+    down_filter: ScalingFilter,
+    location: YuvChromaLocation,
+) -> Result<(), YuvError> {
+    let max = max_value(bit_depth)?;
+    resample_chroma_bi_planar_impl(
+        src,
+        src_subsampling,
+        dst,
+        dst_subsampling,
+        up_filter,
+        down_filter,
+        location,
+        max,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resample_chroma_round_trip() {
+        let width = 15u32;
+        let height = 11u32;
+        let mut src = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv422);
+        for (i, v) in src.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = i as u8;
+        }
+        for (y, row) in src.u_plane.borrow_mut().chunks_exact_mut(8).enumerate() {
+            row.fill(y as u8 * 20);
+        }
+        for (x, v) in src.v_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (x % 8) as u8 * 30;
+        }
+
+        let mut i420 = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        resample_chroma_planar(
+            &src.to_fixed(),
+            YuvChromaSubsampling::Yuv422,
+            &mut i420,
+            YuvChromaSubsampling::Yuv420,
+            ScalingFilter::Bilinear,
+            ScalingFilter::Box,
+            YuvChromaLocation::Left,
+        )
+        .unwrap();
+        assert_eq!(i420.y_plane.borrow(), src.y_plane.borrow());
+        // Horizontal axis is untouched, vertical averages row pairs.
+        assert_eq!(&i420.v_plane.borrow()[..8], &src.v_plane.borrow()[..8]);
+        assert_eq!(i420.u_plane.borrow()[0], 10);
+        assert_eq!(i420.u_plane.borrow()[8], 50);
+
+        let mut i444 = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv444);
+        resample_chroma_planar(
+            &src.to_fixed(),
+            YuvChromaSubsampling::Yuv422,
+            &mut i444,
+            YuvChromaSubsampling::Yuv444,
+            ScalingFilter::Bilinear,
+            ScalingFilter::Box,
+            YuvChromaLocation::Left,
+        )
+        .unwrap();
+        let mut back = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv422);
+        resample_chroma_planar(
+            &i444.to_fixed(),
+            YuvChromaSubsampling::Yuv444,
+            &mut back,
+            YuvChromaSubsampling::Yuv422,
+            ScalingFilter::Bilinear,
+            ScalingFilter::Nearest,
+            YuvChromaLocation::Left,
+        )
+        .unwrap();
+        assert_eq!(back.u_plane.borrow(), src.u_plane.borrow());
+        assert_eq!(back.v_plane.borrow(), src.v_plane.borrow());
+    }
+
+    #[test]
+    fn rejects_unsupported_bit_depth() {
+        let src = YuvPlanarImageMut::<u16>::alloc(4, 4, YuvChromaSubsampling::Yuv422);
+        let mut dst = YuvPlanarImageMut::<u16>::alloc(4, 4, YuvChromaSubsampling::Yuv420);
+        for bit_depth in [7, 17] {
+            assert!(matches!(
+                resample_chroma_planar_p16(
+                    &src.to_fixed(),
+                    YuvChromaSubsampling::Yuv422,
+                    &mut dst,
+                    YuvChromaSubsampling::Yuv420,
+                    bit_depth,
+                    ScalingFilter::Bilinear,
+                    ScalingFilter::Box,
+                    YuvChromaLocation::Left,
+                ),
+                Err(YuvError::UnsupportedBitDepth(_))
+            ));
+        }
+        let src = YuvBiPlanarImageMut::<u16>::alloc(4, 4, YuvChromaSubsampling::Yuv422);
+        let mut dst = YuvBiPlanarImageMut::<u16>::alloc(4, 4, YuvChromaSubsampling::Yuv420);
+        assert!(matches!(
+            resample_chroma_bi_planar_p16(
+                &src.to_fixed(),
+                YuvChromaSubsampling::Yuv422,
+                &mut dst,
+                YuvChromaSubsampling::Yuv420,
+                17,
+                ScalingFilter::Bilinear,
+                ScalingFilter::Box,
+                YuvChromaLocation::Left,
+            ),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+    }
+}
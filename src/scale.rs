@@ -0,0 +1,1439 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::{check_bit_depth, check_rgba_destination};
+use crate::yuv_support::{YuvChromaSubsampling, Yuy2Description};
+use crate::{
+    YuvBiPlanarImage, YuvBiPlanarImageMut, YuvError, YuvGrayImage, YuvGrayImageMut, YuvPackedImage,
+    YuvPackedImageMut, YuvPlanarImage, YuvPlanarImageMut,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, IntoParallelRefIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+use std::fmt::Debug;
+
+/// Resampling filter used for scaling
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum ScalingFilter {
+    /// Picks nearest source sample
+    Nearest,
+    /// Linear interpolation, widens to a triangle filter on downscaling
+    #[default]
+    Bilinear,
+    /// Area averaging, best suited for integer downscaling
+    Box,
+    /// Lanczos windowed sinc with 3 lobes
+    Lanczos3,
+}
+
+/// Position of subsampled chroma samples relative to luma, as in H.273 chroma_sample_loc_type
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum YuvChromaLocation {
+    /// Horizontally co-sited with left luma sample, vertically centered (MPEG-2, H.264 default)
+    #[default]
+    Left,
+    /// Centered between luma samples in both directions (JPEG, MPEG-1)
+    Center,
+    /// Co-sited with top left luma sample (BT.2020, HEVC 4:2:0 UHD)
+    TopLeft,
+}
+
+const PRECISION: i32 = 14;
+
+/// Fixed point weights of separable pass, each destination sample
+/// reads `taps` consecutive source samples starting at `bounds[i]`
+pub(crate) struct ScaleWeights {
+    taps: usize,
+    bounds: Vec<usize>,
+    weights: Vec<i32>,
+}
+
+#[inline]
+fn sinc(x: f64) -> f64 {
+    if x == 0. {
+        1.
+    } else {
+        let x = x * std::f64::consts::PI;
+        x.sin() / x
+    }
+}
+
+impl ScalingFilter {
+    fn support(self) -> f64 {
+        match self {
+            ScalingFilter::Nearest | ScalingFilter::Box => 0.5,
+            ScalingFilter::Bilinear => 1.,
+            ScalingFilter::Lanczos3 => 3.,
+        }
+    }
+
+    fn kernel(self, x: f64) -> f64 {
+        match self {
+            ScalingFilter::Nearest | ScalingFilter::Box => {
+                if (-0.5..0.5).contains(&x) {
+                    1.
+                } else {
+                    0.
+                }
+            }
+            ScalingFilter::Bilinear => (1. - x.abs()).max(0.),
+            ScalingFilter::Lanczos3 => {
+                if x.abs() < 3. {
+                    sinc(x) * sinc(x / 3.)
+                } else {
+                    0.
+                }
+            }
+        }
+    }
+}
+
+/// Builds weights for one axis.
+///
+/// Sample `i` of a plane on grid `(factor, phase)` sits at `i * factor + phase` in luma
+/// coordinates, where luma sample `k` covers `[k, k + 1)`.
+/// `src_luma` and `dst_luma` are full resolution extents used to derive scale ratio.
+pub(crate) fn build_weights(
+    filter: ScalingFilter,
+    src_len: usize,
+    dst_len: usize,
+    src_luma: usize,
+    dst_luma: usize,
+    src_grid: (usize, f64),
+    dst_grid: (usize, f64),
+) -> ScaleWeights {
+    let ratio = src_luma as f64 / dst_luma as f64;
+    let (src_factor, src_phase) = (src_grid.0 as f64, src_grid.1);
+    let (dst_factor, dst_phase) = (dst_grid.0 as f64, dst_grid.1);
+    let last = src_len as isize - 1;
+
+    let mut rows: Vec<(usize, Vec<f64>)> = Vec::with_capacity(dst_len);
+    for i in 0..dst_len {
+        let center = ((i as f64 * dst_factor + dst_phase) * ratio - src_phase) / src_factor;
+        if filter == ScalingFilter::Nearest {
+            let idx = ((center + 0.5).floor() as isize).clamp(0, last) as usize;
+            rows.push((idx, vec![1.]));
+            continue;
+        }
+        let scale = (ratio * dst_factor / src_factor).max(1.);
+        let radius = filter.support() * scale;
+        let lo = (center - radius).floor() as isize;
+        let hi = (center + radius).ceil() as isize;
+        let start = lo.clamp(0, last);
+        let end = hi.clamp(0, last);
+        let mut taps = vec![0f64; (end - start + 1) as usize];
+        let mut sum = 0f64;
+        for j in lo..=hi {
+            let w = filter.kernel((j as f64 - center) / scale);
+            if w == 0. {
+                continue;
+            }
+            taps[(j.clamp(0, last) - start) as usize] += w;
+            sum += w;
+        }
+        if sum == 0. {
+            let idx = ((center + 0.5).floor() as isize).clamp(0, last) as usize;
+            rows.push((idx, vec![1.]));
+            continue;
+        }
+        taps.iter_mut().for_each(|w| *w /= sum);
+        rows.push((start as usize, taps));
+    }
+
+    let taps = rows
+        .iter()
+        .map(|x| x.1.len())
+        .max()
+        .unwrap_or(1)
+        .min(src_len);
+    let mut bounds = Vec::with_capacity(dst_len);
+    let mut weights = vec![0i32; dst_len * taps];
+
+    for ((start, row), dst) in rows.iter().zip(weights.chunks_exact_mut(taps)) {
+        let bound = (*start).min(src_len - taps);
+        let offset = start - bound;
+        let mut total = 0i32;
+        let mut largest = offset;
+        for (k, &w) in row.iter().enumerate() {
+            let q = (w * (1 << PRECISION) as f64).round() as i32;
+            dst[offset + k] = q;
+            total += q;
+            if q > dst[largest] {
+                largest = offset + k;
+            }
+        }
+        dst[largest] += (1 << PRECISION) - total;
+        bounds.push(bound);
+    }
+
+    ScaleWeights {
+        taps,
+        bounds,
+        weights,
+    }
+}
+
+/// Horizontal and vertical phases of chroma samples in luma coordinates
+pub(crate) fn chroma_phase(
+    subsampling: YuvChromaSubsampling,
+    location: YuvChromaLocation,
+) -> (f64, f64) {
+    let h_factor = subsampling.horizontal_factor() as f64;
+    let v_factor = subsampling.vertical_factor() as f64;
+    let h_phase = match location {
+        YuvChromaLocation::Center => h_factor / 2.,
+        YuvChromaLocation::Left | YuvChromaLocation::TopLeft => 0.5,
+    };
+    let v_phase = match location {
+        YuvChromaLocation::Center | YuvChromaLocation::Left => v_factor / 2.,
+        YuvChromaLocation::TopLeft => 0.5,
+    };
+    (h_phase, v_phase)
+}
+
+pub(crate) type VerticalRowHandler<T> = unsafe fn(&[T], usize, &[i32], &mut [T], i32) -> usize;
+
+pub(crate) type HorizontalRowHandler<T> =
+    unsafe fn(&[T], &mut [T], usize, &[usize], &[i32], usize, i32) -> usize;
+
+pub(crate) trait ScaleSample: Copy + Default + Debug + Send + Sync {
+    fn widen(self) -> i32;
+
+    fn narrow(v: i32) -> Self;
+
+    fn vertical_handler() -> VerticalRowHandler<Self>;
+
+    fn horizontal_handler() -> HorizontalRowHandler<Self>;
+}
+
+fn vertical_row_none<T>(_: &[T], _: usize, _: &[i32], _: &mut [T], _: i32) -> usize {
+    0
+}
+
+fn horizontal_row_none<T>(
+    _: &[T],
+    _: &mut [T],
+    _: usize,
+    _: &[usize],
+    _: &[i32],
+    _: usize,
+    _: i32,
+) -> usize {
+    0
+}
+
+impl ScaleSample for u8 {
+    #[inline(always)]
+    fn widen(self) -> i32 {
+        self as i32
+    }
+
+    #[inline(always)]
+    fn narrow(v: i32) -> Self {
+        v as u8
+    }
+
+    fn vertical_handler() -> VerticalRowHandler<u8> {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            use crate::neon::neon_scale_vertical_row_u8;
+            return neon_scale_vertical_row_u8;
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                use crate::avx2::avx2_scale_vertical_row_u8;
+                return avx2_scale_vertical_row_u8;
+            }
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.1") {
+                use crate::sse::sse_scale_vertical_row_u8;
+                return sse_scale_vertical_row_u8;
+            }
+        }
+        #[allow(unreachable_code)]
+        vertical_row_none
+    }
+
+    fn horizontal_handler() -> HorizontalRowHandler<u8> {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            use crate::neon::neon_scale_horizontal_row_u8;
+            return neon_scale_horizontal_row_u8;
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                use crate::avx2::avx2_scale_horizontal_row_u8;
+                return avx2_scale_horizontal_row_u8;
+            }
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.1") {
+                use crate::sse::sse_scale_horizontal_row_u8;
+                return sse_scale_horizontal_row_u8;
+            }
+        }
+        #[allow(unreachable_code)]
+        horizontal_row_none
+    }
+}
+
+impl ScaleSample for u16 {
+    #[inline(always)]
+    fn widen(self) -> i32 {
+        self as i32
+    }
+
+    #[inline(always)]
+    fn narrow(v: i32) -> Self {
+        v as u16
+    }
+
+    fn vertical_handler() -> VerticalRowHandler<u16> {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            use crate::neon::neon_scale_vertical_row_u16;
+            return neon_scale_vertical_row_u16;
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                use crate::avx2::avx2_scale_vertical_row_u16;
+                return avx2_scale_vertical_row_u16;
+            }
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.1") {
+                use crate::sse::sse_scale_vertical_row_u16;
+                return sse_scale_vertical_row_u16;
+            }
+        }
+        #[allow(unreachable_code)]
+        vertical_row_none
+    }
+
+    fn horizontal_handler() -> HorizontalRowHandler<u16> {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            use crate::neon::neon_scale_horizontal_row_u16;
+            return neon_scale_horizontal_row_u16;
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                use crate::avx2::avx2_scale_horizontal_row_u16;
+                return avx2_scale_horizontal_row_u16;
+            }
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.1") {
+                use crate::sse::sse_scale_horizontal_row_u16;
+                return sse_scale_horizontal_row_u16;
+            }
+        }
+        #[allow(unreachable_code)]
+        horizontal_row_none
+    }
+}
+
+/// Source or destination plane with interleaved `channels`
+pub(crate) struct PlaneGeometry {
+    pub(crate) stride: usize,
+    pub(crate) width: usize,
+    pub(crate) height: usize,
+}
+
+fn scale_horizontal_row<T: ScaleSample>(
+    handler: HorizontalRowHandler<T>,
+    src: &[T],
+    dst: &mut [T],
+    channels: usize,
+    weights: &ScaleWeights,
+    max: i32,
+) {
+    let taps = weights.taps;
+    let processed = unsafe {
+        handler(
+            src,
+            dst,
+            channels,
+            &weights.bounds,
+            &weights.weights,
+            taps,
+            max,
+        )
+    };
+    for (n, dst) in dst.iter_mut().enumerate().skip(processed) {
+        let (x, c) = (n / channels, n % channels);
+        let src = &src[weights.bounds[x] * channels + c..];
+        let mut acc = 1 << (PRECISION - 1);
+        for (src, &w) in src
+            .iter()
+            .step_by(channels)
+            .zip(weights.weights[x * taps..(x + 1) * taps].iter())
+        {
+            acc += src.widen() * w;
+        }
+        *dst = T::narrow((acc >> PRECISION).clamp(0, max));
+    }
+}
+
+/// Separable resampling of single plane with interleaved `channels`
+pub(crate) fn scale_plane_impl<T: ScaleSample>(
+    src: &[T],
+    src_geometry: PlaneGeometry,
+    dst: &mut [T],
+    dst_geometry: PlaneGeometry,
+    channels: usize,
+    horizontal: &ScaleWeights,
+    vertical: &ScaleWeights,
+    max: i32,
+) {
+    let tmp_stride = dst_geometry.width * channels;
+    let src_row_length = src_geometry.width * channels;
+    let mut tmp = vec![T::default(); tmp_stride * src_geometry.height];
+    let horizontal_handler = T::horizontal_handler();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = tmp
+            .par_chunks_exact_mut(tmp_stride)
+            .zip(src.par_chunks(src_geometry.stride));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = tmp
+            .chunks_exact_mut(tmp_stride)
+            .zip(src.chunks(src_geometry.stride));
+    }
+    iter.for_each(|(tmp, src)| {
+        scale_horizontal_row(
+            horizontal_handler,
+            &src[..src_row_length],
+            tmp,
+            channels,
+            horizontal,
+            max,
+        );
+    });
+
+    let handler = T::vertical_handler();
+    let taps = vertical.taps;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_geometry.stride)
+            .take(dst_geometry.height)
+            .zip(vertical.bounds.par_iter())
+            .zip(vertical.weights.par_chunks_exact(taps));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_geometry.stride)
+            .take(dst_geometry.height)
+            .zip(vertical.bounds.iter())
+            .zip(vertical.weights.chunks_exact(taps));
+    }
+    iter.for_each(|((dst, &bound), weights)| {
+        let dst = &mut dst[..tmp_stride];
+        let rows = &tmp[bound * tmp_stride..(bound + taps) * tmp_stride];
+        let processed = unsafe { handler(rows, tmp_stride, weights, dst, max) };
+        for (x, dst) in dst.iter_mut().enumerate().skip(processed) {
+            let mut acc = 1 << (PRECISION - 1);
+            for (row, &w) in rows.chunks_exact(tmp_stride).zip(weights.iter()) {
+                acc += row[x].widen() * w;
+            }
+            *dst = T::narrow((acc >> PRECISION).clamp(0, max));
+        }
+    });
+}
+
+/// Scales one plane subsampled by given factors, luma and RGB planes use factor 1 and phase 0.5
+fn scale_subsampled_plane<T: ScaleSample>(
+    src: &[T],
+    src_geometry: PlaneGeometry,
+    dst: &mut [T],
+    dst_geometry: PlaneGeometry,
+    channels: usize,
+    luma_size: ((usize, usize), (usize, usize)),
+    factors: (usize, usize),
+    phases: (f64, f64),
+    filter: ScalingFilter,
+    max: i32,
+) {
+    let ((src_luma_width, src_luma_height), (dst_luma_width, dst_luma_height)) = luma_size;
+    let horizontal = build_weights(
+        filter,
+        src_geometry.width,
+        dst_geometry.width,
+        src_luma_width,
+        dst_luma_width,
+        (factors.0, phases.0),
+        (factors.0, phases.0),
+    );
+    let vertical = build_weights(
+        filter,
+        src_geometry.height,
+        dst_geometry.height,
+        src_luma_height,
+        dst_luma_height,
+        (factors.1, phases.1),
+        (factors.1, phases.1),
+    );
+    scale_plane_impl(
+        src,
+        src_geometry,
+        dst,
+        dst_geometry,
+        channels,
+        &horizontal,
+        &vertical,
+        max,
+    );
+}
+
+fn scale_luma<T: ScaleSample>(
+    src: &[T],
+    src_stride: u32,
+    dst: &mut [T],
+    dst_stride: u32,
+    channels: usize,
+    src_size: (usize, usize),
+    dst_size: (usize, usize),
+    filter: ScalingFilter,
+    max: i32,
+) {
+    scale_subsampled_plane(
+        src,
+        PlaneGeometry {
+            stride: src_stride as usize,
+            width: src_size.0,
+            height: src_size.1,
+        },
+        dst,
+        PlaneGeometry {
+            stride: dst_stride as usize,
+            width: dst_size.0,
+            height: dst_size.1,
+        },
+        channels,
+        (src_size, dst_size),
+        (1, 1),
+        (0.5, 0.5),
+        filter,
+        max,
+    );
+}
+
+/// Resamples plane with interleaved `channels` from `src_size` to `dst_size`
+/// on centered sample grid, used to change chroma layout
+#[cfg(feature = "geometry")]
+pub(crate) fn resample_plane<T: ScaleSample>(
+    src: &[T],
+    src_stride: u32,
+    dst: &mut [T],
+    dst_stride: u32,
+    channels: usize,
+    src_size: (usize, usize),
+    dst_size: (usize, usize),
+    filter: ScalingFilter,
+    max: i32,
+) {
+    scale_luma(
+        src, src_stride, dst, dst_stride, channels, src_size, dst_size, filter, max,
+    );
+}
+
+fn scale_chroma<T: ScaleSample>(
+    src: &[T],
+    src_stride: u32,
+    dst: &mut [T],
+    dst_stride: u32,
+    channels: usize,
+    src_size: (usize, usize),
+    dst_size: (usize, usize),
+    subsampling: YuvChromaSubsampling,
+    location: YuvChromaLocation,
+    filter: ScalingFilter,
+    max: i32,
+) {
+    scale_subsampled_plane(
+        src,
+        PlaneGeometry {
+            stride: src_stride as usize,
+            width: subsampling.chroma_width(src_size.0),
+            height: subsampling.chroma_height(src_size.1),
+        },
+        dst,
+        PlaneGeometry {
+            stride: dst_stride as usize,
+            width: subsampling.chroma_width(dst_size.0),
+            height: subsampling.chroma_height(dst_size.1),
+        },
+        channels,
+        (src_size, dst_size),
+        (
+            subsampling.horizontal_factor(),
+            subsampling.vertical_factor(),
+        ),
+        chroma_phase(subsampling, location),
+        filter,
+        max,
+    );
+}
+
+/// Maximum sample value for bit-depth in 8..=16
+pub(crate) fn max_value(bit_depth: u32) -> Result<i32, YuvError> {
+    check_bit_depth(bit_depth, 8..=16)?;
+    Ok(((1u32 << bit_depth) - 1) as i32)
+}
+
+fn scale_gray_impl<T: ScaleSample>(
+    src: &YuvGrayImage<T>,
+    dst: &mut YuvGrayImageMut<T>,
+    filter: ScalingFilter,
+    max: i32,
+) -> Result<(), YuvError> {
+    src.check_constraints()?;
+    dst.check_constraints()?;
+    scale_luma(
+        src.y_plane,
+        src.y_stride,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride,
+        1,
+        (src.width as usize, src.height as usize),
+        (dst.width as usize, dst.height as usize),
+        filter,
+        max,
+    );
+    Ok(())
+}
+
+/// Scales 8 bit-depth gray image or any single plane to the size of destination.
+///
+/// # Arguments
+///
+/// * `src` - Source image.
+/// * `dst` - Destination image, its width and height define target size.
+/// * `filter` - Resampling filter, see [ScalingFilter] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn scale_yuv_gray(
+    src: &YuvGrayImage<u8>,
+    dst: &mut YuvGrayImageMut<u8>,
+    filter: ScalingFilter,
+) -> Result<(), YuvError> {
+    scale_gray_impl(src, dst, filter, 255)
+}
+
+/// Scales high bit-depth gray image or any single plane to the size of destination.
+///
+/// Samples are expected in host endianness, for MSB packed formats pass 16 as `bit_depth`.
+///
+/// # Arguments
+///
+/// * `src` - Source image.
+/// * `dst` - Destination image, its width and height define target size.
+/// * `bit_depth` - Bit-depth of samples, 8..=16.
+/// * `filter` - Resampling filter, see [ScalingFilter] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn scale_yuv_gray_p16(
+    src: &YuvGrayImage<u16>,
+    dst: &mut YuvGrayImageMut<u16>,
+    bit_depth: u32,
+    filter: ScalingFilter,
+) -> Result<(), YuvError> {
+    let max = max_value(bit_depth)?;
+    scale_gray_impl(src, dst, filter, max)
+}
+
+fn scale_planar_impl<T: ScaleSample>(
+    src: &YuvPlanarImage<T>,
+    dst: &mut YuvPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    filter: ScalingFilter,
+    location: YuvChromaLocation,
+    max: i32,
+) -> Result<(), YuvError> {
+    src.check_constraints(subsampling)?;
+    dst.check_constraints(subsampling)?;
+    let src_size = (src.width as usize, src.height as usize);
+    let dst_size = (dst.width as usize, dst.height as usize);
+    scale_luma(
+        src.y_plane,
+        src.y_stride,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride,
+        1,
+        src_size,
+        dst_size,
+        filter,
+        max,
+    );
+    scale_chroma(
+        src.u_plane,
+        src.u_stride,
+        dst.u_plane.borrow_mut(),
+        dst.u_stride,
+        1,
+        src_size,
+        dst_size,
+        subsampling,
+        location,
+        filter,
+        max,
+    );
+    scale_chroma(
+        src.v_plane,
+        src.v_stride,
+        dst.v_plane.borrow_mut(),
+        dst.v_stride,
+        1,
+        src_size,
+        dst_size,
+        subsampling,
+        location,
+        filter,
+        max,
+    );
+    Ok(())
+}
+
+/// Scales 8 bit-depth planar YUV image to the size of destination.
+///
+/// # Arguments
+///
+/// * `src` - Source planar image.
+/// * `dst` - Destination planar image, its width and height define target size.
+/// * `subsampling` - Chroma subsampling of both images.
+/// * `filter` - Resampling filter, see [ScalingFilter] for more info.
+/// * `location` - Siting of chroma samples, see [YuvChromaLocation] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn scale_yuv_planar(
+    src: &YuvPlanarImage<u8>,
+    dst: &mut YuvPlanarImageMut<u8>,
+    subsampling: YuvChromaSubsampling,
+    filter: ScalingFilter,
+    location: YuvChromaLocation,
+) -> Result<(), YuvError> {
+    scale_planar_impl(src, dst, subsampling, filter, location, 255)
+}
+
+/// Scales high bit-depth planar YUV image to the size of destination.
+///
+/// Samples are expected in host endianness, for MSB packed formats pass 16 as `bit_depth`.
+///
+/// # Arguments
+///
+/// * `src` - Source planar image.
+/// * `dst` - Destination planar image, its width and height define target size.
+/// * `subsampling` - Chroma subsampling of both images.
+/// * `bit_depth` - Bit-depth of samples, 8..=16.
+/// * `filter` - Resampling filter, see [ScalingFilter] for more info.
+/// * `location` - Siting of chroma samples, see [YuvChromaLocation] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn scale_yuv_planar_p16(
+    src: &YuvPlanarImage<u16>,
+    dst: &mut YuvPlanarImageMut<u16>,
+    subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    filter: ScalingFilter,
+    location: YuvChromaLocation,
+) -> Result<(), YuvError> {
+    let max = max_value(bit_depth)?;
+    scale_planar_impl(src, dst, subsampling, filter, location, max)
+}
+
+fn scale_bi_planar_impl<T: ScaleSample>(
+    src: &YuvBiPlanarImage<T>,
+    dst: &mut YuvBiPlanarImageMut<T>,
+    subsampling: YuvChromaSubsampling,
+    filter: ScalingFilter,
+    location: YuvChromaLocation,
+    max: i32,
+) -> Result<(), YuvError> {
+    src.check_constraints(subsampling)?;
+    dst.check_constraints(subsampling)?;
+    let src_size = (src.width as usize, src.height as usize);
+    let dst_size = (dst.width as usize, dst.height as usize);
+    scale_luma(
+        src.y_plane,
+        src.y_stride,
+        dst.y_plane.borrow_mut(),
+        dst.y_stride,
+        1,
+        src_size,
+        dst_size,
+        filter,
+        max,
+    );
+    scale_chroma(
+        src.uv_plane,
+        src.uv_stride,
+        dst.uv_plane.borrow_mut(),
+        dst.uv_stride,
+        2,
+        src_size,
+        dst_size,
+        subsampling,
+        location,
+        filter,
+        max,
+    );
+    Ok(())
+}
+
+/// Scales 8 bit-depth bi-planar YUV image (NV12, NV16, NV24 etc.) to the size of destination.
+///
+/// # Arguments
+///
+/// * `src` - Source bi-planar image.
+/// * `dst` - Destination bi-planar image, its width and height define target size.
+/// * `subsampling` - Chroma subsampling of both images.
+/// * `filter` - Resampling filter, see [ScalingFilter] for more info.
+/// * `location` - Siting of chroma samples, see [YuvChromaLocation] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn scale_yuv_bi_planar(
+    src: &YuvBiPlanarImage<u8>,
+    dst: &mut YuvBiPlanarImageMut<u8>,
+    subsampling: YuvChromaSubsampling,
+    filter: ScalingFilter,
+    location: YuvChromaLocation,
+) -> Result<(), YuvError> {
+    scale_bi_planar_impl(src, dst, subsampling, filter, location, 255)
+}
+
+/// Scales high bit-depth bi-planar YUV image (P010, P210 etc.) to the size of destination.
+///
+/// Samples are expected in host endianness, for MSB packed formats as P010 pass 16 as `bit_depth`.
+///
+/// # Arguments
+///
+/// * `src` - Source bi-planar image.
+/// * `dst` - Destination bi-planar image, its width and height define target size.
+/// * `subsampling` - Chroma subsampling of both images.
+/// * `bit_depth` - Bit-depth of samples, 8..=16.
+/// * `filter` - Resampling filter, see [ScalingFilter] for more info.
+/// * `location` - Siting of chroma samples, see [YuvChromaLocation] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn scale_yuv_bi_planar_p16(
+    src: &YuvBiPlanarImage<u16>,
+    dst: &mut YuvBiPlanarImageMut<u16>,
+    subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    filter: ScalingFilter,
+    location: YuvChromaLocation,
+) -> Result<(), YuvError> {
+    let max = max_value(bit_depth)?;
+    scale_bi_planar_impl(src, dst, subsampling, filter, location, max)
+}
+
+/// Splits packed 4:2:2 image into Y, U, V planes
+fn unpack_yuy2<T: ScaleSample, const PACKING: usize>(
+    src: &YuvPackedImage<T>,
+) -> (Vec<T>, Vec<T>, Vec<T>) {
+    let packing: Yuy2Description = PACKING.into();
+    let width = src.width as usize;
+    let height = src.height as usize;
+    let chroma_width = width.div_ceil(2);
+    let mut y_plane = vec![T::default(); width * height];
+    let mut u_plane = vec![T::default(); chroma_width * height];
+    let mut v_plane = vec![T::default(); chroma_width * height];
+    for (((src, y), u), v) in src
+        .yuy
+        .chunks(src.yuy_stride as usize)
+        .zip(y_plane.chunks_exact_mut(width))
+        .zip(u_plane.chunks_exact_mut(chroma_width))
+        .zip(v_plane.chunks_exact_mut(chroma_width))
+    {
+        for (((src, y), u), v) in src
+            .chunks_exact(4)
+            .zip(y.chunks_mut(2))
+            .zip(u.iter_mut())
+            .zip(v.iter_mut())
+        {
+            y[0] = src[packing.get_first_y_position()];
+            if let Some(y1) = y.get_mut(1) {
+                *y1 = src[packing.get_second_y_position()];
+            }
+            *u = src[packing.get_u_position()];
+            *v = src[packing.get_v_position()];
+        }
+    }
+    (y_plane, u_plane, v_plane)
+}
+
+fn scale_yuy2_impl<T: ScaleSample, const PACKING: usize>(
+    src: &YuvPackedImage<T>,
+    dst: &mut YuvPackedImageMut<T>,
+    filter: ScalingFilter,
+    location: YuvChromaLocation,
+    max: i32,
+) -> Result<(), YuvError> {
+    src.check_constraints()?;
+    dst.check_constraints()?;
+    let packing: Yuy2Description = PACKING.into();
+    let subsampling = YuvChromaSubsampling::Yuv422;
+    let (y_plane, u_plane, v_plane) = unpack_yuy2::<T, PACKING>(src);
+
+    let src_size = (src.width as usize, src.height as usize);
+    let dst_size = (dst.width as usize, dst.height as usize);
+    let src_chroma_stride = subsampling.chroma_width(src_size.0) as u32;
+    let dst_chroma_width = subsampling.chroma_width(dst_size.0);
+
+    let mut dst_y = vec![T::default(); dst_size.0 * dst_size.1];
+    let mut dst_u = vec![T::default(); dst_chroma_width * dst_size.1];
+    let mut dst_v = vec![T::default(); dst_chroma_width * dst_size.1];
+
+    scale_luma(
+        &y_plane, src.width, &mut dst_y, dst.width, 1, src_size, dst_size, filter, max,
+    );
+    for (src, dst) in [(&u_plane, &mut dst_u), (&v_plane, &mut dst_v)] {
+        scale_chroma(
+            src,
+            src_chroma_stride,
+            dst,
+            dst_chroma_width as u32,
+            1,
+            src_size,
+            dst_size,
+            subsampling,
+            location,
+            filter,
+            max,
+        );
+    }
+
+    let dst_stride = dst.yuy_stride as usize;
+    for (((dst, y), u), v) in dst
+        .yuy
+        .borrow_mut()
+        .chunks_mut(dst_stride)
+        .zip(dst_y.chunks_exact(dst_size.0))
+        .zip(dst_u.chunks_exact(dst_chroma_width))
+        .zip(dst_v.chunks_exact(dst_chroma_width))
+    {
+        for (((dst, y), &u), &v) in dst
+            .chunks_exact_mut(4)
+            .zip(y.chunks(2))
+            .zip(u.iter())
+            .zip(v.iter())
+        {
+            dst[packing.get_first_y_position()] = y[0];
+            dst[packing.get_second_y_position()] = *y.get(1).unwrap_or(&y[0]);
+            dst[packing.get_u_position()] = u;
+            dst[packing.get_v_position()] = v;
+        }
+    }
+    Ok(())
+}
+
+macro_rules! define_scale_yuy2 {
+    ($method: ident, $method_p16: ident, $packing: expr, $name: expr) => {
+        #[doc = concat!("Scales 8 bit-depth packed ", $name, " image to the size of destination.
+
+# Arguments
+
+* `src` - Source packed image.
+* `dst` - Destination packed image, its width and height define target size.
+* `filter` - Resampling filter, see [ScalingFilter] for more info.
+* `location` - Horizontal siting of chroma samples, see [YuvChromaLocation] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the buffers are not valid based
+on the specified width, height, and strides.
+")]
+        pub fn $method(
+            src: &YuvPackedImage<u8>,
+            dst: &mut YuvPackedImageMut<u8>,
+            filter: ScalingFilter,
+            location: YuvChromaLocation,
+        ) -> Result<(), YuvError> {
+            scale_yuy2_impl::<u8, { $packing as usize }>(src, dst, filter, location, 255)
+        }
+
+        #[doc = concat!("Scales high bit-depth packed ", $name, " image to the size of destination.
+
+Samples are expected in host endianness, for MSB packed formats pass 16 as `bit_depth`.
+
+# Arguments
+
+* `src` - Source packed image.
+* `dst` - Destination packed image, its width and height define target size.
+* `bit_depth` - Bit-depth of samples, 8..=16.
+* `filter` - Resampling filter, see [ScalingFilter] for more info.
+* `location` - Horizontal siting of chroma samples, see [YuvChromaLocation] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the buffers are not valid based
+on the specified width, height, and strides, or bit depth is not in 8..=16.
+")]
+        pub fn $method_p16(
+            src: &YuvPackedImage<u16>,
+            dst: &mut YuvPackedImageMut<u16>,
+            bit_depth: u32,
+            filter: ScalingFilter,
+            location: YuvChromaLocation,
+        ) -> Result<(), YuvError> {
+            let max = max_value(bit_depth)?;
+            scale_yuy2_impl::<u16, { $packing as usize }>(src, dst, filter, location, max)
+        }
+    };
+}
+
+define_scale_yuy2!(
+    scale_yuyv422,
+    scale_yuyv422_p16,
+    Yuy2Description::YUYV,
+    "YUYV"
+);
+define_scale_yuy2!(
+    scale_uyvy422,
+    scale_uyvy422_p16,
+    Yuy2Description::UYVY,
+    "UYVY"
+);
+define_scale_yuy2!(
+    scale_yvyu422,
+    scale_yvyu422_p16,
+    Yuy2Description::YVYU,
+    "YVYU"
+);
+define_scale_yuy2!(
+    scale_vyuy422,
+    scale_vyuy422_p16,
+    Yuy2Description::VYUY,
+    "VYUY"
+);
+
+fn scale_interleaved_impl<T: ScaleSample>(
+    src: &[T],
+    src_stride: u32,
+    src_width: u32,
+    src_height: u32,
+    dst: &mut [T],
+    dst_stride: u32,
+    dst_width: u32,
+    dst_height: u32,
+    channels: usize,
+    filter: ScalingFilter,
+    max: i32,
+) -> Result<(), YuvError> {
+    check_rgba_destination(src, src_stride, src_width, src_height, channels)?;
+    check_rgba_destination(dst, dst_stride, dst_width, dst_height, channels)?;
+    scale_luma(
+        src,
+        src_stride,
+        dst,
+        dst_stride,
+        channels,
+        (src_width as usize, src_height as usize),
+        (dst_width as usize, dst_height as usize),
+        filter,
+        max,
+    );
+    Ok(())
+}
+
+macro_rules! define_scale_rgb {
+    ($method: ident, $channels: expr, $name: expr) => {
+        #[doc = concat!("Scales 8 bit-depth ", $name, " image to the destination size.
+
+Channels are filtered independently, for images with alpha consider scaling premultiplied data.
+
+# Arguments
+
+* `src` - Source ", $name, " data.
+* `src_stride` - Source stride (components per row).
+* `src_width` - Source width.
+* `src_height` - Source height.
+* `dst` - Destination ", $name, " data.
+* `dst_stride` - Destination stride (components per row).
+* `dst_width` - Destination width.
+* `dst_height` - Destination height.
+* `filter` - Resampling filter, see [ScalingFilter] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the buffers are not valid based
+on the specified width, height, and strides.
+")]
+        pub fn $method(
+            src: &[u8],
+            src_stride: u32,
+            src_width: u32,
+            src_height: u32,
+            dst: &mut [u8],
+            dst_stride: u32,
+            dst_width: u32,
+            dst_height: u32,
+            filter: ScalingFilter,
+        ) -> Result<(), YuvError> {
+            scale_interleaved_impl(
+                src, src_stride, src_width, src_height, dst, dst_stride, dst_width, dst_height,
+                $channels, filter, 255,
+            )
+        }
+    };
+}
+
+define_scale_rgb!(scale_rgb, 3, "RGB");
+define_scale_rgb!(scale_rgba, 4, "RGBA");
+
+macro_rules! define_scale_rgb16 {
+    ($method: ident, $channels: expr, $name: expr) => {
+        #[doc = concat!("Scales high bit-depth ", $name, " image to the destination size.
+
+Channels are filtered independently, for images with alpha consider scaling premultiplied data.
+
+# Arguments
+
+* `src` - Source ", $name, " data.
+* `src_stride` - Source stride (components per row).
+* `src_width` - Source width.
+* `src_height` - Source height.
+* `dst` - Destination ", $name, " data.
+* `dst_stride` - Destination stride (components per row).
+* `dst_width` - Destination width.
+* `dst_height` - Destination height.
+* `bit_depth` - Bit-depth of samples, 8..=16.
+* `filter` - Resampling filter, see [ScalingFilter] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the buffers are not valid based
+on the specified width, height, and strides, or bit depth is not in 8..=16.
+")]
+        pub fn $method(
+            src: &[u16],
+            src_stride: u32,
+            src_width: u32,
+            src_height: u32,
+            dst: &mut [u16],
+            dst_stride: u32,
+            dst_width: u32,
+            dst_height: u32,
+            bit_depth: u32,
+            filter: ScalingFilter,
+        ) -> Result<(), YuvError> {
+            let max = max_value(bit_depth)?;
+            scale_interleaved_impl(
+                src, src_stride, src_width, src_height, dst, dst_stride, dst_width, dst_height,
+                $channels, filter, max,
+            )
+        }
+    };
+}
+
+define_scale_rgb16!(scale_rgb16, 3, "RGB");
+define_scale_rgb16!(scale_rgba16, 4, "RGBA");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn box_downscale_averages_and_identity_preserves() {
+        let width = 64u32;
+        let height = 32u32;
+        let mut src = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in src.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = ((i % width as usize) * 3 + (i / width as usize)) as u8;
+        }
+        src.u_plane.borrow_mut().fill(90);
+        src.v_plane.borrow_mut().fill(200);
+        let fixed = src.to_fixed();
+
+        let mut half =
+            YuvPlanarImageMut::<u8>::alloc(width / 2, height / 2, YuvChromaSubsampling::Yuv420);
+        scale_yuv_planar(
+            &fixed,
+            &mut half,
+            YuvChromaSubsampling::Yuv420,
+            ScalingFilter::Box,
+            YuvChromaLocation::Center,
+        )
+        .unwrap();
+        let src_y = fixed.y_plane;
+        for y in 0..(height / 2) as usize {
+            for x in 0..(width / 2) as usize {
+                let sum = src_y[2 * y * 64 + 2 * x] as u32
+                    + src_y[2 * y * 64 + 2 * x + 1] as u32
+                    + src_y[(2 * y + 1) * 64 + 2 * x] as u32
+                    + src_y[(2 * y + 1) * 64 + 2 * x + 1] as u32;
+                let expected = (sum + 2) / 4;
+                let actual = half.y_plane.borrow()[y * 32 + x] as u32;
+                assert!(
+                    expected.abs_diff(actual) <= 1,
+                    "at {x}, {y}: {actual} vs {expected}"
+                );
+            }
+        }
+        assert!(half.u_plane.borrow().iter().all(|&v| v == 90));
+        assert!(half.v_plane.borrow().iter().all(|&v| v == 200));
+
+        for filter in [
+            ScalingFilter::Nearest,
+            ScalingFilter::Bilinear,
+            ScalingFilter::Box,
+            ScalingFilter::Lanczos3,
+        ] {
+            let mut same =
+                YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+            scale_yuv_planar(
+                &fixed,
+                &mut same,
+                YuvChromaSubsampling::Yuv420,
+                filter,
+                YuvChromaLocation::Left,
+            )
+            .unwrap();
+            assert_eq!(same.y_plane.borrow(), fixed.y_plane);
+            assert_eq!(same.u_plane.borrow(), fixed.u_plane);
+        }
+
+        let packed = vec![[16u16, 128, 235, 64]; 5 * 3]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        let src = YuvPackedImage {
+            yuy: &packed,
+            yuy_stride: 20,
+            width: 10,
+            height: 3,
+        };
+        let mut dst = YuvPackedImageMut {
+            yuy: crate::BufferStoreMut::Owned(vec![0u16; 4 * 4 * 7]),
+            yuy_stride: 16,
+            width: 7,
+            height: 7,
+        };
+        scale_yuyv422_p16(
+            &src,
+            &mut dst,
+            10,
+            ScalingFilter::Lanczos3,
+            YuvChromaLocation::Left,
+        )
+        .unwrap();
+        for px in dst.yuy.borrow().chunks_exact(4) {
+            assert_eq!(px[1], 128);
+            assert_eq!(px[3], 64);
+        }
+    }
+
+    fn scale_row(src: &[u8], dst_width: u32, filter: ScalingFilter) -> Vec<u8> {
+        let src = YuvGrayImage {
+            y_plane: src,
+            y_stride: src.len() as u32,
+            width: src.len() as u32,
+            height: 1,
+        };
+        let mut dst = YuvGrayImageMut::<u8>::alloc(dst_width, 1);
+        scale_yuv_gray(&src, &mut dst, filter).unwrap();
+        dst.y_plane.borrow().to_vec()
+    }
+
+    #[test]
+    fn bilinear_and_lanczos_known_values() {
+        assert_eq!(
+            scale_row(&[0, 100], 4, ScalingFilter::Bilinear),
+            [0, 25, 75, 100]
+        );
+        assert_eq!(
+            scale_row(
+                &[0, 0, 0, 0, 200, 200, 200, 200],
+                16,
+                ScalingFilter::Lanczos3
+            ),
+            [0, 0, 0, 1, 6, 0, 0, 42, 158, 221, 212, 194, 199, 200, 200, 200]
+        );
+        assert_eq!(
+            scale_row(
+                &[10, 200, 30, 180, 60, 90, 240, 0, 120, 50, 70, 220],
+                6,
+                ScalingFilter::Lanczos3
+            ),
+            [87, 114, 112, 125, 50, 144]
+        );
+    }
+
+    #[test]
+    fn chroma_siting_shifts_chroma_grid() {
+        let mut src = YuvPlanarImageMut::<u8>::alloc(4, 4, YuvChromaSubsampling::Yuv420);
+        src.y_plane.borrow_mut().fill(128);
+        src.u_plane.borrow_mut().copy_from_slice(&[0, 200, 0, 200]);
+        src.v_plane.borrow_mut().copy_from_slice(&[0, 0, 200, 200]);
+        let src = src.to_fixed();
+        for (location, u_row, v_column) in [
+            (
+                YuvChromaLocation::Left,
+                [0, 75, 175, 200],
+                [0, 50, 150, 200],
+            ),
+            (
+                YuvChromaLocation::Center,
+                [0, 50, 150, 200],
+                [0, 50, 150, 200],
+            ),
+            (
+                YuvChromaLocation::TopLeft,
+                [0, 75, 175, 200],
+                [0, 75, 175, 200],
+            ),
+        ] {
+            let mut dst = YuvPlanarImageMut::<u8>::alloc(8, 8, YuvChromaSubsampling::Yuv420);
+            scale_yuv_planar(
+                &src,
+                &mut dst,
+                YuvChromaSubsampling::Yuv420,
+                ScalingFilter::Bilinear,
+                location,
+            )
+            .unwrap();
+            for row in dst.u_plane.borrow().chunks_exact(4) {
+                assert_eq!(row, u_row, "{location:?}");
+            }
+            for row in dst.v_plane.borrow().chunks_exact(4).zip(v_column) {
+                assert!(row.0.iter().all(|&v| v == row.1), "{location:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn simd_horizontal_rows_match_scalar() {
+        let src_u8 = (0..61 * 4)
+            .map(|i| (i * 37 % 251) as u8)
+            .collect::<Vec<_>>();
+        let src_u16 = (0..61 * 4)
+            .map(|i| (i * 997 % 1021) as u16)
+            .collect::<Vec<_>>();
+        for channels in 1..=4 {
+            for dst_width in [23, 61, 150] {
+                for filter in [ScalingFilter::Bilinear, ScalingFilter::Lanczos3] {
+                    let weights =
+                        build_weights(filter, 61, dst_width, 61, dst_width, (1, 0.5), (1, 0.5));
+                    let src = &src_u8[..61 * channels];
+                    let mut simd = vec![0u8; dst_width * channels];
+                    let mut scalar = simd.clone();
+                    scale_horizontal_row(
+                        u8::horizontal_handler(),
+                        src,
+                        &mut simd,
+                        channels,
+                        &weights,
+                        255,
+                    );
+                    scale_horizontal_row(
+                        horizontal_row_none,
+                        src,
+                        &mut scalar,
+                        channels,
+                        &weights,
+                        255,
+                    );
+                    assert_eq!(simd, scalar);
+
+                    let src = &src_u16[..61 * channels];
+                    let mut simd = vec![0u16; dst_width * channels];
+                    let mut scalar = simd.clone();
+                    scale_horizontal_row(
+                        u16::horizontal_handler(),
+                        src,
+                        &mut simd,
+                        channels,
+                        &weights,
+                        1023,
+                    );
+                    scale_horizontal_row(
+                        horizontal_row_none,
+                        src,
+                        &mut scalar,
+                        channels,
+                        &weights,
+                        1023,
+                    );
+                    assert_eq!(simd, scalar);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_unsupported_bit_depth() {
+        let plane = vec![0u16; 4 * 4];
+        let src = YuvGrayImage {
+            y_plane: &plane,
+            y_stride: 4,
+            width: 4,
+            height: 4,
+        };
+        let mut dst = YuvGrayImageMut::<u16>::alloc(2, 2);
+        assert!(matches!(
+            scale_yuv_gray_p16(&src, &mut dst, 17, ScalingFilter::Bilinear),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+    }
+}
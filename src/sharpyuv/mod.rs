@@ -32,6 +32,10 @@ mod sharp_gamma;
 mod sharp_rgba_to_yuv;
 
 pub use sharp_gamma::SharpYuvGammaTransfer;
+pub(crate) use sharp_gamma::{
+    hlg_from_linear, hlg_to_linear, pq_from_linear, pq_to_linear, rec709_from_linear,
+    rec709_to_linear,
+};
 pub use sharp_rgba_to_yuv::bgr_to_sharp_yuv420;
 pub use sharp_rgba_to_yuv::bgr_to_sharp_yuv422;
 pub use sharp_rgba_to_yuv::bgra_to_sharp_yuv420;
@@ -119,6 +119,72 @@ pub(crate) fn gamma2p8_to_linear(gamma: f32) -> f32 {
     pure_gamma_function(gamma, 2.8f32)
 }
 
+const PQ_M1: f32 = 2610f32 / 16384f32;
+const PQ_M2: f32 = 2523f32 / 4096f32 * 128f32;
+const PQ_C1: f32 = 3424f32 / 4096f32;
+const PQ_C2: f32 = 2413f32 / 4096f32 * 32f32;
+const PQ_C3: f32 = 2392f32 / 4096f32 * 32f32;
+
+#[inline]
+/// Linear transfer function for SMPTE ST 2084 (PQ), 1.0 corresponds to 10000 nits
+pub(crate) fn pq_to_linear(gamma: f32) -> f32 {
+    if gamma <= 0f32 {
+        0f32
+    } else if gamma < 1f32 {
+        let e = gamma.powf(1f32 / PQ_M2);
+        let num = (e - PQ_C1).max(0f32);
+        let den = PQ_C2 - PQ_C3 * e;
+        (num / den).powf(1f32 / PQ_M1)
+    } else {
+        1f32
+    }
+}
+
+#[inline]
+/// Gamma transfer function for SMPTE ST 2084 (PQ), 1.0 corresponds to 10000 nits
+pub(crate) fn pq_from_linear(linear: f32) -> f32 {
+    if linear <= 0f32 {
+        0f32
+    } else if linear < 1f32 {
+        let y = linear.powf(PQ_M1);
+        ((PQ_C1 + PQ_C2 * y) / (1f32 + PQ_C3 * y)).powf(PQ_M2)
+    } else {
+        1f32
+    }
+}
+
+const HLG_A: f32 = 0.178_832_77f32;
+const HLG_B: f32 = 1f32 - 4f32 * HLG_A;
+const HLG_C: f32 = 0.559_910_7f32;
+
+#[inline]
+/// Linear transfer function for ARIB STD-B67 (HLG), inverse OETF
+pub(crate) fn hlg_to_linear(gamma: f32) -> f32 {
+    if gamma <= 0f32 {
+        0f32
+    } else if gamma <= 0.5f32 {
+        gamma * gamma / 3f32
+    } else if gamma < 1f32 {
+        (((gamma - HLG_C) / HLG_A).exp() + HLG_B) / 12f32
+    } else {
+        1f32
+    }
+}
+
+#[inline]
+/// Gamma transfer function for ARIB STD-B67 (HLG), OETF
+pub(crate) fn hlg_from_linear(linear: f32) -> f32 {
+    if linear <= 0f32 {
+        0f32
+    } else if linear <= 1f32 / 12f32 {
+        (3f32 * linear).sqrt()
+    } else if linear < 1f32 {
+        HLG_A * (12f32 * linear - HLG_B).ln() + HLG_C
+    } else {
+        1f32
+    }
+}
+
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 /// Declares transfer function for transfer components into a linear colorspace and its inverse
 pub enum SharpYuvGammaTransfer {
@@ -0,0 +1,123 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::lut::{tetrahedral_nodes, trilinear_cell};
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Tetrahedral interpolation of `count` pixels holding 3D LUT grid coordinates
+/// in 4 lanes each, returns processed count
+pub(crate) fn sse_lut3d_tetrahedral_row(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    unsafe { sse_lut3d_tetrahedral_row_impl(table, size, row, count) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_lut3d_tetrahedral_row_impl(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    let t_ptr = table.as_ptr();
+    for px in row.chunks_exact_mut(4).take(count) {
+        let (nodes, weights) = tetrahedral_nodes(size, px);
+        let mut acc = _mm_mul_ps(_mm_loadu_ps(t_ptr.add(nodes[0])), _mm_set1_ps(weights[0]));
+        acc = _mm_add_ps(
+            acc,
+            _mm_mul_ps(_mm_loadu_ps(t_ptr.add(nodes[1])), _mm_set1_ps(weights[1])),
+        );
+        acc = _mm_add_ps(
+            acc,
+            _mm_mul_ps(_mm_loadu_ps(t_ptr.add(nodes[2])), _mm_set1_ps(weights[2])),
+        );
+        acc = _mm_add_ps(
+            acc,
+            _mm_mul_ps(_mm_loadu_ps(t_ptr.add(nodes[3])), _mm_set1_ps(weights[3])),
+        );
+        _mm_storeu_ps(px.as_mut_ptr(), acc);
+    }
+    count
+}
+
+#[inline(always)]
+unsafe fn lerp_ps(a: __m128, b: __m128, t: __m128) -> __m128 {
+    _mm_add_ps(a, _mm_mul_ps(_mm_sub_ps(b, a), t))
+}
+
+/// Trilinear interpolation of `count` pixels holding 3D LUT grid coordinates
+/// in 4 lanes each, returns processed count
+pub(crate) fn sse_lut3d_trilinear_row(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    unsafe { sse_lut3d_trilinear_row_impl(table, size, row, count) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_lut3d_trilinear_row_impl(
+    table: &[f32],
+    size: usize,
+    row: &mut [f32],
+    count: usize,
+) -> usize {
+    for px in row.chunks_exact_mut(4).take(count) {
+        let (base, [dr, dg, db], [fr, fg, fb]) = trilinear_cell(size, px);
+        let t_ptr = table.as_ptr().add(base);
+        let v_fr = _mm_set1_ps(fr);
+        let c00 = lerp_ps(_mm_loadu_ps(t_ptr), _mm_loadu_ps(t_ptr.add(dr)), v_fr);
+        let c10 = lerp_ps(
+            _mm_loadu_ps(t_ptr.add(dg)),
+            _mm_loadu_ps(t_ptr.add(dg + dr)),
+            v_fr,
+        );
+        let c01 = lerp_ps(
+            _mm_loadu_ps(t_ptr.add(db)),
+            _mm_loadu_ps(t_ptr.add(db + dr)),
+            v_fr,
+        );
+        let c11 = lerp_ps(
+            _mm_loadu_ps(t_ptr.add(db + dg)),
+            _mm_loadu_ps(t_ptr.add(db + dg + dr)),
+            v_fr,
+        );
+        let v_fg = _mm_set1_ps(fg);
+        let c0 = lerp_ps(c00, c10, v_fg);
+        let c1 = lerp_ps(c01, c11, v_fg);
+        _mm_storeu_ps(px.as_mut_ptr(), lerp_ps(c0, c1, _mm_set1_ps(fb)));
+    }
+    count
+}
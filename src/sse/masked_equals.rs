@@ -0,0 +1,62 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Checks `(src ^ expected) & mask == 0` for every 16 bytes block,
+/// returns result and processed count
+pub(crate) fn sse_masked_equals_row(
+    src: &[u8],
+    expected: &[u8; 16],
+    mask: &[u8; 16],
+) -> (bool, usize) {
+    unsafe { sse_masked_equals_row_impl(src, expected, mask) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_masked_equals_row_impl(
+    src: &[u8],
+    expected: &[u8; 16],
+    mask: &[u8; 16],
+) -> (bool, usize) {
+    let v_expected = _mm_loadu_si128(expected.as_ptr() as *const __m128i);
+    let v_mask = _mm_loadu_si128(mask.as_ptr() as *const __m128i);
+
+    let mut cx = 0usize;
+    while cx + 16 <= src.len() {
+        let v_src = _mm_loadu_si128(src.get_unchecked(cx..).as_ptr() as *const __m128i);
+        if _mm_testz_si128(_mm_xor_si128(v_src, v_expected), v_mask) == 0 {
+            return (false, cx);
+        }
+        cx += 16;
+    }
+    (true, cx)
+}
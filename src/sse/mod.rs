@@ -27,7 +27,13 @@
  * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
  */
 #![deny(unreachable_code, unreachable_pub)]
+mod depth;
 mod gbr_to_rgb;
+mod interleave_cbcr;
+mod lut;
+mod masked_equals;
+mod plane_stats;
+mod premultiply;
 mod rgb_to_nv;
 mod rgb_to_nv420;
 #[cfg(feature = "professional_mode")]
@@ -51,7 +57,11 @@ mod rgba_to_yuv420;
 mod rgba_to_yuv_fast;
 #[cfg(feature = "fast_mode")]
 mod rgba_to_yuv_fast420;
+mod scale_horizontal;
+mod scale_vertical;
 mod shuffle;
+mod squared_error;
+mod ssim;
 pub(crate) mod utils;
 mod y_to_rgba;
 mod y_to_rgba_alpha;
@@ -79,7 +89,19 @@ mod yuv_to_yuy2;
 mod yuy2_to_rgb;
 mod yuy2_to_yuv;
 
+pub(crate) use depth::{sse_depth_row_16_to_16, sse_depth_row_16_to_8, sse_depth_row_8_to_16};
 pub(crate) use gbr_to_rgb::{sse_yuv_to_rgba_row_full, sse_yuv_to_rgba_row_limited};
+pub(crate) use interleave_cbcr::{
+    sse_deinterleave_cbcr_u16, sse_deinterleave_cbcr_u8, sse_interleave_cbcr_u16,
+    sse_interleave_cbcr_u8,
+};
+pub(crate) use lut::{sse_lut3d_tetrahedral_row, sse_lut3d_trilinear_row};
+pub(crate) use masked_equals::sse_masked_equals_row;
+pub(crate) use plane_stats::sse_plane_stats_row;
+pub(crate) use premultiply::{
+    sse_premultiply_row_u16, sse_premultiply_row_u8, sse_unpremultiply_row_u16,
+    sse_unpremultiply_row_u8,
+};
 pub(crate) use rgb_to_nv::sse_rgba_to_nv_row;
 pub(crate) use rgb_to_nv420::sse_rgba_to_nv_row420;
 #[cfg(feature = "professional_mode")]
@@ -103,7 +125,11 @@ pub(crate) use rgba_to_yuv420::sse_rgba_to_yuv_row420;
 pub(crate) use rgba_to_yuv_fast::sse_rgba_to_yuv_dot_rgba;
 #[cfg(feature = "fast_mode")]
 pub(crate) use rgba_to_yuv_fast420::sse_rgba_to_yuv_dot_rgba420;
+pub(crate) use scale_horizontal::{sse_scale_horizontal_row_u16, sse_scale_horizontal_row_u8};
+pub(crate) use scale_vertical::{sse_scale_vertical_row_u16, sse_scale_vertical_row_u8};
 pub(crate) use shuffle::{ShuffleConverterSse, ShuffleQTableConverterSse};
+pub(crate) use squared_error::sse_squared_error_row;
+pub(crate) use ssim::sse_ssim_block_row;
 pub(crate) use utils::*;
 pub(crate) use y_to_rgba::sse_y_to_rgba_row;
 pub(crate) use y_to_rgba_alpha::sse_y_to_rgba_alpha_row;
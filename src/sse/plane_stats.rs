@@ -0,0 +1,66 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Finds min, max and sum of the row, returns them with processed count
+pub(crate) fn sse_plane_stats_row(src: &[u16]) -> (u16, u16, u64, usize) {
+    unsafe { sse_plane_stats_row_impl(src) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_plane_stats_row_impl(src: &[u16]) -> (u16, u16, u64, usize) {
+    let mut v_min = _mm_set1_epi16(-1);
+    let mut v_max = _mm_setzero_si128();
+    let mut v_sum = _mm_setzero_si128();
+
+    let mut cx = 0usize;
+    while cx + 8 <= src.len() {
+        let v_src = _mm_loadu_si128(src.get_unchecked(cx..).as_ptr() as *const __m128i);
+        v_min = _mm_min_epu16(v_min, v_src);
+        v_max = _mm_max_epu16(v_max, v_src);
+        let pairs = _mm_add_epi32(
+            _mm_cvtepu16_epi32(v_src),
+            _mm_cvtepu16_epi32(_mm_srli_si128::<8>(v_src)),
+        );
+        v_sum = _mm_add_epi64(v_sum, _mm_cvtepu32_epi64(pairs));
+        v_sum = _mm_add_epi64(v_sum, _mm_cvtepu32_epi64(_mm_srli_si128::<8>(pairs)));
+        cx += 8;
+    }
+    if cx == 0 {
+        return (u16::MAX, 0, 0, 0);
+    }
+    let min = _mm_extract_epi16::<0>(_mm_minpos_epu16(v_min)) as u16;
+    let inverted_max = _mm_minpos_epu16(_mm_xor_si128(v_max, _mm_set1_epi16(-1)));
+    let max = !(_mm_extract_epi16::<0>(inverted_max) as u16);
+    let sum = _mm_extract_epi64::<0>(v_sum) as u64 + _mm_extract_epi64::<1>(v_sum) as u64;
+    (min, max, sum, cx)
+}
@@ -0,0 +1,223 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+#[inline(always)]
+unsafe fn alpha_shuffle_u8<const A_POS: usize>() -> __m128i {
+    if A_POS == 0 {
+        _mm_setr_epi8(0, 0, 0, 0, 4, 4, 4, 4, 8, 8, 8, 8, 12, 12, 12, 12)
+    } else {
+        _mm_setr_epi8(3, 3, 3, 3, 7, 7, 7, 7, 11, 11, 11, 11, 15, 15, 15, 15)
+    }
+}
+
+#[inline(always)]
+unsafe fn alpha_mask_u8<const A_POS: usize>() -> __m128i {
+    if A_POS == 0 {
+        _mm_set1_epi32(0xff)
+    } else {
+        _mm_set1_epi32(0xff000000u32 as i32)
+    }
+}
+
+#[inline(always)]
+unsafe fn broadcast_alpha_ps<const A_POS: usize>(v: __m128) -> __m128 {
+    if A_POS == 0 {
+        _mm_shuffle_ps::<0x00>(v, v)
+    } else {
+        _mm_shuffle_ps::<0xff>(v, v)
+    }
+}
+
+#[inline(always)]
+unsafe fn keep_alpha_epi16<const A_POS: usize>(v: __m128i, src: __m128i) -> __m128i {
+    if A_POS == 0 {
+        _mm_blend_epi16::<0b0001_0001>(v, src)
+    } else {
+        _mm_blend_epi16::<0b1000_1000>(v, src)
+    }
+}
+
+/// Rounding division by 255 of 16 bit lanes
+#[inline(always)]
+unsafe fn div_by_255_epi16(v: __m128i) -> __m128i {
+    let rounded = _mm_add_epi16(v, _mm_set1_epi16(0x80));
+    _mm_srli_epi16::<8>(_mm_add_epi16(rounded, _mm_srli_epi16::<8>(rounded)))
+}
+
+/// `c * a / max` rounded, for single pixel in 4 lanes
+#[inline(always)]
+unsafe fn premultiply_ps<const A_POS: usize>(v: __m128, v_max: __m128) -> __m128i {
+    let a = broadcast_alpha_ps::<A_POS>(v);
+    let q = _mm_add_ps(_mm_div_ps(_mm_mul_ps(v, a), v_max), _mm_set1_ps(0.5f32));
+    _mm_cvttps_epi32(_mm_min_ps(q, v_max))
+}
+
+/// `c * max / a` rounded, 0 where alpha is 0, for single pixel in 4 lanes
+#[inline(always)]
+unsafe fn unpremultiply_ps<const A_POS: usize>(v: __m128, v_max: __m128) -> __m128i {
+    let a = broadcast_alpha_ps::<A_POS>(v);
+    let q = _mm_add_ps(_mm_div_ps(_mm_mul_ps(v, v_max), a), _mm_set1_ps(0.5f32));
+    let zeros = _mm_cmpeq_ps(a, _mm_setzero_ps());
+    _mm_cvttps_epi32(_mm_andnot_ps(zeros, _mm_min_ps(q, v_max)))
+}
+
+/// Premultiplies `width` 4 channel pixels with alpha at `A_POS`, returns processed count
+pub(crate) fn sse_premultiply_row_u8<const A_POS: usize>(row: &mut [u8], width: usize) -> usize {
+    unsafe { sse_premultiply_row_u8_impl::<A_POS>(row, width) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_premultiply_row_u8_impl<const A_POS: usize>(row: &mut [u8], width: usize) -> usize {
+    let shuffle = alpha_shuffle_u8::<A_POS>();
+    let alpha_mask = alpha_mask_u8::<A_POS>();
+    let zeros = _mm_setzero_si128();
+
+    let mut cx = 0usize;
+    while cx + 4 <= width {
+        let ptr = row.get_unchecked_mut(cx * 4..).as_mut_ptr();
+        let v = _mm_loadu_si128(ptr as *const __m128i);
+        let a = _mm_shuffle_epi8(v, shuffle);
+        let lo = div_by_255_epi16(_mm_mullo_epi16(
+            _mm_unpacklo_epi8(v, zeros),
+            _mm_unpacklo_epi8(a, zeros),
+        ));
+        let hi = div_by_255_epi16(_mm_mullo_epi16(
+            _mm_unpackhi_epi8(v, zeros),
+            _mm_unpackhi_epi8(a, zeros),
+        ));
+        let product = _mm_blendv_epi8(_mm_packus_epi16(lo, hi), v, alpha_mask);
+        _mm_storeu_si128(ptr as *mut __m128i, product);
+        cx += 4;
+    }
+    cx
+}
+
+/// Unpremultiplies `width` 4 channel pixels with alpha at `A_POS`, returns processed count
+pub(crate) fn sse_unpremultiply_row_u8<const A_POS: usize>(row: &mut [u8], width: usize) -> usize {
+    unsafe { sse_unpremultiply_row_u8_impl::<A_POS>(row, width) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_unpremultiply_row_u8_impl<const A_POS: usize>(row: &mut [u8], width: usize) -> usize {
+    let alpha_mask = alpha_mask_u8::<A_POS>();
+    let v_max = _mm_set1_ps(255f32);
+
+    let mut cx = 0usize;
+    while cx + 4 <= width {
+        let ptr = row.get_unchecked_mut(cx * 4..).as_mut_ptr();
+        let v = _mm_loadu_si128(ptr as *const __m128i);
+        let px0 = unpremultiply_ps::<A_POS>(_mm_cvtepi32_ps(_mm_cvtepu8_epi32(v)), v_max);
+        let px1 = unpremultiply_ps::<A_POS>(
+            _mm_cvtepi32_ps(_mm_cvtepu8_epi32(_mm_srli_si128::<4>(v))),
+            v_max,
+        );
+        let px2 = unpremultiply_ps::<A_POS>(
+            _mm_cvtepi32_ps(_mm_cvtepu8_epi32(_mm_srli_si128::<8>(v))),
+            v_max,
+        );
+        let px3 = unpremultiply_ps::<A_POS>(
+            _mm_cvtepi32_ps(_mm_cvtepu8_epi32(_mm_srli_si128::<12>(v))),
+            v_max,
+        );
+        let packed = _mm_packus_epi16(_mm_packus_epi32(px0, px1), _mm_packus_epi32(px2, px3));
+        _mm_storeu_si128(ptr as *mut __m128i, _mm_blendv_epi8(packed, v, alpha_mask));
+        cx += 4;
+    }
+    cx
+}
+
+/// Premultiplies `width` 4 channel pixels with alpha at `A_POS`, returns processed count
+pub(crate) fn sse_premultiply_row_u16<const A_POS: usize>(
+    row: &mut [u16],
+    width: usize,
+    max: f32,
+) -> usize {
+    unsafe { sse_premultiply_row_u16_impl::<A_POS>(row, width, max) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_premultiply_row_u16_impl<const A_POS: usize>(
+    row: &mut [u16],
+    width: usize,
+    max: f32,
+) -> usize {
+    let v_max = _mm_set1_ps(max);
+
+    let mut cx = 0usize;
+    while cx + 2 <= width {
+        let ptr = row.get_unchecked_mut(cx * 4..).as_mut_ptr();
+        let v = _mm_loadu_si128(ptr as *const __m128i);
+        let px0 = premultiply_ps::<A_POS>(_mm_cvtepi32_ps(_mm_cvtepu16_epi32(v)), v_max);
+        let px1 = premultiply_ps::<A_POS>(
+            _mm_cvtepi32_ps(_mm_cvtepu16_epi32(_mm_srli_si128::<8>(v))),
+            v_max,
+        );
+        let packed = _mm_packus_epi32(px0, px1);
+        _mm_storeu_si128(ptr as *mut __m128i, keep_alpha_epi16::<A_POS>(packed, v));
+        cx += 2;
+    }
+    cx
+}
+
+/// Unpremultiplies `width` 4 channel pixels with alpha at `A_POS`, returns processed count
+pub(crate) fn sse_unpremultiply_row_u16<const A_POS: usize>(
+    row: &mut [u16],
+    width: usize,
+    max: f32,
+) -> usize {
+    unsafe { sse_unpremultiply_row_u16_impl::<A_POS>(row, width, max) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_unpremultiply_row_u16_impl<const A_POS: usize>(
+    row: &mut [u16],
+    width: usize,
+    max: f32,
+) -> usize {
+    let v_max = _mm_set1_ps(max);
+
+    let mut cx = 0usize;
+    while cx + 2 <= width {
+        let ptr = row.get_unchecked_mut(cx * 4..).as_mut_ptr();
+        let v = _mm_loadu_si128(ptr as *const __m128i);
+        let px0 = unpremultiply_ps::<A_POS>(_mm_cvtepi32_ps(_mm_cvtepu16_epi32(v)), v_max);
+        let px1 = unpremultiply_ps::<A_POS>(
+            _mm_cvtepi32_ps(_mm_cvtepu16_epi32(_mm_srli_si128::<8>(v))),
+            v_max,
+        );
+        let packed = _mm_packus_epi32(px0, px1);
+        _mm_storeu_si128(ptr as *mut __m128i, keep_alpha_epi16::<A_POS>(packed, v));
+        cx += 2;
+    }
+    cx
+}
@@ -0,0 +1,152 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Accumulates 4 consecutive destination components starting at `n`,
+/// each lane gathers its own pixel taps
+#[inline]
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_horizontal_lanes<T: Copy + Into<i32>>(
+    src: &[T],
+    n: usize,
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> __m128i {
+    let mut src_idx = [0usize; 4];
+    let mut w_idx = [0usize; 4];
+    for (l, (src_idx, w_idx)) in src_idx.iter_mut().zip(w_idx.iter_mut()).enumerate() {
+        let px = (n + l) / channels;
+        *src_idx = *bounds.get_unchecked(px) * channels + (n + l) % channels;
+        *w_idx = px * taps;
+    }
+    let mut acc = _mm_set1_epi32(1 << 13);
+    for k in 0..taps {
+        let v_src = _mm_setr_epi32(
+            (*src.get_unchecked(src_idx[0] + k * channels)).into(),
+            (*src.get_unchecked(src_idx[1] + k * channels)).into(),
+            (*src.get_unchecked(src_idx[2] + k * channels)).into(),
+            (*src.get_unchecked(src_idx[3] + k * channels)).into(),
+        );
+        let v_weight = _mm_setr_epi32(
+            *weights.get_unchecked(w_idx[0] + k),
+            *weights.get_unchecked(w_idx[1] + k),
+            *weights.get_unchecked(w_idx[2] + k),
+            *weights.get_unchecked(w_idx[3] + k),
+        );
+        acc = _mm_add_epi32(acc, _mm_mullo_epi32(v_src, v_weight));
+    }
+    _mm_min_epi32(
+        _mm_max_epi32(_mm_srai_epi32::<14>(acc), _mm_setzero_si128()),
+        _mm_set1_epi32(max),
+    )
+}
+
+/// Filters row with interleaved `channels`, destination pixel `i` reads `taps` pixels
+/// from `bounds[i]` with weights in 14 bits fixed point, returns processed components count
+pub(crate) fn sse_scale_horizontal_row_u8(
+    src: &[u8],
+    dst: &mut [u8],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    unsafe { sse_scale_horizontal_row_u8_impl(src, dst, channels, bounds, weights, taps, max) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_scale_horizontal_row_u8_impl(
+    src: &[u8],
+    dst: &mut [u8],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    let length = dst.len();
+
+    let mut n = 0usize;
+    while n + 4 <= length {
+        let v = sse_horizontal_lanes(src, n, channels, bounds, weights, taps, max);
+        let v = _mm_packus_epi16(_mm_packs_epi32(v, v), _mm_setzero_si128());
+        std::ptr::write_unaligned(
+            dst.get_unchecked_mut(n..).as_mut_ptr() as *mut i32,
+            _mm_cvtsi128_si32(v),
+        );
+        n += 4;
+    }
+    n
+}
+
+/// Filters row with interleaved `channels`, destination pixel `i` reads `taps` pixels
+/// from `bounds[i]` with weights in 14 bits fixed point clamped to `max`,
+/// returns processed components count
+pub(crate) fn sse_scale_horizontal_row_u16(
+    src: &[u16],
+    dst: &mut [u16],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    unsafe { sse_scale_horizontal_row_u16_impl(src, dst, channels, bounds, weights, taps, max) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_scale_horizontal_row_u16_impl(
+    src: &[u16],
+    dst: &mut [u16],
+    channels: usize,
+    bounds: &[usize],
+    weights: &[i32],
+    taps: usize,
+    max: i32,
+) -> usize {
+    let length = dst.len();
+
+    let mut n = 0usize;
+    while n + 4 <= length {
+        let v = sse_horizontal_lanes(src, n, channels, bounds, weights, taps, max);
+        _mm_storel_epi64(
+            dst.get_unchecked_mut(n..).as_mut_ptr() as *mut __m128i,
+            _mm_packus_epi32(v, v),
+        );
+        n += 4;
+    }
+    n
+}
@@ -0,0 +1,137 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Weighted sum of `weights.len()` source rows spaced by `stride` with weights in 14 bits fixed point,
+/// returns processed count
+pub(crate) fn sse_scale_vertical_row_u8(
+    src: &[u8],
+    stride: usize,
+    weights: &[i32],
+    dst: &mut [u8],
+    _: i32,
+) -> usize {
+    unsafe { sse_scale_vertical_row_u8_impl(src, stride, weights, dst) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_scale_vertical_row_u8_impl(
+    src: &[u8],
+    stride: usize,
+    weights: &[i32],
+    dst: &mut [u8],
+) -> usize {
+    let width = dst.len();
+    let rounding = _mm_set1_epi32(1 << 13);
+
+    let mut cx = 0usize;
+    while cx + 16 <= width {
+        let mut acc0 = rounding;
+        let mut acc1 = rounding;
+        let mut acc2 = rounding;
+        let mut acc3 = rounding;
+        for (k, &weight) in weights.iter().enumerate() {
+            let row = src.get_unchecked(k * stride..);
+            let v_weight = _mm_set1_epi32(weight);
+            let v = _mm_loadu_si128(row.get_unchecked(cx..).as_ptr() as *const __m128i);
+            acc0 = _mm_add_epi32(acc0, _mm_mullo_epi32(_mm_cvtepu8_epi32(v), v_weight));
+            acc1 = _mm_add_epi32(
+                acc1,
+                _mm_mullo_epi32(_mm_cvtepu8_epi32(_mm_srli_si128::<4>(v)), v_weight),
+            );
+            acc2 = _mm_add_epi32(
+                acc2,
+                _mm_mullo_epi32(_mm_cvtepu8_epi32(_mm_srli_si128::<8>(v)), v_weight),
+            );
+            acc3 = _mm_add_epi32(
+                acc3,
+                _mm_mullo_epi32(_mm_cvtepu8_epi32(_mm_srli_si128::<12>(v)), v_weight),
+            );
+        }
+        let lo = _mm_packs_epi32(_mm_srai_epi32::<14>(acc0), _mm_srai_epi32::<14>(acc1));
+        let hi = _mm_packs_epi32(_mm_srai_epi32::<14>(acc2), _mm_srai_epi32::<14>(acc3));
+        _mm_storeu_si128(
+            dst.get_unchecked_mut(cx..).as_mut_ptr() as *mut __m128i,
+            _mm_packus_epi16(lo, hi),
+        );
+        cx += 16;
+    }
+    cx
+}
+
+/// Weighted sum of `weights.len()` source rows spaced by `stride` with weights in 14 bits fixed point clamped to `max`,
+/// returns processed count
+pub(crate) fn sse_scale_vertical_row_u16(
+    src: &[u16],
+    stride: usize,
+    weights: &[i32],
+    dst: &mut [u16],
+    max: i32,
+) -> usize {
+    unsafe { sse_scale_vertical_row_u16_impl(src, stride, weights, dst, max) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_scale_vertical_row_u16_impl(
+    src: &[u16],
+    stride: usize,
+    weights: &[i32],
+    dst: &mut [u16],
+    max: i32,
+) -> usize {
+    let width = dst.len();
+    let rounding = _mm_set1_epi32(1 << 13);
+    let v_max = _mm_set1_epi16(max as u16 as i16);
+
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let mut acc0 = rounding;
+        let mut acc1 = rounding;
+        for (k, &weight) in weights.iter().enumerate() {
+            let row = src.get_unchecked(k * stride..);
+            let v_weight = _mm_set1_epi32(weight);
+            let v = _mm_loadu_si128(row.get_unchecked(cx..).as_ptr() as *const __m128i);
+            acc0 = _mm_add_epi32(acc0, _mm_mullo_epi32(_mm_cvtepu16_epi32(v), v_weight));
+            acc1 = _mm_add_epi32(
+                acc1,
+                _mm_mullo_epi32(_mm_cvtepu16_epi32(_mm_srli_si128::<8>(v)), v_weight),
+            );
+        }
+        let v = _mm_packus_epi32(_mm_srai_epi32::<14>(acc0), _mm_srai_epi32::<14>(acc1));
+        _mm_storeu_si128(
+            dst.get_unchecked_mut(cx..).as_mut_ptr() as *mut __m128i,
+            _mm_min_epu16(v, v_max),
+        );
+        cx += 8;
+    }
+    cx
+}
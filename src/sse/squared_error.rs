@@ -0,0 +1,63 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#[cfg(target_arch = "x86")]
+use std::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+/// Sums `(a - b)^2` over the row, returns sum and processed count
+pub(crate) fn sse_squared_error_row(a: &[u16], b: &[u16]) -> (u64, usize) {
+    unsafe { sse_squared_error_row_impl(a, b) }
+}
+
+#[target_feature(enable = "sse4.1")]
+unsafe fn sse_squared_error_row_impl(a: &[u16], b: &[u16]) -> (u64, usize) {
+    let width = a.len().min(b.len());
+    let mut v_acc = _mm_setzero_si128();
+
+    let mut cx = 0usize;
+    while cx + 8 <= width {
+        let v_a = _mm_loadu_si128(a.get_unchecked(cx..).as_ptr() as *const __m128i);
+        let v_b = _mm_loadu_si128(b.get_unchecked(cx..).as_ptr() as *const __m128i);
+        let diff_lo = _mm_sub_epi32(_mm_cvtepu16_epi32(v_a), _mm_cvtepu16_epi32(v_b));
+        let diff_hi = _mm_sub_epi32(
+            _mm_cvtepu16_epi32(_mm_srli_si128::<8>(v_a)),
+            _mm_cvtepu16_epi32(_mm_srli_si128::<8>(v_b)),
+        );
+        for diff in [diff_lo, diff_hi] {
+            let odd = _mm_srli_epi64::<32>(diff);
+            v_acc = _mm_add_epi64(v_acc, _mm_mul_epi32(diff, diff));
+            v_acc = _mm_add_epi64(v_acc, _mm_mul_epi32(odd, odd));
+        }
+        cx += 8;
+    }
+    let mut store = [0u64; 2];
+    _mm_storeu_si128(store.as_mut_ptr() as *mut __m128i, v_acc);
+    (store[0] + store[1], cx)
+}
@@ -0,0 +1,788 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::{check_bit_depth, check_rgba_destination};
+use crate::yuv_support::get_yuv_range;
+use crate::{
+    YuvBiPlanarImage, YuvBitDepth, YuvChromaSubsampling, YuvError, YuvGrayAlphaImage, YuvGrayImage,
+    YuvPlanarImage, YuvPlanarImageWithAlpha, YuvRange,
+};
+use std::fmt::Debug;
+
+/// Statistics of a single plane
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaneStats {
+    /// Minimum sample value
+    pub min: u16,
+    /// Maximum sample value
+    pub max: u16,
+    /// Mean of sample values
+    pub mean: f64,
+    /// Count of samples for every value, `1 << bit_depth` bins,
+    /// samples above maximum for bit-depth are counted in the last bin
+    pub histogram: Vec<u32>,
+    /// Count of samples outside the legal limited range,
+    /// always 0 for alpha
+    pub out_of_limited_range: u64,
+    /// Count of samples above maximum value for bit-depth
+    pub out_of_bit_depth: u64,
+    /// Bit-depth the plane was evaluated with
+    pub bit_depth: u32,
+}
+
+impl PlaneStats {
+    /// Whether every sample has maximum value for bit-depth
+    pub fn is_opaque(&self) -> bool {
+        self.min as u32 == (1u32 << self.bit_depth) - 1
+    }
+}
+
+/// Statistics of YUV image planes
+#[derive(Debug, Clone, PartialEq)]
+pub struct YuvStats {
+    /// Luma plane
+    pub y: PlaneStats,
+    /// First chroma plane, in stored order for bi-planar images, `None` for gray images
+    pub u: Option<PlaneStats>,
+    /// Second chroma plane, `None` for gray images
+    pub v: Option<PlaneStats>,
+    /// Alpha plane if image has one
+    pub a: Option<PlaneStats>,
+}
+
+impl YuvStats {
+    /// Total count of luma and chroma samples outside the legal limited range
+    pub fn out_of_limited_range(&self) -> u64 {
+        self.y.out_of_limited_range
+            + self.u.as_ref().map_or(0, |u| u.out_of_limited_range)
+            + self.v.as_ref().map_or(0, |v| v.out_of_limited_range)
+    }
+
+    /// Suggests range of content, any sample outside legal limited range means full range.
+    ///
+    /// Note: content with limited dynamic is reported as limited even if it was full range.
+    pub fn detected_range(&self) -> YuvRange {
+        if self.out_of_limited_range() > 0 {
+            YuvRange::Full
+        } else {
+            YuvRange::Limited
+        }
+    }
+
+    /// Whether alpha plane is absent or fully opaque
+    pub fn is_opaque(&self) -> bool {
+        self.a.as_ref().is_none_or(|a| a.is_opaque())
+    }
+}
+
+#[derive(Copy, Clone)]
+enum PlaneKind {
+    Luma,
+    Chroma,
+    Alpha,
+}
+
+/// Returns inclusive legal limited range of the plane
+fn legal_range(kind: PlaneKind, bit_depth: u32) -> Option<(u32, u32)> {
+    let range = get_yuv_range(bit_depth, YuvRange::Limited);
+    match kind {
+        PlaneKind::Luma => Some((range.bias_y, range.bias_y + range.range_y)),
+        PlaneKind::Chroma => Some((
+            range.bias_uv - range.range_uv / 2,
+            range.bias_uv + range.range_uv / 2,
+        )),
+        PlaneKind::Alpha => None,
+    }
+}
+
+type PlaneStatsRow = unsafe fn(&[u16]) -> (u16, u16, u64, usize);
+
+fn plane_stats_row_none(_: &[u16]) -> (u16, u16, u64, usize) {
+    (u16::MAX, 0, 0, 0)
+}
+
+fn make_plane_stats_row() -> PlaneStatsRow {
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        use crate::neon::neon_plane_stats_row;
+        return neon_plane_stats_row;
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            use crate::avx2::avx2_plane_stats_row;
+            return avx2_plane_stats_row;
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            use crate::sse::sse_plane_stats_row;
+            return sse_plane_stats_row;
+        }
+    }
+    #[allow(unreachable_code)]
+    plane_stats_row_none
+}
+
+/// Collects statistics of plane with samples taken every `step` items starting from `offset`.
+///
+/// Every row is loaded once into host order samples, min, max and sum are accumulated
+/// by SIMD kernel and only histogram binning is scalar.
+///
+/// Binning stays a separate pass over the row: SSE and NEON have no scatter, AVX2 has only
+/// gather, and lanes hitting the same bin would conflict anyway, so kernel could do no better
+/// than the scalar loop. Row buffer is just loaded by the kernel, so second pass reads it from L1.
+fn plane_stats<V: Copy>(
+    src: &[V],
+    stride: u32,
+    width: usize,
+    height: usize,
+    step: usize,
+    offset: usize,
+    bit_depth: u32,
+    kind: PlaneKind,
+    load: impl Fn(V) -> u16,
+) -> PlaneStats {
+    let handler = make_plane_stats_row();
+    let max_value = ((1u32 << bit_depth) - 1) as u16;
+    let mut histogram = vec![0u32; 1 << bit_depth];
+    let mut samples = vec![0u16; width];
+    let (mut min, mut max, mut sum) = (u16::MAX, 0u16, 0u64);
+    let mut out_of_bit_depth = 0u64;
+    for row in src.chunks(stride as usize).take(height) {
+        for (dst, &v) in samples.iter_mut().zip(row[offset..].iter().step_by(step)) {
+            *dst = load(v);
+        }
+        let (row_min, row_max, row_sum, processed) = unsafe { handler(&samples) };
+        min = min.min(row_min);
+        max = max.max(row_max);
+        sum += row_sum;
+        for &v in samples.iter().skip(processed) {
+            min = min.min(v);
+            max = max.max(v);
+            sum += v as u64;
+        }
+        for &v in samples.iter() {
+            if v > max_value {
+                out_of_bit_depth += 1;
+            }
+            histogram[v.min(max_value) as usize] += 1;
+        }
+    }
+
+    let out_of_limited_range = legal_range(kind, bit_depth).map_or(0, |(low, high)| {
+        histogram
+            .iter()
+            .enumerate()
+            .filter(|&(value, _)| (value as u32) < low || (value as u32) > high)
+            .map(|(_, &bin)| bin as u64)
+            .sum()
+    });
+    let count = (width * height) as u64;
+    PlaneStats {
+        min,
+        max,
+        mean: if count == 0 {
+            0.
+        } else {
+            sum as f64 / count as f64
+        },
+        histogram,
+        out_of_limited_range,
+        out_of_bit_depth,
+        bit_depth,
+    }
+}
+
+fn planar_stats<V: Copy + Debug>(
+    image: &YuvPlanarImage<V>,
+    alpha: Option<(&[V], u32)>,
+    subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    load: impl Fn(V) -> u16 + Copy,
+) -> Result<YuvStats, YuvError> {
+    image.check_constraints(subsampling)?;
+    let width = image.width as usize;
+    let height = image.height as usize;
+    let chroma_width = subsampling.chroma_width(width);
+    let chroma_height = subsampling.chroma_height(height);
+    let chroma = |plane: &[V], stride: u32| {
+        plane_stats(
+            plane,
+            stride,
+            chroma_width,
+            chroma_height,
+            1,
+            0,
+            bit_depth,
+            PlaneKind::Chroma,
+            load,
+        )
+    };
+    Ok(YuvStats {
+        y: plane_stats(
+            image.y_plane,
+            image.y_stride,
+            width,
+            height,
+            1,
+            0,
+            bit_depth,
+            PlaneKind::Luma,
+            load,
+        ),
+        u: Some(chroma(image.u_plane, image.u_stride)),
+        v: Some(chroma(image.v_plane, image.v_stride)),
+        a: alpha.map(|(plane, stride)| {
+            plane_stats(
+                plane,
+                stride,
+                width,
+                height,
+                1,
+                0,
+                bit_depth,
+                PlaneKind::Alpha,
+                load,
+            )
+        }),
+    })
+}
+
+fn bi_planar_stats<V: Copy + Debug>(
+    image: &YuvBiPlanarImage<V>,
+    subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    load: impl Fn(V) -> u16 + Copy,
+) -> Result<YuvStats, YuvError> {
+    image.check_constraints(subsampling)?;
+    let width = image.width as usize;
+    let height = image.height as usize;
+    let chroma_width = subsampling.chroma_width(width);
+    let chroma_height = subsampling.chroma_height(height);
+    let chroma = |offset: usize| {
+        plane_stats(
+            image.uv_plane,
+            image.uv_stride,
+            chroma_width,
+            chroma_height,
+            2,
+            offset,
+            bit_depth,
+            PlaneKind::Chroma,
+            load,
+        )
+    };
+    Ok(YuvStats {
+        y: plane_stats(
+            image.y_plane,
+            image.y_stride,
+            width,
+            height,
+            1,
+            0,
+            bit_depth,
+            PlaneKind::Luma,
+            load,
+        ),
+        u: Some(chroma(0)),
+        v: Some(chroma(1)),
+        a: None,
+    })
+}
+
+fn gray_stats<V: Copy + Debug>(
+    y_plane: &[V],
+    y_stride: u32,
+    alpha: Option<(&[V], u32)>,
+    width: u32,
+    height: u32,
+    bit_depth: u32,
+    load: impl Fn(V) -> u16 + Copy,
+) -> YuvStats {
+    let (width, height) = (width as usize, height as usize);
+    YuvStats {
+        y: plane_stats(
+            y_plane,
+            y_stride,
+            width,
+            height,
+            1,
+            0,
+            bit_depth,
+            PlaneKind::Luma,
+            load,
+        ),
+        u: None,
+        v: None,
+        a: alpha.map(|(plane, stride)| {
+            plane_stats(
+                plane,
+                stride,
+                width,
+                height,
+                1,
+                0,
+                bit_depth,
+                PlaneKind::Alpha,
+                load,
+            )
+        }),
+    }
+}
+
+/// Collects min, max, mean, histogram and out of limited range count of 8 bit-depth planar image.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `subsampling` - Chroma subsampling of the image.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn yuv_planar_stats(
+    image: &YuvPlanarImage<u8>,
+    subsampling: YuvChromaSubsampling,
+) -> Result<YuvStats, YuvError> {
+    planar_stats(image, None, subsampling, 8, |v| v as u16)
+}
+
+/// Collects min, max, mean, histogram and out of limited range count of high bit-depth planar image.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image.
+/// * `subsampling` - Chroma subsampling of the image.
+/// * `depth` - Storage of samples, see [YuvBitDepth] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn yuv_planar_p16_stats(
+    image: &YuvPlanarImage<u16>,
+    subsampling: YuvChromaSubsampling,
+    depth: YuvBitDepth,
+) -> Result<YuvStats, YuvError> {
+    check_bit_depth(depth.bit_depth, 8..=16)?;
+    planar_stats(image, None, subsampling, depth.bit_depth, |v| {
+        depth.native(v)
+    })
+}
+
+fn planar_with_alpha_stats<V: Copy + Debug>(
+    image: &YuvPlanarImageWithAlpha<V>,
+    subsampling: YuvChromaSubsampling,
+    bit_depth: u32,
+    load: impl Fn(V) -> u16 + Copy,
+) -> Result<YuvStats, YuvError> {
+    image.check_constraints(subsampling)?;
+    let planar = YuvPlanarImage {
+        y_plane: image.y_plane,
+        y_stride: image.y_stride,
+        u_plane: image.u_plane,
+        u_stride: image.u_stride,
+        v_plane: image.v_plane,
+        v_stride: image.v_stride,
+        width: image.width,
+        height: image.height,
+    };
+    planar_stats(
+        &planar,
+        Some((image.a_plane, image.a_stride)),
+        subsampling,
+        bit_depth,
+        load,
+    )
+}
+
+/// Collects statistics of 8 bit-depth planar image including alpha plane.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image with alpha.
+/// * `subsampling` - Chroma subsampling of the image.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn yuv_planar_with_alpha_stats(
+    image: &YuvPlanarImageWithAlpha<u8>,
+    subsampling: YuvChromaSubsampling,
+) -> Result<YuvStats, YuvError> {
+    planar_with_alpha_stats(image, subsampling, 8, |v| v as u16)
+}
+
+/// Collects statistics of high bit-depth planar image including alpha plane.
+///
+/// # Arguments
+///
+/// * `image` - Source planar image with alpha.
+/// * `subsampling` - Chroma subsampling of the image.
+/// * `depth` - Storage of samples, see [YuvBitDepth] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn yuv_planar_with_alpha_p16_stats(
+    image: &YuvPlanarImageWithAlpha<u16>,
+    subsampling: YuvChromaSubsampling,
+    depth: YuvBitDepth,
+) -> Result<YuvStats, YuvError> {
+    check_bit_depth(depth.bit_depth, 8..=16)?;
+    planar_with_alpha_stats(image, subsampling, depth.bit_depth, |v| depth.native(v))
+}
+
+/// Collects statistics of 8 bit-depth bi-planar image.
+///
+/// # Arguments
+///
+/// * `image` - Source bi-planar image.
+/// * `subsampling` - Chroma subsampling of the image.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn yuv_bi_planar_stats(
+    image: &YuvBiPlanarImage<u8>,
+    subsampling: YuvChromaSubsampling,
+) -> Result<YuvStats, YuvError> {
+    bi_planar_stats(image, subsampling, 8, |v| v as u16)
+}
+
+/// Collects statistics of high bit-depth bi-planar image.
+///
+/// # Arguments
+///
+/// * `image` - Source bi-planar image.
+/// * `subsampling` - Chroma subsampling of the image.
+/// * `depth` - Storage of samples, for P010 use [YuvBitDepth::msb].
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn yuv_bi_planar_p16_stats(
+    image: &YuvBiPlanarImage<u16>,
+    subsampling: YuvChromaSubsampling,
+    depth: YuvBitDepth,
+) -> Result<YuvStats, YuvError> {
+    check_bit_depth(depth.bit_depth, 8..=16)?;
+    bi_planar_stats(image, subsampling, depth.bit_depth, |v| depth.native(v))
+}
+
+/// Collects statistics of 8 bit-depth gray image.
+///
+/// # Arguments
+///
+/// * `image` - Source gray image.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn yuv_gray_stats(image: &YuvGrayImage<u8>) -> Result<YuvStats, YuvError> {
+    image.check_constraints()?;
+    Ok(gray_stats(
+        image.y_plane,
+        image.y_stride,
+        None,
+        image.width,
+        image.height,
+        8,
+        |v| v as u16,
+    ))
+}
+
+/// Collects statistics of high bit-depth gray image.
+///
+/// # Arguments
+///
+/// * `image` - Source gray image.
+/// * `depth` - Storage of samples, see [YuvBitDepth] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn yuv_gray_p16_stats(
+    image: &YuvGrayImage<u16>,
+    depth: YuvBitDepth,
+) -> Result<YuvStats, YuvError> {
+    check_bit_depth(depth.bit_depth, 8..=16)?;
+    image.check_constraints()?;
+    Ok(gray_stats(
+        image.y_plane,
+        image.y_stride,
+        None,
+        image.width,
+        image.height,
+        depth.bit_depth,
+        |v| depth.native(v),
+    ))
+}
+
+/// Collects statistics of 8 bit-depth gray image with alpha.
+///
+/// # Arguments
+///
+/// * `image` - Source gray image with alpha.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides.
+pub fn yuv_gray_alpha_stats(image: &YuvGrayAlphaImage<u8>) -> Result<YuvStats, YuvError> {
+    image.check_constraints()?;
+    Ok(gray_stats(
+        image.y_plane,
+        image.y_stride,
+        Some((image.a_plane, image.a_stride)),
+        image.width,
+        image.height,
+        8,
+        |v| v as u16,
+    ))
+}
+
+/// Collects statistics of high bit-depth gray image with alpha.
+///
+/// # Arguments
+///
+/// * `image` - Source gray image with alpha.
+/// * `depth` - Storage of samples, see [YuvBitDepth] for more info.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or bit depth is not in 8..=16.
+pub fn yuv_gray_alpha_p16_stats(
+    image: &YuvGrayAlphaImage<u16>,
+    depth: YuvBitDepth,
+) -> Result<YuvStats, YuvError> {
+    check_bit_depth(depth.bit_depth, 8..=16)?;
+    image.check_constraints()?;
+    Ok(gray_stats(
+        image.y_plane,
+        image.y_stride,
+        Some((image.a_plane, image.a_stride)),
+        image.width,
+        image.height,
+        depth.bit_depth,
+        |v| depth.native(v),
+    ))
+}
+
+type MaskedEqualsRow = unsafe fn(&[u8], &[u8; 16], &[u8; 16]) -> (bool, usize);
+
+fn masked_equals_row_none(_: &[u8], _: &[u8; 16], _: &[u8; 16]) -> (bool, usize) {
+    (true, 0)
+}
+
+fn make_masked_equals_row() -> MaskedEqualsRow {
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+    {
+        use crate::neon::neon_masked_equals_row;
+        return neon_masked_equals_row;
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+    {
+        if std::arch::is_x86_feature_detected!("avx2") {
+            use crate::avx2::avx2_masked_equals_row;
+            return avx2_masked_equals_row;
+        }
+    }
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+    {
+        if std::arch::is_x86_feature_detected!("sse4.1") {
+            use crate::sse::sse_masked_equals_row;
+            return sse_masked_equals_row;
+        }
+    }
+    #[allow(unreachable_code)]
+    masked_equals_row_none
+}
+
+/// Checks that every row matches repeating `expected` bytes pattern under `mask`
+fn rows_masked_equal(
+    bytes: &[u8],
+    stride: usize,
+    row_length: usize,
+    height: usize,
+    expected: &[u8; 16],
+    mask: &[u8; 16],
+) -> bool {
+    let handler = make_masked_equals_row();
+    bytes.chunks(stride).take(height).all(|row| {
+        let row = &row[..row_length];
+        let (equal, processed) = unsafe { handler(row, expected, mask) };
+        equal
+            && row
+                .iter()
+                .enumerate()
+                .skip(processed)
+                .all(|(i, &v)| (v ^ expected[i % 16]) & mask[i % 16] == 0)
+    })
+}
+
+/// Checks if every pixel of RGBA or BGRA image has maximum alpha.
+///
+/// # Arguments
+///
+/// * `rgba` - Source image data with alpha as last component.
+/// * `rgba_stride` - The stride (components per row) for the image data.
+/// * `width` - Image width.
+/// * `height` - Image height.
+///
+pub fn rgba_is_opaque(
+    rgba: &[u8],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+) -> Result<bool, YuvError> {
+    check_rgba_destination(rgba, rgba_stride, width, height, 4)?;
+    let mut expected = [0u8; 16];
+    let mut mask = [0u8; 16];
+    for i in (3..16).step_by(4) {
+        expected[i] = 255;
+        mask[i] = 255;
+    }
+    Ok(rows_masked_equal(
+        rgba,
+        rgba_stride as usize,
+        width as usize * 4,
+        height as usize,
+        &expected,
+        &mask,
+    ))
+}
+
+/// Checks if every pixel of high bit-depth RGBA or BGRA image has maximum alpha.
+///
+/// # Arguments
+///
+/// * `rgba` - Source image data with alpha as last component.
+/// * `rgba_stride` - The stride (components per row) for the image data.
+/// * `width` - Image width.
+/// * `height` - Image height.
+/// * `bit_depth` - Bit-depth of the image, 8..=16.
+///
+pub fn rgba16_is_opaque(
+    rgba: &[u16],
+    rgba_stride: u32,
+    width: u32,
+    height: u32,
+    bit_depth: u32,
+) -> Result<bool, YuvError> {
+    check_bit_depth(bit_depth, 8..=16)?;
+    check_rgba_destination(rgba, rgba_stride, width, height, 4)?;
+    let max_bytes = (((1u32 << bit_depth) - 1) as u16).to_ne_bytes();
+    let mut expected = [0u8; 16];
+    let mut mask = [0u8; 16];
+    for i in (6..16).step_by(8) {
+        expected[i..i + 2].copy_from_slice(&max_bytes);
+        mask[i..i + 2].copy_from_slice(&[255, 255]);
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(rgba.as_ptr() as *const u8, rgba.len() * 2) };
+    Ok(rows_masked_equal(
+        bytes,
+        rgba_stride as usize * 2,
+        width as usize * 8,
+        height as usize,
+        &expected,
+        &mask,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::YuvPlanarImageMut;
+
+    #[test]
+    fn detects_full_range_and_opacity() {
+        let mut image = YuvPlanarImageMut::<u8>::alloc(20, 10, YuvChromaSubsampling::Yuv420);
+        image.y_plane.borrow_mut().fill(100);
+        image.y_plane.borrow_mut()[5] = 250;
+        image.y_plane.borrow_mut()[6] = 3;
+        image.u_plane.borrow_mut().fill(128);
+        image.v_plane.borrow_mut().fill(128);
+        let stats = yuv_planar_stats(&image.to_fixed(), YuvChromaSubsampling::Yuv420).unwrap();
+        assert_eq!(stats.y.min, 3);
+        assert_eq!(stats.y.max, 250);
+        assert_eq!(stats.y.histogram[100], 198);
+        assert_eq!(stats.out_of_limited_range(), 2);
+        assert_eq!(stats.detected_range(), YuvRange::Full);
+        assert!(stats.is_opaque());
+
+        let mut rgba = vec![255u8; 37 * 5 * 4];
+        assert!(rgba_is_opaque(&rgba, 37 * 4, 37, 5).unwrap());
+        rgba[4 * 4 * 37 + 35 * 4 + 3] = 254;
+        assert!(!rgba_is_opaque(&rgba, 37 * 4, 37, 5).unwrap());
+        rgba[4 * 4 * 37 + 35 * 4 + 2] = 0;
+        rgba[4 * 4 * 37 + 35 * 4 + 3] = 255;
+        assert!(rgba_is_opaque(&rgba, 37 * 4, 37, 5).unwrap());
+
+        let mut rgba16 = vec![1023u16; 33 * 3 * 4];
+        assert!(rgba16_is_opaque(&rgba16, 33 * 4, 33, 3, 10).unwrap());
+        rgba16[3] = 1000;
+        assert!(!rgba16_is_opaque(&rgba16, 33 * 4, 33, 3, 10).unwrap());
+    }
+
+    #[test]
+    fn clamps_samples_above_bit_depth() {
+        let mut plane = vec![512u16; 37 * 3];
+        plane[40] = 1100;
+        plane[41] = 7;
+        let image = YuvGrayImage {
+            y_plane: &plane,
+            y_stride: 37,
+            width: 37,
+            height: 3,
+        };
+        let stats = yuv_gray_p16_stats(&image, YuvBitDepth::lsb(10)).unwrap();
+        assert_eq!(stats.y.min, 7);
+        assert_eq!(stats.y.max, 1100);
+        assert_eq!(stats.y.out_of_bit_depth, 1);
+        assert_eq!(stats.y.histogram[1023], 1);
+        assert_eq!(stats.y.histogram[512], 37 * 3 - 2);
+        let expected_mean = (512. * (37. * 3. - 2.) + 1100. + 7.) / (37. * 3.);
+        assert!((stats.y.mean - expected_mean).abs() < 1e-9);
+
+        assert!(matches!(
+            yuv_gray_p16_stats(&image, YuvBitDepth::lsb(17)),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+    }
+}
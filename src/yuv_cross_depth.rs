@@ -0,0 +1,570 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::{check_bit_depth, check_rgba_destination};
+use crate::yuv_support::{
+    get_forward_transform, get_inverse_transform, get_yuv_range, ToIntegerTransform,
+    YuvSourceChannels,
+};
+use crate::{
+    YuvChromaSubsampling, YuvError, YuvPlanarImage, YuvPlanarImageMut, YuvRange, YuvStandardMatrix,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Decodes 8-bit planar YUV straight to RGB with `bit_depth` bits,
+/// fixed point coefficients scaled to target range so value is rounded only once.
+fn yuv8_to_rgb16_impl<const DESTINATION_CHANNELS: u8, const SAMPLING: u8>(
+    image: &YuvPlanarImage<u8>,
+    rgba16: &mut [u16],
+    rgba_stride: u32,
+    bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+
+    check_bit_depth(bit_depth, 8..=16)?;
+    image.check_constraints(chroma_subsampling)?;
+    check_rgba_destination(rgba16, rgba_stride, image.width, image.height, channels)?;
+
+    const PRECISION: i32 = 13;
+    let chroma_range = get_yuv_range(8, range);
+    let kr_kb = matrix.get_kr_kb();
+    let max_value = ((1u32 << bit_depth) - 1) as i32;
+    let transform = get_inverse_transform(
+        max_value as u32,
+        chroma_range.range_y,
+        chroma_range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    )
+    .to_integers(PRECISION as u32);
+
+    let bias_y = chroma_range.bias_y as i32;
+    let bias_uv = chroma_range.bias_uv as i32;
+    let rounding = 1i32 << (PRECISION - 1);
+    let round = |v: i32| ((v + rounding) >> PRECISION).min(max_value).max(0) as u16;
+
+    let width = image.width as usize;
+    let height = image.height as usize;
+    let h_factor = chroma_subsampling.horizontal_factor();
+    let v_factor = chroma_subsampling.vertical_factor();
+    let chroma_height = chroma_subsampling.chroma_height(height);
+
+    let process_block =
+        |chroma_y: usize, y_plane: &[u8], u_plane: &[u8], v_plane: &[u8], rgba: &mut [u16]| {
+            let rows = v_factor.min(height - chroma_y * v_factor);
+            for row in 0..rows {
+                let y_src = &y_plane[row * image.y_stride as usize..][..width];
+                let rgba = &mut rgba[row * rgba_stride as usize..][..width * channels];
+                for (x, (&y_value, dst)) in y_src
+                    .iter()
+                    .zip(rgba.chunks_exact_mut(channels))
+                    .enumerate()
+                {
+                    let cb_value = u_plane[x / h_factor] as i32 - bias_uv;
+                    let cr_value = v_plane[x / h_factor] as i32 - bias_uv;
+                    let y_value = (y_value as i32 - bias_y) * transform.y_coef;
+
+                    let r = round(y_value + transform.cr_coef * cr_value);
+                    let b = round(y_value + transform.cb_coef * cb_value);
+                    let g = round(
+                        y_value - transform.g_coeff_1 * cr_value - transform.g_coeff_2 * cb_value,
+                    );
+
+                    dst[dst_chans.get_r_channel_offset()] = r;
+                    dst[dst_chans.get_g_channel_offset()] = g;
+                    dst[dst_chans.get_b_channel_offset()] = b;
+                    if dst_chans.has_alpha() {
+                        dst[dst_chans.get_a_channel_offset()] = max_value as u16;
+                    }
+                }
+            }
+        };
+
+    let y_block = image.y_stride as usize * v_factor;
+    let rgba_block = rgba_stride as usize * v_factor;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = rgba16
+            .par_chunks_mut(rgba_block)
+            .zip(image.y_plane.par_chunks(y_block))
+            .zip(image.u_plane.par_chunks(image.u_stride as usize))
+            .zip(image.v_plane.par_chunks(image.v_stride as usize))
+            .take(chroma_height)
+            .enumerate();
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = rgba16
+            .chunks_mut(rgba_block)
+            .zip(image.y_plane.chunks(y_block))
+            .zip(image.u_plane.chunks(image.u_stride as usize))
+            .zip(image.v_plane.chunks(image.v_stride as usize))
+            .take(chroma_height)
+            .enumerate();
+    }
+    iter.for_each(|(chroma_y, (((rgba, y_plane), u_plane), v_plane))| {
+        process_block(chroma_y, y_plane, u_plane, v_plane, rgba);
+    });
+
+    Ok(())
+}
+
+/// Encodes RGB with `bit_depth` bits straight to 8-bit planar YUV,
+/// chroma is averaged at source precision before the single rounding.
+fn rgb16_to_yuv8_impl<const ORIGIN_CHANNELS: u8, const SAMPLING: u8>(
+    image: &mut YuvPlanarImageMut<u8>,
+    rgba16: &[u16],
+    rgba_stride: u32,
+    bit_depth: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    let src_chans: YuvSourceChannels = ORIGIN_CHANNELS.into();
+    let channels = src_chans.get_channels_count();
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+
+    check_bit_depth(bit_depth, 8..=16)?;
+    image.check_constraints(chroma_subsampling)?;
+    check_rgba_destination(rgba16, rgba_stride, image.width, image.height, channels)?;
+
+    // Keeps the same coefficients resolution as 8-bit path with 13 bits precision
+    let precision = 13 + bit_depth as i32 - 8;
+    let chroma_range = get_yuv_range(8, range);
+    let kr_kb = matrix.get_kr_kb();
+    let transform = get_forward_transform(
+        (1u32 << bit_depth) - 1,
+        chroma_range.range_y,
+        chroma_range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    )
+    .to_integers(precision as u32);
+
+    let rounding = 1i32 << (precision - 1);
+    let bias_y = ((chroma_range.bias_y as i32) << precision) + rounding;
+    let bias_uv = ((chroma_range.bias_uv as i32) << precision) + rounding;
+    let max_y = (chroma_range.bias_y + chroma_range.range_y) as i32;
+    let max_uv = (chroma_range.bias_uv + chroma_range.range_uv / 2) as i32;
+    let min_y = chroma_range.bias_y as i32;
+    let min_uv = chroma_range.bias_uv as i32 - (chroma_range.range_uv / 2) as i32;
+
+    let width = image.width as usize;
+    let height = image.height as usize;
+    let h_factor = chroma_subsampling.horizontal_factor();
+    let v_factor = chroma_subsampling.vertical_factor();
+    let chroma_height = chroma_subsampling.chroma_height(height);
+    let chroma_width = chroma_subsampling.chroma_width(width);
+
+    let y_stride = image.y_stride as usize;
+    let u_stride = image.u_stride as usize;
+    let v_stride = image.v_stride as usize;
+
+    let process_block = |chroma_y: usize,
+                         y_plane: &mut [u8],
+                         u_plane: &mut [u8],
+                         v_plane: &mut [u8],
+                         rgba: &[u16]| {
+        let rows = v_factor.min(height - chroma_y * v_factor);
+        for row in 0..rows {
+            let src = &rgba[row * rgba_stride as usize..][..width * channels];
+            let y_dst = &mut y_plane[row * y_stride..][..width];
+            for (dst, src) in y_dst.iter_mut().zip(src.chunks_exact(channels)) {
+                let r = src[src_chans.get_r_channel_offset()] as i32;
+                let g = src[src_chans.get_g_channel_offset()] as i32;
+                let b = src[src_chans.get_b_channel_offset()] as i32;
+                let y =
+                    (r * transform.yr + g * transform.yg + b * transform.yb + bias_y) >> precision;
+                *dst = y.min(max_y).max(min_y) as u8;
+            }
+        }
+        for (cx, (u_dst, v_dst)) in u_plane
+            .iter_mut()
+            .zip(v_plane.iter_mut())
+            .take(chroma_width)
+            .enumerate()
+        {
+            let x_start = cx * h_factor;
+            let columns = h_factor.min(width - x_start);
+            let count = (columns * rows) as i32;
+            let (mut r, mut g, mut b) = (0i32, 0i32, 0i32);
+            for row in 0..rows {
+                let src =
+                    &rgba[row * rgba_stride as usize + x_start * channels..][..columns * channels];
+                for px in src.chunks_exact(channels) {
+                    r += px[src_chans.get_r_channel_offset()] as i32;
+                    g += px[src_chans.get_g_channel_offset()] as i32;
+                    b += px[src_chans.get_b_channel_offset()] as i32;
+                }
+            }
+            let r = (r + count / 2) / count;
+            let g = (g + count / 2) / count;
+            let b = (b + count / 2) / count;
+            let cb = (r * transform.cb_r + g * transform.cb_g + b * transform.cb_b + bias_uv)
+                >> precision;
+            let cr = (r * transform.cr_r + g * transform.cr_g + b * transform.cr_b + bias_uv)
+                >> precision;
+            *u_dst = cb.min(max_uv).max(min_uv) as u8;
+            *v_dst = cr.min(max_uv).max(min_uv) as u8;
+        }
+    };
+
+    let y_block = y_stride * v_factor;
+    let rgba_block = rgba_stride as usize * v_factor;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = image
+            .y_plane
+            .borrow_mut()
+            .par_chunks_mut(y_block)
+            .zip(image.u_plane.borrow_mut().par_chunks_mut(u_stride))
+            .zip(image.v_plane.borrow_mut().par_chunks_mut(v_stride))
+            .zip(rgba16.par_chunks(rgba_block))
+            .take(chroma_height)
+            .enumerate();
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = image
+            .y_plane
+            .borrow_mut()
+            .chunks_mut(y_block)
+            .zip(image.u_plane.borrow_mut().chunks_mut(u_stride))
+            .zip(image.v_plane.borrow_mut().chunks_mut(v_stride))
+            .zip(rgba16.chunks(rgba_block))
+            .take(chroma_height)
+            .enumerate();
+    }
+    iter.for_each(|(chroma_y, (((y_plane, u_plane), v_plane), rgba))| {
+        process_block(chroma_y, y_plane, u_plane, v_plane, rgba);
+    });
+
+    Ok(())
+}
+
+macro_rules! d_cnv_yuv8_to_rgb16 {
+    ($method: ident, $px_fmt: expr, $sampling: expr, $yuv_name: expr, $px_name: expr) => {
+        #[doc = concat!("Convert 8 bit-depth ", $yuv_name, " planar format to ", $px_name, " with arbitrary bit-depth.
+
+Coefficients are scaled to target bit-depth directly, so extra precision of the result
+is preserved instead of decoding to 8 bit and upscaling.
+
+# Arguments
+
+* `planar_image` - Source ", $yuv_name, " planar image.
+* `dst` - A mutable slice to store the converted ", $px_name, " data.
+* `dst_stride` - The stride (components per row) for ", $px_name, " data.
+* `bit_depth` - Target bit-depth of ", $px_name, " data, 8..=16.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $px_name, " data are not valid based
+on the specified width, height, and strides, or if bit-depth is out of supported range.")]
+        pub fn $method(
+            planar_image: &YuvPlanarImage<u8>,
+            dst: &mut [u16],
+            dst_stride: u32,
+            bit_depth: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            yuv8_to_rgb16_impl::<{ $px_fmt as u8 }, { $sampling as u8 }>(
+                planar_image,
+                dst,
+                dst_stride,
+                bit_depth,
+                range,
+                matrix,
+            )
+        }
+    };
+}
+
+d_cnv_yuv8_to_rgb16!(
+    yuv420_to_rgb16,
+    YuvSourceChannels::Rgb,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420",
+    "RGB"
+);
+d_cnv_yuv8_to_rgb16!(
+    yuv420_to_rgba16,
+    YuvSourceChannels::Rgba,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420",
+    "RGBA"
+);
+d_cnv_yuv8_to_rgb16!(
+    yuv422_to_rgb16,
+    YuvSourceChannels::Rgb,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422",
+    "RGB"
+);
+d_cnv_yuv8_to_rgb16!(
+    yuv422_to_rgba16,
+    YuvSourceChannels::Rgba,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422",
+    "RGBA"
+);
+d_cnv_yuv8_to_rgb16!(
+    yuv444_to_rgb16,
+    YuvSourceChannels::Rgb,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444",
+    "RGB"
+);
+d_cnv_yuv8_to_rgb16!(
+    yuv444_to_rgba16,
+    YuvSourceChannels::Rgba,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444",
+    "RGBA"
+);
+
+macro_rules! d_cnv_rgb16_to_yuv8 {
+    ($method: ident, $px_fmt: expr, $sampling: expr, $yuv_name: expr, $px_name: expr) => {
+        #[doc = concat!("Convert ", $px_name, " with arbitrary bit-depth to 8 bit-depth ", $yuv_name, " planar format.
+
+Source is converted directly using coefficients scaled from source bit-depth,
+so the result is rounded once instead of reducing ", $px_name, " to 8 bit first.
+
+# Arguments
+
+* `planar_image` - Target ", $yuv_name, " planar image.
+* `src` - The input ", $px_name, " image data slice.
+* `src_stride` - The stride (components per row) for ", $px_name, " data.
+* `bit_depth` - Bit-depth of ", $px_name, " data, 8..=16.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $px_name, " data are not valid based
+on the specified width, height, and strides, or if bit-depth is out of supported range.")]
+        pub fn $method(
+            planar_image: &mut YuvPlanarImageMut<u8>,
+            src: &[u16],
+            src_stride: u32,
+            bit_depth: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            rgb16_to_yuv8_impl::<{ $px_fmt as u8 }, { $sampling as u8 }>(
+                planar_image,
+                src,
+                src_stride,
+                bit_depth,
+                range,
+                matrix,
+            )
+        }
+    };
+}
+
+d_cnv_rgb16_to_yuv8!(
+    rgb16_to_yuv420,
+    YuvSourceChannels::Rgb,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420",
+    "RGB"
+);
+d_cnv_rgb16_to_yuv8!(
+    rgba16_to_yuv420,
+    YuvSourceChannels::Rgba,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420",
+    "RGBA"
+);
+d_cnv_rgb16_to_yuv8!(
+    rgb16_to_yuv422,
+    YuvSourceChannels::Rgb,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422",
+    "RGB"
+);
+d_cnv_rgb16_to_yuv8!(
+    rgba16_to_yuv422,
+    YuvSourceChannels::Rgba,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422",
+    "RGBA"
+);
+d_cnv_rgb16_to_yuv8!(
+    rgb16_to_yuv444,
+    YuvSourceChannels::Rgb,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444",
+    "RGB"
+);
+d_cnv_rgb16_to_yuv8!(
+    rgba16_to_yuv444,
+    YuvSourceChannels::Rgba,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444",
+    "RGBA"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BufferStoreMut;
+
+    #[test]
+    fn cross_depth_round_trip() {
+        let (width, height) = (33u32, 17u32);
+        let channels = 4usize;
+        let mut rgba = vec![0u16; width as usize * height as usize * channels];
+        for px in rgba.chunks_exact_mut(channels) {
+            px.copy_from_slice(&[512, 600, 300, 1023]);
+        }
+
+        let mut planar =
+            YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        rgba16_to_yuv420(
+            &mut planar,
+            &rgba,
+            width * channels as u32,
+            10,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+
+        let mut decoded = vec![0u16; rgba.len()];
+        yuv420_to_rgba16(
+            &planar.to_fixed(),
+            &mut decoded,
+            width * channels as u32,
+            10,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+
+        for (src, dst) in rgba
+            .chunks_exact(channels)
+            .zip(decoded.chunks_exact(channels))
+        {
+            for c in 0..3 {
+                let diff = (src[c] as i32 - dst[c] as i32).abs();
+                assert!(diff <= 8, "Source {:?}, decoded {:?}", src, dst);
+            }
+            assert_eq!(dst[3], 1023);
+        }
+    }
+
+    #[test]
+    fn decodes_gray_with_target_precision() {
+        let mut planar = YuvPlanarImageMut::<u8>::alloc(4, 4, YuvChromaSubsampling::Yuv444);
+        planar.y_plane.borrow_mut().fill(128);
+        planar.u_plane.borrow_mut().fill(128);
+        planar.v_plane.borrow_mut().fill(128);
+        let mut rgb = vec![0u16; 4 * 4 * 3];
+        yuv444_to_rgb16(
+            &planar.to_fixed(),
+            &mut rgb,
+            4 * 3,
+            16,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        // 128 / 255 * 65535 = 32896
+        assert!(rgb.iter().all(|&v| v == 32896), "{:?}", &rgb[..3]);
+    }
+
+    #[test]
+    fn accepts_planes_longer_than_image() {
+        let y_plane = vec![128u8; 32];
+        let u_plane = vec![128u8; 8];
+        let v_plane = vec![128u8; 8];
+        let image = YuvPlanarImage {
+            y_plane: &y_plane,
+            y_stride: 4,
+            u_plane: &u_plane,
+            u_stride: 2,
+            v_plane: &v_plane,
+            v_stride: 2,
+            width: 4,
+            height: 2,
+        };
+        let mut rgb = vec![0u16; 96];
+        yuv420_to_rgb16(
+            &image,
+            &mut rgb,
+            4 * 3,
+            10,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        assert!(rgb[..24].iter().all(|&v| v == 514), "{:?}", &rgb[..3]);
+        assert!(rgb[24..].iter().all(|&v| v == 0));
+
+        let mut y_plane = vec![0u8; 32];
+        let mut u_plane = vec![0u8; 8];
+        let mut v_plane = vec![0u8; 8];
+        let mut target = YuvPlanarImageMut {
+            y_plane: BufferStoreMut::Borrowed(&mut y_plane),
+            y_stride: 4,
+            u_plane: BufferStoreMut::Borrowed(&mut u_plane),
+            u_stride: 2,
+            v_plane: BufferStoreMut::Borrowed(&mut v_plane),
+            v_stride: 2,
+            width: 4,
+            height: 2,
+        };
+        rgb16_to_yuv420(
+            &mut target,
+            &rgb,
+            4 * 3,
+            10,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        assert!(y_plane[..8].iter().all(|&v| v == 128));
+        assert!(y_plane[8..].iter().all(|&v| v == 0));
+        assert_eq!(&u_plane[..2], &[128, 128]);
+        assert!(u_plane[2..].iter().all(|&v| v == 0));
+    }
+}
@@ -0,0 +1,924 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::dither::{DitherTable, YuvDitherMode};
+use crate::yuv_error::check_bit_depth;
+use crate::{
+    YuvBiPlanarImage, YuvBiPlanarImageMut, YuvBytesPacking, YuvChromaSubsampling, YuvEndianness,
+    YuvError, YuvPackedImage, YuvPackedImageMut, YuvPlanarImage, YuvPlanarImageMut,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+
+/// Describes how high bit-depth samples are stored in `u16`
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct YuvBitDepth {
+    /// Bit depth of samples, 8..=16
+    pub bit_depth: u32,
+    /// Where valid bits are stored, P010 etc. use most significant bits
+    pub packing: YuvBytesPacking,
+    /// Byte order of stored samples
+    pub endianness: YuvEndianness,
+}
+
+impl YuvBitDepth {
+    /// Little endian samples stored in least significant bits, as in I010, I012
+    pub const fn lsb(bit_depth: u32) -> YuvBitDepth {
+        YuvBitDepth {
+            bit_depth,
+            packing: YuvBytesPacking::LeastSignificantBytes,
+            endianness: YuvEndianness::LittleEndian,
+        }
+    }
+
+    /// Little endian samples stored in most significant bits, as in P010, P012
+    pub const fn msb(bit_depth: u32) -> YuvBitDepth {
+        YuvBitDepth {
+            bit_depth,
+            packing: YuvBytesPacking::MostSignificantBytes,
+            endianness: YuvEndianness::LittleEndian,
+        }
+    }
+
+    fn validate(&self) -> Result<(), YuvError> {
+        check_bit_depth(self.bit_depth, 8..=16)
+    }
+
+    /// Bit depth as seen by raw `u16` value
+    fn storage_depth(&self) -> u32 {
+        match self.packing {
+            YuvBytesPacking::MostSignificantBytes => 16,
+            YuvBytesPacking::LeastSignificantBytes => self.bit_depth,
+        }
+    }
+
+    /// Whether stored bytes differs from host byte order
+    fn needs_swap(&self) -> bool {
+        let big_endian = match self.endianness {
+            #[cfg(feature = "big_endian")]
+            YuvEndianness::BigEndian => true,
+            YuvEndianness::LittleEndian => false,
+        };
+        big_endian != cfg!(target_endian = "big")
+    }
+
+    /// Reads stored sample as host order value in least significant bits
+    #[inline]
+    pub(crate) fn native(&self, v: u16) -> u16 {
+        let v = if self.needs_swap() { v.swap_bytes() } else { v };
+        v >> (self.storage_depth() - self.bit_depth)
+    }
+}
+
+/// Raw value transform `min((v + threshold) >> r_shift, max) << l_shift`
+#[derive(Debug, Copy, Clone)]
+struct DepthTransform {
+    r_shift: u32,
+    l_shift: u32,
+    max: u16,
+}
+
+impl DepthTransform {
+    fn new(src: YuvBitDepth, dst: YuvBitDepth) -> DepthTransform {
+        let src_depth = src.storage_depth();
+        let dst_msb_shift = dst.storage_depth() - dst.bit_depth;
+        if src_depth >= dst.bit_depth {
+            DepthTransform {
+                r_shift: src_depth - dst.bit_depth,
+                l_shift: dst_msb_shift,
+                max: ((1u32 << dst.bit_depth) - 1) as u16,
+            }
+        } else {
+            let widen = dst.bit_depth - src_depth;
+            DepthTransform {
+                r_shift: 0,
+                l_shift: widen + dst_msb_shift,
+                max: (((1u32 << dst.bit_depth) - 1) >> widen) as u16,
+            }
+        }
+    }
+
+    #[inline(always)]
+    fn apply(&self, v: u16, threshold: u16) -> u16 {
+        ((v.saturating_add(threshold) >> self.r_shift).min(self.max)) << self.l_shift
+    }
+}
+
+type Depth16To16Row = unsafe fn(&[u16], &mut [u16], &[u16], u32, u32, u16, usize) -> usize;
+type Depth16To8Row = unsafe fn(&[u16], &mut [u8], &[u16], u32, usize) -> usize;
+type Depth8To16Row = unsafe fn(&[u8], &mut [u16], u32, usize) -> usize;
+
+/// Runtime selected SIMD row kernels, each returns count of processed items
+#[derive(Copy, Clone)]
+pub(crate) struct DepthRowHandler {
+    row_16_to_16: Depth16To16Row,
+    row_16_to_8: Depth16To8Row,
+    row_8_to_16: Depth8To16Row,
+}
+
+impl DepthRowHandler {
+    /// Narrows host order row to 8 bit as `min((v + threshold) >> r_shift, 255)`
+    pub(crate) fn narrow_row_to_8(
+        &self,
+        src: &[u16],
+        dst: &mut [u8],
+        thresholds: &[u16],
+        r_shift: u32,
+    ) {
+        let width = dst.len();
+        let processed = unsafe { (self.row_16_to_8)(src, dst, thresholds, r_shift, width) };
+        for ((dst, &src), &threshold) in dst
+            .iter_mut()
+            .zip(src.iter())
+            .zip(thresholds.iter())
+            .skip(processed)
+        {
+            *dst = (src.saturating_add(threshold) >> r_shift).min(255) as u8;
+        }
+    }
+}
+
+fn depth_row_16_to_16_none(
+    _: &[u16],
+    _: &mut [u16],
+    _: &[u16],
+    _: u32,
+    _: u32,
+    _: u16,
+    _: usize,
+) -> usize {
+    0
+}
+
+fn depth_row_16_to_8_none(_: &[u16], _: &mut [u8], _: &[u16], _: u32, _: usize) -> usize {
+    0
+}
+
+fn depth_row_8_to_16_none(_: &[u8], _: &mut [u16], _: u32, _: usize) -> usize {
+    0
+}
+
+impl Default for DepthRowHandler {
+    fn default() -> Self {
+        #[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+        {
+            use crate::neon::{
+                neon_depth_row_16_to_16, neon_depth_row_16_to_8, neon_depth_row_8_to_16,
+            };
+            return DepthRowHandler {
+                row_16_to_16: neon_depth_row_16_to_16,
+                row_16_to_8: neon_depth_row_16_to_8,
+                row_8_to_16: neon_depth_row_8_to_16,
+            };
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "avx"))]
+        {
+            if std::arch::is_x86_feature_detected!("avx2") {
+                use crate::avx2::{
+                    avx2_depth_row_16_to_16, avx2_depth_row_16_to_8, avx2_depth_row_8_to_16,
+                };
+                return DepthRowHandler {
+                    row_16_to_16: avx2_depth_row_16_to_16,
+                    row_16_to_8: avx2_depth_row_16_to_8,
+                    row_8_to_16: avx2_depth_row_8_to_16,
+                };
+            }
+        }
+        #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "sse"))]
+        {
+            if std::arch::is_x86_feature_detected!("sse4.1") {
+                use crate::sse::{
+                    sse_depth_row_16_to_16, sse_depth_row_16_to_8, sse_depth_row_8_to_16,
+                };
+                return DepthRowHandler {
+                    row_16_to_16: sse_depth_row_16_to_16,
+                    row_16_to_8: sse_depth_row_16_to_8,
+                    row_8_to_16: sse_depth_row_8_to_16,
+                };
+            }
+        }
+        #[allow(unreachable_code)]
+        DepthRowHandler {
+            row_16_to_16: depth_row_16_to_16_none,
+            row_16_to_8: depth_row_16_to_8_none,
+            row_8_to_16: depth_row_8_to_16_none,
+        }
+    }
+}
+
+/// Reads row to host order, copying only when byte swap is required
+fn load_row<'a>(src: &'a [u16], format: YuvBitDepth, scratch: &'a mut Vec<u16>) -> &'a [u16] {
+    if format.needs_swap() {
+        scratch.clear();
+        scratch.extend(src.iter().map(|v| v.swap_bytes()));
+        scratch
+    } else {
+        src
+    }
+}
+
+fn depth_row_16_to_16(
+    handler: &DepthRowHandler,
+    transform: &DepthTransform,
+    src: &[u16],
+    src_format: YuvBitDepth,
+    dst: &mut [u16],
+    dst_format: YuvBitDepth,
+    thresholds: &[u16],
+) {
+    let mut scratch = Vec::new();
+    let src = load_row(src, src_format, &mut scratch);
+    let width = dst.len();
+    let processed = unsafe {
+        (handler.row_16_to_16)(
+            src,
+            dst,
+            thresholds,
+            transform.r_shift,
+            transform.l_shift,
+            transform.max,
+            width,
+        )
+    };
+    for ((dst, &src), &threshold) in dst
+        .iter_mut()
+        .zip(src.iter())
+        .zip(thresholds.iter())
+        .skip(processed)
+    {
+        *dst = transform.apply(src, threshold);
+    }
+    if dst_format.needs_swap() {
+        for dst in dst.iter_mut() {
+            *dst = dst.swap_bytes();
+        }
+    }
+}
+
+fn depth_row_16_to_8(
+    handler: &DepthRowHandler,
+    transform: &DepthTransform,
+    src: &[u16],
+    src_format: YuvBitDepth,
+    dst: &mut [u8],
+    thresholds: &[u16],
+) {
+    let mut scratch = Vec::new();
+    let src = load_row(src, src_format, &mut scratch);
+    handler.narrow_row_to_8(src, dst, thresholds, transform.r_shift);
+}
+
+fn depth_row_8_to_16(
+    handler: &DepthRowHandler,
+    transform: &DepthTransform,
+    src: &[u8],
+    dst: &mut [u16],
+    dst_format: YuvBitDepth,
+) {
+    let width = dst.len();
+    let processed = unsafe { (handler.row_8_to_16)(src, dst, transform.l_shift, width) };
+    for (dst, &src) in dst.iter_mut().zip(src.iter()).skip(processed) {
+        *dst = (src as u16) << transform.l_shift;
+    }
+    if dst_format.needs_swap() {
+        for dst in dst.iter_mut() {
+            *dst = dst.swap_bytes();
+        }
+    }
+}
+
+/// Applies row converter to every row of the plane
+fn convert_plane<S: Copy + Sync, D: Copy + Send>(
+    src: &[S],
+    src_stride: usize,
+    dst: &mut [D],
+    dst_stride: usize,
+    width: usize,
+    height: usize,
+    row_converter: impl Fn(&[S], &mut [D], usize) + Send + Sync,
+) {
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride)
+            .zip(src.par_chunks(src_stride))
+            .take(height)
+            .enumerate();
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride)
+            .zip(src.chunks(src_stride))
+            .take(height)
+            .enumerate();
+    }
+    iter.for_each(|(y, (dst, src))| {
+        row_converter(&src[0..width], &mut dst[0..width], y);
+    });
+}
+
+/// Describes one plane of image as (source, source stride, destination, destination stride, width, height)
+type PlanePair<'a, S, D> = (&'a [S], usize, &'a mut [D], usize, usize, usize);
+
+fn convert_planes_16_to_16(
+    planes: Vec<PlanePair<u16, u16>>,
+    src_format: YuvBitDepth,
+    dst_format: YuvBitDepth,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    src_format.validate()?;
+    dst_format.validate()?;
+    let handler = DepthRowHandler::default();
+    let transform = DepthTransform::new(src_format, dst_format);
+    for (src, src_stride, dst, dst_stride, width, height) in planes {
+        let table = DitherTable::new(dither, transform.r_shift, width);
+        convert_plane(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            |src, dst, y| {
+                depth_row_16_to_16(
+                    &handler,
+                    &transform,
+                    src,
+                    src_format,
+                    dst,
+                    dst_format,
+                    table.row(y),
+                )
+            },
+        );
+    }
+    Ok(())
+}
+
+fn convert_planes_16_to_8(
+    planes: Vec<PlanePair<u16, u8>>,
+    src_format: YuvBitDepth,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    src_format.validate()?;
+    let handler = DepthRowHandler::default();
+    let transform = DepthTransform::new(src_format, YuvBitDepth::lsb(8));
+    for (src, src_stride, dst, dst_stride, width, height) in planes {
+        let table = DitherTable::new(dither, transform.r_shift, width);
+        convert_plane(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            |src, dst, y| {
+                depth_row_16_to_8(&handler, &transform, src, src_format, dst, table.row(y))
+            },
+        );
+    }
+    Ok(())
+}
+
+fn convert_planes_8_to_16(
+    planes: Vec<PlanePair<u8, u16>>,
+    dst_format: YuvBitDepth,
+) -> Result<(), YuvError> {
+    dst_format.validate()?;
+    let handler = DepthRowHandler::default();
+    let transform = DepthTransform::new(YuvBitDepth::lsb(8), dst_format);
+    for (src, src_stride, dst, dst_stride, width, height) in planes {
+        convert_plane(
+            src,
+            src_stride,
+            dst,
+            dst_stride,
+            width,
+            height,
+            |src, dst, _| depth_row_8_to_16(&handler, &transform, src, dst, dst_format),
+        );
+    }
+    Ok(())
+}
+
+fn check_same_size(src: (u32, u32), dst: (u32, u32)) -> Result<(), YuvError> {
+    if src != dst {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+    Ok(())
+}
+
+macro_rules! planar_planes {
+    ($src: expr, $dst: expr, $sampling: expr) => {{
+        $src.check_constraints($sampling)?;
+        $dst.check_constraints($sampling)?;
+        check_same_size(($src.width, $src.height), ($dst.width, $dst.height))?;
+        let width = $src.width as usize;
+        let height = $src.height as usize;
+        let chroma_width = $sampling.chroma_width(width);
+        let chroma_height = $sampling.chroma_height(height);
+        let y_stride = $dst.y_stride as usize;
+        let u_stride = $dst.u_stride as usize;
+        let v_stride = $dst.v_stride as usize;
+        vec![
+            (
+                $src.y_plane,
+                $src.y_stride as usize,
+                $dst.y_plane.borrow_mut(),
+                y_stride,
+                width,
+                height,
+            ),
+            (
+                $src.u_plane,
+                $src.u_stride as usize,
+                $dst.u_plane.borrow_mut(),
+                u_stride,
+                chroma_width,
+                chroma_height,
+            ),
+            (
+                $src.v_plane,
+                $src.v_stride as usize,
+                $dst.v_plane.borrow_mut(),
+                v_stride,
+                chroma_width,
+                chroma_height,
+            ),
+        ]
+    }};
+}
+
+macro_rules! bi_planar_planes {
+    ($src: expr, $dst: expr, $sampling: expr) => {{
+        $src.check_constraints($sampling)?;
+        $dst.check_constraints($sampling)?;
+        check_same_size(($src.width, $src.height), ($dst.width, $dst.height))?;
+        let width = $src.width as usize;
+        let height = $src.height as usize;
+        let y_stride = $dst.y_stride as usize;
+        let uv_stride = $dst.uv_stride as usize;
+        vec![
+            (
+                $src.y_plane,
+                $src.y_stride as usize,
+                $dst.y_plane.borrow_mut(),
+                y_stride,
+                width,
+                height,
+            ),
+            (
+                $src.uv_plane,
+                $src.uv_stride as usize,
+                $dst.uv_plane.borrow_mut(),
+                uv_stride,
+                $sampling.chroma_width(width) * 2,
+                $sampling.chroma_height(height),
+            ),
+        ]
+    }};
+}
+
+macro_rules! packed_planes {
+    ($src: expr, $dst: expr) => {{
+        $src.check_constraints()?;
+        $dst.check_constraints()?;
+        check_same_size(($src.width, $src.height), ($dst.width, $dst.height))?;
+        let yuy_stride = $dst.yuy_stride as usize;
+        vec![(
+            $src.yuy,
+            $src.yuy_stride as usize,
+            $dst.yuy.borrow_mut(),
+            yuy_stride,
+            ($src.width as usize).div_ceil(2) * 4,
+            $src.height as usize,
+        )]
+    }};
+}
+
+/// Converts bit depth of planar high bit-depth image into another high bit-depth.
+///
+/// Narrowing uses `dither` to reduce values, widening shifts values left,
+/// so limited range stays limited range on the new bit depth.
+///
+/// # Arguments
+///
+/// * `src` - Source planar image.
+/// * `src_depth` - Bit depth and storage of the source image.
+/// * `dst` - Target planar image, must have the same dimensions as source.
+/// * `dst_depth` - Bit depth and storage of the target image.
+/// * `sampling` - Chroma subsampling of both images.
+/// * `dither` - How values are reduced when narrowing.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, images dimensions do not match
+/// or bit depth is not in 8..=16.
+pub fn convert_planar_depth_16_to_16(
+    src: &YuvPlanarImage<u16>,
+    src_depth: YuvBitDepth,
+    dst: &mut YuvPlanarImageMut<u16>,
+    dst_depth: YuvBitDepth,
+    sampling: YuvChromaSubsampling,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    let planes = planar_planes!(src, dst, sampling);
+    convert_planes_16_to_16(planes, src_depth, dst_depth, dither)
+}
+
+/// Converts planar high bit-depth image to 8 bit-depth planar image.
+///
+/// # Arguments
+///
+/// * `src` - Source planar image.
+/// * `src_depth` - Bit depth and storage of the source image.
+/// * `dst` - Target 8-bit planar image, must have the same dimensions as source.
+/// * `sampling` - Chroma subsampling of both images.
+/// * `dither` - How values are reduced.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, images dimensions do not match
+/// or bit depth is not in 8..=16.
+pub fn convert_planar_depth_16_to_8(
+    src: &YuvPlanarImage<u16>,
+    src_depth: YuvBitDepth,
+    dst: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSubsampling,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    let planes = planar_planes!(src, dst, sampling);
+    convert_planes_16_to_8(planes, src_depth, dither)
+}
+
+/// Converts 8 bit-depth planar image to high bit-depth planar image.
+///
+/// # Arguments
+///
+/// * `src` - Source 8-bit planar image.
+/// * `dst` - Target planar image, must have the same dimensions as source.
+/// * `dst_depth` - Bit depth and storage of the target image.
+/// * `sampling` - Chroma subsampling of both images.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, images dimensions do not match
+/// or bit depth is not in 8..=16.
+pub fn convert_planar_depth_8_to_16(
+    src: &YuvPlanarImage<u8>,
+    dst: &mut YuvPlanarImageMut<u16>,
+    dst_depth: YuvBitDepth,
+    sampling: YuvChromaSubsampling,
+) -> Result<(), YuvError> {
+    let planes = planar_planes!(src, dst, sampling);
+    convert_planes_8_to_16(planes, dst_depth)
+}
+
+/// Converts bit depth of bi-planar high bit-depth image into another high bit-depth.
+///
+/// # Arguments
+///
+/// * `src` - Source bi-planar image.
+/// * `src_depth` - Bit depth and storage of the source image.
+/// * `dst` - Target bi-planar image, must have the same dimensions as source.
+/// * `dst_depth` - Bit depth and storage of the target image.
+/// * `sampling` - Chroma subsampling of both images.
+/// * `dither` - How values are reduced when narrowing.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, images dimensions do not match
+/// or bit depth is not in 8..=16.
+pub fn convert_bi_planar_depth_16_to_16(
+    src: &YuvBiPlanarImage<u16>,
+    src_depth: YuvBitDepth,
+    dst: &mut YuvBiPlanarImageMut<u16>,
+    dst_depth: YuvBitDepth,
+    sampling: YuvChromaSubsampling,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    let planes = bi_planar_planes!(src, dst, sampling);
+    convert_planes_16_to_16(planes, src_depth, dst_depth, dither)
+}
+
+/// Converts bi-planar high bit-depth image to 8 bit-depth bi-planar image, e.g. P010 to NV12.
+///
+/// # Arguments
+///
+/// * `src` - Source bi-planar image.
+/// * `src_depth` - Bit depth and storage of the source image.
+/// * `dst` - Target 8-bit bi-planar image, must have the same dimensions as source.
+/// * `sampling` - Chroma subsampling of both images.
+/// * `dither` - How values are reduced.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, images dimensions do not match
+/// or bit depth is not in 8..=16.
+pub fn convert_bi_planar_depth_16_to_8(
+    src: &YuvBiPlanarImage<u16>,
+    src_depth: YuvBitDepth,
+    dst: &mut YuvBiPlanarImageMut<u8>,
+    sampling: YuvChromaSubsampling,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    let planes = bi_planar_planes!(src, dst, sampling);
+    convert_planes_16_to_8(planes, src_depth, dither)
+}
+
+/// Converts 8 bit-depth bi-planar image to high bit-depth bi-planar image, e.g. NV12 to P010.
+///
+/// # Arguments
+///
+/// * `src` - Source 8-bit bi-planar image.
+/// * `dst` - Target bi-planar image, must have the same dimensions as source.
+/// * `dst_depth` - Bit depth and storage of the target image.
+/// * `sampling` - Chroma subsampling of both images.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, images dimensions do not match
+/// or bit depth is not in 8..=16.
+pub fn convert_bi_planar_depth_8_to_16(
+    src: &YuvBiPlanarImage<u8>,
+    dst: &mut YuvBiPlanarImageMut<u16>,
+    dst_depth: YuvBitDepth,
+    sampling: YuvChromaSubsampling,
+) -> Result<(), YuvError> {
+    let planes = bi_planar_planes!(src, dst, sampling);
+    convert_planes_8_to_16(planes, dst_depth)
+}
+
+/// Converts bit depth of packed YUV 4:2:2 high bit-depth image into another high bit-depth.
+///
+/// Packed layout ( YUYV, UYVY etc. ) is preserved.
+///
+/// # Arguments
+///
+/// * `src` - Source packed image.
+/// * `src_depth` - Bit depth and storage of the source image.
+/// * `dst` - Target packed image, must have the same dimensions as source.
+/// * `dst_depth` - Bit depth and storage of the target image.
+/// * `dither` - How values are reduced when narrowing.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the images are not valid based
+/// on the specified width, height, and strides, images dimensions do not match
+/// or bit depth is not in 8..=16.
+pub fn convert_packed_depth_16_to_16(
+    src: &YuvPackedImage<u16>,
+    src_depth: YuvBitDepth,
+    dst: &mut YuvPackedImageMut<u16>,
+    dst_depth: YuvBitDepth,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    let planes = packed_planes!(src, dst);
+    convert_planes_16_to_16(planes, src_depth, dst_depth, dither)
+}
+
+/// Converts packed YUV 4:2:2 high bit-depth image to 8 bit-depth packed image.
+///
+/// Packed layout ( YUYV, UYVY etc. ) is preserved.
+///
+/// # Arguments
+///
+/// * `src` - Source packed image.
+/// * `src_depth` - Bit depth and storage of the source image.
+/// * `dst` - Target 8-bit packed image, must have the same dimensions as source.
+/// * `dither` - How values are reduced.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the images are not valid based
+/// on the specified width, height, and strides, images dimensions do not match
+/// or bit depth is not in 8..=16.
+pub fn convert_packed_depth_16_to_8(
+    src: &YuvPackedImage<u16>,
+    src_depth: YuvBitDepth,
+    dst: &mut YuvPackedImageMut<u8>,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    let planes = packed_planes!(src, dst);
+    convert_planes_16_to_8(planes, src_depth, dither)
+}
+
+/// Converts packed YUV 4:2:2 8 bit-depth image to high bit-depth packed image.
+///
+/// Packed layout ( YUYV, UYVY etc. ) is preserved.
+///
+/// # Arguments
+///
+/// * `src` - Source 8-bit packed image.
+/// * `dst` - Target packed image, must have the same dimensions as source.
+/// * `dst_depth` - Bit depth and storage of the target image.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the images are not valid based
+/// on the specified width, height, and strides, images dimensions do not match
+/// or bit depth is not in 8..=16.
+pub fn convert_packed_depth_8_to_16(
+    src: &YuvPackedImage<u8>,
+    dst: &mut YuvPackedImageMut<u16>,
+    dst_depth: YuvBitDepth,
+) -> Result<(), YuvError> {
+    let planes = packed_planes!(src, dst);
+    convert_planes_8_to_16(planes, dst_depth)
+}
+
+/// Converts I010 to I420 ( YUV 420 8-bit ).
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or images dimensions do not match.
+pub fn i010_to_i420(
+    src: &YuvPlanarImage<u16>,
+    dst: &mut YuvPlanarImageMut<u8>,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    convert_planar_depth_16_to_8(
+        src,
+        YuvBitDepth::lsb(10),
+        dst,
+        YuvChromaSubsampling::Yuv420,
+        dither,
+    )
+}
+
+/// Converts I420 ( YUV 420 8-bit ) to I010.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or images dimensions do not match.
+pub fn i420_to_i010(
+    src: &YuvPlanarImage<u8>,
+    dst: &mut YuvPlanarImageMut<u16>,
+) -> Result<(), YuvError> {
+    convert_planar_depth_8_to_16(src, dst, YuvBitDepth::lsb(10), YuvChromaSubsampling::Yuv420)
+}
+
+/// Converts P010 to NV12.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or images dimensions do not match.
+pub fn p010_to_nv12(
+    src: &YuvBiPlanarImage<u16>,
+    dst: &mut YuvBiPlanarImageMut<u8>,
+    dither: YuvDitherMode,
+) -> Result<(), YuvError> {
+    convert_bi_planar_depth_16_to_8(
+        src,
+        YuvBitDepth::msb(10),
+        dst,
+        YuvChromaSubsampling::Yuv420,
+        dither,
+    )
+}
+
+/// Converts NV12 to P010.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or images dimensions do not match.
+pub fn nv12_to_p010(
+    src: &YuvBiPlanarImage<u8>,
+    dst: &mut YuvBiPlanarImageMut<u16>,
+) -> Result<(), YuvError> {
+    convert_bi_planar_depth_8_to_16(src, dst, YuvBitDepth::msb(10), YuvChromaSubsampling::Yuv420)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_depth_round_trip() {
+        let width = 37u32;
+        let height = 11u32;
+        let mut source =
+            YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in source.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (i * 7 % 256) as u8;
+        }
+        for (i, v) in source.u_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (i * 5 % 256) as u8;
+        }
+        let mut wide = YuvPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        i420_to_i010(&source.to_fixed(), &mut wide).unwrap();
+        assert!(wide.y_plane.borrow().iter().all(|&v| v <= 1023));
+
+        let mut msb = YuvPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        convert_planar_depth_16_to_16(
+            &wide.to_fixed(),
+            YuvBitDepth::lsb(10),
+            &mut msb,
+            YuvBitDepth::msb(12),
+            YuvChromaSubsampling::Yuv420,
+            YuvDitherMode::Round,
+        )
+        .unwrap();
+        assert!(matches!(
+            convert_planar_depth_16_to_16(
+                &wide.to_fixed(),
+                YuvBitDepth::lsb(10),
+                &mut msb,
+                YuvBitDepth::msb(17),
+                YuvChromaSubsampling::Yuv420,
+                YuvDitherMode::Round,
+            ),
+            Err(YuvError::UnsupportedBitDepth(17))
+        ));
+
+        for dither in [
+            YuvDitherMode::Truncate,
+            YuvDitherMode::Round,
+            YuvDitherMode::Ordered,
+            YuvDitherMode::BlueNoise,
+        ] {
+            let mut narrow =
+                YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+            convert_planar_depth_16_to_8(
+                &msb.to_fixed(),
+                YuvBitDepth::msb(12),
+                &mut narrow,
+                YuvChromaSubsampling::Yuv420,
+                dither,
+            )
+            .unwrap();
+            assert_eq!(narrow.y_plane.borrow(), source.y_plane.borrow());
+            assert_eq!(narrow.u_plane.borrow(), source.u_plane.borrow());
+        }
+    }
+
+    #[test]
+    fn test_dither_preserves_mean() {
+        let width = 64usize;
+        let height = 16usize;
+        // 10-bit value 513 is 128.25 in 8-bit
+        let src = vec![513u16; width * height];
+        let src_image = YuvPackedImage {
+            yuy: &src,
+            yuy_stride: width as u32,
+            width: width as u32 / 2,
+            height: height as u32,
+        };
+        for (dither, expected) in [
+            (YuvDitherMode::Round, 128f64),
+            (YuvDitherMode::Ordered, 128.25f64),
+            (YuvDitherMode::BlueNoise, 128.25f64),
+        ] {
+            let mut dst = YuvPackedImageMut::<u8> {
+                yuy: crate::BufferStoreMut::Owned(vec![0u8; width * height]),
+                yuy_stride: width as u32,
+                width: width as u32 / 2,
+                height: height as u32,
+            };
+            convert_packed_depth_16_to_8(&src_image, YuvBitDepth::lsb(10), &mut dst, dither)
+                .unwrap();
+            let mean =
+                dst.yuy.borrow().iter().map(|&v| v as f64).sum::<f64>() / (width * height) as f64;
+            assert!((mean - expected).abs() < 0.02, "{:?} mean {}", dither, mean);
+        }
+    }
+}
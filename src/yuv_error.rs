@@ -29,6 +29,7 @@
 use crate::yuv_support::YuvChromaSubsampling;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
 
 #[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq)]
 pub struct MismatchedSize {
@@ -49,12 +50,32 @@ pub enum YuvError {
     PackedFrameSizeMismatch(MismatchedSize),
     ImagesSizesNotMatch,
     ImageDimensionsNotMatch,
+    RegionOutOfBounds,
+    CropOriginNotAligned,
+    RegionNotBorrowable,
+    InvalidLut(String),
+    UnsupportedBitDepth(u32),
+    UnsupportedChannelsCount(usize),
 }
 
 impl Display for YuvError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             YuvError::ImageDimensionsNotMatch => f.write_str("Buffer must match image dimensions"),
+            YuvError::RegionOutOfBounds => f.write_str("Region must lie inside the image"),
+            YuvError::InvalidLut(reason) => f.write_fmt(format_args!("Invalid LUT: {reason}")),
+            YuvError::UnsupportedBitDepth(bit_depth) => {
+                f.write_fmt(format_args!("Bit depth {bit_depth} is not supported"))
+            }
+            YuvError::UnsupportedChannelsCount(channels) => f.write_fmt(format_args!(
+                "Only RGB and RGBA is supported, but {channels} channels were given"
+            )),
+            YuvError::RegionNotBorrowable => f.write_str(
+                "Region would overrun the end of the plane with whole rows, it must be copied",
+            ),
+            YuvError::CropOriginNotAligned => {
+                f.write_str("Crop origin must be aligned to the chroma subsampling")
+            }
             YuvError::ImagesSizesNotMatch => {
                 f.write_str("All images size must match in one function")
             }
@@ -94,6 +115,17 @@ impl Display for YuvError {
 
 impl Error for YuvError {}
 
+#[inline]
+pub(crate) fn check_bit_depth(
+    bit_depth: u32,
+    supported: RangeInclusive<u32>,
+) -> Result<(), YuvError> {
+    if !supported.contains(&bit_depth) {
+        return Err(YuvError::UnsupportedBitDepth(bit_depth));
+    }
+    Ok(())
+}
+
 #[inline]
 pub(crate) fn check_overflow_v2(v0: usize, v1: usize) -> Result<(), YuvError> {
     let (_, overflow) = v0.overflowing_mul(v1);
@@ -155,12 +187,14 @@ pub(crate) fn check_yuv_packed422<V>(
     }
     check_overflow_v2(stride as usize, height as usize)?;
     check_overflow_v2(width as usize, height as usize)?;
-    let full_size = if width % 2 == 0 {
-        2 * width as usize * height as usize
-    } else {
-        2 * (width as usize + 1) * height as usize
-    };
-    if data.len() != full_size {
+    let row_length = width.div_ceil(2) as usize * 4;
+    if (stride as usize) < row_length {
+        return Err(YuvError::MinimumStrideSizeMismatch(MismatchedSize {
+            expected: row_length,
+            received: stride as usize,
+        }));
+    }
+    if data.len() < stride as usize * height as usize {
         return Err(YuvError::PackedFrameSizeMismatch(MismatchedSize {
             expected: stride as usize * height as usize,
             received: data.len(),
@@ -263,9 +297,7 @@ pub(crate) fn check_interleaved_chroma_channel<V>(
             received: stride as usize * chroma_height as usize,
         }));
     }
-    if stride as usize * chroma_height as usize != data.len()
-        || chroma_min_width as usize * chroma_height as usize != data.len()
-    {
+    if data.len() < stride as usize * chroma_height as usize {
         return Err(YuvError::ChromaPlaneSizeMismatch(MismatchedSize {
             expected: stride as usize * chroma_height as usize,
             received: data.len(),
@@ -0,0 +1,842 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+#![forbid(unsafe_code)]
+use crate::yuv_error::check_bit_depth;
+use crate::yuv_support::{get_forward_transform, get_inverse_transform, get_yuv_range};
+use crate::{
+    YuvBiPlanarImage, YuvBiPlanarImageMut, YuvChromaSubsampling, YuvError, YuvPackedImage,
+    YuvPackedImageMut, YuvPlanarImage, YuvPlanarImageMut, YuvRange, YuvStandardMatrix,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+use std::fmt::Debug;
+
+const PRECISION: i64 = 14;
+
+/// Fixed point (Y, Cb, Cr) -> (Y', Cb', Cr') affine transform
+struct MatrixRangeTransform {
+    coeffs: [[i64; 3]; 3],
+    src_bias: [i64; 3],
+    dst_bias: [i64; 3],
+    max_value: i64,
+}
+
+impl MatrixRangeTransform {
+    fn new(
+        bit_depth: u32,
+        src_range: YuvRange,
+        src_matrix: YuvStandardMatrix,
+        dst_range: YuvRange,
+        dst_matrix: YuvStandardMatrix,
+    ) -> Self {
+        let max_value = (1u32 << bit_depth) - 1;
+        let src_chroma_range = get_yuv_range(bit_depth, src_range);
+        let dst_chroma_range = get_yuv_range(bit_depth, dst_range);
+        let src_bias = src_matrix.get_kr_kb();
+        let dst_bias = dst_matrix.get_kr_kb();
+        let inverse = get_inverse_transform(
+            max_value,
+            src_chroma_range.range_y,
+            src_chroma_range.range_uv,
+            src_bias.kr,
+            src_bias.kb,
+        );
+        let forward = get_forward_transform(
+            max_value,
+            dst_chroma_range.range_y,
+            dst_chroma_range.range_uv,
+            dst_bias.kr,
+            dst_bias.kb,
+        );
+        // Rows are R, G, B, columns are Y, Cb, Cr
+        let to_rgb = [
+            [inverse.y_coef, 0f32, inverse.cr_coef],
+            [inverse.y_coef, -inverse.g_coeff_2, -inverse.g_coeff_1],
+            [inverse.y_coef, inverse.cb_coef, 0f32],
+        ];
+        let from_rgb = [
+            [forward.yr, forward.yg, forward.yb],
+            [forward.cb_r, forward.cb_g, forward.cb_b],
+            [forward.cr_r, forward.cr_g, forward.cr_b],
+        ];
+        let scale = (1i64 << PRECISION) as f32;
+        let mut coeffs = [[0i64; 3]; 3];
+        for (dst_row, from_rgb) in coeffs.iter_mut().zip(from_rgb.iter()) {
+            for (column, dst) in dst_row.iter_mut().enumerate() {
+                let value = from_rgb[0] * to_rgb[0][column]
+                    + from_rgb[1] * to_rgb[1][column]
+                    + from_rgb[2] * to_rgb[2][column];
+                *dst = (value * scale).round() as i64;
+            }
+        }
+        MatrixRangeTransform {
+            coeffs,
+            src_bias: [
+                src_chroma_range.bias_y as i64,
+                src_chroma_range.bias_uv as i64,
+                src_chroma_range.bias_uv as i64,
+            ],
+            dst_bias: [
+                dst_chroma_range.bias_y as i64,
+                dst_chroma_range.bias_uv as i64,
+                dst_chroma_range.bias_uv as i64,
+            ],
+            max_value: max_value as i64,
+        }
+    }
+
+    #[inline(always)]
+    fn apply_row(&self, row: usize, y: i32, cb: i32, cr: i32) -> i32 {
+        const ROUNDING: i64 = 1 << (PRECISION - 1);
+        let coeffs = &self.coeffs[row];
+        let acc = coeffs[0] * (y as i64 - self.src_bias[0])
+            + coeffs[1] * (cb as i64 - self.src_bias[1])
+            + coeffs[2] * (cr as i64 - self.src_bias[2]);
+        (((acc + ROUNDING) >> PRECISION) + self.dst_bias[row])
+            .min(self.max_value)
+            .max(0) as i32
+    }
+
+    #[inline(always)]
+    fn luma(&self, y: i32, cb: i32, cr: i32) -> i32 {
+        self.apply_row(0, y, cb, cr)
+    }
+
+    /// Chroma takes averaged luma of the block chroma sample covers
+    #[inline(always)]
+    fn chroma(&self, y: i32, cb: i32, cr: i32) -> (i32, i32) {
+        (self.apply_row(1, y, cb, cr), self.apply_row(2, y, cb, cr))
+    }
+}
+
+fn check_same_size(src: (u32, u32), dst: (u32, u32)) -> Result<(), YuvError> {
+    if src != dst {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+    Ok(())
+}
+
+fn convert_planar<V: Copy + Debug + Send + Sync, const SAMPLING: u8>(
+    src: &YuvPlanarImage<V>,
+    dst: &mut YuvPlanarImageMut<V>,
+    transform: &MatrixRangeTransform,
+    load: impl Fn(V) -> i32 + Send + Sync,
+    store: impl Fn(i32) -> V + Send + Sync,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    src.check_constraints(chroma_subsampling)?;
+    dst.check_constraints(chroma_subsampling)?;
+    check_same_size((src.width, src.height), (dst.width, dst.height))?;
+
+    let width = src.width as usize;
+    let horizontal = chroma_subsampling.horizontal_factor();
+    let vertical = chroma_subsampling.vertical_factor();
+    let chroma_width = chroma_subsampling.chroma_width(width);
+    let chroma_rows = chroma_subsampling.chroma_height(src.height as usize);
+    let src_y_stride = src.y_stride as usize;
+    let dst_y_stride = dst.y_stride as usize;
+    let dst_u_stride = dst.u_stride as usize;
+    let dst_v_stride = dst.v_stride as usize;
+
+    let y_plane = dst.y_plane.borrow_mut();
+    let u_plane = dst.u_plane.borrow_mut();
+    let v_plane = dst.v_plane.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = y_plane
+            .par_chunks_mut(dst_y_stride * vertical)
+            .zip(u_plane.par_chunks_mut(dst_u_stride))
+            .zip(v_plane.par_chunks_mut(dst_v_stride))
+            .zip(src.y_plane.par_chunks(src_y_stride * vertical))
+            .zip(src.u_plane.par_chunks(src.u_stride as usize))
+            .zip(src.v_plane.par_chunks(src.v_stride as usize))
+            .take(chroma_rows);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = y_plane
+            .chunks_mut(dst_y_stride * vertical)
+            .zip(u_plane.chunks_mut(dst_u_stride))
+            .zip(v_plane.chunks_mut(dst_v_stride))
+            .zip(src.y_plane.chunks(src_y_stride * vertical))
+            .zip(src.u_plane.chunks(src.u_stride as usize))
+            .zip(src.v_plane.chunks(src.v_stride as usize))
+            .take(chroma_rows);
+    }
+    iter.for_each(|(((((y_dst, u_dst), v_dst), y_src), u_src), v_src)| {
+        let rows = y_src.len().div_ceil(src_y_stride).min(vertical);
+        for cx in 0..chroma_width {
+            let cb = load(u_src[cx]);
+            let cr = load(v_src[cx]);
+            let x_start = cx * horizontal;
+            let x_end = (x_start + horizontal).min(width);
+            let mut luma_sum = 0i32;
+            for row in 0..rows {
+                for x in x_start..x_end {
+                    let y = load(y_src[row * src_y_stride + x]);
+                    luma_sum += y;
+                    y_dst[row * dst_y_stride + x] = store(transform.luma(y, cb, cr));
+                }
+            }
+            let count = (rows * (x_end - x_start)) as i32;
+            let (new_cb, new_cr) = transform.chroma((luma_sum + count / 2) / count, cb, cr);
+            u_dst[cx] = store(new_cb);
+            v_dst[cx] = store(new_cr);
+        }
+    });
+
+    Ok(())
+}
+
+fn convert_bi_planar<V: Copy + Debug + Send + Sync, const SAMPLING: u8>(
+    src: &YuvBiPlanarImage<V>,
+    dst: &mut YuvBiPlanarImageMut<V>,
+    transform: &MatrixRangeTransform,
+    u_position: usize,
+    load: impl Fn(V) -> i32 + Send + Sync,
+    store: impl Fn(i32) -> V + Send + Sync,
+) -> Result<(), YuvError> {
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    src.check_constraints(chroma_subsampling)?;
+    dst.check_constraints(chroma_subsampling)?;
+    check_same_size((src.width, src.height), (dst.width, dst.height))?;
+
+    let width = src.width as usize;
+    let horizontal = chroma_subsampling.horizontal_factor();
+    let vertical = chroma_subsampling.vertical_factor();
+    let chroma_width = chroma_subsampling.chroma_width(width);
+    let chroma_rows = chroma_subsampling.chroma_height(src.height as usize);
+    let v_position = 1 - u_position;
+    let src_y_stride = src.y_stride as usize;
+    let dst_y_stride = dst.y_stride as usize;
+    let dst_uv_stride = dst.uv_stride as usize;
+
+    let y_plane = dst.y_plane.borrow_mut();
+    let uv_plane = dst.uv_plane.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = y_plane
+            .par_chunks_mut(dst_y_stride * vertical)
+            .zip(uv_plane.par_chunks_mut(dst_uv_stride))
+            .zip(src.y_plane.par_chunks(src_y_stride * vertical))
+            .zip(src.uv_plane.par_chunks(src.uv_stride as usize))
+            .take(chroma_rows);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = y_plane
+            .chunks_mut(dst_y_stride * vertical)
+            .zip(uv_plane.chunks_mut(dst_uv_stride))
+            .zip(src.y_plane.chunks(src_y_stride * vertical))
+            .zip(src.uv_plane.chunks(src.uv_stride as usize))
+            .take(chroma_rows);
+    }
+    iter.for_each(|(((y_dst, uv_dst), y_src), uv_src)| {
+        let rows = y_src.len().div_ceil(src_y_stride).min(vertical);
+        for cx in 0..chroma_width {
+            let cb = load(uv_src[cx * 2 + u_position]);
+            let cr = load(uv_src[cx * 2 + v_position]);
+            let x_start = cx * horizontal;
+            let x_end = (x_start + horizontal).min(width);
+            let mut luma_sum = 0i32;
+            for row in 0..rows {
+                for x in x_start..x_end {
+                    let y = load(y_src[row * src_y_stride + x]);
+                    luma_sum += y;
+                    y_dst[row * dst_y_stride + x] = store(transform.luma(y, cb, cr));
+                }
+            }
+            let count = (rows * (x_end - x_start)) as i32;
+            let (new_cb, new_cr) = transform.chroma((luma_sum + count / 2) / count, cb, cr);
+            uv_dst[cx * 2 + u_position] = store(new_cb);
+            uv_dst[cx * 2 + v_position] = store(new_cr);
+        }
+    });
+
+    Ok(())
+}
+
+/// Packed layout is described as positions of Y0, U, Y1, V in 4 components group
+fn convert_packed<V: Copy + Debug + Send + Sync>(
+    src: &YuvPackedImage<V>,
+    dst: &mut YuvPackedImageMut<V>,
+    transform: &MatrixRangeTransform,
+    positions: [usize; 4],
+    load: impl Fn(V) -> i32 + Send + Sync,
+    store: impl Fn(i32) -> V + Send + Sync,
+) -> Result<(), YuvError> {
+    src.check_constraints()?;
+    dst.check_constraints()?;
+    check_same_size((src.width, src.height), (dst.width, dst.height))?;
+
+    let [y0_pos, u_pos, y1_pos, v_pos] = positions;
+    let groups = (src.width as usize).div_ceil(2);
+    let dst_stride = dst.yuy_stride as usize;
+    let yuy = dst.yuy.borrow_mut();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = yuy
+            .par_chunks_mut(dst_stride)
+            .zip(src.yuy.par_chunks(src.yuy_stride as usize))
+            .take(src.height as usize);
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = yuy
+            .chunks_mut(dst_stride)
+            .zip(src.yuy.chunks(src.yuy_stride as usize))
+            .take(src.height as usize);
+    }
+    iter.for_each(|(dst, src)| {
+        for (dst, src) in dst[0..groups * 4]
+            .chunks_exact_mut(4)
+            .zip(src[0..groups * 4].chunks_exact(4))
+        {
+            let y0 = load(src[y0_pos]);
+            let y1 = load(src[y1_pos]);
+            let cb = load(src[u_pos]);
+            let cr = load(src[v_pos]);
+            dst[y0_pos] = store(transform.luma(y0, cb, cr));
+            dst[y1_pos] = store(transform.luma(y1, cb, cr));
+            let (new_cb, new_cr) = transform.chroma((y0 + y1 + 1) >> 1, cb, cr);
+            dst[u_pos] = store(new_cb);
+            dst[v_pos] = store(new_cr);
+        }
+    });
+
+    Ok(())
+}
+
+macro_rules! d_planar {
+    ($method: ident, $sampling: expr, $sampling_written: expr) => {
+        #[doc = concat!("Converts ", $sampling_written, " planar 8-bit image between YUV matrices and ranges.
+
+Transform is applied directly on (Y, Cb, Cr) in one fixed point pass without intermediate RGB,
+so only one quantization happens. When chroma is subsampled, new chroma is computed with averaged
+luma of the block it covers.
+
+# Arguments
+
+* `src` - Source planar image.
+* `dst` - Target planar image, must have the same dimensions as source.
+* `src_range` - The YUV range (limited or full) of source image.
+* `src_matrix` - The YUV standard matrix of source image.
+* `dst_range` - The YUV range (limited or full) of target image.
+* `dst_matrix` - The YUV standard matrix of target image.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or images dimensions do not match.
+")]
+        pub fn $method(
+            src: &YuvPlanarImage<u8>,
+            dst: &mut YuvPlanarImageMut<u8>,
+            src_range: YuvRange,
+            src_matrix: YuvStandardMatrix,
+            dst_range: YuvRange,
+            dst_matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            let transform =
+                MatrixRangeTransform::new(8, src_range, src_matrix, dst_range, dst_matrix);
+            convert_planar::<u8, { $sampling as u8 }>(
+                src,
+                dst,
+                &transform,
+                |v| v as i32,
+                |v| v as u8,
+            )
+        }
+    };
+}
+
+d_planar!(
+    yuv420_convert_matrix_range,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420"
+);
+d_planar!(
+    yuv422_convert_matrix_range,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422"
+);
+d_planar!(
+    yuv444_convert_matrix_range,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444"
+);
+
+macro_rules! d_planar_p16 {
+    ($method: ident, $sampling: expr, $sampling_written: expr) => {
+        #[doc = concat!("Converts ", $sampling_written, " planar high bit-depth image between YUV matrices and ranges.
+
+Transform is applied directly on (Y, Cb, Cr) in one fixed point pass without intermediate RGB,
+so only one quantization happens. When chroma is subsampled, new chroma is computed with averaged
+luma of the block it covers. Values are expected in least significant bits, as I010, I012 etc. do.
+
+# Arguments
+
+* `src` - Source planar image.
+* `dst` - Target planar image, must have the same dimensions as source.
+* `bit_depth` - Bit depth of the images, 9..=16.
+* `src_range` - The YUV range (limited or full) of source image.
+* `src_matrix` - The YUV standard matrix of source image.
+* `dst_range` - The YUV range (limited or full) of target image.
+* `dst_matrix` - The YUV standard matrix of target image.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides,
+images dimensions do not match or bit depth is not in 9..=16.
+")]
+        pub fn $method(
+            src: &YuvPlanarImage<u16>,
+            dst: &mut YuvPlanarImageMut<u16>,
+            bit_depth: u32,
+            src_range: YuvRange,
+            src_matrix: YuvStandardMatrix,
+            dst_range: YuvRange,
+            dst_matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            check_bit_depth(bit_depth, 9..=16)?;
+            let transform =
+                MatrixRangeTransform::new(bit_depth, src_range, src_matrix, dst_range, dst_matrix);
+            convert_planar::<u16, { $sampling as u8 }>(
+                src,
+                dst,
+                &transform,
+                |v| v as i32,
+                |v| v as u16,
+            )
+        }
+    };
+}
+
+d_planar_p16!(
+    yuv420_p16_convert_matrix_range,
+    YuvChromaSubsampling::Yuv420,
+    "YUV 420"
+);
+d_planar_p16!(
+    yuv422_p16_convert_matrix_range,
+    YuvChromaSubsampling::Yuv422,
+    "YUV 422"
+);
+d_planar_p16!(
+    yuv444_p16_convert_matrix_range,
+    YuvChromaSubsampling::Yuv444,
+    "YUV 444"
+);
+
+macro_rules! d_bi_planar {
+    ($method: ident, $sampling: expr, $u_position: expr, $yuv_name: expr) => {
+        #[doc = concat!("Converts ", $yuv_name, " 8-bit image between YUV matrices and ranges.
+
+Transform is applied directly on (Y, Cb, Cr) in one fixed point pass without intermediate RGB,
+so only one quantization happens. When chroma is subsampled, new chroma is computed with averaged
+luma of the block it covers.
+
+# Arguments
+
+* `src` - Source bi-planar image.
+* `dst` - Target bi-planar image, must have the same dimensions as source.
+* `src_range` - The YUV range (limited or full) of source image.
+* `src_matrix` - The YUV standard matrix of source image.
+* `dst_range` - The YUV range (limited or full) of target image.
+* `dst_matrix` - The YUV standard matrix of target image.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides, or images dimensions do not match.
+")]
+        pub fn $method(
+            src: &YuvBiPlanarImage<u8>,
+            dst: &mut YuvBiPlanarImageMut<u8>,
+            src_range: YuvRange,
+            src_matrix: YuvStandardMatrix,
+            dst_range: YuvRange,
+            dst_matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            let transform =
+                MatrixRangeTransform::new(8, src_range, src_matrix, dst_range, dst_matrix);
+            convert_bi_planar::<u8, { $sampling as u8 }>(
+                src,
+                dst,
+                &transform,
+                $u_position,
+                |v| v as i32,
+                |v| v as u8,
+            )
+        }
+    };
+}
+
+d_bi_planar!(
+    yuv_nv12_convert_matrix_range,
+    YuvChromaSubsampling::Yuv420,
+    0,
+    "NV12"
+);
+d_bi_planar!(
+    yuv_nv21_convert_matrix_range,
+    YuvChromaSubsampling::Yuv420,
+    1,
+    "NV21"
+);
+d_bi_planar!(
+    yuv_nv16_convert_matrix_range,
+    YuvChromaSubsampling::Yuv422,
+    0,
+    "NV16"
+);
+d_bi_planar!(
+    yuv_nv61_convert_matrix_range,
+    YuvChromaSubsampling::Yuv422,
+    1,
+    "NV61"
+);
+d_bi_planar!(
+    yuv_nv24_convert_matrix_range,
+    YuvChromaSubsampling::Yuv444,
+    0,
+    "NV24"
+);
+d_bi_planar!(
+    yuv_nv42_convert_matrix_range,
+    YuvChromaSubsampling::Yuv444,
+    1,
+    "NV42"
+);
+
+macro_rules! d_bi_planar_p16 {
+    ($method: ident, $sampling: expr, $yuv_name: expr) => {
+        #[doc = concat!("Converts ", $yuv_name, " image between YUV matrices and ranges.
+
+Transform is applied directly on (Y, Cb, Cr) in one fixed point pass without intermediate RGB,
+so only one quantization happens. When chroma is subsampled, new chroma is computed with averaged
+luma of the block it covers. Values are expected in most significant bits, as P010, P012 etc. do.
+
+# Arguments
+
+* `src` - Source bi-planar image.
+* `dst` - Target bi-planar image, must have the same dimensions as source.
+* `bit_depth` - Bit depth of the images, 9..=16.
+* `src_range` - The YUV range (limited or full) of source image.
+* `src_matrix` - The YUV standard matrix of source image.
+* `dst_range` - The YUV range (limited or full) of target image.
+* `dst_matrix` - The YUV standard matrix of target image.
+
+# Errors
+
+This function returns an error if the lengths of the planes are not valid based
+on the specified width, height, and strides,
+images dimensions do not match or bit depth is not in 9..=16.
+")]
+        pub fn $method(
+            src: &YuvBiPlanarImage<u16>,
+            dst: &mut YuvBiPlanarImageMut<u16>,
+            bit_depth: u32,
+            src_range: YuvRange,
+            src_matrix: YuvStandardMatrix,
+            dst_range: YuvRange,
+            dst_matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            check_bit_depth(bit_depth, 9..=16)?;
+            let transform =
+                MatrixRangeTransform::new(bit_depth, src_range, src_matrix, dst_range, dst_matrix);
+            let msb_shift = 16 - bit_depth;
+            convert_bi_planar::<u16, { $sampling as u8 }>(
+                src,
+                dst,
+                &transform,
+                0,
+                |v| (v >> msb_shift) as i32,
+                |v| (v as u16) << msb_shift,
+            )
+        }
+    };
+}
+
+d_bi_planar_p16!(
+    p010_convert_matrix_range,
+    YuvChromaSubsampling::Yuv420,
+    "P010, P012, P016"
+);
+d_bi_planar_p16!(
+    p210_convert_matrix_range,
+    YuvChromaSubsampling::Yuv422,
+    "P210, P212, P216"
+);
+d_bi_planar_p16!(
+    p410_convert_matrix_range,
+    YuvChromaSubsampling::Yuv444,
+    "P410, P412, P416"
+);
+
+macro_rules! d_packed {
+    ($method: ident, $method_p16: ident, $positions: expr, $yuv_name: expr) => {
+        #[doc = concat!("Converts packed ", $yuv_name, " 8-bit image between YUV matrices and ranges.
+
+Transform is applied directly on (Y, Cb, Cr) in one fixed point pass without intermediate RGB,
+so only one quantization happens. New chroma is computed with averaged luma of the pixel pair.
+
+# Arguments
+
+* `src` - Source packed image.
+* `dst` - Target packed image, must have the same dimensions as source.
+* `src_range` - The YUV range (limited or full) of source image.
+* `src_matrix` - The YUV standard matrix of source image.
+* `dst_range` - The YUV range (limited or full) of target image.
+* `dst_matrix` - The YUV standard matrix of target image.
+
+# Errors
+
+This function returns an error if the lengths of the images are not valid based
+on the specified width, height, and strides, or images dimensions do not match.
+")]
+        pub fn $method(
+            src: &YuvPackedImage<u8>,
+            dst: &mut YuvPackedImageMut<u8>,
+            src_range: YuvRange,
+            src_matrix: YuvStandardMatrix,
+            dst_range: YuvRange,
+            dst_matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            let transform =
+                MatrixRangeTransform::new(8, src_range, src_matrix, dst_range, dst_matrix);
+            convert_packed(
+                src,
+                dst,
+                &transform,
+                $positions,
+                |v| v as i32,
+                |v| v as u8,
+            )
+        }
+
+        #[doc = concat!("Converts packed ", $yuv_name, " high bit-depth image between YUV matrices and ranges.
+
+Transform is applied directly on (Y, Cb, Cr) in one fixed point pass without intermediate RGB,
+so only one quantization happens. New chroma is computed with averaged luma of the pixel pair.
+Values are expected in least significant bits.
+
+# Arguments
+
+* `src` - Source packed image.
+* `dst` - Target packed image, must have the same dimensions as source.
+* `bit_depth` - Bit depth of the images, 9..=16.
+* `src_range` - The YUV range (limited or full) of source image.
+* `src_matrix` - The YUV standard matrix of source image.
+* `dst_range` - The YUV range (limited or full) of target image.
+* `dst_matrix` - The YUV standard matrix of target image.
+
+# Errors
+
+This function returns an error if the lengths of the images are not valid based
+on the specified width, height, and strides,
+images dimensions do not match or bit depth is not in 9..=16.
+")]
+        pub fn $method_p16(
+            src: &YuvPackedImage<u16>,
+            dst: &mut YuvPackedImageMut<u16>,
+            bit_depth: u32,
+            src_range: YuvRange,
+            src_matrix: YuvStandardMatrix,
+            dst_range: YuvRange,
+            dst_matrix: YuvStandardMatrix,
+        ) -> Result<(), YuvError> {
+            check_bit_depth(bit_depth, 9..=16)?;
+            let transform =
+                MatrixRangeTransform::new(bit_depth, src_range, src_matrix, dst_range, dst_matrix);
+            convert_packed(
+                src,
+                dst,
+                &transform,
+                $positions,
+                |v| v as i32,
+                |v| v as u16,
+            )
+        }
+    };
+}
+
+d_packed!(
+    yuyv422_convert_matrix_range,
+    yuyv422_p16_convert_matrix_range,
+    [0, 1, 2, 3],
+    "YUYV"
+);
+d_packed!(
+    uyvy422_convert_matrix_range,
+    uyvy422_p16_convert_matrix_range,
+    [1, 0, 3, 2],
+    "UYVY"
+);
+d_packed!(
+    yvyu422_convert_matrix_range,
+    yvyu422_p16_convert_matrix_range,
+    [0, 3, 2, 1],
+    "YVYU"
+);
+d_packed!(
+    vyuy422_convert_matrix_range,
+    vyuy422_p16_convert_matrix_range,
+    [1, 2, 3, 0],
+    "VYUY"
+);
+
+/// Converts planar 8-bit image between YUV matrices and ranges.
+///
+/// Shorthand for [yuv420_convert_matrix_range], [yuv422_convert_matrix_range]
+/// and [yuv444_convert_matrix_range] when subsampling is known only at runtime.
+///
+/// # Errors
+///
+/// This function returns an error if the lengths of the planes are not valid based
+/// on the specified width, height, and strides, or images dimensions do not match.
+pub fn yuv_convert_matrix_range(
+    src: &YuvPlanarImage<u8>,
+    dst: &mut YuvPlanarImageMut<u8>,
+    sampling: YuvChromaSubsampling,
+    src_range: YuvRange,
+    src_matrix: YuvStandardMatrix,
+    dst_range: YuvRange,
+    dst_matrix: YuvStandardMatrix,
+) -> Result<(), YuvError> {
+    match sampling {
+        YuvChromaSubsampling::Yuv420 => {
+            yuv420_convert_matrix_range(src, dst, src_range, src_matrix, dst_range, dst_matrix)
+        }
+        YuvChromaSubsampling::Yuv422 => {
+            yuv422_convert_matrix_range(src, dst, src_range, src_matrix, dst_range, dst_matrix)
+        }
+        YuvChromaSubsampling::Yuv444 => {
+            yuv444_convert_matrix_range(src, dst, src_range, src_matrix, dst_range, dst_matrix)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rgb_to_yuv420, yuv420_to_rgb, YuvConversionMode};
+
+    #[test]
+    fn test_matrix_range_matches_rgb_round_trip() {
+        let width = 34usize;
+        let height = 18usize;
+        let mut rgb = vec![0u8; width * height * 3];
+        for (i, px) in rgb.chunks_exact_mut(3).enumerate() {
+            px[0] = (i * 7 % 200 + 20) as u8;
+            px[1] = (i * 13 % 180 + 30) as u8;
+            px[2] = (i * 3 % 220 + 10) as u8;
+        }
+        let mut source = YuvPlanarImageMut::<u8>::alloc(
+            width as u32,
+            height as u32,
+            YuvChromaSubsampling::Yuv420,
+        );
+        rgb_to_yuv420(
+            &mut source,
+            &rgb,
+            width as u32 * 3,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt601,
+            YuvConversionMode::Balanced,
+        )
+        .unwrap();
+
+        let mut target = YuvPlanarImageMut::<u8>::alloc(
+            width as u32,
+            height as u32,
+            YuvChromaSubsampling::Yuv420,
+        );
+        yuv_convert_matrix_range(
+            &source.to_fixed(),
+            &mut target,
+            YuvChromaSubsampling::Yuv420,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt601,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+
+        let mut expected = vec![0u8; width * height * 3];
+        yuv420_to_rgb(
+            &source.to_fixed(),
+            &mut expected,
+            width as u32 * 3,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt601,
+        )
+        .unwrap();
+        let mut decoded = vec![0u8; width * height * 3];
+        yuv420_to_rgb(
+            &target.to_fixed(),
+            &mut decoded,
+            width as u32 * 3,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        for (&src, &dst) in expected.iter().zip(decoded.iter()) {
+            assert!(
+                (src as i32 - dst as i32).abs() <= 6,
+                "Expected {src}, got {dst}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_matrix_range_rejects_bit_depth() {
+        let src = YuvPlanarImageMut::<u16>::alloc(4, 4, YuvChromaSubsampling::Yuv420);
+        let mut dst = YuvPlanarImageMut::<u16>::alloc(4, 4, YuvChromaSubsampling::Yuv420);
+        let result = yuv420_p16_convert_matrix_range(
+            &src.to_fixed(),
+            &mut dst,
+            17,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt601,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+        );
+        assert!(matches!(result, Err(YuvError::UnsupportedBitDepth(17))));
+    }
+}
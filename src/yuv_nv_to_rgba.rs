@@ -2777,4 +2777,41 @@ mod tests {
         #[cfg(feature = "professional_mode")]
         matrix(YuvConversionMode::Professional, 70);
     }
+
+    #[test]
+    fn short_final_chroma_row_is_rejected() {
+        // 6x4 NV12 with chroma stride 8, last chroma row holds only its 6 samples
+        let y_plane = vec![128u8; 6 * 4];
+        let uv_plane = vec![128u8; 8 * 2];
+        let mut rgba = vec![0u8; 6 * 4 * 4];
+        let image = |uv_plane| YuvBiPlanarImage {
+            y_plane: &y_plane,
+            y_stride: 6,
+            uv_plane,
+            uv_stride: 8,
+            width: 6,
+            height: 4,
+        };
+        assert!(matches!(
+            yuv_nv12_to_rgba(
+                &image(&uv_plane[..14]),
+                &mut rgba,
+                6 * 4,
+                YuvRange::Full,
+                YuvStandardMatrix::Bt709,
+                YuvConversionMode::Balanced,
+            ),
+            Err(YuvError::ChromaPlaneSizeMismatch(_))
+        ));
+        yuv_nv12_to_rgba(
+            &image(&uv_plane),
+            &mut rgba,
+            6 * 4,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+            YuvConversionMode::Balanced,
+        )
+        .unwrap();
+        assert!(rgba[6 * 3 * 4..].chunks_exact(4).all(|px| px[3] == 255));
+    }
 }
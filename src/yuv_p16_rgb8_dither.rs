@@ -0,0 +1,890 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::dither::{DitherTable, YuvDitherMode};
+use crate::yuv_depth::DepthRowHandler;
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::{
+    get_inverse_transform, get_yuv_range, CbCrInverseTransform, YuvBytesPacking, YuvSourceChannels,
+};
+use crate::{
+    YuvBiPlanarImage, YuvChromaSubsampling, YuvError, YuvPlanarImage, YuvRange, YuvStandardMatrix,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+
+/// Rows narrowed by one worker with one intermediate row buffer
+const STRIP_ROWS: usize = 16;
+
+/// Extra bits kept below 8 bit-depth value in intermediate row
+const FRACTION_BITS: u32 = 8;
+
+/// Low enough to keep 16 bit-depth sums inside `i32`
+const PRECISION: i32 = 12;
+
+/// Fixed point YUV to RGB transform producing 8 bit-depth values
+/// with [FRACTION_BITS] fractional bits, so the only rounding is done by dithering.
+struct FractionalDecoder {
+    transform: CbCrInverseTransform<i32>,
+    bias_y: i32,
+    bias_uv: i32,
+    msb_shift: u32,
+}
+
+impl FractionalDecoder {
+    fn new(
+        bit_depth: u32,
+        packing: YuvBytesPacking,
+        range: YuvRange,
+        matrix: YuvStandardMatrix,
+    ) -> FractionalDecoder {
+        let chroma_range = get_yuv_range(bit_depth, range);
+        let kr_kb = matrix.get_kr_kb();
+        let transform = get_inverse_transform(
+            255 << FRACTION_BITS,
+            chroma_range.range_y,
+            chroma_range.range_uv,
+            kr_kb.kr,
+            kr_kb.kb,
+        )
+        .to_integers(PRECISION as u32);
+        FractionalDecoder {
+            transform,
+            bias_y: chroma_range.bias_y as i32,
+            bias_uv: chroma_range.bias_uv as i32,
+            msb_shift: match packing {
+                YuvBytesPacking::MostSignificantBytes => 16 - bit_depth,
+                YuvBytesPacking::LeastSignificantBytes => 0,
+            },
+        }
+    }
+
+    #[inline(always)]
+    fn unpack(&self, v: u16) -> i32 {
+        (u16::from_le(v) >> self.msb_shift) as i32
+    }
+
+    /// Decodes one row, `chroma_at` returns stored (Cb, Cr) for luma index
+    #[inline(always)]
+    fn decode_row(
+        &self,
+        y_plane: &[u16],
+        dst: &mut [u16],
+        dst_chans: YuvSourceChannels,
+        chroma_at: impl Fn(usize) -> (u16, u16),
+    ) {
+        const MAX_VALUE: i32 = 255 << FRACTION_BITS;
+        const ROUNDING: i32 = 1 << (PRECISION - 1);
+        let store = |v: i32| ((v + ROUNDING) >> PRECISION).clamp(0, MAX_VALUE) as u16;
+        let transform = &self.transform;
+        for (x, (&y_src, dst)) in y_plane
+            .iter()
+            .zip(dst.chunks_exact_mut(dst_chans.get_channels_count()))
+            .enumerate()
+        {
+            let (u_src, v_src) = chroma_at(x);
+            let y_value = (self.unpack(y_src) - self.bias_y) * transform.y_coef;
+            let cb_value = self.unpack(u_src) - self.bias_uv;
+            let cr_value = self.unpack(v_src) - self.bias_uv;
+
+            dst[dst_chans.get_r_channel_offset()] = store(y_value + transform.cr_coef * cr_value);
+            dst[dst_chans.get_g_channel_offset()] =
+                store(y_value - transform.g_coeff_1 * cr_value - transform.g_coeff_2 * cb_value);
+            dst[dst_chans.get_b_channel_offset()] = store(y_value + transform.cb_coef * cb_value);
+            if dst_chans.has_alpha() {
+                dst[dst_chans.get_a_channel_offset()] = MAX_VALUE as u16;
+            }
+        }
+    }
+}
+
+/// Decodes image row by row into intermediate row with fractional bits
+/// and narrows it to 8 bit with thresholds taken by absolute pixel position,
+/// so the result doesn't depend on selected SIMD path.
+fn dither_rows(
+    width: u32,
+    height: u32,
+    channels: usize,
+    dst: &mut [u8],
+    dst_stride: u32,
+    dither: YuvDitherMode,
+    decode: impl Fn(usize, &mut [u16]) + Send + Sync,
+) -> Result<(), YuvError> {
+    check_rgba_destination(dst, dst_stride, width, height, channels)?;
+
+    let height = height as usize;
+    let dst_stride = dst_stride as usize;
+    let row_length = width as usize * channels;
+    let table = DitherTable::interleaved(dither, FRACTION_BITS, row_length, channels);
+    let handler = DepthRowHandler::default();
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = dst
+            .par_chunks_mut(dst_stride * STRIP_ROWS)
+            .take(height.div_ceil(STRIP_ROWS))
+            .enumerate();
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = dst
+            .chunks_mut(dst_stride * STRIP_ROWS)
+            .take(height.div_ceil(STRIP_ROWS))
+            .enumerate();
+    }
+    iter.for_each(|(strip, dst)| {
+        let y_start = strip * STRIP_ROWS;
+        let mut intermediate = vec![0u16; row_length];
+        for (i, dst) in dst
+            .chunks_mut(dst_stride)
+            .take(STRIP_ROWS.min(height - y_start))
+            .enumerate()
+        {
+            let y = y_start + i;
+            decode(y, &mut intermediate);
+            handler.narrow_row_to_8(
+                &intermediate,
+                &mut dst[..row_length],
+                table.row(y),
+                FRACTION_BITS,
+            );
+        }
+    });
+    Ok(())
+}
+
+fn yuv_p16_to_rgb8_dithered(
+    image: &YuvPlanarImage<u16>,
+    dst: &mut [u8],
+    dst_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    dither: YuvDitherMode,
+    sampling: YuvChromaSubsampling,
+    dst_chans: YuvSourceChannels,
+    bit_depth: u32,
+) -> Result<(), YuvError> {
+    image.check_constraints(sampling)?;
+    let decoder = FractionalDecoder::new(
+        bit_depth,
+        YuvBytesPacking::LeastSignificantBytes,
+        range,
+        matrix,
+    );
+    let width = image.width as usize;
+    let horizontal_factor = sampling.horizontal_factor();
+    let vertical_factor = sampling.vertical_factor();
+    dither_rows(
+        image.width,
+        image.height,
+        dst_chans.get_channels_count(),
+        dst,
+        dst_stride,
+        dither,
+        |y, intermediate| {
+            let y_row = &image.y_plane[y * image.y_stride as usize..][..width];
+            let chroma_y = y / vertical_factor;
+            let u_row = &image.u_plane[chroma_y * image.u_stride as usize..];
+            let v_row = &image.v_plane[chroma_y * image.v_stride as usize..];
+            decoder.decode_row(y_row, intermediate, dst_chans, |x| {
+                (u_row[x / horizontal_factor], v_row[x / horizontal_factor])
+            });
+        },
+    )
+}
+
+fn yuv_nv_p16_to_rgb8_dithered(
+    image: &YuvBiPlanarImage<u16>,
+    dst: &mut [u8],
+    dst_stride: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    dither: YuvDitherMode,
+    sampling: YuvChromaSubsampling,
+    dst_chans: YuvSourceChannels,
+    bit_depth: u32,
+) -> Result<(), YuvError> {
+    image.check_constraints(sampling)?;
+    let decoder = FractionalDecoder::new(
+        bit_depth,
+        YuvBytesPacking::MostSignificantBytes,
+        range,
+        matrix,
+    );
+    let width = image.width as usize;
+    let horizontal_factor = sampling.horizontal_factor();
+    let vertical_factor = sampling.vertical_factor();
+    dither_rows(
+        image.width,
+        image.height,
+        dst_chans.get_channels_count(),
+        dst,
+        dst_stride,
+        dither,
+        |y, intermediate| {
+            let y_row = &image.y_plane[y * image.y_stride as usize..][..width];
+            let uv_row = &image.uv_plane[(y / vertical_factor) * image.uv_stride as usize..];
+            decoder.decode_row(y_row, intermediate, dst_chans, |x| {
+                let cx = x / horizontal_factor * 2;
+                (uv_row[cx], uv_row[cx + 1])
+            });
+        },
+    )
+}
+
+macro_rules! d_cnv_planar {
+    ($method: ident, $sampling: expr, $dst_chans: expr, $yuv_name: expr, $px_name: expr, $bit_depth: expr) => {
+        #[doc = concat!("Convert ", $yuv_name, " planar format to 8 bit-depth ", $px_name, " format with dithering.
+
+Colour is computed in fixed point with fractional bits kept below 8 bit-depth value and
+reduced straight to 8 bit using provided dither mode, so the dither mode is the only rounding.
+Dither thresholds depend only on pixel position, so result is the same on every platform.
+
+# Arguments
+
+* `planar_image` - Source ", $yuv_name, " planar image.
+* `dst` - A mutable slice to store the converted ", $px_name, " data.
+* `dst_stride` - The stride (components per row) for ", $px_name, " data.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `dither` - see [YuvDitherMode] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $px_name, " data are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            planar_image: &YuvPlanarImage<u16>,
+            dst: &mut [u8],
+            dst_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            dither: YuvDitherMode,
+        ) -> Result<(), YuvError> {
+            yuv_p16_to_rgb8_dithered(
+                planar_image,
+                dst,
+                dst_stride,
+                range,
+                matrix,
+                dither,
+                $sampling,
+                $dst_chans,
+                $bit_depth,
+            )
+        }
+    };
+}
+
+macro_rules! d_cnv_bi_planar {
+    ($method: ident, $sampling: expr, $dst_chans: expr, $yuv_name: expr, $px_name: expr, $bit_depth: expr) => {
+        #[doc = concat!("Convert ", $yuv_name, " format to 8 bit-depth ", $px_name, " format with dithering.
+
+Colour is computed in fixed point with fractional bits kept below 8 bit-depth value and
+reduced straight to 8 bit using provided dither mode, so the dither mode is the only rounding.
+Dither thresholds depend only on pixel position, so result is the same on every platform.
+
+# Arguments
+
+* `bi_planar_image` - Source ", $yuv_name, " image.
+* `dst` - A mutable slice to store the converted ", $px_name, " data.
+* `dst_stride` - The stride (components per row) for ", $px_name, " data.
+* `range` - range of YUV, see [YuvRange] for more info.
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `dither` - see [YuvDitherMode] for more info.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the input ", $px_name, " data are not valid based
+on the specified width, height, and strides.")]
+        pub fn $method(
+            bi_planar_image: &YuvBiPlanarImage<u16>,
+            dst: &mut [u8],
+            dst_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            dither: YuvDitherMode,
+        ) -> Result<(), YuvError> {
+            yuv_nv_p16_to_rgb8_dithered(
+                bi_planar_image,
+                dst,
+                dst_stride,
+                range,
+                matrix,
+                dither,
+                $sampling,
+                $dst_chans,
+                $bit_depth,
+            )
+        }
+    };
+}
+
+d_cnv_planar!(
+    i010_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    "I010",
+    "RGB",
+    10
+);
+d_cnv_planar!(
+    i010_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    "I010",
+    "RGBA",
+    10
+);
+d_cnv_planar!(
+    i210_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    "I210",
+    "RGB",
+    10
+);
+d_cnv_planar!(
+    i210_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    "I210",
+    "RGBA",
+    10
+);
+d_cnv_planar!(
+    i410_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    "I410",
+    "RGB",
+    10
+);
+d_cnv_planar!(
+    i410_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    "I410",
+    "RGBA",
+    10
+);
+d_cnv_planar!(
+    i012_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    "I012",
+    "RGB",
+    12
+);
+d_cnv_planar!(
+    i012_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    "I012",
+    "RGBA",
+    12
+);
+d_cnv_planar!(
+    i212_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    "I212",
+    "RGB",
+    12
+);
+d_cnv_planar!(
+    i212_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    "I212",
+    "RGBA",
+    12
+);
+d_cnv_planar!(
+    i412_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    "I412",
+    "RGB",
+    12
+);
+d_cnv_planar!(
+    i412_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    "I412",
+    "RGBA",
+    12
+);
+d_cnv_planar!(
+    i014_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    "I014",
+    "RGB",
+    14
+);
+d_cnv_planar!(
+    i014_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    "I014",
+    "RGBA",
+    14
+);
+d_cnv_planar!(
+    i214_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    "I214",
+    "RGB",
+    14
+);
+d_cnv_planar!(
+    i214_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    "I214",
+    "RGBA",
+    14
+);
+d_cnv_planar!(
+    i414_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    "I414",
+    "RGB",
+    14
+);
+d_cnv_planar!(
+    i414_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    "I414",
+    "RGBA",
+    14
+);
+d_cnv_planar!(
+    i016_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    "I016",
+    "RGB",
+    16
+);
+d_cnv_planar!(
+    i016_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    "I016",
+    "RGBA",
+    16
+);
+d_cnv_planar!(
+    i216_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    "I216",
+    "RGB",
+    16
+);
+d_cnv_planar!(
+    i216_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    "I216",
+    "RGBA",
+    16
+);
+d_cnv_planar!(
+    i416_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    "I416",
+    "RGB",
+    16
+);
+d_cnv_planar!(
+    i416_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    "I416",
+    "RGBA",
+    16
+);
+
+d_cnv_bi_planar!(
+    p010_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    "P010",
+    "RGB",
+    10
+);
+d_cnv_bi_planar!(
+    p010_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    "P010",
+    "RGBA",
+    10
+);
+d_cnv_bi_planar!(
+    p210_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    "P210",
+    "RGB",
+    10
+);
+d_cnv_bi_planar!(
+    p210_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    "P210",
+    "RGBA",
+    10
+);
+d_cnv_bi_planar!(
+    p410_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    "P410",
+    "RGB",
+    10
+);
+d_cnv_bi_planar!(
+    p410_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    "P410",
+    "RGBA",
+    10
+);
+d_cnv_bi_planar!(
+    p012_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    "P012",
+    "RGB",
+    12
+);
+d_cnv_bi_planar!(
+    p012_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    "P012",
+    "RGBA",
+    12
+);
+d_cnv_bi_planar!(
+    p212_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    "P212",
+    "RGB",
+    12
+);
+d_cnv_bi_planar!(
+    p212_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    "P212",
+    "RGBA",
+    12
+);
+d_cnv_bi_planar!(
+    p412_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    "P412",
+    "RGB",
+    12
+);
+d_cnv_bi_planar!(
+    p412_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    "P412",
+    "RGBA",
+    12
+);
+d_cnv_bi_planar!(
+    p014_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    "P014",
+    "RGB",
+    14
+);
+d_cnv_bi_planar!(
+    p014_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    "P014",
+    "RGBA",
+    14
+);
+d_cnv_bi_planar!(
+    p214_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    "P214",
+    "RGB",
+    14
+);
+d_cnv_bi_planar!(
+    p214_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    "P214",
+    "RGBA",
+    14
+);
+d_cnv_bi_planar!(
+    p414_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    "P414",
+    "RGB",
+    14
+);
+d_cnv_bi_planar!(
+    p414_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    "P414",
+    "RGBA",
+    14
+);
+d_cnv_bi_planar!(
+    p016_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgb,
+    "P016",
+    "RGB",
+    16
+);
+d_cnv_bi_planar!(
+    p016_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv420,
+    YuvSourceChannels::Rgba,
+    "P016",
+    "RGBA",
+    16
+);
+d_cnv_bi_planar!(
+    p216_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgb,
+    "P216",
+    "RGB",
+    16
+);
+d_cnv_bi_planar!(
+    p216_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv422,
+    YuvSourceChannels::Rgba,
+    "P216",
+    "RGBA",
+    16
+);
+d_cnv_bi_planar!(
+    p416_to_rgb_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgb,
+    "P416",
+    "RGB",
+    16
+);
+d_cnv_bi_planar!(
+    p416_to_rgba_dithered,
+    YuvChromaSubsampling::Yuv444,
+    YuvSourceChannels::Rgba,
+    "P416",
+    "RGBA",
+    16
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{YuvBiPlanarImageMut, YuvPlanarImageMut};
+
+    #[test]
+    fn dithered_flat_field_keeps_fraction() {
+        let (width, height) = (64u32, 32u32);
+        let mut image =
+            YuvPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        image.y_plane.borrow_mut().fill(513);
+        image.u_plane.borrow_mut().fill(512);
+        image.v_plane.borrow_mut().fill(512);
+        let image = image.to_fixed();
+
+        // Grey in full range, the exact value is not representable in 8 bit
+        let exact = 513. * 255. / 1023.;
+
+        let mut rounded = vec![0u8; (width * height * 4) as usize];
+        let mut dithered = vec![0u8; (width * height * 4) as usize];
+        for (mode, dst) in [
+            (YuvDitherMode::Round, &mut rounded),
+            (YuvDitherMode::Ordered, &mut dithered),
+        ] {
+            i010_to_rgba_dithered(
+                &image,
+                dst,
+                width * 4,
+                YuvRange::Full,
+                YuvStandardMatrix::Bt709,
+                mode,
+            )
+            .unwrap();
+        }
+
+        let rounded_value = f64::round(exact) as u8;
+        assert!(rounded
+            .chunks_exact(4)
+            .all(|px| px == [rounded_value, rounded_value, rounded_value, 255]));
+        let mean =
+            dithered.chunks_exact(4).map(|px| px[0] as f64).sum::<f64>() / (width * height) as f64;
+        assert!((mean - exact).abs() < 0.02, "Mean {mean}, exact {exact}");
+        assert!(dithered.chunks_exact(4).all(|px| px[3] == 255));
+    }
+
+    #[test]
+    fn rounded_matches_float_reference_for_every_depth() {
+        let (width, height) = (19u32, 6u32);
+        let (kr, kb) = (0.2126f64, 0.0722f64);
+        for bit_depth in [10u32, 12, 14, 16] {
+            let max = ((1u32 << bit_depth) - 1) as f64;
+            let scale = (1u32 << (bit_depth - 8)) as f64;
+            let mut planar =
+                YuvPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv444);
+            let mut bi_planar =
+                YuvBiPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv444);
+            for (i, v) in planar.y_plane.borrow_mut().iter_mut().enumerate() {
+                *v = ((16. + (i * 37 % 219) as f64) * scale) as u16;
+            }
+            for (i, (u, v)) in planar
+                .u_plane
+                .borrow_mut()
+                .iter_mut()
+                .zip(planar.v_plane.borrow_mut().iter_mut())
+                .enumerate()
+            {
+                *u = ((72. + (i * 13 % 112) as f64) * scale) as u16;
+                *v = ((72. + (i * 29 % 112) as f64) * scale) as u16;
+            }
+            let msb = 16 - bit_depth;
+            for (dst, &src) in bi_planar
+                .y_plane
+                .borrow_mut()
+                .iter_mut()
+                .zip(planar.y_plane.borrow().iter())
+            {
+                *dst = src << msb;
+            }
+            for ((dst, &u), &v) in bi_planar
+                .uv_plane
+                .borrow_mut()
+                .chunks_exact_mut(2)
+                .zip(planar.u_plane.borrow().iter())
+                .zip(planar.v_plane.borrow().iter())
+            {
+                dst[0] = u << msb;
+                dst[1] = v << msb;
+            }
+
+            let mut from_planar = vec![0u8; (width * height * 3) as usize];
+            let mut from_bi_planar = vec![0u8; (width * height * 3) as usize];
+            let (planar_method, bi_planar_method): (
+                fn(
+                    &YuvPlanarImage<u16>,
+                    &mut [u8],
+                    u32,
+                    YuvRange,
+                    YuvStandardMatrix,
+                    YuvDitherMode,
+                ) -> Result<(), YuvError>,
+                fn(
+                    &YuvBiPlanarImage<u16>,
+                    &mut [u8],
+                    u32,
+                    YuvRange,
+                    YuvStandardMatrix,
+                    YuvDitherMode,
+                ) -> Result<(), YuvError>,
+            ) = match bit_depth {
+                10 => (i410_to_rgb_dithered, p410_to_rgb_dithered),
+                12 => (i412_to_rgb_dithered, p412_to_rgb_dithered),
+                14 => (i414_to_rgb_dithered, p414_to_rgb_dithered),
+                _ => (i416_to_rgb_dithered, p416_to_rgb_dithered),
+            };
+            planar_method(
+                &planar.to_fixed(),
+                &mut from_planar,
+                width * 3,
+                YuvRange::Limited,
+                YuvStandardMatrix::Bt709,
+                YuvDitherMode::Round,
+            )
+            .unwrap();
+            bi_planar_method(
+                &bi_planar.to_fixed(),
+                &mut from_bi_planar,
+                width * 3,
+                YuvRange::Limited,
+                YuvStandardMatrix::Bt709,
+                YuvDitherMode::Round,
+            )
+            .unwrap();
+            assert_eq!(from_planar, from_bi_planar);
+
+            let (bias_y, range_y, range_uv) = (16. * scale, 219. * scale, 224. * scale);
+            let bias_uv = (max + 1.) / 2.;
+            for (i, px) in from_planar.chunks_exact(3).enumerate() {
+                let y = (planar.y_plane.borrow()[i] as f64 - bias_y) / range_y;
+                let cb = (planar.u_plane.borrow()[i] as f64 - bias_uv) / range_uv;
+                let cr = (planar.v_plane.borrow()[i] as f64 - bias_uv) / range_uv;
+                let r = y + 2. * (1. - kr) * cr;
+                let b = y + 2. * (1. - kb) * cb;
+                let g = (y - kr * r - kb * b) / (1. - kr - kb);
+                for (&value, reference) in px.iter().zip([r, g, b]) {
+                    let reference = (reference * 255.).clamp(0., 255.);
+                    assert!(
+                        (value as f64 - reference).abs() <= 0.51,
+                        "Bit depth {bit_depth}, pixel {i}: {value} vs {reference}"
+                    );
+                }
+            }
+        }
+    }
+}
@@ -371,8 +371,46 @@ impl From<u8> for YuvChromaSubsampling {
     }
 }
 
+impl YuvChromaSubsampling {
+    /// Chroma plane width in samples for given luma width
+    #[inline]
+    pub(crate) const fn chroma_width(self, width: usize) -> usize {
+        match self {
+            YuvChromaSubsampling::Yuv420 | YuvChromaSubsampling::Yuv422 => width.div_ceil(2),
+            YuvChromaSubsampling::Yuv444 => width,
+        }
+    }
+
+    /// Chroma plane height in rows for given luma height
+    #[inline]
+    pub(crate) const fn chroma_height(self, height: usize) -> usize {
+        match self {
+            YuvChromaSubsampling::Yuv420 => height.div_ceil(2),
+            YuvChromaSubsampling::Yuv422 | YuvChromaSubsampling::Yuv444 => height,
+        }
+    }
+
+    /// Count of luma columns covered by one chroma sample
+    #[inline]
+    pub(crate) const fn horizontal_factor(self) -> usize {
+        match self {
+            YuvChromaSubsampling::Yuv420 | YuvChromaSubsampling::Yuv422 => 2,
+            YuvChromaSubsampling::Yuv444 => 1,
+        }
+    }
+
+    /// Count of luma rows covered by one chroma row
+    #[inline]
+    pub(crate) const fn vertical_factor(self) -> usize {
+        match self {
+            YuvChromaSubsampling::Yuv420 => 2,
+            YuvChromaSubsampling::Yuv422 | YuvChromaSubsampling::Yuv444 => 1,
+        }
+    }
+}
+
 #[repr(u8)]
-#[derive(Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 /// This controls endianness of YUV storage format
 pub enum YuvEndianness {
     #[cfg(feature = "big_endian")]
@@ -515,13 +553,18 @@ impl YuvSourceChannels {
     }
 }
 
+/// Order of components inside packed 4:2:2 macropixel
 #[repr(usize)]
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[allow(clippy::upper_case_acronyms)]
-pub(crate) enum Yuy2Description {
+pub enum Yuy2Description {
+    /// Y0, U, Y1, V
     YUYV = 0,
+    /// U, Y0, V, Y1
     UYVY = 1,
+    /// Y0, V, Y1, U
     YVYU = 2,
+    /// V, Y0, U, Y1
     VYUY = 3,
 }
 
@@ -0,0 +1,673 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::check_rgba_destination;
+use crate::yuv_support::{get_inverse_transform, get_yuv_range, YuvSourceChannels};
+use crate::{
+    YuvBiPlanarImage, YuvBitDepth, YuvChromaSubsampling, YuvError, YuvPlanarImage, YuvRange,
+    YuvStandardMatrix,
+};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::ParallelSliceMut;
+use std::ops::Range;
+
+/// Downscaling applied while decoding thumbnails
+#[derive(Debug, Copy, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Default)]
+pub enum ThumbnailMode {
+    /// Box filtered to 1/2 of source size
+    #[default]
+    Half,
+    /// Box filtered to 1/4 of source size
+    Quarter,
+    /// Box filtered to 1/8 of source size
+    Eighth,
+    /// Bilinear interpolation to any destination size
+    Bilinear,
+}
+
+impl ThumbnailMode {
+    const fn divisor(self) -> Option<usize> {
+        match self {
+            ThumbnailMode::Half => Some(2),
+            ThumbnailMode::Quarter => Some(4),
+            ThumbnailMode::Eighth => Some(8),
+            ThumbnailMode::Bilinear => None,
+        }
+    }
+
+    /// Destination size required for source of `width` x `height`,
+    /// `None` if mode accepts any destination size.
+    pub const fn target_size(self, width: u32, height: u32) -> Option<(u32, u32)> {
+        match self.divisor() {
+            Some(divisor) => Some((
+                width.div_ceil(divisor as u32),
+                height.div_ceil(divisor as u32),
+            )),
+            None => None,
+        }
+    }
+}
+
+/// Source 4:2:0 planes, interleaved chroma is addressed with `chroma_step` 2
+struct ThumbnailPlanes<'a, T> {
+    y_plane: &'a [T],
+    y_stride: usize,
+    u_plane: &'a [T],
+    u_stride: usize,
+    v_plane: &'a [T],
+    v_stride: usize,
+    chroma_step: usize,
+    width: usize,
+    height: usize,
+}
+
+/// Averages `divisor` x `divisor` blocks of `rows` into `dst`
+fn box_row<T: Copy>(
+    plane: &[T],
+    stride: usize,
+    step: usize,
+    width: usize,
+    rows: Range<usize>,
+    divisor: usize,
+    load: &impl Fn(T) -> i32,
+    dst: &mut [i32],
+) {
+    dst.fill(0);
+    let row_count = rows.len();
+    for row in rows {
+        let src = &plane[row * stride..];
+        for (ox, acc) in dst.iter_mut().enumerate() {
+            let x_end = (ox * divisor + divisor).min(width);
+            for x in ox * divisor..x_end {
+                *acc += load(src[x * step]);
+            }
+        }
+    }
+    for (ox, acc) in dst.iter_mut().enumerate() {
+        let columns = (ox * divisor + divisor).min(width) - ox * divisor;
+        let count = (row_count * columns) as i32;
+        *acc = (*acc + count / 2) / count;
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct LinearTap {
+    first: usize,
+    second: usize,
+    /// Weight of second sample in 8 bits fixed point
+    weight: i32,
+}
+
+/// Centered bilinear taps for plane subsampled by `factor` against luma extents
+fn linear_taps(
+    src_len: usize,
+    dst_len: usize,
+    src_luma: usize,
+    dst_luma: usize,
+    factor: usize,
+) -> Vec<LinearTap> {
+    let ratio = src_luma as f64 / dst_luma as f64;
+    let last = (src_len - 1) as f64;
+    (0..dst_len)
+        .map(|i| {
+            let center = (((i as f64 + 0.5) * ratio) / factor as f64 - 0.5).clamp(0., last);
+            let first = center.floor() as usize;
+            LinearTap {
+                first,
+                second: (first + 1).min(src_len - 1),
+                weight: ((center - first as f64) * 256.).round() as i32,
+            }
+        })
+        .collect()
+}
+
+fn bilinear_row<T: Copy>(
+    plane: &[T],
+    stride: usize,
+    step: usize,
+    row: LinearTap,
+    columns: &[LinearTap],
+    load: &impl Fn(T) -> i32,
+    dst: &mut [i32],
+) {
+    let r0 = &plane[row.first * stride..];
+    let r1 = &plane[row.second * stride..];
+    for (dst, column) in dst.iter_mut().zip(columns.iter()) {
+        let top = load(r0[column.first * step]) * (256 - column.weight)
+            + load(r0[column.second * step]) * column.weight;
+        let bottom = load(r1[column.first * step]) * (256 - column.weight)
+            + load(r1[column.second * step]) * column.weight;
+        *dst = (top * (256 - row.weight) + bottom * row.weight + (1 << 15)) >> 16;
+    }
+}
+
+/// Downscales 4:2:0 frame into full resolution Y, U, V rows of destination,
+/// and decodes them straight to 8-bit RGB.
+fn yuv420_thumbnail_impl<T: Copy + Send + Sync, const DESTINATION_CHANNELS: u8>(
+    planes: ThumbnailPlanes<T>,
+    load: impl Fn(T) -> i32 + Send + Sync,
+    bit_depth: u32,
+    rgba: &mut [u8],
+    rgba_stride: u32,
+    rgba_width: u32,
+    rgba_height: u32,
+    range: YuvRange,
+    matrix: YuvStandardMatrix,
+    mode: ThumbnailMode,
+) -> Result<(), YuvError> {
+    let dst_chans: YuvSourceChannels = DESTINATION_CHANNELS.into();
+    let channels = dst_chans.get_channels_count();
+    check_rgba_destination(rgba, rgba_stride, rgba_width, rgba_height, channels)?;
+    if let Some(size) = mode.target_size(planes.width as u32, planes.height as u32) {
+        if size != (rgba_width, rgba_height) {
+            return Err(YuvError::ImageDimensionsNotMatch);
+        }
+    }
+
+    const PRECISION: i32 = 13;
+    let chroma_range = get_yuv_range(bit_depth, range);
+    let kr_kb = matrix.get_kr_kb();
+    let transform = get_inverse_transform(
+        255,
+        chroma_range.range_y,
+        chroma_range.range_uv,
+        kr_kb.kr,
+        kr_kb.kb,
+    )
+    .to_integers(PRECISION as u32);
+
+    let bias_y = chroma_range.bias_y as i32;
+    let bias_uv = chroma_range.bias_uv as i32;
+    let rounding = 1i32 << (PRECISION - 1);
+    let round = |v: i32| ((v + rounding) >> PRECISION).clamp(0, 255) as u8;
+
+    let subsampling = YuvChromaSubsampling::Yuv420;
+    let width = planes.width;
+    let height = planes.height;
+    let chroma_width = subsampling.chroma_width(width);
+    let chroma_height = subsampling.chroma_height(height);
+    let dst_width = rgba_width as usize;
+    let dst_height = rgba_height as usize;
+
+    let (luma_columns, chroma_columns, luma_rows, chroma_rows) = match mode.divisor() {
+        Some(_) => (vec![], vec![], vec![], vec![]),
+        None => (
+            linear_taps(width, dst_width, width, dst_width, 1),
+            linear_taps(chroma_width, dst_width, width, dst_width, 2),
+            linear_taps(height, dst_height, height, dst_height, 1),
+            linear_taps(chroma_height, dst_height, height, dst_height, 2),
+        ),
+    };
+
+    // `scratch` holds Y, U and V rows of destination width, allocated once per thread
+    let process_row = |oy: usize, rgba: &mut [u8], scratch: &mut [i32]| {
+        let (y_row, scratch) = scratch.split_at_mut(dst_width);
+        let (u_row, v_row) = scratch.split_at_mut(dst_width);
+        match mode.divisor() {
+            Some(divisor) => {
+                let luma_rows = oy * divisor..(oy * divisor + divisor).min(height);
+                let chroma_divisor = divisor / 2;
+                let chroma_rows =
+                    oy * chroma_divisor..(oy * chroma_divisor + chroma_divisor).min(chroma_height);
+                box_row(
+                    planes.y_plane,
+                    planes.y_stride,
+                    1,
+                    width,
+                    luma_rows,
+                    divisor,
+                    &load,
+                    y_row,
+                );
+                box_row(
+                    planes.u_plane,
+                    planes.u_stride,
+                    planes.chroma_step,
+                    chroma_width,
+                    chroma_rows.clone(),
+                    chroma_divisor,
+                    &load,
+                    u_row,
+                );
+                box_row(
+                    planes.v_plane,
+                    planes.v_stride,
+                    planes.chroma_step,
+                    chroma_width,
+                    chroma_rows,
+                    chroma_divisor,
+                    &load,
+                    v_row,
+                );
+            }
+            None => {
+                bilinear_row(
+                    planes.y_plane,
+                    planes.y_stride,
+                    1,
+                    luma_rows[oy],
+                    &luma_columns,
+                    &load,
+                    y_row,
+                );
+                bilinear_row(
+                    planes.u_plane,
+                    planes.u_stride,
+                    planes.chroma_step,
+                    chroma_rows[oy],
+                    &chroma_columns,
+                    &load,
+                    u_row,
+                );
+                bilinear_row(
+                    planes.v_plane,
+                    planes.v_stride,
+                    planes.chroma_step,
+                    chroma_rows[oy],
+                    &chroma_columns,
+                    &load,
+                    v_row,
+                );
+            }
+        }
+
+        for (((dst, &y_value), &u_value), &v_value) in rgba
+            .chunks_exact_mut(channels)
+            .zip(y_row.iter())
+            .zip(u_row.iter())
+            .zip(v_row.iter())
+        {
+            let cb_value = u_value - bias_uv;
+            let cr_value = v_value - bias_uv;
+            let y_value = (y_value - bias_y) * transform.y_coef;
+
+            dst[dst_chans.get_r_channel_offset()] = round(y_value + transform.cr_coef * cr_value);
+            dst[dst_chans.get_b_channel_offset()] = round(y_value + transform.cb_coef * cb_value);
+            dst[dst_chans.get_g_channel_offset()] =
+                round(y_value - transform.g_coeff_1 * cr_value - transform.g_coeff_2 * cb_value);
+            if dst_chans.has_alpha() {
+                dst[dst_chans.get_a_channel_offset()] = 255;
+            }
+        }
+    };
+
+    #[cfg(feature = "rayon")]
+    {
+        rgba.par_chunks_mut(rgba_stride as usize)
+            .take(dst_height)
+            .enumerate()
+            .for_each_init(
+                || vec![0i32; dst_width * 3],
+                |scratch, (oy, rgba)| {
+                    process_row(oy, &mut rgba[..dst_width * channels], scratch);
+                },
+            );
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        let mut scratch = vec![0i32; dst_width * 3];
+        rgba.chunks_mut(rgba_stride as usize)
+            .take(dst_height)
+            .enumerate()
+            .for_each(|(oy, rgba)| {
+                process_row(oy, &mut rgba[..dst_width * channels], &mut scratch);
+            });
+    }
+
+    Ok(())
+}
+
+macro_rules! d_thumbnail_nv {
+    ($method: ident, $px_fmt: expr, $yuv_name: expr, $px_name: expr, $u_offset: expr) => {
+        #[doc = concat!("Decodes ", $yuv_name, " frame to downscaled ", $px_name, " thumbnail.
+
+Source rows are downscaled and converted in a single pass without full resolution intermediate.
+
+# Arguments
+
+* `bi_planar_image` - Source ", $yuv_name, " image.
+* `rgba` - A mutable slice to store the thumbnail ", $px_name, " data.
+* `rgba_stride` - The stride (components per row) for the ", $px_name, " image data.
+* `rgba_width` - Thumbnail width, must match [ThumbnailMode::target_size] for box modes.
+* `rgba_height` - Thumbnail height, must match [ThumbnailMode::target_size] for box modes.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `mode` - Downscaling mode, see [ThumbnailMode] for more info.
+")]
+        pub fn $method(
+            bi_planar_image: &YuvBiPlanarImage<u8>,
+            rgba: &mut [u8],
+            rgba_stride: u32,
+            rgba_width: u32,
+            rgba_height: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            mode: ThumbnailMode,
+        ) -> Result<(), YuvError> {
+            bi_planar_image.check_constraints(YuvChromaSubsampling::Yuv420)?;
+            let uv_plane = bi_planar_image.uv_plane;
+            let planes = ThumbnailPlanes {
+                y_plane: bi_planar_image.y_plane,
+                y_stride: bi_planar_image.y_stride as usize,
+                u_plane: &uv_plane[$u_offset..],
+                u_stride: bi_planar_image.uv_stride as usize,
+                v_plane: &uv_plane[1 - $u_offset..],
+                v_stride: bi_planar_image.uv_stride as usize,
+                chroma_step: 2,
+                width: bi_planar_image.width as usize,
+                height: bi_planar_image.height as usize,
+            };
+            yuv420_thumbnail_impl::<u8, { $px_fmt as u8 }>(
+                planes,
+                |v| v as i32,
+                8,
+                rgba,
+                rgba_stride,
+                rgba_width,
+                rgba_height,
+                range,
+                matrix,
+                mode,
+            )
+        }
+    };
+}
+
+d_thumbnail_nv!(
+    yuv_nv12_to_rgba_thumbnail,
+    YuvSourceChannels::Rgba,
+    "NV12",
+    "RGBA",
+    0
+);
+d_thumbnail_nv!(
+    yuv_nv12_to_rgb_thumbnail,
+    YuvSourceChannels::Rgb,
+    "NV12",
+    "RGB",
+    0
+);
+d_thumbnail_nv!(
+    yuv_nv12_to_bgra_thumbnail,
+    YuvSourceChannels::Bgra,
+    "NV12",
+    "BGRA",
+    0
+);
+d_thumbnail_nv!(
+    yuv_nv12_to_bgr_thumbnail,
+    YuvSourceChannels::Bgr,
+    "NV12",
+    "BGR",
+    0
+);
+d_thumbnail_nv!(
+    yuv_nv21_to_rgba_thumbnail,
+    YuvSourceChannels::Rgba,
+    "NV21",
+    "RGBA",
+    1
+);
+d_thumbnail_nv!(
+    yuv_nv21_to_rgb_thumbnail,
+    YuvSourceChannels::Rgb,
+    "NV21",
+    "RGB",
+    1
+);
+d_thumbnail_nv!(
+    yuv_nv21_to_bgra_thumbnail,
+    YuvSourceChannels::Bgra,
+    "NV21",
+    "BGRA",
+    1
+);
+d_thumbnail_nv!(
+    yuv_nv21_to_bgr_thumbnail,
+    YuvSourceChannels::Bgr,
+    "NV21",
+    "BGR",
+    1
+);
+
+macro_rules! d_thumbnail_planar {
+    ($method: ident, $px_fmt: expr, $px_name: expr) => {
+        #[doc = concat!("Decodes YUV 420 planar frame to downscaled ", $px_name, " thumbnail.
+
+Source rows are downscaled and converted in a single pass without full resolution intermediate.
+
+# Arguments
+
+* `planar_image` - Source YUV 420 planar image.
+* `rgba` - A mutable slice to store the thumbnail ", $px_name, " data.
+* `rgba_stride` - The stride (components per row) for the ", $px_name, " image data.
+* `rgba_width` - Thumbnail width, must match [ThumbnailMode::target_size] for box modes.
+* `rgba_height` - Thumbnail height, must match [ThumbnailMode::target_size] for box modes.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `mode` - Downscaling mode, see [ThumbnailMode] for more info.
+")]
+        pub fn $method(
+            planar_image: &YuvPlanarImage<u8>,
+            rgba: &mut [u8],
+            rgba_stride: u32,
+            rgba_width: u32,
+            rgba_height: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            mode: ThumbnailMode,
+        ) -> Result<(), YuvError> {
+            planar_image.check_constraints(YuvChromaSubsampling::Yuv420)?;
+            let planes = ThumbnailPlanes {
+                y_plane: planar_image.y_plane,
+                y_stride: planar_image.y_stride as usize,
+                u_plane: planar_image.u_plane,
+                u_stride: planar_image.u_stride as usize,
+                v_plane: planar_image.v_plane,
+                v_stride: planar_image.v_stride as usize,
+                chroma_step: 1,
+                width: planar_image.width as usize,
+                height: planar_image.height as usize,
+            };
+            yuv420_thumbnail_impl::<u8, { $px_fmt as u8 }>(
+                planes,
+                |v| v as i32,
+                8,
+                rgba,
+                rgba_stride,
+                rgba_width,
+                rgba_height,
+                range,
+                matrix,
+                mode,
+            )
+        }
+    };
+}
+
+d_thumbnail_planar!(yuv420_to_rgba_thumbnail, YuvSourceChannels::Rgba, "RGBA");
+d_thumbnail_planar!(yuv420_to_rgb_thumbnail, YuvSourceChannels::Rgb, "RGB");
+d_thumbnail_planar!(yuv420_to_bgra_thumbnail, YuvSourceChannels::Bgra, "BGRA");
+d_thumbnail_planar!(yuv420_to_bgr_thumbnail, YuvSourceChannels::Bgr, "BGR");
+
+macro_rules! d_thumbnail_p010 {
+    ($method: ident, $px_fmt: expr, $px_name: expr) => {
+        #[doc = concat!("Decodes P010 frame to downscaled 8-bit ", $px_name, " thumbnail.
+
+Source rows are downscaled at 10-bit precision and converted in a single pass without full resolution intermediate.
+
+# Arguments
+
+* `bi_planar_image` - Source P010 image.
+* `rgba` - A mutable slice to store the thumbnail ", $px_name, " data.
+* `rgba_stride` - The stride (components per row) for the ", $px_name, " image data.
+* `rgba_width` - Thumbnail width, must match [ThumbnailMode::target_size] for box modes.
+* `rgba_height` - Thumbnail height, must match [ThumbnailMode::target_size] for box modes.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `mode` - Downscaling mode, see [ThumbnailMode] for more info.
+")]
+        pub fn $method(
+            bi_planar_image: &YuvBiPlanarImage<u16>,
+            rgba: &mut [u8],
+            rgba_stride: u32,
+            rgba_width: u32,
+            rgba_height: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            mode: ThumbnailMode,
+        ) -> Result<(), YuvError> {
+            bi_planar_image.check_constraints(YuvChromaSubsampling::Yuv420)?;
+            let depth = YuvBitDepth::msb(10);
+            let uv_plane = bi_planar_image.uv_plane;
+            let planes = ThumbnailPlanes {
+                y_plane: bi_planar_image.y_plane,
+                y_stride: bi_planar_image.y_stride as usize,
+                u_plane: uv_plane,
+                u_stride: bi_planar_image.uv_stride as usize,
+                v_plane: &uv_plane[1..],
+                v_stride: bi_planar_image.uv_stride as usize,
+                chroma_step: 2,
+                width: bi_planar_image.width as usize,
+                height: bi_planar_image.height as usize,
+            };
+            yuv420_thumbnail_impl::<u16, { $px_fmt as u8 }>(
+                planes,
+                |v| depth.native(v) as i32,
+                depth.bit_depth,
+                rgba,
+                rgba_stride,
+                rgba_width,
+                rgba_height,
+                range,
+                matrix,
+                mode,
+            )
+        }
+    };
+}
+
+d_thumbnail_p010!(p010_to_rgba_thumbnail, YuvSourceChannels::Rgba, "RGBA");
+d_thumbnail_p010!(p010_to_rgb_thumbnail, YuvSourceChannels::Rgb, "RGB");
+d_thumbnail_p010!(p010_to_bgra_thumbnail, YuvSourceChannels::Bgra, "BGRA");
+d_thumbnail_p010!(p010_to_bgr_thumbnail, YuvSourceChannels::Bgr, "BGR");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{yuv420_to_rgba, YuvPlanarImageMut};
+
+    #[test]
+    fn thumbnail_matches_decode_then_average() {
+        let width = 64u32;
+        let height = 48u32;
+        let mut image = YuvPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in image.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (40 + (i % 64) * 2 + i / 64) as u8;
+        }
+        for (i, v) in image.u_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (100 + (i % 32) / 4) as u8;
+        }
+        image.v_plane.borrow_mut().fill(140);
+        let fixed = image.to_fixed();
+
+        let mut full = vec![0u8; width as usize * height as usize * 4];
+        yuv420_to_rgba(
+            &fixed,
+            &mut full,
+            width * 4,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+
+        let (tw, th) = ThumbnailMode::Quarter.target_size(width, height).unwrap();
+        let mut thumb = vec![0u8; tw as usize * th as usize * 4];
+        yuv420_to_rgba_thumbnail(
+            &fixed,
+            &mut thumb,
+            tw * 4,
+            tw,
+            th,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+            ThumbnailMode::Quarter,
+        )
+        .unwrap();
+
+        for ty in 0..th as usize {
+            for tx in 0..tw as usize {
+                for c in 0..3 {
+                    let mut sum = 0u32;
+                    for y in ty * 4..ty * 4 + 4 {
+                        for x in tx * 4..tx * 4 + 4 {
+                            sum += full[(y * width as usize + x) * 4 + c] as u32;
+                        }
+                    }
+                    let expected = (sum + 8) / 16;
+                    let actual = thumb[(ty * tw as usize + tx) * 4 + c] as u32;
+                    assert!(
+                        expected.abs_diff(actual) <= 3,
+                        "at {tx}, {ty}, {c}: {actual} vs {expected}"
+                    );
+                }
+            }
+        }
+
+        let mut same = vec![0u8; full.len()];
+        yuv420_to_rgba_thumbnail(
+            &fixed,
+            &mut same,
+            width * 4,
+            width,
+            height,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+            ThumbnailMode::Bilinear,
+        )
+        .unwrap();
+        assert!(full
+            .iter()
+            .zip(same.iter())
+            .all(|(&a, &b)| a.abs_diff(b) <= 3));
+
+        assert!(yuv420_to_rgba_thumbnail(
+            &fixed,
+            &mut same,
+            width * 4,
+            width,
+            height,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt709,
+            ThumbnailMode::Half,
+        )
+        .is_err());
+    }
+}
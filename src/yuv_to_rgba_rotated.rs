@@ -0,0 +1,578 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_error::check_rgba_destination;
+use crate::{
+    MirrorMode, RotationMode, YuvBiPlanarImage, YuvChromaSubsampling, YuvConversionMode, YuvError,
+    YuvPackedImage, YuvPlanarImage, YuvRange, YuvStandardMatrix,
+};
+
+/// Rows decoded at once before being placed into destination
+const STRIP_ROWS: usize = 16;
+
+/// Destination offset of source pixel `(x, y)` is `origin + x * dx + y * dy`
+#[derive(Debug, Copy, Clone)]
+struct Orientation {
+    origin: isize,
+    dx: isize,
+    dy: isize,
+}
+
+impl Orientation {
+    /// Source is mirrored first, then rotated
+    fn new(
+        width: usize,
+        height: usize,
+        channels: usize,
+        stride: usize,
+        rotation: Option<RotationMode>,
+        mirror: Option<MirrorMode>,
+    ) -> Orientation {
+        let (w, h) = (width as isize - 1, height as isize - 1);
+        let (s, c) = (stride as isize, channels as isize);
+        let mut orientation = match rotation {
+            None => Orientation {
+                origin: 0,
+                dx: c,
+                dy: s,
+            },
+            Some(RotationMode::Rotate90) => Orientation {
+                origin: w * s,
+                dx: -s,
+                dy: c,
+            },
+            Some(RotationMode::Rotate180) => Orientation {
+                origin: h * s + w * c,
+                dx: -c,
+                dy: -s,
+            },
+            Some(RotationMode::Rotate270) => Orientation {
+                origin: h * c,
+                dx: s,
+                dy: -c,
+            },
+            Some(RotationMode::Transpose) => Orientation {
+                origin: 0,
+                dx: s,
+                dy: c,
+            },
+            Some(RotationMode::Transverse) => Orientation {
+                origin: w * s + h * c,
+                dx: -s,
+                dy: -c,
+            },
+        };
+        match mirror {
+            Some(MirrorMode::Horizontal) => {
+                orientation.origin += w * orientation.dx;
+                orientation.dx = -orientation.dx;
+            }
+            Some(MirrorMode::Vertical) => {
+                orientation.origin += h * orientation.dy;
+                orientation.dy = -orientation.dy;
+            }
+            None => {}
+        }
+        orientation
+    }
+}
+
+/// Decodes source by strips of `STRIP_ROWS` with `decode(y, rows, strip, strip_stride)`
+/// and writes every pixel directly into its final place of `dst`.
+fn decode_oriented(
+    width: u32,
+    height: u32,
+    channels: usize,
+    dst: &mut [u8],
+    dst_stride: u32,
+    rotation: Option<RotationMode>,
+    mirror: Option<MirrorMode>,
+    mut decode: impl FnMut(usize, usize, &mut [u8], u32) -> Result<(), YuvError>,
+) -> Result<(), YuvError> {
+    let (dst_width, dst_height) = match rotation {
+        Some(rotation) if rotation.swaps_dimensions() => (height, width),
+        _ => (width, height),
+    };
+    check_rgba_destination(dst, dst_stride, dst_width, dst_height, channels)?;
+
+    let width = width as usize;
+    let height = height as usize;
+    let orientation = Orientation::new(
+        width,
+        height,
+        channels,
+        dst_stride as usize,
+        rotation,
+        mirror,
+    );
+
+    let strip_stride = width * channels;
+    let mut strip = vec![0u8; strip_stride * STRIP_ROWS];
+    for y in (0..height).step_by(STRIP_ROWS) {
+        let rows = STRIP_ROWS.min(height - y);
+        let strip = &mut strip[..strip_stride * rows];
+        decode(y, rows, strip, strip_stride as u32)?;
+        for (row, src) in strip.chunks_exact(strip_stride).enumerate() {
+            let row_origin = orientation.origin + (y + row) as isize * orientation.dy;
+            for (x, px) in src.chunks_exact(channels).enumerate() {
+                let offset = (row_origin + x as isize * orientation.dx) as usize;
+                dst[offset..offset + channels].copy_from_slice(px);
+            }
+        }
+    }
+    Ok(())
+}
+
+macro_rules! d_rotated_nv {
+    ($method: ident, $decoder: ident, $subsampling: expr, $yuv_name: expr, $px_name: expr, $channels: expr) => {
+        #[doc = concat!("Convert ", $yuv_name, " format to ", $px_name, " format placing pixels in rotated or mirrored position.
+
+Source is mirrored first and then rotated, decoding goes by strips directly into destination
+without full frame intermediate. When rotation exchanges dimensions destination is `height` x `width`.
+
+# Arguments
+
+* `bi_planar_image` - Source Bi-Planar image.
+* `rgba` - A mutable slice to store the converted ", $px_name, " data.
+* `rgba_stride` - The stride (components per row) for the ", $px_name, " image data.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `mode` - See [YuvConversionMode] for more info.
+* `rotation` - Refer to [RotationMode] for mode info, `None` keeps orientation.
+* `mirror` - Refer to [MirrorMode] for mode info, `None` disables mirroring.
+")]
+        pub fn $method(
+            bi_planar_image: &YuvBiPlanarImage<u8>,
+            rgba: &mut [u8],
+            rgba_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            mode: YuvConversionMode,
+            rotation: Option<RotationMode>,
+            mirror: Option<MirrorMode>,
+        ) -> Result<(), YuvError> {
+            let subsampling: YuvChromaSubsampling = $subsampling;
+            bi_planar_image.check_constraints(subsampling)?;
+            let v_factor = subsampling.vertical_factor();
+            let y_stride = bi_planar_image.y_stride as usize;
+            let uv_stride = bi_planar_image.uv_stride as usize;
+            decode_oriented(
+                bi_planar_image.width,
+                bi_planar_image.height,
+                $channels,
+                rgba,
+                rgba_stride,
+                rotation,
+                mirror,
+                |y, rows, strip, strip_stride| {
+                    let chroma_y = y / v_factor;
+                    let strip_image = YuvBiPlanarImage {
+                        y_plane: &bi_planar_image.y_plane[y * y_stride..],
+                        y_stride: bi_planar_image.y_stride,
+                        uv_plane: &bi_planar_image.uv_plane[chroma_y * uv_stride..],
+                        uv_stride: bi_planar_image.uv_stride,
+                        width: bi_planar_image.width,
+                        height: rows as u32,
+                    };
+                    crate::$decoder(&strip_image, strip, strip_stride, range, matrix, mode)
+                },
+            )
+        }
+    };
+}
+
+macro_rules! d_rotated_nv_family {
+    ($subsampling: expr, $yuv_name: expr, $rgba: ident, $rgba_dec: ident, $bgra: ident, $bgra_dec: ident, $rgb: ident, $rgb_dec: ident, $bgr: ident, $bgr_dec: ident) => {
+        d_rotated_nv!($rgba, $rgba_dec, $subsampling, $yuv_name, "RGBA", 4);
+        d_rotated_nv!($bgra, $bgra_dec, $subsampling, $yuv_name, "BGRA", 4);
+        d_rotated_nv!($rgb, $rgb_dec, $subsampling, $yuv_name, "RGB", 3);
+        d_rotated_nv!($bgr, $bgr_dec, $subsampling, $yuv_name, "BGR", 3);
+    };
+}
+
+d_rotated_nv_family!(
+    YuvChromaSubsampling::Yuv420,
+    "YUV NV12",
+    yuv_nv12_to_rgba_rotated,
+    yuv_nv12_to_rgba,
+    yuv_nv12_to_bgra_rotated,
+    yuv_nv12_to_bgra,
+    yuv_nv12_to_rgb_rotated,
+    yuv_nv12_to_rgb,
+    yuv_nv12_to_bgr_rotated,
+    yuv_nv12_to_bgr
+);
+d_rotated_nv_family!(
+    YuvChromaSubsampling::Yuv420,
+    "YUV NV21",
+    yuv_nv21_to_rgba_rotated,
+    yuv_nv21_to_rgba,
+    yuv_nv21_to_bgra_rotated,
+    yuv_nv21_to_bgra,
+    yuv_nv21_to_rgb_rotated,
+    yuv_nv21_to_rgb,
+    yuv_nv21_to_bgr_rotated,
+    yuv_nv21_to_bgr
+);
+d_rotated_nv_family!(
+    YuvChromaSubsampling::Yuv422,
+    "YUV NV16",
+    yuv_nv16_to_rgba_rotated,
+    yuv_nv16_to_rgba,
+    yuv_nv16_to_bgra_rotated,
+    yuv_nv16_to_bgra,
+    yuv_nv16_to_rgb_rotated,
+    yuv_nv16_to_rgb,
+    yuv_nv16_to_bgr_rotated,
+    yuv_nv16_to_bgr
+);
+d_rotated_nv_family!(
+    YuvChromaSubsampling::Yuv422,
+    "YUV NV61",
+    yuv_nv61_to_rgba_rotated,
+    yuv_nv61_to_rgba,
+    yuv_nv61_to_bgra_rotated,
+    yuv_nv61_to_bgra,
+    yuv_nv61_to_rgb_rotated,
+    yuv_nv61_to_rgb,
+    yuv_nv61_to_bgr_rotated,
+    yuv_nv61_to_bgr
+);
+d_rotated_nv_family!(
+    YuvChromaSubsampling::Yuv444,
+    "YUV NV24",
+    yuv_nv24_to_rgba_rotated,
+    yuv_nv24_to_rgba,
+    yuv_nv24_to_bgra_rotated,
+    yuv_nv24_to_bgra,
+    yuv_nv24_to_rgb_rotated,
+    yuv_nv24_to_rgb,
+    yuv_nv24_to_bgr_rotated,
+    yuv_nv24_to_bgr
+);
+d_rotated_nv_family!(
+    YuvChromaSubsampling::Yuv444,
+    "YUV NV42",
+    yuv_nv42_to_rgba_rotated,
+    yuv_nv42_to_rgba,
+    yuv_nv42_to_bgra_rotated,
+    yuv_nv42_to_bgra,
+    yuv_nv42_to_rgb_rotated,
+    yuv_nv42_to_rgb,
+    yuv_nv42_to_bgr_rotated,
+    yuv_nv42_to_bgr
+);
+
+macro_rules! d_rotated_planar {
+    ($method: ident, $decoder: ident, $px_name: expr, $channels: expr) => {
+        #[doc = concat!("Convert YUV 420 planar format to ", $px_name, " format placing pixels in rotated or mirrored position.
+
+Source is mirrored first and then rotated, decoding goes by strips directly into destination
+without full frame intermediate. When rotation exchanges dimensions destination is `height` x `width`.
+
+# Arguments
+
+* `planar_image` - Source planar image.
+* `rgba` - A mutable slice to store the converted ", $px_name, " data.
+* `rgba_stride` - The stride (components per row) for the ", $px_name, " image data.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `rotation` - Refer to [RotationMode] for mode info, `None` keeps orientation.
+* `mirror` - Refer to [MirrorMode] for mode info, `None` disables mirroring.
+")]
+        pub fn $method(
+            planar_image: &YuvPlanarImage<u8>,
+            rgba: &mut [u8],
+            rgba_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            rotation: Option<RotationMode>,
+            mirror: Option<MirrorMode>,
+        ) -> Result<(), YuvError> {
+            planar_image.check_constraints(YuvChromaSubsampling::Yuv420)?;
+            decode_oriented(
+                planar_image.width,
+                planar_image.height,
+                $channels,
+                rgba,
+                rgba_stride,
+                rotation,
+                mirror,
+                |y, rows, strip, strip_stride| {
+                    let chroma_y = y / 2;
+                    let strip_image = YuvPlanarImage {
+                        y_plane: &planar_image.y_plane[y * planar_image.y_stride as usize..],
+                        y_stride: planar_image.y_stride,
+                        u_plane: &planar_image.u_plane[chroma_y * planar_image.u_stride as usize..],
+                        u_stride: planar_image.u_stride,
+                        v_plane: &planar_image.v_plane[chroma_y * planar_image.v_stride as usize..],
+                        v_stride: planar_image.v_stride,
+                        width: planar_image.width,
+                        height: rows as u32,
+                    };
+                    crate::$decoder(&strip_image, strip, strip_stride, range, matrix)
+                },
+            )
+        }
+    };
+}
+
+d_rotated_planar!(yuv420_to_rgba_rotated, yuv420_to_rgba, "RGBA", 4);
+d_rotated_planar!(yuv420_to_bgra_rotated, yuv420_to_bgra, "BGRA", 4);
+d_rotated_planar!(yuv420_to_rgb_rotated, yuv420_to_rgb, "RGB", 3);
+d_rotated_planar!(yuv420_to_bgr_rotated, yuv420_to_bgr, "BGR", 3);
+
+macro_rules! d_rotated_yuy2 {
+    ($method: ident, $decoder: ident, $yuv_name: expr, $px_name: expr, $channels: expr) => {
+        #[doc = concat!("Convert packed ", $yuv_name, " format to ", $px_name, " format placing pixels in rotated or mirrored position.
+
+Source is mirrored first and then rotated, decoding goes by strips directly into destination
+without full frame intermediate. When rotation exchanges dimensions destination is `height` x `width`.
+
+# Arguments
+
+* `packed_image` - Source packed image.
+* `rgba` - A mutable slice to store the converted ", $px_name, " data.
+* `rgba_stride` - The stride (components per row) for the ", $px_name, " image data.
+* `range` - The YUV range (limited or full).
+* `matrix` - The YUV standard matrix (BT.601 or BT.709 or BT.2020 or other).
+* `rotation` - Refer to [RotationMode] for mode info, `None` keeps orientation.
+* `mirror` - Refer to [MirrorMode] for mode info, `None` disables mirroring.
+")]
+        pub fn $method(
+            packed_image: &YuvPackedImage<u8>,
+            rgba: &mut [u8],
+            rgba_stride: u32,
+            range: YuvRange,
+            matrix: YuvStandardMatrix,
+            rotation: Option<RotationMode>,
+            mirror: Option<MirrorMode>,
+        ) -> Result<(), YuvError> {
+            packed_image.check_constraints()?;
+            let stride = packed_image.yuy_stride as usize;
+            decode_oriented(
+                packed_image.width,
+                packed_image.height,
+                $channels,
+                rgba,
+                rgba_stride,
+                rotation,
+                mirror,
+                |y, rows, strip, strip_stride| {
+                    let strip_image = YuvPackedImage {
+                        yuy: &packed_image.yuy[y * stride..],
+                        yuy_stride: packed_image.yuy_stride,
+                        width: packed_image.width,
+                        height: rows as u32,
+                    };
+                    crate::$decoder(&strip_image, strip, strip_stride, range, matrix)
+                },
+            )
+        }
+    };
+}
+
+macro_rules! d_rotated_yuy2_family {
+    ($yuv_name: expr, $rgba: ident, $rgba_dec: ident, $bgra: ident, $bgra_dec: ident, $rgb: ident, $rgb_dec: ident, $bgr: ident, $bgr_dec: ident) => {
+        d_rotated_yuy2!($rgba, $rgba_dec, $yuv_name, "RGBA", 4);
+        d_rotated_yuy2!($bgra, $bgra_dec, $yuv_name, "BGRA", 4);
+        d_rotated_yuy2!($rgb, $rgb_dec, $yuv_name, "RGB", 3);
+        d_rotated_yuy2!($bgr, $bgr_dec, $yuv_name, "BGR", 3);
+    };
+}
+
+d_rotated_yuy2_family!(
+    "YUYV",
+    yuyv422_to_rgba_rotated,
+    yuyv422_to_rgba,
+    yuyv422_to_bgra_rotated,
+    yuyv422_to_bgra,
+    yuyv422_to_rgb_rotated,
+    yuyv422_to_rgb,
+    yuyv422_to_bgr_rotated,
+    yuyv422_to_bgr
+);
+d_rotated_yuy2_family!(
+    "UYVY",
+    uyvy422_to_rgba_rotated,
+    uyvy422_to_rgba,
+    uyvy422_to_bgra_rotated,
+    uyvy422_to_bgra,
+    uyvy422_to_rgb_rotated,
+    uyvy422_to_rgb,
+    uyvy422_to_bgr_rotated,
+    uyvy422_to_bgr
+);
+d_rotated_yuy2_family!(
+    "YVYU",
+    yvyu422_to_rgba_rotated,
+    yvyu422_to_rgba,
+    yvyu422_to_bgra_rotated,
+    yvyu422_to_bgra,
+    yvyu422_to_rgb_rotated,
+    yvyu422_to_rgb,
+    yvyu422_to_bgr_rotated,
+    yvyu422_to_bgr
+);
+d_rotated_yuy2_family!(
+    "VYUY",
+    vyuy422_to_rgba_rotated,
+    vyuy422_to_rgba,
+    vyuy422_to_bgra_rotated,
+    vyuy422_to_bgra,
+    vyuy422_to_rgb_rotated,
+    vyuy422_to_rgb,
+    vyuy422_to_bgr_rotated,
+    vyuy422_to_bgr
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{yuv_nv21_to_rgba, YuvBiPlanarImageMut};
+
+    #[test]
+    fn fused_rotation_places_decoded_pixels() {
+        let (width, height) = (37u32, 21u32);
+        let mut image =
+            YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+        for (i, v) in image.y_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (i * 7 % 220 + 16) as u8;
+        }
+        for (i, v) in image.uv_plane.borrow_mut().iter_mut().enumerate() {
+            *v = (i * 13 % 200 + 28) as u8;
+        }
+        let image = image.to_fixed();
+        let (w, h) = (width as usize, height as usize);
+
+        let mut decoded = vec![0u8; w * h * 4];
+        yuv_nv21_to_rgba(
+            &image,
+            &mut decoded,
+            width * 4,
+            YuvRange::Limited,
+            YuvStandardMatrix::Bt601,
+            YuvConversionMode::Balanced,
+        )
+        .unwrap();
+
+        // Destination (column, row) of source pixel after horizontal mirror and rotation
+        let place = |rotation: RotationMode, x: usize, y: usize| {
+            let x = w - 1 - x;
+            match rotation {
+                RotationMode::Rotate90 => (y, w - 1 - x),
+                RotationMode::Rotate180 => (w - 1 - x, h - 1 - y),
+                RotationMode::Rotate270 => (h - 1 - y, x),
+                RotationMode::Transpose => (y, x),
+                RotationMode::Transverse => (h - 1 - y, w - 1 - x),
+            }
+        };
+
+        for rotation in [
+            RotationMode::Rotate90,
+            RotationMode::Rotate180,
+            RotationMode::Rotate270,
+            RotationMode::Transpose,
+            RotationMode::Transverse,
+        ] {
+            let dst_stride = if rotation.swaps_dimensions() { h } else { w } * 4;
+            let mut expected = vec![0u8; w * h * 4];
+            for y in 0..h {
+                for x in 0..w {
+                    let (dx, dy) = place(rotation, x, y);
+                    let src = &decoded[(y * w + x) * 4..][..4];
+                    expected[dy * dst_stride + dx * 4..][..4].copy_from_slice(src);
+                }
+            }
+            let mut fused = vec![0u8; w * h * 4];
+            yuv_nv21_to_rgba_rotated(
+                &image,
+                &mut fused,
+                dst_stride as u32,
+                YuvRange::Limited,
+                YuvStandardMatrix::Bt601,
+                YuvConversionMode::Balanced,
+                Some(rotation),
+                Some(MirrorMode::Horizontal),
+            )
+            .unwrap();
+            assert!(fused == expected, "{rotation:?}");
+        }
+    }
+
+    #[test]
+    fn strided_sources_do_not_overrun() {
+        // YUYV 4x2 with stride 12 and NV16 5x2 with chroma stride 6
+        let yuy = [128u8; 12 * 2];
+        let mut rgb = vec![0u8; 4 * 2 * 3];
+        for (len, is_ok) in [(20, false), (24, true)] {
+            let packed = YuvPackedImage {
+                yuy: &yuy[..len],
+                yuy_stride: 12,
+                width: 4,
+                height: 2,
+            };
+            let result = yuyv422_to_rgb_rotated(
+                &packed,
+                &mut rgb,
+                2 * 3,
+                YuvRange::Full,
+                YuvStandardMatrix::Bt709,
+                Some(RotationMode::Rotate90),
+                None,
+            );
+            assert_eq!(result.is_ok(), is_ok, "YUYV of {len}");
+        }
+
+        let y_plane = [128u8; 5 * 2];
+        let uv_plane = [128u8; 6 * 2];
+        let mut rgb = vec![0u8; 5 * 2 * 3];
+        for (len, is_ok) in [(10, false), (12, true)] {
+            let image = YuvBiPlanarImage {
+                y_plane: &y_plane,
+                y_stride: 5,
+                uv_plane: &uv_plane[..len],
+                uv_stride: 6,
+                width: 5,
+                height: 2,
+            };
+            let result = yuv_nv16_to_rgb_rotated(
+                &image,
+                &mut rgb,
+                2 * 3,
+                YuvRange::Full,
+                YuvStandardMatrix::Bt709,
+                YuvConversionMode::Balanced,
+                Some(RotationMode::Rotate90),
+                None,
+            );
+            assert_eq!(result.is_ok(), is_ok, "NV16 of {len}");
+        }
+        assert!(rgb.iter().all(|&v| v.abs_diff(128) <= 1));
+    }
+}
@@ -0,0 +1,1016 @@
+/*
+ * Copyright (c) Radzivon Bartoshyk, 10/2026. All rights reserved.
+ *
+ * Redistribution and use in source and binary forms, with or without modification,
+ * are permitted provided that the following conditions are met:
+ *
+ * 1.  Redistributions of source code must retain the above copyright notice, this
+ * list of conditions and the following disclaimer.
+ *
+ * 2.  Redistributions in binary form must reproduce the above copyright notice,
+ * this list of conditions and the following disclaimer in the documentation
+ * and/or other materials provided with the distribution.
+ *
+ * 3.  Neither the name of the copyright holder nor the names of its
+ * contributors may be used to endorse or promote products derived from
+ * this software without specific prior written permission.
+ *
+ * THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS"
+ * AND ANY EXPRESS OR IMPLIED WARRANTIES, INCLUDING, BUT NOT LIMITED TO, THE
+ * IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+ * DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE
+ * FOR ANY DIRECT, INDIRECT, INCIDENTAL, SPECIAL, EXEMPLARY, OR CONSEQUENTIAL
+ * DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+ * SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER
+ * CAUSED AND ON ANY THEORY OF LIABILITY, WHETHER IN CONTRACT, STRICT LIABILITY,
+ * OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE USE
+ * OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+ */
+use crate::yuv_support::{YuvChromaSubsampling, YuvNVOrder, Yuy2Description};
+use crate::yuv_to_yuy2::AveragesIntensity;
+use crate::{YuvBiPlanarImage, YuvBiPlanarImageMut, YuvError, YuvPackedImage, YuvPackedImageMut};
+#[cfg(feature = "rayon")]
+use rayon::iter::{IndexedParallelIterator, ParallelIterator};
+#[cfg(feature = "rayon")]
+use rayon::prelude::{ParallelSlice, ParallelSliceMut};
+use std::fmt::Debug;
+
+fn yuy2_to_nv_impl<
+    V: Copy + Debug + Send + Sync + Default + AveragesIntensity<V>,
+    const SAMPLING: u8,
+    const YUY2_TARGET: usize,
+    const UV_ORDER: u8,
+>(
+    bi_planar_image: &mut YuvBiPlanarImageMut<V>,
+    packed_image: &YuvPackedImage<V>,
+) -> Result<(), YuvError> {
+    let yuy2_target: Yuy2Description = YUY2_TARGET.into();
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    let order: YuvNVOrder = UV_ORDER.into();
+
+    bi_planar_image.check_constraints(chroma_subsampling)?;
+    packed_image.check_constraints()?;
+    if bi_planar_image.width != packed_image.width || bi_planar_image.height != packed_image.height
+    {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+
+    let width = packed_image.width as usize;
+    let yuy2_width = width.div_ceil(2) * 4;
+    let rows = chroma_subsampling.vertical_factor();
+    let y_stride = bi_planar_image.y_stride as usize;
+    let uv_stride = bi_planar_image.uv_stride as usize;
+    let yuy_stride = packed_image.yuy_stride as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = bi_planar_image
+            .y_plane
+            .borrow_mut()
+            .par_chunks_mut(y_stride * rows)
+            .zip(
+                bi_planar_image
+                    .uv_plane
+                    .borrow_mut()
+                    .par_chunks_exact_mut(uv_stride),
+            )
+            .zip(packed_image.yuy.par_chunks(yuy_stride * rows));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = bi_planar_image
+            .y_plane
+            .borrow_mut()
+            .chunks_mut(y_stride * rows)
+            .zip(
+                bi_planar_image
+                    .uv_plane
+                    .borrow_mut()
+                    .chunks_exact_mut(uv_stride),
+            )
+            .zip(packed_image.yuy.chunks(yuy_stride * rows));
+    }
+    iter.for_each(|((y_dst, uv_dst), yuy2_src)| {
+        for (y_dst, yuy2) in y_dst.chunks_mut(y_stride).zip(yuy2_src.chunks(yuy_stride)) {
+            for (y_dst, yuy2) in y_dst[0..width]
+                .chunks_mut(2)
+                .zip(yuy2[0..yuy2_width].chunks_exact(4))
+            {
+                y_dst[0] = yuy2[yuy2_target.get_first_y_position()];
+                if let Some(second) = y_dst.get_mut(1) {
+                    *second = yuy2[yuy2_target.get_second_y_position()];
+                }
+            }
+        }
+
+        // 4:2:0 averages chroma of both rows, the last odd row uses its own chroma
+        let mut packed_rows = yuy2_src.chunks(yuy_stride);
+        let row0 = &packed_rows.next().unwrap()[0..yuy2_width];
+        let row1 = packed_rows
+            .next()
+            .map(|x| &x[0..yuy2_width])
+            .unwrap_or(row0);
+        for ((uv_dst, yuy2_0), yuy2_1) in uv_dst[0..yuy2_width / 2]
+            .chunks_exact_mut(2)
+            .zip(row0.chunks_exact(4))
+            .zip(row1.chunks_exact(4))
+        {
+            uv_dst[order.get_u_position()] =
+                yuy2_0[yuy2_target.get_u_position()].averages(yuy2_1[yuy2_target.get_u_position()]);
+            uv_dst[order.get_v_position()] =
+                yuy2_0[yuy2_target.get_v_position()].averages(yuy2_1[yuy2_target.get_v_position()]);
+        }
+    });
+
+    Ok(())
+}
+
+fn nv_to_yuy2_impl<
+    V: Copy + Debug + Send + Sync,
+    const SAMPLING: u8,
+    const YUY2_TARGET: usize,
+    const UV_ORDER: u8,
+>(
+    packed_image: &mut YuvPackedImageMut<V>,
+    bi_planar_image: &YuvBiPlanarImage<V>,
+) -> Result<(), YuvError> {
+    let yuy2_target: Yuy2Description = YUY2_TARGET.into();
+    let chroma_subsampling: YuvChromaSubsampling = SAMPLING.into();
+    let order: YuvNVOrder = UV_ORDER.into();
+
+    bi_planar_image.check_constraints(chroma_subsampling)?;
+    packed_image.check_constraints()?;
+    if bi_planar_image.width != packed_image.width || bi_planar_image.height != packed_image.height
+    {
+        return Err(YuvError::ImagesSizesNotMatch);
+    }
+
+    let width = bi_planar_image.width as usize;
+    let yuy2_width = width.div_ceil(2) * 4;
+    let rows = chroma_subsampling.vertical_factor();
+    let y_stride = bi_planar_image.y_stride as usize;
+    let uv_stride = bi_planar_image.uv_stride as usize;
+    let yuy_stride = packed_image.yuy_stride as usize;
+
+    let iter;
+    #[cfg(feature = "rayon")]
+    {
+        iter = packed_image
+            .yuy
+            .borrow_mut()
+            .par_chunks_mut(yuy_stride * rows)
+            .zip(bi_planar_image.y_plane.par_chunks(y_stride * rows))
+            .zip(bi_planar_image.uv_plane.par_chunks_exact(uv_stride));
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        iter = packed_image
+            .yuy
+            .borrow_mut()
+            .chunks_mut(yuy_stride * rows)
+            .zip(bi_planar_image.y_plane.chunks(y_stride * rows))
+            .zip(bi_planar_image.uv_plane.chunks_exact(uv_stride));
+    }
+    iter.for_each(|((yuy2_dst, y_src), uv_src)| {
+        for (yuy2, y_src) in yuy2_dst.chunks_mut(yuy_stride).zip(y_src.chunks(y_stride)) {
+            for ((yuy2, y_src), uv_src) in yuy2[0..yuy2_width]
+                .chunks_exact_mut(4)
+                .zip(y_src[0..width].chunks(2))
+                .zip(uv_src.chunks_exact(2))
+            {
+                yuy2[yuy2_target.get_first_y_position()] = y_src[0];
+                // Padding pixel of odd width replicates the edge
+                yuy2[yuy2_target.get_second_y_position()] =
+                    y_src.get(1).copied().unwrap_or(y_src[0]);
+                yuy2[yuy2_target.get_u_position()] = uv_src[order.get_u_position()];
+                yuy2[yuy2_target.get_v_position()] = uv_src[order.get_v_position()];
+            }
+        }
+    });
+
+    Ok(())
+}
+
+macro_rules! d_yuy2_to_nv {
+    ($method: ident, $v: ty, $sampling: expr, $yuy2: expr, $order: expr, $src_name: expr, $dst_name: expr, $depth: expr) => {
+        #[doc = concat!("Converts ", $src_name, " packed image to ", $dst_name, " bi-planar image in a single pass.
+
+This function takes ", $src_name, " (4:2:2) format data with ", $depth, " precision.", "
+When vertical chroma subsampling is required chroma of two rows is averaged.
+
+# Arguments
+
+* `bi_planar_image` - Target bi-planar image.
+* `packed_image` - Source packed image.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the packed data are not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $method(
+            bi_planar_image: &mut YuvBiPlanarImageMut<$v>,
+            packed_image: &YuvPackedImage<$v>,
+        ) -> Result<(), YuvError> {
+            yuy2_to_nv_impl::<$v, { $sampling as u8 }, { $yuy2 as usize }, { $order as u8 }>(
+                bi_planar_image,
+                packed_image,
+            )
+        }
+    };
+}
+
+macro_rules! d_nv_to_yuy2 {
+    ($method: ident, $v: ty, $sampling: expr, $yuy2: expr, $order: expr, $src_name: expr, $dst_name: expr, $depth: expr) => {
+        #[doc = concat!("Converts ", $src_name, " bi-planar image to ", $dst_name, " packed image in a single pass.
+
+This function takes ", $src_name, " format data with ", $depth, " precision.", "
+When source chroma is vertically subsampled each chroma row is shared by two packed rows.
+
+# Arguments
+
+* `packed_image` - Target packed image.
+* `bi_planar_image` - Source bi-planar image.
+
+# Errors
+
+This function returns an error if the lengths of the planes or the packed data are not valid based
+on the specified width, height, and strides, or images dimensions do not match.")]
+        pub fn $method(
+            packed_image: &mut YuvPackedImageMut<$v>,
+            bi_planar_image: &YuvBiPlanarImage<$v>,
+        ) -> Result<(), YuvError> {
+            nv_to_yuy2_impl::<$v, { $sampling as u8 }, { $yuy2 as usize }, { $order as u8 }>(
+                packed_image,
+                bi_planar_image,
+            )
+        }
+    };
+}
+
+d_yuy2_to_nv!(
+    yuyv422_to_nv12,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YUYV,
+    YuvNVOrder::UV,
+    "YUYV",
+    "NV12",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    yuyv422_to_nv21,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YUYV,
+    YuvNVOrder::VU,
+    "YUYV",
+    "NV21",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    yuyv422_to_nv16,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YUYV,
+    YuvNVOrder::UV,
+    "YUYV",
+    "NV16",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    yuyv422_to_nv61,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YUYV,
+    YuvNVOrder::VU,
+    "YUYV",
+    "NV61",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    uyvy422_to_nv12,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::UYVY,
+    YuvNVOrder::UV,
+    "UYVY",
+    "NV12",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    uyvy422_to_nv21,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::UYVY,
+    YuvNVOrder::VU,
+    "UYVY",
+    "NV21",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    uyvy422_to_nv16,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::UYVY,
+    YuvNVOrder::UV,
+    "UYVY",
+    "NV16",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    uyvy422_to_nv61,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::UYVY,
+    YuvNVOrder::VU,
+    "UYVY",
+    "NV61",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    yvyu422_to_nv12,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YVYU,
+    YuvNVOrder::UV,
+    "YVYU",
+    "NV12",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    yvyu422_to_nv21,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YVYU,
+    YuvNVOrder::VU,
+    "YVYU",
+    "NV21",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    yvyu422_to_nv16,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YVYU,
+    YuvNVOrder::UV,
+    "YVYU",
+    "NV16",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    yvyu422_to_nv61,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YVYU,
+    YuvNVOrder::VU,
+    "YVYU",
+    "NV61",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    vyuy422_to_nv12,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::VYUY,
+    YuvNVOrder::UV,
+    "VYUY",
+    "NV12",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    vyuy422_to_nv21,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::VYUY,
+    YuvNVOrder::VU,
+    "VYUY",
+    "NV21",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    vyuy422_to_nv16,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::VYUY,
+    YuvNVOrder::UV,
+    "VYUY",
+    "NV16",
+    "8-bit"
+);
+d_yuy2_to_nv!(
+    vyuy422_to_nv61,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::VYUY,
+    YuvNVOrder::VU,
+    "VYUY",
+    "NV61",
+    "8-bit"
+);
+
+d_nv_to_yuy2!(
+    nv12_to_yuyv422,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YUYV,
+    YuvNVOrder::UV,
+    "NV12",
+    "YUYV",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv21_to_yuyv422,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YUYV,
+    YuvNVOrder::VU,
+    "NV21",
+    "YUYV",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv16_to_yuyv422,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YUYV,
+    YuvNVOrder::UV,
+    "NV16",
+    "YUYV",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv61_to_yuyv422,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YUYV,
+    YuvNVOrder::VU,
+    "NV61",
+    "YUYV",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv12_to_uyvy422,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::UYVY,
+    YuvNVOrder::UV,
+    "NV12",
+    "UYVY",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv21_to_uyvy422,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::UYVY,
+    YuvNVOrder::VU,
+    "NV21",
+    "UYVY",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv16_to_uyvy422,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::UYVY,
+    YuvNVOrder::UV,
+    "NV16",
+    "UYVY",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv61_to_uyvy422,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::UYVY,
+    YuvNVOrder::VU,
+    "NV61",
+    "UYVY",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv12_to_yvyu422,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YVYU,
+    YuvNVOrder::UV,
+    "NV12",
+    "YVYU",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv21_to_yvyu422,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YVYU,
+    YuvNVOrder::VU,
+    "NV21",
+    "YVYU",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv16_to_yvyu422,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YVYU,
+    YuvNVOrder::UV,
+    "NV16",
+    "YVYU",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv61_to_yvyu422,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YVYU,
+    YuvNVOrder::VU,
+    "NV61",
+    "YVYU",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv12_to_vyuy422,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::VYUY,
+    YuvNVOrder::UV,
+    "NV12",
+    "VYUY",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv21_to_vyuy422,
+    u8,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::VYUY,
+    YuvNVOrder::VU,
+    "NV21",
+    "VYUY",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv16_to_vyuy422,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::VYUY,
+    YuvNVOrder::UV,
+    "NV16",
+    "VYUY",
+    "8-bit"
+);
+d_nv_to_yuy2!(
+    nv61_to_vyuy422,
+    u8,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::VYUY,
+    YuvNVOrder::VU,
+    "NV61",
+    "VYUY",
+    "8-bit"
+);
+
+d_yuy2_to_nv!(
+    yuyv422_to_nv12_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YUYV,
+    YuvNVOrder::UV,
+    "YUYV",
+    "NV12",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    yuyv422_to_nv21_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YUYV,
+    YuvNVOrder::VU,
+    "YUYV",
+    "NV21",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    yuyv422_to_nv16_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YUYV,
+    YuvNVOrder::UV,
+    "YUYV",
+    "NV16",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    yuyv422_to_nv61_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YUYV,
+    YuvNVOrder::VU,
+    "YUYV",
+    "NV61",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    uyvy422_to_nv12_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::UYVY,
+    YuvNVOrder::UV,
+    "UYVY",
+    "NV12",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    uyvy422_to_nv21_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::UYVY,
+    YuvNVOrder::VU,
+    "UYVY",
+    "NV21",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    uyvy422_to_nv16_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::UYVY,
+    YuvNVOrder::UV,
+    "UYVY",
+    "NV16",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    uyvy422_to_nv61_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::UYVY,
+    YuvNVOrder::VU,
+    "UYVY",
+    "NV61",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    yvyu422_to_nv12_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YVYU,
+    YuvNVOrder::UV,
+    "YVYU",
+    "NV12",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    yvyu422_to_nv21_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YVYU,
+    YuvNVOrder::VU,
+    "YVYU",
+    "NV21",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    yvyu422_to_nv16_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YVYU,
+    YuvNVOrder::UV,
+    "YVYU",
+    "NV16",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    yvyu422_to_nv61_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YVYU,
+    YuvNVOrder::VU,
+    "YVYU",
+    "NV61",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    vyuy422_to_nv12_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::VYUY,
+    YuvNVOrder::UV,
+    "VYUY",
+    "NV12",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    vyuy422_to_nv21_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::VYUY,
+    YuvNVOrder::VU,
+    "VYUY",
+    "NV21",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    vyuy422_to_nv16_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::VYUY,
+    YuvNVOrder::UV,
+    "VYUY",
+    "NV16",
+    "high bit-depth"
+);
+d_yuy2_to_nv!(
+    vyuy422_to_nv61_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::VYUY,
+    YuvNVOrder::VU,
+    "VYUY",
+    "NV61",
+    "high bit-depth"
+);
+
+d_nv_to_yuy2!(
+    nv12_to_yuyv422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YUYV,
+    YuvNVOrder::UV,
+    "NV12",
+    "YUYV",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv21_to_yuyv422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YUYV,
+    YuvNVOrder::VU,
+    "NV21",
+    "YUYV",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv16_to_yuyv422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YUYV,
+    YuvNVOrder::UV,
+    "NV16",
+    "YUYV",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv61_to_yuyv422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YUYV,
+    YuvNVOrder::VU,
+    "NV61",
+    "YUYV",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv12_to_uyvy422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::UYVY,
+    YuvNVOrder::UV,
+    "NV12",
+    "UYVY",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv21_to_uyvy422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::UYVY,
+    YuvNVOrder::VU,
+    "NV21",
+    "UYVY",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv16_to_uyvy422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::UYVY,
+    YuvNVOrder::UV,
+    "NV16",
+    "UYVY",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv61_to_uyvy422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::UYVY,
+    YuvNVOrder::VU,
+    "NV61",
+    "UYVY",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv12_to_yvyu422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YVYU,
+    YuvNVOrder::UV,
+    "NV12",
+    "YVYU",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv21_to_yvyu422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::YVYU,
+    YuvNVOrder::VU,
+    "NV21",
+    "YVYU",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv16_to_yvyu422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YVYU,
+    YuvNVOrder::UV,
+    "NV16",
+    "YVYU",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv61_to_yvyu422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::YVYU,
+    YuvNVOrder::VU,
+    "NV61",
+    "YVYU",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv12_to_vyuy422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::VYUY,
+    YuvNVOrder::UV,
+    "NV12",
+    "VYUY",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv21_to_vyuy422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv420,
+    Yuy2Description::VYUY,
+    YuvNVOrder::VU,
+    "NV21",
+    "VYUY",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv16_to_vyuy422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::VYUY,
+    YuvNVOrder::UV,
+    "NV16",
+    "VYUY",
+    "high bit-depth"
+);
+d_nv_to_yuy2!(
+    nv61_to_vyuy422_p16,
+    u16,
+    YuvChromaSubsampling::Yuv422,
+    Yuy2Description::VYUY,
+    YuvNVOrder::VU,
+    "NV61",
+    "VYUY",
+    "high bit-depth"
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// YUYV rows where the padding luma of odd width replicates the edge pixel
+    fn make_yuyv(width: u32, height: u32) -> YuvPackedImageMut<'static, u8> {
+        let stride = width.div_ceil(2) as usize * 4;
+        let mut packed = YuvPackedImageMut::<u8> {
+            yuy: crate::BufferStoreMut::Owned(vec![0u8; stride * height as usize]),
+            yuy_stride: stride as u32,
+            width,
+            height,
+        };
+        for (y, row) in packed.yuy.borrow_mut().chunks_exact_mut(stride).enumerate() {
+            for (x, px) in row.chunks_exact_mut(4).enumerate() {
+                px[0] = (y * 16 + x * 2) as u8;
+                px[1] = (x * 10 + y * 2) as u8;
+                px[2] = if x * 2 + 1 < width as usize {
+                    (y * 16 + x * 2 + 1) as u8
+                } else {
+                    px[0]
+                };
+                px[3] = 200 - (x * 10) as u8;
+            }
+        }
+        packed
+    }
+
+    #[test]
+    fn yuyv_nv12_round_trip() {
+        for width in [8u32, 7] {
+            let height = 5u32;
+            let packed = make_yuyv(width, height);
+            let stride = packed.yuy_stride as usize;
+
+            let mut nv12 =
+                YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv420);
+            yuyv422_to_nv12(&mut nv12, &packed.to_fixed()).unwrap();
+            let uv = nv12.uv_plane.borrow();
+            // Rows 0 and 1 are averaged, last row stays alone
+            assert_eq!(&uv[0..4], &[1, 200, 11, 190]);
+            assert_eq!(&uv[2 * nv12.uv_stride as usize..][..2], &[8, 200]);
+
+            let mut back = YuvPackedImageMut::<u8> {
+                yuy: crate::BufferStoreMut::Owned(vec![0u8; stride * height as usize]),
+                yuy_stride: stride as u32,
+                width,
+                height,
+            };
+            nv12_to_yuyv422(&mut back, &nv12.to_fixed()).unwrap();
+            for (y, (src, dst)) in packed
+                .yuy
+                .borrow()
+                .chunks_exact(stride)
+                .zip(back.yuy.borrow().chunks_exact(stride))
+                .enumerate()
+            {
+                let row1 = (y | 1).min(height as usize - 1);
+                let pair = &packed.yuy.borrow()[(y & !1) * stride..][..stride];
+                let next = &packed.yuy.borrow()[row1 * stride..][..stride];
+                for (((src, dst), px0), px1) in src
+                    .chunks_exact(4)
+                    .zip(dst.chunks_exact(4))
+                    .zip(pair.chunks_exact(4))
+                    .zip(next.chunks_exact(4))
+                {
+                    assert_eq!(src[0], dst[0]);
+                    assert_eq!(src[2], dst[2]);
+                    assert_eq!(dst[1], px0[1].averages(px1[1]));
+                    assert_eq!(dst[3], px0[3].averages(px1[3]));
+                }
+            }
+
+            // 4:2:2 keeps every sample
+            let mut nv16 =
+                YuvBiPlanarImageMut::<u8>::alloc(width, height, YuvChromaSubsampling::Yuv422);
+            yuyv422_to_nv16(&mut nv16, &packed.to_fixed()).unwrap();
+            let mut back = YuvPackedImageMut::<u8> {
+                yuy: crate::BufferStoreMut::Owned(vec![0u8; stride * height as usize]),
+                yuy_stride: stride as u32,
+                width,
+                height,
+            };
+            nv16_to_yuyv422(&mut back, &nv16.to_fixed()).unwrap();
+            assert_eq!(back.yuy.borrow(), packed.yuy.borrow());
+
+            let mut nv61 =
+                YuvBiPlanarImageMut::<u16>::alloc(width, height, YuvChromaSubsampling::Yuv422);
+            let packed16 = YuvPackedImageMut::<u16> {
+                yuy: crate::BufferStoreMut::Owned(
+                    packed.yuy.borrow().iter().map(|&x| x as u16 * 4).collect(),
+                ),
+                yuy_stride: stride as u32,
+                width,
+                height,
+            };
+            uyvy422_to_nv61_p16(&mut nv61, &packed16.to_fixed()).unwrap();
+            let mut back16 = YuvPackedImageMut::<u16> {
+                yuy: crate::BufferStoreMut::Owned(vec![0u16; stride * height as usize]),
+                yuy_stride: stride as u32,
+                width,
+                height,
+            };
+            nv61_to_uyvy422_p16(&mut back16, &nv61.to_fixed()).unwrap();
+            if width % 2 == 0 {
+                assert_eq!(back16.yuy.borrow(), packed16.yuy.borrow());
+            } else {
+                // Read as UYVY the padding luma sits at index 3 of the last macropixel
+                // and replicates the edge luma at index 1.
+                for (src, dst) in packed16
+                    .yuy
+                    .borrow()
+                    .chunks_exact(stride)
+                    .zip(back16.yuy.borrow().chunks_exact(stride))
+                {
+                    assert_eq!(&src[..stride - 1], &dst[..stride - 1]);
+                    assert_eq!(dst[stride - 1], dst[stride - 3]);
+                }
+            }
+        }
+    }
+}
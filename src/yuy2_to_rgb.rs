@@ -776,3 +776,40 @@ pub fn vyuy422_to_bgra(
         matrix,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_final_row_is_rejected() {
+        // 4x2 YUYV with stride 12, last row holds only its 8 samples
+        let yuy = vec![128u8; 12 * 2];
+        let mut rgb = vec![0u8; 4 * 2 * 3];
+        let image = |yuy| YuvPackedImage {
+            yuy,
+            yuy_stride: 12,
+            width: 4,
+            height: 2,
+        };
+        assert!(matches!(
+            yuyv422_to_rgb(
+                &image(&yuy[..20]),
+                &mut rgb,
+                4 * 3,
+                YuvRange::Full,
+                YuvStandardMatrix::Bt709,
+            ),
+            Err(YuvError::PackedFrameSizeMismatch(_))
+        ));
+        yuyv422_to_rgb(
+            &image(&yuy),
+            &mut rgb,
+            4 * 3,
+            YuvRange::Full,
+            YuvStandardMatrix::Bt709,
+        )
+        .unwrap();
+        assert!(rgb.iter().all(|&v| v.abs_diff(128) <= 1));
+    }
+}